@@ -1,10 +1,7 @@
-use std::{
-    f32::consts::PI,
-    ops::{Add, Div, Mul, Sub},
-};
+use std::f32::consts::PI;
 
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
-use rand_distr::num_traits::{Float, ToPrimitive};
+use crate::problem_definitions::{HasGradient, Real};
 
 // source: https://www.sfu.ca/~ssurjano/ackley.html
 #[derive(Debug)]
@@ -98,32 +95,59 @@ impl Benchmark for Ackley {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
+        ackley(self.a, self.b, self.c, input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        ackley(self.a, self.b, self.c, input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-32.768, 32.768))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+impl HasGradient for Ackley {
+    fn get_gradient(&self, input: &[f32]) -> Vec<f32> {
         let d = self.get_dim() as f32;
-        ( - self.a as f32)
-            .mul(
-                (-self.b.mul(
-                    input
-                        .iter()
-                        .map(|x| x.powi(2))
-                        .sum::<f32>()
-                        .mul(1f32.div(d))
-                        .sqrt(),
-                ))
-                .exp(),
-            )
-            .sub(
-                input
-                    .iter()
-                    .map(|x| self.c.mul(x).cos())
-                    .sum::<f32>()
-                    .mul(1f32.div(d))
-                    .exp(),
-            )
-            .add(self.a as f32)
-            .add(1f32.exp())
+        let a = self.a as f32;
+        let radius = (input.iter().map(|x| x.powi(2)).sum::<f32>() / d).sqrt();
+        let cosines = (input.iter().map(|x| (self.c * x).cos()).sum::<f32>() / d).exp();
+        input
+            .iter()
+            .map(|x| {
+                let fst = if radius > 0f32 {
+                    a * self.b * (-self.b * radius).exp() * x / (d * radius)
+                } else {
+                    0f32
+                };
+                fst + cosines * self.c * (self.c * x).sin() / d
+            })
+            .collect()
     }
 }
 
+fn ackley<F: Real>(a: i32, b: f32, c: f32, input: &[F]) -> F {
+    let (a, b, c) = (
+        F::from_f64(f64::from(a)),
+        F::from_f64(f64::from(b)),
+        F::from_f64(f64::from(c)),
+    );
+    let d = F::from_f64(input.len() as f64);
+    -a * (-b * (input.iter().map(|&x| x.powi(2)).sum::<F>() / d).sqrt()).exp()
+        - (input.iter().map(|&x| (c * x).cos()).sum::<F>() / d).exp()
+        + a
+        + F::E()
+}
+
 impl Default for Ackley {
     fn default() -> Self {
         Self {
@@ -142,7 +166,7 @@ impl Default for Ackley {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::problem_definitions::{HasLocal, HasRandom};
+    use crate::problem_definitions::{numerical_gradient, HasLocal, HasRandom};
 
     #[test]
     fn random() {
@@ -210,4 +234,33 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn gradient() {
+        let problem = Ackley::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(5usize)
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let input = problem.get_random();
+            let analytic = problem.get_gradient(&input);
+            let numerical = numerical_gradient(&problem, &input, 1e-3);
+            assert!(analytic
+                .iter()
+                .zip(numerical.iter())
+                .all(|(a, n)| (a - n).abs() <= 1e-2 * a.abs().max(1f32)));
+        }
+    }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Ackley::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
@@ -1,10 +1,5 @@
-use std::{
-    f32::consts::PI,
-    ops::{Add, Div, Mul, Sub},
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
-use rand_distr::num_traits::{Float, ToPrimitive};
+use crate::problem_definitions::Real;
 
 // source: https://al-roomi.org/benchmarks/unconstrained/n-dimensions/163-alpine-function-no-2/
 
@@ -67,11 +62,36 @@ impl Benchmark for Alpine2 {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-       input
-            .iter()
-            .map(|x| x.sin().mul(x.sqrt()))
-            .fold(1f32, |acc, cur| acc * cur)
+        alpine2(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        alpine2(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((0f32, 10f32))
+    }
+
+    // one variable on the negative peak, the others on the positive one
+    fn get_known_min(dim: usize) -> Option<f32> {
+        Some(-2.182_769_8 * 2.808_131_2f32.powi(dim as i32 - 1))
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(
+            (0..dim)
+                .map(|i| if i == 0 { 4.815_84 } else { 7.917_05 })
+                .collect(),
+        )
+    }
+}
+
+fn alpine2<F: Real>(input: &[F]) -> F {
+    input
+        .iter()
+        .map(|x| x.sin() * x.sqrt())
+        .fold(F::one(), |acc, cur| acc * cur)
 }
 
 impl Default for Alpine2 {
@@ -157,4 +177,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Alpine2::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
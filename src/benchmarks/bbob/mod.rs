@@ -0,0 +1,2 @@
+pub mod noiseless;
+mod random;
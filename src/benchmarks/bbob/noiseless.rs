@@ -0,0 +1,759 @@
+use std::{cmp::Ordering, f64::consts::PI};
+
+use crate::benchmarks::bbob::random::{
+    compute_fopt, compute_rotation, compute_xopt, gauss, round, unif,
+};
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+
+const FUNCTION_NAMES: [&str; 24] = [
+    "BBOB f1 Sphere function",
+    "BBOB f2 Separable Ellipsoidal function",
+    "BBOB f3 Rastrigin function",
+    "BBOB f4 Bueche-Rastrigin function",
+    "BBOB f5 Linear Slope",
+    "BBOB f6 Attractive Sector function",
+    "BBOB f7 Step Ellipsoidal function",
+    "BBOB f8 Rosenbrock function, original",
+    "BBOB f9 Rosenbrock function, rotated",
+    "BBOB f10 Ellipsoidal function",
+    "BBOB f11 Discus function",
+    "BBOB f12 Bent Cigar function",
+    "BBOB f13 Sharp Ridge function",
+    "BBOB f14 Different Powers function",
+    "BBOB f15 Rastrigin function, rotated",
+    "BBOB f16 Weierstrass function",
+    "BBOB f17 Schaffers F7 function",
+    "BBOB f18 Schaffers F7 function, moderately ill-conditioned",
+    "BBOB f19 Composite Griewank-Rosenbrock function F8F2",
+    "BBOB f20 Schwefel function",
+    "BBOB f21 Gallagher's Gaussian 101-me Peaks function",
+    "BBOB f22 Gallagher's Gaussian 21-hi Peaks function",
+    "BBOB f23 Katsuura function",
+    "BBOB f24 Lunacek bi-Rastrigin function",
+];
+
+// local optima of the Gallagher functions
+#[derive(Debug, Default)]
+struct Peaks {
+    values: Vec<f64>,
+    scales: Vec<Vec<f64>>,
+    // one row per peak
+    locations: Vec<Vec<f64>>,
+}
+
+// the 24 noiseless functions of the BBOB testbed, F is the number of the function (1 - 24),
+// every instance has its own optimum and optimal value (f_opt), the search domain is [-5, 5]
+// source: https://numbbo.github.io/coco/testsuites/bbob
+#[derive(Debug)]
+pub struct Bbob<const F: usize> {
+    min: f32,
+    max: f32,
+    dim: usize,
+    instance: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+    xopt: Vec<f64>,
+    fopt: f64,
+    // rotation applied first (R in the documentation)
+    rotation: Vec<Vec<f64>>,
+    // combined linear transformation applied afterwards
+    linear: Vec<Vec<f64>>,
+    peaks: Peaks,
+    optimum: Vec<f32>,
+}
+
+impl<const F: usize> HasBuilder<Bbob<F>> for Bbob<F> {}
+
+impl<const F: usize> Bbob<F> {
+    pub fn set_instance(mut self, instance: usize) -> Self {
+        self.instance = instance;
+        self.generate_data();
+        self
+    }
+
+    pub fn get_instance(&self) -> usize {
+        self.instance
+    }
+
+    pub fn get_function_id(&self) -> usize {
+        F
+    }
+
+    pub fn get_fopt(&self) -> f64 {
+        self.fopt
+    }
+
+    fn generate_data(&mut self) {
+        let dim = self.dim;
+        let seed = match F {
+            4 => 3,
+            18 => 17,
+            _ => F,
+        } as i64
+            + 10000 * self.instance as i64;
+        self.fopt = compute_fopt(F, self.instance);
+        self.rotation = vec![];
+        self.linear = vec![];
+        self.peaks = Peaks::default();
+        self.xopt = match F {
+            12 => compute_xopt(seed + 1000000, dim),
+            9 | 19 | 20 | 21 | 22 | 24 => vec![],
+            _ => compute_xopt(seed, dim),
+        };
+
+        match F {
+            4 => self.xopt.iter_mut().step_by(2).for_each(|x| *x = x.abs()),
+            5 => self
+                .xopt
+                .iter_mut()
+                .for_each(|x| *x = if *x > 0.0 { 5.0 } else { -5.0 }),
+            6 | 13 | 15 => {
+                self.rotation = compute_rotation(seed + 1000000, dim);
+                self.linear = conditioned(&self.rotation, 10.0, &compute_rotation(seed, dim));
+            }
+            7 => {
+                // Q is applied after rounding, R with the conditioning before
+                self.rotation = compute_rotation(seed + 1000000, dim);
+                self.linear = scale_rows(&compute_rotation(seed, dim), 10.0);
+            }
+            8 => self.xopt.iter_mut().for_each(|x| *x *= 0.75),
+            9 | 19 => {
+                let factor = rosenbrock_factor(dim);
+                self.rotation = compute_rotation(seed, dim);
+                self.xopt = (0..dim)
+                    .map(|i| (0..dim).map(|j| self.rotation[j][i] * 0.5 / factor).sum())
+                    .collect();
+            }
+            10 | 11 | 12 | 14 => self.rotation = compute_rotation(seed + 1000000, dim),
+            16 => {
+                self.rotation = compute_rotation(seed + 1000000, dim);
+                self.linear =
+                    conditioned(&self.rotation, 1.0 / 100.0, &compute_rotation(seed, dim));
+            }
+            17 | 18 => {
+                self.rotation = compute_rotation(seed + 1000000, dim);
+                let condition = if F == 17 { 10.0 } else { 1000.0 };
+                self.linear = scale_rows(&compute_rotation(seed, dim), condition);
+            }
+            20 => {
+                self.xopt = unif(dim, seed)
+                    .into_iter()
+                    .map(|u| {
+                        if u - 0.5 < 0.0 {
+                            -0.5 * 4.2096874637
+                        } else {
+                            0.5 * 4.2096874637
+                        }
+                    })
+                    .collect()
+            }
+            21 | 22 => self.generate_peaks(seed),
+            23 => {
+                self.rotation = compute_rotation(seed + 1000000, dim);
+                self.linear = conditioned(&self.rotation, 100.0, &compute_rotation(seed, dim));
+            }
+            24 => {
+                self.xopt = gauss(dim, seed)
+                    .into_iter()
+                    .map(|g| if g < 0.0 { -1.25 } else { 1.25 })
+                    .collect();
+                self.rotation = compute_rotation(seed + 1000000, dim);
+                self.linear = conditioned(&self.rotation, 100.0, &compute_rotation(seed, dim));
+            }
+            _ => {}
+        }
+        self.optimum = self.xopt.iter().map(|x| *x as f32).collect();
+    }
+
+    fn generate_peaks(&mut self, seed: i64) {
+        let dim = self.dim;
+        let count = if F == 21 { 101 } else { 21 };
+        let (max_condition, b, c) = if F == 21 {
+            (1000f64.sqrt(), 10.0, 5.0)
+        } else {
+            (1000.0, 9.8, 4.9)
+        };
+        self.rotation = compute_rotation(seed, dim);
+
+        let order = sorted_indices(&unif(count - 1, seed));
+        let mut conditions = vec![max_condition];
+        let mut values = vec![10.0];
+        for i in 1..count {
+            conditions.push(1000f64.powf(order[i - 1] as f64 / (count - 2) as f64));
+            values.push((i - 1) as f64 / (count - 2) as f64 * (9.1 - 1.1) + 1.1);
+        }
+        let scales = (0..count)
+            .map(|i| {
+                let order = sorted_indices(&unif(dim, seed + 1000 * i as i64));
+                order
+                    .iter()
+                    .map(|j| conditions[i].powf(*j as f64 / (dim - 1).max(1) as f64 - 0.5))
+                    .collect()
+            })
+            .collect();
+
+        let uniform = unif(dim * count, seed);
+        self.xopt = (0..dim).map(|i| 0.8 * (b * uniform[i] - c)).collect();
+        let locations = (0..count)
+            .map(|j| {
+                let point: Vec<f64> = (0..dim).map(|k| b * uniform[j * dim + k] - c).collect();
+                let factor = if j == 0 { 0.8 } else { 1.0 };
+                multiply(&self.rotation, &point)
+                    .into_iter()
+                    .map(|x| factor * x)
+                    .collect()
+            })
+            .collect();
+        self.peaks = Peaks {
+            values,
+            scales,
+            locations,
+        };
+    }
+
+    // cost in double precision, f_opt included
+    pub fn evaluate(&self, input: &[f64]) -> f64 {
+        let n = input.len();
+        let shifted: Vec<f64> = input
+            .iter()
+            .zip(self.xopt.iter())
+            .map(|(x, o)| x - o)
+            .collect();
+        let value = match F {
+            1 => shifted.iter().map(|z| z * z).sum(),
+            2 => ellipsoid(&tosz(&shifted)),
+            3 => {
+                let z = tasy(&tosz(&shifted), 0.2)
+                    .iter()
+                    .enumerate()
+                    .map(|(i, z)| z * conditioning(10.0, i, n))
+                    .collect::<Vec<f64>>();
+                rastrigin(&z)
+            }
+            4 => {
+                let z: Vec<f64> = tosz(&shifted)
+                    .iter()
+                    .enumerate()
+                    .map(|(i, z)| {
+                        let scale = conditioning(10.0, i, n);
+                        if i % 2 == 0 && *z > 0.0 {
+                            10.0 * scale * z
+                        } else {
+                            scale * z
+                        }
+                    })
+                    .collect();
+                rastrigin(&z) + 100.0 * penalty(input)
+            }
+            5 => input
+                .iter()
+                .zip(self.xopt.iter())
+                .enumerate()
+                .map(|(i, (x, o))| {
+                    let s = o.signum() * 10f64.powf(ratio(i, n));
+                    let z = if x * o < 25.0 { *x } else { *o };
+                    5.0 * s.abs() - s * z
+                })
+                .sum(),
+            6 => {
+                let sum: f64 = multiply(&self.linear, &shifted)
+                    .iter()
+                    .zip(self.xopt.iter())
+                    .map(|(z, o)| {
+                        if z * o > 0.0 {
+                            (100.0 * z).powi(2)
+                        } else {
+                            z * z
+                        }
+                    })
+                    .sum();
+                tosz_value(sum).powf(0.9)
+            }
+            7 => {
+                let z_hat = multiply(&self.linear, &shifted);
+                let rounded: Vec<f64> = z_hat
+                    .iter()
+                    .map(|z| {
+                        if z.abs() > 0.5 {
+                            round(*z)
+                        } else {
+                            round(10.0 * z) / 10.0
+                        }
+                    })
+                    .collect();
+                let sum: f64 = multiply(&self.rotation, &rounded)
+                    .iter()
+                    .enumerate()
+                    .map(|(i, z)| 100f64.powf(ratio(i, n)) * z * z)
+                    .sum();
+                0.1 * (z_hat[0].abs() / 1e4).max(sum) + penalty(input)
+            }
+            8 => {
+                let factor = rosenbrock_factor(n);
+                rosenbrock(
+                    &shifted
+                        .iter()
+                        .map(|z| factor * z + 1.0)
+                        .collect::<Vec<f64>>(),
+                )
+            }
+            9 => {
+                let factor = rosenbrock_factor(n);
+                rosenbrock(
+                    &multiply(&self.rotation, input)
+                        .iter()
+                        .map(|z| factor * z + 0.5)
+                        .collect::<Vec<f64>>(),
+                )
+            }
+            10 => ellipsoid(&tosz(&multiply(&self.rotation, &shifted))),
+            11 => {
+                let z = tosz(&multiply(&self.rotation, &shifted));
+                1e6 * z[0] * z[0] + z[1..].iter().map(|z| z * z).sum::<f64>()
+            }
+            12 => {
+                let z = multiply(
+                    &self.rotation,
+                    &tasy(&multiply(&self.rotation, &shifted), 0.5),
+                );
+                z[0] * z[0] + 1e6 * z[1..].iter().map(|z| z * z).sum::<f64>()
+            }
+            13 => {
+                let z = multiply(&self.linear, &shifted);
+                z[0] * z[0] + 100.0 * z[1..].iter().map(|z| z * z).sum::<f64>().sqrt()
+            }
+            14 => multiply(&self.rotation, &shifted)
+                .iter()
+                .enumerate()
+                .map(|(i, z)| z.abs().powf(2.0 + 4.0 * ratio(i, n)))
+                .sum::<f64>()
+                .sqrt(),
+            15 => rastrigin(&multiply(
+                &self.linear,
+                &tasy(&tosz(&multiply(&self.rotation, &shifted)), 0.2),
+            )),
+            16 => {
+                let z = multiply(&self.linear, &tosz(&multiply(&self.rotation, &shifted)));
+                let offset: f64 = (0..12)
+                    .map(|k| 0.5f64.powi(k) * (PI * 3f64.powi(k)).cos())
+                    .sum();
+                let sum: f64 = z
+                    .iter()
+                    .map(|z| {
+                        (0..12)
+                            .map(|k| 0.5f64.powi(k) * (2.0 * PI * 3f64.powi(k) * (z + 0.5)).cos())
+                            .sum::<f64>()
+                    })
+                    .sum();
+                10.0 * (sum / n as f64 - offset).powi(3) + 10.0 / n as f64 * penalty(input)
+            }
+            17 | 18 => {
+                let z = multiply(
+                    &self.linear,
+                    &tasy(&multiply(&self.rotation, &shifted), 0.5),
+                );
+                let sum: f64 = z
+                    .windows(2)
+                    .map(|pair| {
+                        let s = pair[0] * pair[0] + pair[1] * pair[1];
+                        s.powf(0.25) * ((50.0 * s.powf(0.1)).sin().powi(2) + 1.0)
+                    })
+                    .sum();
+                (sum / (n - 1).max(1) as f64).powi(2) + 10.0 * penalty(input)
+            }
+            19 => {
+                let factor = rosenbrock_factor(n);
+                let z: Vec<f64> = multiply(&self.rotation, input)
+                    .iter()
+                    .map(|z| factor * z + 0.5)
+                    .collect();
+                let sum: f64 = z
+                    .windows(2)
+                    .map(|pair| {
+                        let s =
+                            100.0 * (pair[0] * pair[0] - pair[1]).powi(2) + (pair[0] - 1.0).powi(2);
+                        s / 4000.0 - s.cos()
+                    })
+                    .sum();
+                10.0 + 10.0 * sum / (n - 1).max(1) as f64
+            }
+            20 => {
+                let x_hat: Vec<f64> = input
+                    .iter()
+                    .zip(self.xopt.iter())
+                    .map(|(x, o)| if *o < 0.0 { -2.0 * x } else { 2.0 * x })
+                    .collect();
+                let z: Vec<f64> = (0..n)
+                    .map(|i| {
+                        let mut z = x_hat[i];
+                        if i > 0 {
+                            z += 0.25 * (x_hat[i - 1] - 2.0 * self.xopt[i - 1].abs());
+                        }
+                        let conditioned = (z - 2.0 * self.xopt[i].abs()) * conditioning(10.0, i, n);
+                        100.0 * (conditioned + 2.0 * self.xopt[i].abs())
+                    })
+                    .collect();
+                let sum: f64 = z.iter().map(|z| z * z.abs().sqrt().sin()).sum();
+                let penalty: f64 = z.iter().map(|z| (z.abs() - 500.0).max(0.0).powi(2)).sum();
+                0.01 * (418.9828872724339 - sum / n as f64) + 0.01 * penalty
+            }
+            21 | 22 => {
+                let rotated = multiply(&self.rotation, input);
+                let best = self
+                    .peaks
+                    .locations
+                    .iter()
+                    .zip(self.peaks.scales.iter())
+                    .zip(self.peaks.values.iter())
+                    .map(|((location, scales), value)| {
+                        let distance: f64 = rotated
+                            .iter()
+                            .zip(location.iter())
+                            .zip(scales.iter())
+                            .map(|((x, y), s)| s * (x - y).powi(2))
+                            .sum();
+                        value * (-0.5 / n as f64 * distance).exp()
+                    })
+                    .fold(0f64, f64::max);
+                tosz_value(10.0 - best).powi(2) + penalty(input)
+            }
+            23 => {
+                let z = multiply(&self.linear, &shifted);
+                let exponent = 10.0 / (n as f64).powf(1.2);
+                let product: f64 = z
+                    .iter()
+                    .enumerate()
+                    .map(|(i, z)| {
+                        let sum: f64 = (1..=32)
+                            .map(|j| {
+                                let power = 2f64.powi(j);
+                                (power * z - round(power * z)).abs() / power
+                            })
+                            .sum();
+                        (1.0 + (i + 1) as f64 * sum).powf(exponent)
+                    })
+                    .product();
+                let factor = 10.0 / (n * n) as f64;
+                factor * (product - 1.0) + penalty(input)
+            }
+            _ => {
+                let (mu0, d) = (2.5, 1.0);
+                let s = 1.0 - 0.5 / ((n as f64 + 20.0).sqrt() - 4.1);
+                let mu1 = -((mu0 * mu0 - d) / s).sqrt();
+                let x_hat: Vec<f64> = input
+                    .iter()
+                    .zip(self.xopt.iter())
+                    .map(|(x, o)| if *o < 0.0 { -2.0 * x } else { 2.0 * x })
+                    .collect();
+                let first: f64 = x_hat.iter().map(|x| (x - mu0).powi(2)).sum();
+                let second: f64 =
+                    d * n as f64 + s * x_hat.iter().map(|x| (x - mu1).powi(2)).sum::<f64>();
+                let centred: Vec<f64> = x_hat.iter().map(|x| x - mu0).collect();
+                let cosines: f64 = multiply(&self.linear, &centred)
+                    .iter()
+                    .map(|z| (2.0 * PI * z).cos())
+                    .sum();
+                first.min(second) + 10.0 * (n as f64 - cosines) + 1e4 * penalty(input)
+            }
+        };
+        value + self.fopt
+    }
+}
+
+fn ratio(i: usize, n: usize) -> f64 {
+    if n > 1 {
+        i as f64 / (n - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+// diagonal of the matrix Lambda^alpha
+fn conditioning(alpha: f64, i: usize, n: usize) -> f64 {
+    alpha.sqrt().powf(ratio(i, n))
+}
+
+fn rosenbrock_factor(n: usize) -> f64 {
+    ((n as f64).sqrt() / 8.0).max(1.0)
+}
+
+fn multiply(matrix: &[Vec<f64>], vector: &[f64]) -> Vec<f64> {
+    matrix
+        .iter()
+        .map(|row| row.iter().zip(vector.iter()).map(|(m, v)| m * v).sum())
+        .collect()
+}
+
+// first * Lambda^alpha * second
+fn conditioned(first: &[Vec<f64>], alpha: f64, second: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = first.len();
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    (0..n)
+                        .map(|k| first[i][k] * conditioning(alpha, k, n) * second[k][j])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Lambda^alpha * matrix
+fn scale_rows(matrix: &[Vec<f64>], alpha: f64) -> Vec<Vec<f64>> {
+    let n = matrix.len();
+    matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| row.iter().map(|m| conditioning(alpha, i, n) * m).collect())
+        .collect()
+}
+
+fn sorted_indices(values: &[f64]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..values.len()).collect();
+    indices.sort_by(|a, b| {
+        values[*a]
+            .partial_cmp(&values[*b])
+            .unwrap_or(Ordering::Equal)
+    });
+    indices
+}
+
+fn tosz_value(value: f64) -> f64 {
+    if value > 0.0 {
+        let x = value.ln() / 0.1;
+        (x + 0.49 * (x.sin() + (0.79 * x).sin())).exp().powf(0.1)
+    } else if value < 0.0 {
+        let x = (-value).ln() / 0.1;
+        -(x + 0.49 * ((0.55 * x).sin() + (0.31 * x).sin()))
+            .exp()
+            .powf(0.1)
+    } else {
+        value
+    }
+}
+
+fn tosz(values: &[f64]) -> Vec<f64> {
+    values.iter().map(|x| tosz_value(*x)).collect()
+}
+
+fn tasy(values: &[f64], beta: f64) -> Vec<f64> {
+    let n = values.len();
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            if *x > 0.0 {
+                x.powf(1.0 + beta * ratio(i, n) * x.sqrt())
+            } else {
+                *x
+            }
+        })
+        .collect()
+}
+
+fn penalty(input: &[f64]) -> f64 {
+    input.iter().map(|x| (x.abs() - 5.0).max(0.0).powi(2)).sum()
+}
+
+fn ellipsoid(z: &[f64]) -> f64 {
+    let n = z.len();
+    z.iter()
+        .enumerate()
+        .map(|(i, z)| 1e6f64.powf(ratio(i, n)) * z * z)
+        .sum()
+}
+
+fn rastrigin(z: &[f64]) -> f64 {
+    10.0 * (z.len() as f64 - z.iter().map(|z| (2.0 * PI * z).cos()).sum::<f64>())
+        + z.iter().map(|z| z * z).sum::<f64>()
+}
+
+fn rosenbrock(z: &[f64]) -> f64 {
+    z.windows(2)
+        .map(|pair| 100.0 * (pair[0] * pair[0] - pair[1]).powi(2) + (pair[0] - 1.0).powi(2))
+        .sum()
+}
+
+impl<const F: usize> Benchmark for Bbob<F> {
+    const FUNCTION_NAME: &'static str = FUNCTION_NAMES[F - 1];
+
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min.or(Some(self.fopt as f32))
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords
+            .as_deref()
+            .or(Some(self.optimum.as_slice()))
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // the instance data depend on the dimension and are generated again
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self.generate_data();
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        let input: Vec<f64> = input.iter().map(|x| f64::from(*x)).collect();
+        self.evaluate(&input) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        self.evaluate(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5f32, 5f32))
+    }
+}
+
+impl<const F: usize> Default for Bbob<F> {
+    fn default() -> Self {
+        assert!((1..=24).contains(&F), "BBOB has functions 1 to 24");
+        let mut default = Self {
+            min: -5f32,
+            max: 5f32,
+            dim: 0usize,
+            instance: 1,
+            expected_min: None,
+            expected_min_coords: None,
+            xopt: vec![],
+            fopt: 0.0,
+            rotation: vec![],
+            linear: vec![],
+            peaks: Peaks::default(),
+            optimum: vec![],
+        };
+        default.generate_data();
+        default
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    fn build<const F: usize>(dimensions: usize) -> Bbob<F> {
+        Bbob::<F>::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(dimensions)
+            .build()
+            .unwrap()
+    }
+
+    // optimal values of the first instances as listed in the COCO data archive
+    fn check<const F: usize>(fopt: f64) {
+        for dimensions in [2, 5, 10, 20, 40] {
+            let problem = build::<F>(dimensions);
+            assert_eq!(problem.get_fopt(), fopt, "{}", Bbob::<F>::FUNCTION_NAME);
+            let optimum: Vec<f64> = problem.xopt.clone();
+            assert!(
+                (problem.evaluate(&optimum) - fopt).abs() < 1e-6,
+                "{}: {} at the optimum in {} dimensions",
+                Bbob::<F>::FUNCTION_NAME,
+                problem.evaluate(&optimum),
+                dimensions
+            );
+            let random: Vec<f64> = problem.get_random().into_iter().map(f64::from).collect();
+            assert!(
+                problem.evaluate(&random) > fopt,
+                "{}",
+                Bbob::<F>::FUNCTION_NAME
+            );
+        }
+    }
+
+    #[test]
+    fn separable() {
+        check::<1>(79.48);
+        check::<2>(-209.88);
+        check::<3>(-462.09);
+        check::<4>(-462.09);
+        check::<5>(-9.21);
+    }
+
+    #[test]
+    fn moderate_conditioning() {
+        check::<6>(35.9);
+        check::<7>(92.94);
+        check::<8>(149.15);
+        check::<9>(123.83);
+    }
+
+    #[test]
+    fn high_conditioning() {
+        check::<10>(-54.94);
+        check::<11>(76.27);
+        check::<12>(-621.11);
+        check::<13>(29.97);
+        check::<14>(-52.35);
+    }
+
+    #[test]
+    fn multimodal_adequate_structure() {
+        check::<15>(1000.0);
+        check::<16>(71.35);
+        check::<17>(-16.94);
+        check::<18>(-16.94);
+        check::<19>(-102.55);
+    }
+
+    #[test]
+    fn multimodal_weak_structure() {
+        check::<20>(-546.5);
+        check::<21>(40.78);
+        check::<22>(-1000.0);
+        check::<23>(6.87);
+        check::<24>(102.61);
+    }
+
+    #[test]
+    fn instances() {
+        let first = build::<15>(10);
+        let second = build::<15>(10).set_instance(2);
+        assert_eq!(second.get_instance(), 2);
+        assert_ne!(first.get_fopt(), second.get_fopt());
+        assert_ne!(
+            first.get_expected_min_coords(),
+            second.get_expected_min_coords()
+        );
+        assert_eq!(first.get_expected_min(), Some(1000f32));
+    }
+}
@@ -0,0 +1,106 @@
+// random numbers of the bbob 2009 reference code, the instances are only reproduced exactly
+// with this generator
+// source: https://github.com/numbbo/coco/blob/master/code-experiments/src/suite_bbob_legacy_code.c
+
+// legacy rounding, half values go up
+pub(crate) fn round(value: f64) -> f64 {
+    (value + 0.5).floor()
+}
+
+fn next_seed(seed: i64) -> i64 {
+    let tmp = seed / 127773;
+    let next = 16807 * (seed - tmp * 127773) - 2836 * tmp;
+    if next < 0 {
+        next + 2147483647
+    } else {
+        next
+    }
+}
+
+pub(crate) fn unif(count: usize, seed: i64) -> Vec<f64> {
+    let mut seed = seed.abs().max(1);
+    let mut table = [0i64; 32];
+    for i in (0..40).rev() {
+        seed = next_seed(seed);
+        if i < 32 {
+            table[i] = seed;
+        }
+    }
+    let mut current = table[0];
+    (0..count)
+        .map(|_| {
+            seed = next_seed(seed);
+            let index = (current / 67108865) as usize;
+            current = table[index];
+            table[index] = seed;
+            let value = current as f64 / 2.147483647e9;
+            if value == 0.0 {
+                1e-99
+            } else {
+                value
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn gauss(count: usize, seed: i64) -> Vec<f64> {
+    let uniform = unif(2 * count, seed);
+    (0..count)
+        .map(|i| {
+            let value = (-2.0 * uniform[i].ln()).sqrt()
+                * (2.0 * std::f64::consts::PI * uniform[count + i]).cos();
+            if value == 0.0 {
+                1e-99
+            } else {
+                value
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn compute_xopt(seed: i64, dim: usize) -> Vec<f64> {
+    unif(dim, seed)
+        .into_iter()
+        .map(|u| {
+            let x = 8.0 * (1e4 * u).floor() / 1e4 - 4.0;
+            if x == 0.0 {
+                -1e-5
+            } else {
+                x
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn compute_fopt(function: usize, instance: usize) -> f64 {
+    let seed = match function {
+        4 => 3,
+        18 => 17,
+        _ => function,
+    } as i64
+        + 10000 * instance as i64;
+    let numerator = gauss(1, seed)[0];
+    let denominator = gauss(1, seed + 1)[0];
+    (round(100.0 * 100.0 * numerator / denominator) / 100.0).clamp(-1000.0, 1000.0)
+}
+
+// gaussian matrix filled by columns, the columns are orthonormalised by Gram-Schmidt
+pub(crate) fn compute_rotation(seed: i64, dim: usize) -> Vec<Vec<f64>> {
+    let values = gauss(dim * dim, seed);
+    let mut matrix: Vec<Vec<f64>> = (0..dim)
+        .map(|i| (0..dim).map(|j| values[j * dim + i]).collect())
+        .collect();
+    for i in 0..dim {
+        for j in 0..i {
+            let product: f64 = (0..dim).map(|k| matrix[k][i] * matrix[k][j]).sum();
+            for row in matrix.iter_mut() {
+                row[i] -= product * row[j];
+            }
+        }
+        let norm: f64 = (0..dim).map(|k| matrix[k][i].powi(2)).sum::<f64>().sqrt();
+        for row in matrix.iter_mut() {
+            row[i] /= norm;
+        }
+    }
+    matrix
+}
@@ -0,0 +1,153 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// the first Bohachevsky function, generalised to d dimensions over consecutive pairs
+// source: https://www.sfu.ca/~ssurjano/boha.html
+#[derive(Debug)]
+pub struct Bohachevsky {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Bohachevsky> for Bohachevsky {}
+
+impl Benchmark for Bohachevsky {
+    const FUNCTION_NAME: &'static str = "Bohachevsky function";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        bohachevsky(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        bohachevsky(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-100f32, 100f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn bohachevsky<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    input
+        .windows(2)
+        .map(|pair| {
+            pair[0].powi(2) + c(2.0) * pair[1].powi(2)
+                - c(0.3) * (c(3.0) * F::PI() * pair[0]).cos()
+                - c(0.4) * (c(4.0) * F::PI() * pair[1]).cos()
+                + c(0.7)
+        })
+        .sum()
+}
+
+impl Default for Bohachevsky {
+    fn default() -> Self {
+        Self {
+            min: -100f32,
+            max: 100f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn random_in_range() {
+        let bohachevsky = Bohachevsky::builder()
+            .minimum(-100f32)
+            .maximum(100f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = bohachevsky.get_random();
+        let random_2 = bohachevsky.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1
+            .into_iter()
+            .all(|x| (-100f32..=100f32).contains(&x)));
+    }
+
+    #[test]
+    fn optimum() {
+        for dimensions in [2usize, 5, 10] {
+            let bohachevsky = Bohachevsky::builder()
+                .minimum(-100f32)
+                .maximum(100f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = bohachevsky.get_expected_min().unwrap();
+            let coords = bohachevsky.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (bohachevsky.cost_function(coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(bohachevsky.cost_function(&bohachevsky.get_random()) >= expected);
+            }
+        }
+    }
+}
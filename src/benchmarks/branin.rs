@@ -0,0 +1,177 @@
+use std::f32::consts::PI;
+
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// x1 is in [-5, 10] and x2 in [0, 15]
+// source: https://www.sfu.ca/~ssurjano/branin.html
+#[derive(Debug)]
+pub struct Branin {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Branin> for Branin {}
+
+const MINIMISERS: [[f32; 2]; 3] = [[-PI, 12.275], [PI, 2.275], [9.424_778, 2.475]];
+
+impl Benchmark for Branin {
+    const FUNCTION_NAME: &'static str = "Branin function";
+    const DIMENSIONS: Option<usize> = Some(2);
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords
+            .as_deref()
+            .or(Some(MINIMISERS[0].as_slice()))
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only in 2 dimensions, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        branin(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        branin(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5f32, 15f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0.397_887_36)
+    }
+
+    fn get_all_expected_min_coords(&self) -> Vec<Vec<f32>> {
+        match &self.expected_min_coords {
+            Some(coords) => vec![coords.clone()],
+            None => MINIMISERS.iter().map(|coords| coords.to_vec()).collect(),
+        }
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        [(-5f32, 10f32), (0f32, 15f32)]
+            .into_iter()
+            .map(|(min, max)| (min.max(self.min), max.min(self.max)))
+            .collect()
+    }
+}
+
+fn branin<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    let (x, y) = (input[0], input[1]);
+    let b = c(5.1) / (c(4.0) * F::PI().powi(2));
+    let d = c(5.0) / F::PI();
+    let t = F::one() / (c(8.0) * F::PI());
+    (y - b * x.powi(2) + d * x - c(6.0)).powi(2) + c(10.0) * (F::one() - t) * x.cos() + c(10.0)
+}
+
+impl Default for Branin {
+    fn default() -> Self {
+        Self {
+            min: -5f32,
+            max: 15f32,
+            dim: 2usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::traits::BuilderError;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn optimum() {
+        let branin = Branin::builder()
+            .minimum(-5f32)
+            .maximum(15f32)
+            .build()
+            .unwrap();
+        let expected = branin.get_expected_min().unwrap();
+        assert_eq!(branin.get_all_expected_min_coords().len(), 3);
+        for coords in branin.get_all_expected_min_coords() {
+            assert!(
+                (branin.cost_function(&coords) - expected).abs() <= 1e-4 * expected.abs().max(1f32)
+            );
+        }
+        for _ in 0..100 {
+            assert!(branin.cost_function(&branin.get_random()) >= expected - 1e-4 * expected.abs());
+        }
+    }
+
+    #[test]
+    fn fixed_dimensions() {
+        let result = Branin::builder()
+            .minimum(-5f32)
+            .maximum(15f32)
+            .dimensions(3)
+            .build();
+        assert!(matches!(
+            result,
+            Err(BuilderError::WrongDim {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn random_in_bounds() {
+        let branin = Branin::builder()
+            .minimum(-5f32)
+            .maximum(15f32)
+            .build()
+            .unwrap();
+        for _ in 0..100 {
+            let random = branin.get_random();
+            assert!((-5f32..=10f32).contains(&random[0]));
+            assert!((0f32..=15f32).contains(&random[1]));
+        }
+    }
+}
@@ -0,0 +1,999 @@
+use std::f64::consts::PI;
+
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+
+const FUNCTION_NAMES: [&str; 24] = [
+    "CEC 2006 G01",
+    "CEC 2006 G02",
+    "CEC 2006 G03",
+    "CEC 2006 G04",
+    "CEC 2006 G05",
+    "CEC 2006 G06",
+    "CEC 2006 G07",
+    "CEC 2006 G08",
+    "CEC 2006 G09",
+    "CEC 2006 G10",
+    "CEC 2006 G11",
+    "CEC 2006 G12",
+    "CEC 2006 G13",
+    "CEC 2006 G14",
+    "CEC 2006 G15",
+    "CEC 2006 G16",
+    "CEC 2006 G17",
+    "CEC 2006 G18",
+    "CEC 2006 G19",
+    "CEC 2006 G20",
+    "CEC 2006 G21",
+    "CEC 2006 G22",
+    "CEC 2006 G23",
+    "CEC 2006 G24",
+];
+
+const VARIABLES: [usize; 24] = [
+    13, 20, 10, 5, 4, 2, 10, 2, 7, 8, 2, 3, 5, 10, 3, 5, 6, 9, 15, 24, 7, 22, 9, 2,
+];
+
+// best known values of the report, G20 has no known feasible solution
+const BEST_KNOWN: [Option<f64>; 24] = [
+    Some(-15.0),
+    Some(-0.803_619_104_2),
+    Some(-1.000_500_100_0),
+    Some(-30_665.538_671_783_4),
+    Some(5_126.496_714_007_1),
+    Some(-6_961.813_875_580_2),
+    Some(24.306_209_068_1),
+    Some(-0.095_825_041_5),
+    Some(680.630_057_374_5),
+    Some(7_049.248_020_528_6),
+    Some(0.749_9),
+    Some(-1.0),
+    Some(0.053_941_514_0),
+    Some(-47.764_888_459_5),
+    Some(961.715_022_290_0),
+    Some(-1.905_155_258_6),
+    Some(8_853.539_674_806_4),
+    Some(-0.866_025_403_8),
+    Some(32.655_592_950_2),
+    None,
+    Some(193.724_510_070_0),
+    Some(236.430_975_504_0),
+    Some(-400.055_100_000_0),
+    Some(-5.508_013_271_6),
+];
+
+// CEC 2006 suite of constrained problems, G is the number of the problem (1 - 24), every
+// problem has a fixed number of variables with their own bounds, the constraints are
+// g(x) <= 0 and h(x) = 0 and an equality counts as satisfied within 1e-4
+// source: https://www3.ntu.edu.sg/home/epnsugan/index_files/CEC-06/CEC06.htm
+#[derive(Debug)]
+pub struct Cec2006<const G: usize> {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl<const G: usize> HasBuilder<Cec2006<G>> for Cec2006<G> {}
+
+impl<const G: usize> Cec2006<G> {
+    // the bounds are the published ones, 0.5236 of G17 is not meant as pi / 6
+    #[allow(clippy::approx_constant)]
+    fn variable_bounds() -> Vec<(f64, f64)> {
+        let n = VARIABLES[G - 1];
+        match G {
+            1 => {
+                let mut bounds = vec![(0.0, 1.0); 13];
+                bounds[9..12].fill((0.0, 100.0));
+                bounds
+            }
+            2 => vec![(0.0, 10.0); n],
+            3 => vec![(0.0, 1.0); n],
+            4 => vec![
+                (78.0, 102.0),
+                (33.0, 45.0),
+                (27.0, 45.0),
+                (27.0, 45.0),
+                (27.0, 45.0),
+            ],
+            5 => vec![(0.0, 1200.0), (0.0, 1200.0), (-0.55, 0.55), (-0.55, 0.55)],
+            6 => vec![(13.0, 100.0), (0.0, 100.0)],
+            7 | 9 => vec![(-10.0, 10.0); n],
+            8 | 12 | 14 | 19 | 20 => vec![(0.0, 10.0); n],
+            10 => {
+                let mut bounds = vec![(100.0, 10000.0), (1000.0, 10000.0), (1000.0, 10000.0)];
+                bounds.extend(vec![(10.0, 1000.0); 5]);
+                bounds
+            }
+            11 => vec![(-1.0, 1.0); n],
+            13 => vec![
+                (-2.3, 2.3),
+                (-2.3, 2.3),
+                (-3.2, 3.2),
+                (-3.2, 3.2),
+                (-3.2, 3.2),
+            ],
+            15 => vec![(0.0, 10.0); n],
+            16 => vec![
+                (704.4148, 906.3855),
+                (68.6, 288.88),
+                (0.0, 134.75),
+                (193.0, 287.0966),
+                (25.0, 84.1988),
+            ],
+            17 => vec![
+                (0.0, 400.0),
+                (0.0, 1000.0),
+                (340.0, 420.0),
+                (340.0, 420.0),
+                (-1000.0, 1000.0),
+                (0.0, 0.5236),
+            ],
+            18 => {
+                let mut bounds = vec![(-10.0, 10.0); 9];
+                bounds[8] = (0.0, 20.0);
+                bounds
+            }
+            21 => vec![
+                (0.0, 1000.0),
+                (0.0, 40.0),
+                (0.0, 40.0),
+                (100.0, 300.0),
+                (6.3, 6.7),
+                (5.9, 6.4),
+                (4.5, 6.25),
+            ],
+            22 => {
+                let mut bounds = vec![(0.0, 20000.0)];
+                bounds.extend(vec![(0.0, 1e6); 3]);
+                bounds.extend(vec![(0.0, 4e7); 3]);
+                bounds.extend([
+                    (100.0, 299.99),
+                    (100.0, 399.99),
+                    (100.01, 300.0),
+                    (100.0, 400.0),
+                    (100.0, 600.0),
+                ]);
+                bounds.extend(vec![(0.0, 500.0); 3]);
+                bounds.extend([(0.01, 300.0), (0.01, 400.0)]);
+                bounds.extend(vec![(-4.7, 6.25); 5]);
+                bounds
+            }
+            23 => vec![
+                (0.0, 300.0),
+                (0.0, 300.0),
+                (0.0, 100.0),
+                (0.0, 200.0),
+                (0.0, 100.0),
+                (0.0, 300.0),
+                (0.0, 100.0),
+                (0.0, 200.0),
+                (0.01, 0.03),
+            ],
+            _ => vec![(0.0, 3.0), (0.0, 4.0)],
+        }
+    }
+
+    fn envelope() -> (f32, f32) {
+        Self::variable_bounds()
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), bound| {
+                (min.min(bound.0 as f32), max.max(bound.1 as f32))
+            })
+    }
+
+    fn best_known_coords() -> Option<Vec<f64>> {
+        let coords = match G {
+            1 => vec![
+                1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 3.0, 3.0, 3.0, 1.0,
+            ],
+            3 => vec![0.316_243_576_472_830_7; 10],
+            4 => vec![78.0, 33.0, 29.995_256_025_681_6, 45.0, 36.775_812_905_788_2],
+            5 => vec![
+                679.945_148_297_028_7,
+                1_026.066_976_000_047,
+                0.118_876_369_094_410_4,
+                -0.396_233_485_215_178_3,
+            ],
+            6 => vec![14.095, 0.842_960_789_215_479_6],
+            7 => vec![
+                2.171_996_341_426_92,
+                2.363_683_041_603_4,
+                8.773_925_739_131_57,
+                5.095_984_437_451_73,
+                0.990_654_756_560_493,
+                1.430_573_928_534_63,
+                1.321_644_153_643_06,
+                9.828_725_765_244_95,
+                8.280_091_588_735_6,
+                8.375_926_647_734_7,
+            ],
+            8 => vec![1.227_971_352_607_526, 4.245_373_366_122_749],
+            9 => vec![
+                2.330_499_351_474_052,
+                1.951_372_368_471_146,
+                -0.477_541_399_510_615_8,
+                4.365_726_249_236_259,
+                -0.624_486_959_100_389,
+                1.038_130_994_109_622,
+                1.594_226_678_067_152,
+            ],
+            10 => vec![
+                579.306_685_017_979_6,
+                1_359.970_678_079_356,
+                5_109.970_657_431_333,
+                182.017_699_630_615_3,
+                295.601_173_702_746_8,
+                217.982_300_369_384_6,
+                286.416_525_927_868_5,
+                395.601_173_702_746_7,
+            ],
+            11 => vec![-0.707_036_070_037_170_6, 0.500_000_004_333_606_8],
+            12 => vec![5.0, 5.0, 5.0],
+            13 => vec![
+                -1.717_142_240_03,
+                1.595_721_240_494_68,
+                1.827_250_240_627_1,
+                -0.763_659_881_912_867,
+                -0.763_659_867_364_98,
+            ],
+            14 => vec![
+                0.040_668_411_321_628_2,
+                0.147_721_240_492_452,
+                0.783_205_732_104_114,
+                0.001_414_339_318_890_84,
+                0.485_293_636_780_388,
+                0.000_693_183_051_556_082,
+                0.027_405_204_068_776_6,
+                0.017_950_966_021_481_8,
+                0.037_326_818_685_971_7,
+                0.096_884_460_433_684_5,
+            ],
+            15 => vec![
+                3.512_128_126_117_951,
+                0.216_987_510_429_556_1,
+                3.552_178_549_291_799,
+            ],
+            16 => vec![
+                705.174_537_070_090_5,
+                68.6,
+                102.9,
+                282.324_931_593_660_3,
+                37.584_116_425_805_48,
+            ],
+            // the second cost rate changes at 100, the coordinate stays below it in f32
+            17 => vec![
+                201.784_467_214_523_7,
+                99.999_99,
+                383.071_034_852_773_3,
+                420.0,
+                -10.907_658_451_429_27,
+                0.073_148_231_208_428_71,
+            ],
+            18 => vec![
+                -0.657_776_192_427_943_2,
+                -0.153_418_773_482_438_5,
+                0.323_413_871_675_240_9,
+                -0.946_257_611_651_304_4,
+                -0.657_776_194_376_798_9,
+                -0.753_213_434_632_691_4,
+                0.323_413_874_123_577,
+                -0.346_462_947_962_331_7,
+                0.599_794_662_852_175_4,
+            ],
+            19 => vec![
+                1.669_913_413_262_913e-17,
+                3.953_782_292_824_565e-16,
+                3.945_990_451_432_338,
+                1.060_365_974_797_212e-16,
+                3.283_177_345_845_416,
+                10.0,
+                1.128_294_146_716_053e-17,
+                1.202_619_459_979_471e-17,
+                2.507_062_760_007_697e-15,
+                2.246_241_229_879_707e-15,
+                0.370_764_847_417_014,
+                0.278_456_024_942_955_6,
+                0.523_838_487_672_241_2,
+                0.388_620_152_510_322_8,
+                0.298_156_764_974_678_6,
+            ],
+            21 => vec![
+                193.724_510_070_035,
+                5.569_441_315_533_684e-27,
+                17.319_188_729_408_49,
+                100.047_897_801_386_8,
+                6.684_451_853_623_779,
+                5.991_684_284_442_648,
+                6.214_516_488_860_705,
+            ],
+            23 => vec![
+                0.005_100_000_000_002_595,
+                99.994_700_000_000_05,
+                9.019_201_629_960_459e-18,
+                99.999_900_000_000_05,
+                0.000_100_000_000_027_086_09,
+                2.757_006_833_895_845e-14,
+                99.999_999_999_999_96,
+                200.0,
+                0.010_000_010_000_01,
+            ],
+            24 => vec![2.329_520_197_477_62, 3.178_493_074_117_74],
+            _ => return None,
+        };
+        Some(coords)
+    }
+
+    fn objective(input: &[f64]) -> f64 {
+        let x = |i: usize| input[i - 1];
+        match G {
+            1 => {
+                5.0 * (1..=4).map(x).sum::<f64>()
+                    - 5.0 * (1..=4).map(|i| x(i).powi(2)).sum::<f64>()
+                    - (5..=13).map(x).sum::<f64>()
+            }
+            2 => {
+                let sum_cos4: f64 = input.iter().map(|xi| xi.cos().powi(4)).sum();
+                let prod_cos2: f64 = input.iter().map(|xi| xi.cos().powi(2)).product();
+                let norm: f64 = input
+                    .iter()
+                    .enumerate()
+                    .map(|(i, xi)| (i + 1) as f64 * xi.powi(2))
+                    .sum::<f64>()
+                    .sqrt();
+                // the origin lies outside the domain 0 < x
+                if norm == 0.0 {
+                    return 0.0;
+                }
+                -((sum_cos4 - 2.0 * prod_cos2) / norm).abs()
+            }
+            3 => {
+                let n = input.len() as f64;
+                -n.sqrt().powf(n) * input.iter().product::<f64>()
+            }
+            4 => {
+                5.357_854_7 * x(3).powi(2) + 0.835_689_1 * x(1) * x(5) + 37.293_239 * x(1)
+                    - 40_792.141
+            }
+            5 => {
+                3.0 * x(1) + 0.000_001 * x(1).powi(3) + 2.0 * x(2) + 0.000_002 / 3.0 * x(2).powi(3)
+            }
+            6 => (x(1) - 10.0).powi(3) + (x(2) - 20.0).powi(3),
+            7 => {
+                x(1).powi(2) + x(2).powi(2) + x(1) * x(2) - 14.0 * x(1) - 16.0 * x(2)
+                    + (x(3) - 10.0).powi(2)
+                    + 4.0 * (x(4) - 5.0).powi(2)
+                    + (x(5) - 3.0).powi(2)
+                    + 2.0 * (x(6) - 1.0).powi(2)
+                    + 5.0 * x(7).powi(2)
+                    + 7.0 * (x(8) - 11.0).powi(2)
+                    + 2.0 * (x(9) - 10.0).powi(2)
+                    + (x(10) - 7.0).powi(2)
+                    + 45.0
+            }
+            8 => {
+                -(2.0 * PI * x(1)).sin().powi(3) * (2.0 * PI * x(2)).sin()
+                    / (x(1).powi(3) * (x(1) + x(2)))
+            }
+            9 => {
+                (x(1) - 10.0).powi(2)
+                    + 5.0 * (x(2) - 12.0).powi(2)
+                    + x(3).powi(4)
+                    + 3.0 * (x(4) - 11.0).powi(2)
+                    + 10.0 * x(5).powi(6)
+                    + 7.0 * x(6).powi(2)
+                    + x(7).powi(4)
+                    - 4.0 * x(6) * x(7)
+                    - 10.0 * x(6)
+                    - 8.0 * x(7)
+            }
+            10 => x(1) + x(2) + x(3),
+            11 => x(1).powi(2) + (x(2) - 1.0).powi(2),
+            12 => {
+                -(100.0 - (x(1) - 5.0).powi(2) - (x(2) - 5.0).powi(2) - (x(3) - 5.0).powi(2))
+                    / 100.0
+            }
+            13 => (x(1) * x(2) * x(3) * x(4) * x(5)).exp(),
+            14 => {
+                let c = [
+                    -6.089, -17.164, -34.054, -5.914, -24.721, -14.986, -24.1, -10.708, -26.662,
+                    -22.179,
+                ];
+                let sum: f64 = input.iter().sum();
+                input
+                    .iter()
+                    .zip(c.iter())
+                    // x ln x goes to 0 at the lower bound
+                    .map(|(xi, ci)| {
+                        if *xi > 0.0 {
+                            xi * (ci + (xi / sum).ln())
+                        } else {
+                            0.0
+                        }
+                    })
+                    .sum()
+            }
+            15 => {
+                1000.0
+                    - x(1).powi(2)
+                    - 2.0 * x(2).powi(2)
+                    - x(3).powi(2)
+                    - x(1) * x(2)
+                    - x(1) * x(3)
+            }
+            16 => g16(input).0,
+            17 => {
+                let f1 = if x(1) < 300.0 {
+                    30.0 * x(1)
+                } else {
+                    31.0 * x(1)
+                };
+                let f2 = if x(2) < 100.0 {
+                    28.0 * x(2)
+                } else if x(2) < 200.0 {
+                    29.0 * x(2)
+                } else {
+                    30.0 * x(2)
+                };
+                f1 + f2
+            }
+            18 => {
+                -0.5 * (x(1) * x(4) - x(2) * x(3) + x(3) * x(9) - x(5) * x(9) + x(5) * x(8)
+                    - x(6) * x(7))
+            }
+            19 => {
+                let y = |j: usize| x(10 + j);
+                let quadratic: f64 = (1..=5)
+                    .flat_map(|j| (1..=5).map(move |i| (i, j)))
+                    .map(|(i, j)| G19_C[i - 1][j - 1] * y(i) * y(j))
+                    .sum();
+                let cubic: f64 = (1..=5).map(|j| G19_D[j - 1] * y(j).powi(3)).sum();
+                let linear: f64 = (1..=10).map(|i| G19_B[i - 1] * x(i)).sum();
+                quadratic + 2.0 * cubic - linear
+            }
+            20 => input.iter().zip(G20_A.iter()).map(|(xi, a)| a * xi).sum(),
+            21 | 22 => x(1),
+            23 => -9.0 * x(5) - 15.0 * x(8) + 6.0 * x(1) + 16.0 * x(2) + 10.0 * (x(6) + x(7)),
+            _ => -x(1) - x(2),
+        }
+    }
+
+    fn inequality(input: &[f64]) -> Vec<f64> {
+        let x = |i: usize| input[i - 1];
+        match G {
+            1 => vec![
+                2.0 * x(1) + 2.0 * x(2) + x(10) + x(11) - 10.0,
+                2.0 * x(1) + 2.0 * x(3) + x(10) + x(12) - 10.0,
+                2.0 * x(2) + 2.0 * x(3) + x(11) + x(12) - 10.0,
+                -8.0 * x(1) + x(10),
+                -8.0 * x(2) + x(11),
+                -8.0 * x(3) + x(12),
+                -2.0 * x(4) - x(5) + x(10),
+                -2.0 * x(6) - x(7) + x(11),
+                -2.0 * x(8) - x(9) + x(12),
+            ],
+            2 => vec![
+                0.75 - input.iter().product::<f64>(),
+                input.iter().sum::<f64>() - 7.5 * input.len() as f64,
+            ],
+            4 => {
+                let u = 85.334_407 + 0.005_685_8 * x(2) * x(5) + 0.000_626_2 * x(1) * x(4)
+                    - 0.002_205_3 * x(3) * x(5);
+                let v = 80.512_49
+                    + 0.007_131_7 * x(2) * x(5)
+                    + 0.002_995_5 * x(1) * x(2)
+                    + 0.002_181_3 * x(3).powi(2);
+                let w = 9.300_961
+                    + 0.004_702_6 * x(3) * x(5)
+                    + 0.001_254_7 * x(1) * x(3)
+                    + 0.001_908_5 * x(3) * x(4);
+                vec![u - 92.0, -u, v - 110.0, -v + 90.0, w - 25.0, -w + 20.0]
+            }
+            5 => vec![-x(4) + x(3) - 0.55, -x(3) + x(4) - 0.55],
+            6 => vec![
+                -(x(1) - 5.0).powi(2) - (x(2) - 5.0).powi(2) + 100.0,
+                (x(1) - 6.0).powi(2) + (x(2) - 5.0).powi(2) - 82.81,
+            ],
+            7 => vec![
+                -105.0 + 4.0 * x(1) + 5.0 * x(2) - 3.0 * x(7) + 9.0 * x(8),
+                10.0 * x(1) - 8.0 * x(2) - 17.0 * x(7) + 2.0 * x(8),
+                -8.0 * x(1) + 2.0 * x(2) + 5.0 * x(9) - 2.0 * x(10) - 12.0,
+                3.0 * (x(1) - 2.0).powi(2) + 4.0 * (x(2) - 3.0).powi(2) + 2.0 * x(3).powi(2)
+                    - 7.0 * x(4)
+                    - 120.0,
+                5.0 * x(1).powi(2) + 8.0 * x(2) + (x(3) - 6.0).powi(2) - 2.0 * x(4) - 40.0,
+                x(1).powi(2) + 2.0 * (x(2) - 2.0).powi(2) - 2.0 * x(1) * x(2) + 14.0 * x(5)
+                    - 6.0 * x(6),
+                0.5 * (x(1) - 8.0).powi(2) + 2.0 * (x(2) - 4.0).powi(2) + 3.0 * x(5).powi(2)
+                    - x(6)
+                    - 30.0,
+                -3.0 * x(1) + 6.0 * x(2) + 12.0 * (x(9) - 8.0).powi(2) - 7.0 * x(10),
+            ],
+            8 => vec![x(1).powi(2) - x(2) + 1.0, 1.0 - x(1) + (x(2) - 4.0).powi(2)],
+            9 => vec![
+                -127.0
+                    + 2.0 * x(1).powi(2)
+                    + 3.0 * x(2).powi(4)
+                    + x(3)
+                    + 4.0 * x(4).powi(2)
+                    + 5.0 * x(5),
+                -282.0 + 7.0 * x(1) + 3.0 * x(2) + 10.0 * x(3).powi(2) + x(4) - x(5),
+                -196.0 + 23.0 * x(1) + x(2).powi(2) + 6.0 * x(6).powi(2) - 8.0 * x(7),
+                4.0 * x(1).powi(2) + x(2).powi(2) - 3.0 * x(1) * x(2)
+                    + 2.0 * x(3).powi(2)
+                    + 5.0 * x(6)
+                    - 11.0 * x(7),
+            ],
+            10 => vec![
+                -1.0 + 0.0025 * (x(4) + x(6)),
+                -1.0 + 0.0025 * (x(5) + x(7) - x(4)),
+                -1.0 + 0.01 * (x(8) - x(5)),
+                -x(1) * x(6) + 833.332_52 * x(4) + 100.0 * x(1) - 83_333.333,
+                -x(2) * x(7) + 1250.0 * x(5) + x(2) * x(4) - 1250.0 * x(4),
+                -x(3) * x(8) + 1_250_000.0 + x(3) * x(5) - 2500.0 * x(5),
+            ],
+            // feasible inside any of the 9^3 balls around the grid points 1 - 9, the closest
+            // one decides
+            12 => vec![
+                input
+                    .iter()
+                    .map(|xi| (xi - xi.round().clamp(1.0, 9.0)).powi(2))
+                    .sum::<f64>()
+                    - 0.0625,
+            ],
+            16 => g16(input).1,
+            18 => vec![
+                x(3).powi(2) + x(4).powi(2) - 1.0,
+                x(9).powi(2) - 1.0,
+                x(5).powi(2) + x(6).powi(2) - 1.0,
+                x(1).powi(2) + (x(2) - x(9)).powi(2) - 1.0,
+                (x(1) - x(5)).powi(2) + (x(2) - x(6)).powi(2) - 1.0,
+                (x(1) - x(7)).powi(2) + (x(2) - x(8)).powi(2) - 1.0,
+                (x(3) - x(5)).powi(2) + (x(4) - x(6)).powi(2) - 1.0,
+                (x(3) - x(7)).powi(2) + (x(4) - x(8)).powi(2) - 1.0,
+                x(7).powi(2) + (x(8) - x(9)).powi(2) - 1.0,
+                x(2) * x(3) - x(1) * x(4),
+                -x(3) * x(9),
+                x(5) * x(9),
+                x(6) * x(7) - x(5) * x(8),
+            ],
+            19 => (1..=5)
+                .map(|j| {
+                    -2.0 * (1..=5)
+                        .map(|i| G19_C[i - 1][j - 1] * x(10 + i))
+                        .sum::<f64>()
+                        - 3.0 * G19_D[j - 1] * x(10 + j).powi(2)
+                        - G19_E[j - 1]
+                        + (1..=10).map(|i| G19_A[i - 1][j - 1] * x(i)).sum::<f64>()
+                })
+                .collect(),
+            20 => {
+                let sum: f64 = input.iter().sum();
+                (1..=6)
+                    .map(|i| {
+                        let pair = if i <= 3 {
+                            x(i) + x(i + 12)
+                        } else {
+                            x(i + 3) + x(i + 15)
+                        };
+                        pair / (sum + G20_E[i - 1])
+                    })
+                    .collect()
+            }
+            21 => vec![-x(1) + 35.0 * x(2).powf(0.6) + 35.0 * x(3).powf(0.6)],
+            22 => vec![-x(1) + x(2).powf(0.6) + x(3).powf(0.6) + x(4).powf(0.6)],
+            23 => vec![
+                x(9) * x(3) + 0.02 * x(6) - 0.025 * x(5),
+                x(9) * x(4) + 0.02 * x(7) - 0.015 * x(8),
+            ],
+            24 => vec![
+                -2.0 * x(1).powi(4) + 8.0 * x(1).powi(3) - 8.0 * x(1).powi(2) + x(2) - 2.0,
+                -4.0 * x(1).powi(4) + 32.0 * x(1).powi(3) - 88.0 * x(1).powi(2)
+                    + 96.0 * x(1)
+                    + x(2)
+                    - 36.0,
+            ],
+            _ => vec![],
+        }
+    }
+
+    fn equality(input: &[f64]) -> Vec<f64> {
+        let x = |i: usize| input[i - 1];
+        match G {
+            3 => vec![input.iter().map(|xi| xi.powi(2)).sum::<f64>() - 1.0],
+            5 => vec![
+                1000.0 * (-x(3) - 0.25).sin() + 1000.0 * (-x(4) - 0.25).sin() + 894.8 - x(1),
+                1000.0 * (x(3) - 0.25).sin() + 1000.0 * (x(3) - x(4) - 0.25).sin() + 894.8 - x(2),
+                1000.0 * (x(4) - 0.25).sin() + 1000.0 * (x(4) - x(3) - 0.25).sin() + 1294.8,
+            ],
+            11 => vec![x(2) - x(1).powi(2)],
+            13 => vec![
+                input.iter().map(|xi| xi.powi(2)).sum::<f64>() - 10.0,
+                x(2) * x(3) - 5.0 * x(4) * x(5),
+                x(1).powi(3) + x(2).powi(3) + 1.0,
+            ],
+            14 => vec![
+                x(1) + 2.0 * x(2) + 2.0 * x(3) + x(6) + x(10) - 2.0,
+                x(4) + 2.0 * x(5) + x(6) + x(7) - 1.0,
+                x(3) + x(7) + x(8) + 2.0 * x(9) + x(10) - 1.0,
+            ],
+            15 => vec![
+                x(1).powi(2) + x(2).powi(2) + x(3).powi(2) - 25.0,
+                8.0 * x(1) + 14.0 * x(2) + 7.0 * x(3) - 56.0,
+            ],
+            17 => {
+                let (a, b) = (1.484_77f64, 1.475_88f64);
+                let k = 131.078;
+                vec![
+                    -x(1) + 300.0 - x(3) * x(4) / k * (a - x(6)).cos()
+                        + 0.907_98 * x(3).powi(2) / k * b.cos(),
+                    -x(2) - x(3) * x(4) / k * (a + x(6)).cos()
+                        + 0.907_98 * x(4).powi(2) / k * b.cos(),
+                    -x(5) - x(3) * x(4) / k * (a + x(6)).sin()
+                        + 0.907_98 * x(4).powi(2) / k * b.sin(),
+                    200.0 - x(3) * x(4) / k * (a - x(6)).sin()
+                        + 0.907_98 * x(3).powi(2) / k * b.sin(),
+                ]
+            }
+            20 => {
+                let low: f64 = (1..=12).map(|j| x(j) / G20_B[j - 1]).sum();
+                let high: f64 = (13..=24).map(|j| x(j) / G20_B[j - 1]).sum();
+                let k = 0.7302 * 530.0 * 14.7 / 40.0;
+                let mut h: Vec<f64> = (1..=12)
+                    .map(|i| {
+                        x(i + 12) / (G20_B[i + 11] * high)
+                            - G20_C[i - 1] * x(i) / (40.0 * G20_B[i - 1] * low)
+                    })
+                    .collect();
+                h.push(input.iter().sum::<f64>() - 1.0);
+                h.push((1..=12).map(|i| x(i) / G20_D[i - 1]).sum::<f64>() + k * high - 1.671);
+                h
+            }
+            21 => vec![
+                -300.0 * x(3) + 7500.0 * x(5) - 7500.0 * x(6) - 25.0 * x(4) * x(5)
+                    + 25.0 * x(4) * x(6)
+                    + x(3) * x(4),
+                100.0 * x(2) + 155.365 * x(4) + 2500.0 * x(7)
+                    - x(2) * x(4)
+                    - 25.0 * x(4) * x(7)
+                    - 15_536.5,
+                -x(5) + (-x(4) + 900.0).ln(),
+                -x(6) + (x(4) + 300.0).ln(),
+                -x(7) + (-2.0 * x(4) + 700.0).ln(),
+            ],
+            22 => vec![
+                x(5) - 100_000.0 * x(8) + 1e7,
+                x(6) + 100_000.0 * x(8) - 100_000.0 * x(9),
+                x(7) + 100_000.0 * x(9) - 5e7,
+                x(5) + 100_000.0 * x(10) - 3.3e7,
+                x(6) + 100_000.0 * x(11) - 4.4e7,
+                x(7) + 100_000.0 * x(12) - 6.6e7,
+                x(5) - 120.0 * x(2) * x(13),
+                x(6) - 80.0 * x(3) * x(14),
+                x(7) - 40.0 * x(4) * x(15),
+                x(8) - x(11) + x(16),
+                x(9) - x(12) + x(17),
+                -x(18) + (x(10) - 100.0).ln(),
+                -x(19) + (-x(8) + 300.0).ln(),
+                -x(20) + x(16).ln(),
+                -x(21) + (-x(9) + 400.0).ln(),
+                -x(22) + x(17).ln(),
+                -x(8) - x(10) + x(13) * x(18) - x(13) * x(19) + 400.0,
+                x(8) - x(9) - x(11) + x(14) * x(20) - x(14) * x(21) + 400.0,
+                x(9) - x(12) - 4.605_17 * x(15) + x(15) * x(22) + 100.0,
+            ],
+            23 => vec![
+                x(1) + x(2) - x(3) - x(4),
+                0.03 * x(1) + 0.01 * x(2) - x(9) * (x(3) + x(4)),
+                x(3) + x(6) - x(5),
+                x(4) + x(7) - x(8),
+            ],
+            _ => vec![],
+        }
+    }
+}
+
+const G19_A: [[f64; 5]; 10] = [
+    [-16.0, 2.0, 0.0, 1.0, 0.0],
+    [0.0, -2.0, 0.0, 0.4, 2.0],
+    [-3.5, 0.0, 2.0, 0.0, 0.0],
+    [0.0, -2.0, 0.0, -4.0, -1.0],
+    [0.0, -9.0, -2.0, 1.0, -2.8],
+    [2.0, 0.0, -4.0, 0.0, 0.0],
+    [-1.0, -1.0, -1.0, -1.0, -1.0],
+    [-1.0, -2.0, -3.0, -2.0, -1.0],
+    [1.0, 2.0, 3.0, 4.0, 5.0],
+    [1.0, 1.0, 1.0, 1.0, 1.0],
+];
+const G19_B: [f64; 10] = [-40.0, -2.0, -0.25, -4.0, -4.0, -1.0, -40.0, -60.0, 5.0, 1.0];
+const G19_C: [[f64; 5]; 5] = [
+    [30.0, -20.0, -10.0, 32.0, -10.0],
+    [-20.0, 39.0, -6.0, -31.0, 32.0],
+    [-10.0, -6.0, 10.0, -6.0, -10.0],
+    [32.0, -31.0, -6.0, 39.0, -20.0],
+    [-10.0, 32.0, -10.0, -20.0, 30.0],
+];
+const G19_D: [f64; 5] = [4.0, 8.0, 10.0, 6.0, 2.0];
+const G19_E: [f64; 5] = [-15.0, -27.0, -36.0, -18.0, -12.0];
+
+const G20_A: [f64; 24] = [
+    0.0693, 0.0577, 0.05, 0.2, 0.26, 0.55, 0.06, 0.1, 0.12, 0.18, 0.1, 0.09, 0.0693, 0.0577, 0.05,
+    0.2, 0.26, 0.55, 0.06, 0.1, 0.12, 0.18, 0.1, 0.09,
+];
+const G20_B: [f64; 24] = [
+    44.094, 58.12, 58.12, 137.4, 120.9, 170.9, 62.501, 84.94, 133.425, 82.507, 46.07, 60.097,
+    44.094, 58.12, 58.12, 137.4, 120.9, 170.9, 62.501, 84.94, 133.425, 82.507, 46.07, 60.097,
+];
+const G20_C: [f64; 12] = [
+    123.7, 31.7, 45.7, 14.7, 84.7, 27.7, 49.7, 7.1, 2.1, 17.7, 0.85, 0.64,
+];
+const G20_D: [f64; 12] = [
+    31.244, 36.12, 34.784, 92.7, 82.7, 91.6, 56.708, 82.7, 80.8, 64.517, 49.4, 49.1,
+];
+const G20_E: [f64; 6] = [0.1, 0.3, 0.4, 0.3, 0.6, 0.3];
+
+// G16 goes through a chain of intermediate quantities y and c, the inequalities keep every y
+// inside its range
+fn g16(input: &[f64]) -> (f64, Vec<f64>) {
+    let x = |i: usize| input[i - 1];
+    let y1 = x(2) + x(3) + 41.6;
+    let c1 = 0.024 * x(4) - 4.62;
+    let y2 = 12.5 / c1 + 12.0;
+    let c2 = 0.000_353_5 * x(1).powi(2) + 0.5311 * x(1) + 0.087_05 * y2 * x(1);
+    let c3 = 0.052 * x(1) + 78.0 + 0.002_377 * y2 * x(1);
+    let y3 = c2 / c3;
+    let y4 = 19.0 * y3;
+    let c4 =
+        0.047_82 * (x(1) - y3) + 0.1956 * (x(1) - y3).powi(2) / x(2) + 0.6376 * y4 + 1.594 * y3;
+    let c5 = 100.0 * x(2);
+    let c6 = x(1) - y3 - y4;
+    let c7 = 0.950 - c4 / c5;
+    let y5 = c6 * c7;
+    let y6 = x(1) - y5 - y4 - y3;
+    let c8 = (y5 + y4) * 0.995;
+    let y7 = c8 / y1;
+    let y8 = c8 / 3798.0;
+    let c9 = y7 - 0.0663 * y7 / y8 - 0.3153;
+    let y9 = 96.82 / c9 + 0.321 * y1;
+    let y10 = 1.29 * y5 + 1.258 * y4 + 2.29 * y3 + 1.71 * y6;
+    let y11 = 1.71 * x(1) - 0.452 * y4 + 0.580 * y3;
+    let c10 = 12.3 / 752.3;
+    let c11 = (1.75 * y2) * (0.995 * x(1));
+    let c12 = 0.995 * y10 + 1998.0;
+    let y12 = c10 * x(1) + c11 / c12;
+    let y13 = c12 - 1.75 * y2;
+    let y14 = 3623.0 + 64.4 * x(2) + 58.4 * x(3) + 146_312.0 / (y9 + x(5));
+    let c13 = 0.995 * y10 + 60.8 * x(2) + 48.0 * x(4) - 0.1121 * y14 - 5095.0;
+    let y15 = y13 / c13;
+    let y16 = 148_000.0 - 331_000.0 * y15 + 40.0 * y13 - 61.0 * y15 * y13;
+    let c14 = 2324.0 * y10 - 28_740_000.0 * y2;
+    let y17 = 14_130_000.0 - 1328.0 * y10 - 531.0 * y11 + c14 / c12;
+    let c15 = y13 / y15 - y13 / 0.52;
+    let c16 = 1.104 - 0.72 * y15;
+    let c17 = y9 + x(5);
+
+    let objective = 0.000_117 * y14
+        + 0.1365
+        + 0.000_023_58 * y13
+        + 0.000_001_502 * y16
+        + 0.0321 * y12
+        + 0.004_324 * y5
+        + 0.0001 * c15 / c16
+        + 37.48 * y2 / c12
+        - 0.000_000_584_3 * y17;
+
+    let ranges = [
+        (y1, 213.1, 405.23),
+        (y2, 17.505, 1053.6667),
+        (y3, 11.275, 35.03),
+        (y4, 214.228, 665.585),
+        (y5, 7.458, 584.463),
+        (y6, 0.961, 265.916),
+        (y7, 1.612, 7.046),
+        (y8, 0.146, 0.222),
+        (y9, 107.99, 273.366),
+        (y10, 922.693, 1286.105),
+        (y11, 926.832, 1444.046),
+        (y12, 18.766, 537.141),
+        (y13, 1072.163, 3247.039),
+        (y14, 8961.448, 26844.086),
+        (y15, 0.063, 0.386),
+        (y16, 71084.33, 140_000.0),
+        (y17, 2_802_713.0, 12_146_108.0),
+    ];
+    let mut inequality = vec![
+        0.28 / 0.72 * y5 - y4,
+        x(3) - 1.5 * x(2),
+        3496.0 * y2 / c12 - 21.0,
+        110.6 + y1 - 62212.0 / c17,
+    ];
+    for (y, lower, upper) in ranges {
+        inequality.push(lower - y);
+        inequality.push(y - upper);
+    }
+    (objective, inequality)
+}
+
+impl<const G: usize> Benchmark for Cec2006<G> {
+    const FUNCTION_NAME: &'static str = FUNCTION_NAMES[G - 1];
+    const DIMENSIONS: Option<usize> = Some(VARIABLES[G - 1]);
+
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only for DIMENSIONS, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        let input: Vec<f64> = input.iter().map(|x| f64::from(*x)).collect();
+        Self::objective(&input) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        Self::objective(input)
+    }
+
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        Self::inequality(input)
+    }
+
+    fn get_equality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        Self::equality(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some(Self::envelope())
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        BEST_KNOWN[G - 1].map(|value| value as f32)
+    }
+
+    fn get_known_min_coords(_dim: usize) -> Option<Vec<f32>> {
+        Self::best_known_coords().map(|coords| coords.into_iter().map(|x| x as f32).collect())
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        Self::variable_bounds()
+            .into_iter()
+            .map(|(min, max)| ((min as f32).max(self.min), (max as f32).min(self.max)))
+            .collect()
+    }
+}
+
+impl<const G: usize> Default for Cec2006<G> {
+    fn default() -> Self {
+        assert!((1..=24).contains(&G), "CEC 2006 has problems 1 to 24");
+        let (min, max) = Self::envelope();
+        Self {
+            min,
+            max,
+            dim: VARIABLES[G - 1],
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::double_precision::DoublePrecision;
+    use crate::constraints::total_violation;
+    use crate::evol_arg::de::{De, Strategy, Variant};
+
+    // the best known solution reaches the best known value and is feasible
+    fn check_best_known<const G: usize>() {
+        let problem = Cec2006::<G>::builder().build().unwrap();
+        assert_eq!(problem.get_bounds().len(), problem.get_dim());
+        let (Some(expected), Some(coords)) = (BEST_KNOWN[G - 1], Cec2006::<G>::best_known_coords())
+        else {
+            return;
+        };
+        assert_eq!(coords.len(), problem.get_dim());
+        let problem = DoublePrecision::new(problem);
+        let cost = crate::problem_definitions::ProblemDomain::cost_function(&problem, &coords);
+        let violation = total_violation(&problem, &coords);
+        assert!(
+            (cost - expected).abs() <= 1e-4 * expected.abs().max(1.0),
+            "G{:02}: {} at the best known solution",
+            G,
+            cost
+        );
+        assert!(violation <= 1e-3, "G{:02}: violation {}", G, violation);
+    }
+
+    #[test]
+    fn solved_within_bounds() {
+        let problem = Cec2006::<1>::builder().build().unwrap();
+        let bounds = problem.get_bounds();
+        let mut de = De::new(
+            Variant::Rnd,
+            1,
+            Strategy::Bin,
+            100000,
+            40,
+            0.8,
+            0.9,
+            problem,
+        );
+        de.run();
+        let best = de.get_best().unwrap();
+        // outside the bounds of its variables the best went far below the optimum of -15
+        assert!(best
+            .get_coordinates()
+            .iter()
+            .zip(bounds.iter())
+            .all(|(x, (min, max))| x >= min && x <= max));
+        assert!(best.is_feasible());
+        println!("best: {}", best.get_cost());
+        assert!(best.get_cost() >= -15.001);
+    }
+
+    #[test]
+    fn best_known() {
+        check_best_known::<1>();
+        check_best_known::<2>();
+        check_best_known::<3>();
+        check_best_known::<4>();
+        check_best_known::<5>();
+        check_best_known::<6>();
+        check_best_known::<7>();
+        check_best_known::<8>();
+        check_best_known::<9>();
+        check_best_known::<10>();
+        check_best_known::<11>();
+        check_best_known::<12>();
+        check_best_known::<13>();
+        check_best_known::<14>();
+        check_best_known::<15>();
+        check_best_known::<16>();
+        check_best_known::<17>();
+        check_best_known::<18>();
+        check_best_known::<19>();
+        check_best_known::<20>();
+        check_best_known::<21>();
+        check_best_known::<22>();
+        check_best_known::<23>();
+        check_best_known::<24>();
+    }
+}
@@ -0,0 +1,773 @@
+use std::{fs, path::Path};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::benchmarks::cec::functions::{shift_rotate, Basic};
+use crate::benchmarks::traits::{Benchmark, BuilderError, HasBuilder};
+use crate::benchmarks::transformed::{random_orthogonal, TransformError};
+
+// dimensions the reference data are published for
+pub const DIMENSIONS: [usize; 4] = [10, 30, 50, 100];
+
+const FUNCTION_NAMES: [&str; 30] = [
+    "CEC 2017 F1 Shifted and Rotated Bent Cigar function (unofficial data)",
+    "CEC 2017 F2 Shifted and Rotated Sum of Different Power function (unofficial data)",
+    "CEC 2017 F3 Shifted and Rotated Zakharov function (unofficial data)",
+    "CEC 2017 F4 Shifted and Rotated Rosenbrock function (unofficial data)",
+    "CEC 2017 F5 Shifted and Rotated Rastrigin function (unofficial data)",
+    "CEC 2017 F6 Shifted and Rotated Schaffer F7 function (unofficial data)",
+    "CEC 2017 F7 Shifted and Rotated Lunacek Bi-Rastrigin function (unofficial data)",
+    "CEC 2017 F8 Shifted and Rotated Non-Continuous Rastrigin function (unofficial data)",
+    "CEC 2017 F9 Shifted and Rotated Levy function (unofficial data)",
+    "CEC 2017 F10 Shifted and Rotated Schwefel function (unofficial data)",
+    "CEC 2017 F11 Hybrid function 1 (N=3) (unofficial data)",
+    "CEC 2017 F12 Hybrid function 2 (N=3) (unofficial data)",
+    "CEC 2017 F13 Hybrid function 3 (N=3) (unofficial data)",
+    "CEC 2017 F14 Hybrid function 4 (N=4) (unofficial data)",
+    "CEC 2017 F15 Hybrid function 5 (N=4) (unofficial data)",
+    "CEC 2017 F16 Hybrid function 6 (N=4) (unofficial data)",
+    "CEC 2017 F17 Hybrid function 7 (N=5) (unofficial data)",
+    "CEC 2017 F18 Hybrid function 8 (N=5) (unofficial data)",
+    "CEC 2017 F19 Hybrid function 9 (N=5) (unofficial data)",
+    "CEC 2017 F20 Hybrid function 10 (N=6) (unofficial data)",
+    "CEC 2017 F21 Composition function 1 (N=3) (unofficial data)",
+    "CEC 2017 F22 Composition function 2 (N=3) (unofficial data)",
+    "CEC 2017 F23 Composition function 3 (N=4) (unofficial data)",
+    "CEC 2017 F24 Composition function 4 (N=4) (unofficial data)",
+    "CEC 2017 F25 Composition function 5 (N=5) (unofficial data)",
+    "CEC 2017 F26 Composition function 6 (N=5) (unofficial data)",
+    "CEC 2017 F27 Composition function 7 (N=6) (unofficial data)",
+    "CEC 2017 F28 Composition function 8 (N=6) (unofficial data)",
+    "CEC 2017 F29 Composition function 9 (N=3) (unofficial data)",
+    "CEC 2017 F30 Composition function 10 (N=3) (unofficial data)",
+];
+
+enum Component {
+    // basic function and the factor its value is scaled by
+    Basic(Basic, f64),
+    Hybrid(usize),
+}
+
+// CEC 2017 single objective bound constrained suite, F is the number of the function (1 - 30),
+// the optimum value is 100 * F
+// the built in shift vectors, rotation matrices and shuffles are generated from a seed, so the
+// functions are not the official ones and results on them are not comparable to published
+// ones, the names say so, the official data are read by load_data from the input_data directory
+// of the reference code, after which is_official is true
+// source: https://github.com/P-N-Suganthan/CEC2017-BoundContrained
+#[derive(Debug)]
+pub struct Cec2017<const F: usize> {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+    shift: Vec<Vec<f64>>,
+    rotation: Vec<Vec<Vec<f64>>>,
+    shuffle: Vec<Vec<usize>>,
+    optimum: Vec<f32>,
+    official: bool,
+}
+
+impl<const F: usize> HasBuilder<Cec2017<F>> for Cec2017<F> {}
+
+impl<const F: usize> Cec2017<F> {
+    pub fn load_data(mut self, directory: impl AsRef<Path>) -> Result<Self, TransformError> {
+        let directory = directory.as_ref();
+        let dim = self.dim;
+        let components = Self::components();
+
+        let rows = read_rows(directory.join(format!("shift_data_{}.txt", F)))?;
+        self.shift = if components == 1 {
+            let values: Vec<f64> = rows.into_iter().flatten().collect();
+            check_size(dim, values.len())?;
+            vec![values[..dim].to_vec()]
+        } else {
+            check_size(components, rows.len())?;
+            rows.into_iter()
+                .take(components)
+                .map(|row| {
+                    check_size(dim, row.len())?;
+                    Ok(row[..dim].to_vec())
+                })
+                .collect::<Result<_, TransformError>>()?
+        };
+
+        let values: Vec<f64> = read_rows(directory.join(format!("M_{}_D{}.txt", F, dim)))?
+            .into_iter()
+            .flatten()
+            .collect();
+        check_size(components * dim * dim, values.len())?;
+        self.rotation = values
+            .chunks(dim * dim)
+            .take(components)
+            .map(|matrix| matrix.chunks(dim).map(|row| row.to_vec()).collect())
+            .collect();
+
+        let shuffles = Self::shuffles();
+        if shuffles > 0 {
+            let values: Vec<f64> =
+                read_rows(directory.join(format!("shuffle_data_{}_D{}.txt", F, dim)))?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+            check_size(shuffles * dim, values.len())?;
+            // the reference files index from one
+            self.shuffle = values
+                .chunks(dim)
+                .take(shuffles)
+                .map(|shuffle| {
+                    shuffle
+                        .iter()
+                        .map(
+                            |i| match i.fract() == 0.0 && (1.0..=dim as f64).contains(i) {
+                                true => Ok(*i as usize - 1),
+                                false => Err(TransformError::WrongIndex { index: *i, dim }),
+                            },
+                        )
+                        .collect()
+                })
+                .collect::<Result<_, TransformError>>()?;
+        }
+        self.update_optimum();
+        self.official = true;
+        Ok(self)
+    }
+
+    pub fn is_official(&self) -> bool {
+        self.official
+    }
+
+    fn components() -> usize {
+        match F {
+            21 | 22 | 29 | 30 => 3,
+            23 | 24 => 4,
+            25 | 26 => 5,
+            27 | 28 => 6,
+            _ => 1,
+        }
+    }
+
+    fn shuffles() -> usize {
+        match F {
+            11..=20 => 1,
+            29 | 30 => 3,
+            _ => 0,
+        }
+    }
+
+    fn generate_data(&mut self) {
+        let mut rng = StdRng::seed_from_u64((F * 1000 + self.dim) as u64);
+        let dim = self.dim;
+        self.shift = (0..Self::components())
+            .map(|_| {
+                // drawn as f32 so the optimum can be passed to cost_function exactly
+                (0..dim)
+                    .map(|_| f64::from(rng.gen_range(-80f32..=80f32)))
+                    .collect()
+            })
+            .collect();
+        self.rotation = (0..Self::components())
+            .map(|_| {
+                random_orthogonal(dim, &mut rng)
+                    .into_iter()
+                    .map(|row| row.into_iter().map(f64::from).collect())
+                    .collect()
+            })
+            .collect();
+        self.shuffle = (0..Self::shuffles())
+            .map(|_| {
+                let mut shuffle: Vec<usize> = (0..dim).collect();
+                shuffle.shuffle(&mut rng);
+                shuffle
+            })
+            .collect();
+        self.update_optimum();
+    }
+
+    fn update_optimum(&mut self) {
+        self.optimum = self.shift[0].iter().map(|x| *x as f32).collect();
+    }
+
+    fn evaluate(&self, input: &[f64]) -> f64 {
+        let basic = match F {
+            1 => Basic::BentCigar,
+            2 => Basic::SumDiffPow,
+            3 => Basic::Zakharov,
+            4 => Basic::Rosenbrock,
+            5 => Basic::Rastrigin,
+            6 => Basic::SchafferF7,
+            7 => Basic::BiRastrigin,
+            8 => Basic::StepRastrigin,
+            9 => Basic::Levy,
+            10 => Basic::Schwefel,
+            11..=20 => {
+                return hybrid(
+                    F - 10,
+                    input,
+                    &self.shift[0],
+                    &self.rotation[0],
+                    &self.shuffle[0],
+                )
+            }
+            _ => return self.composition(input),
+        };
+        basic.evaluate(input, &self.shift[0], true, Some(&self.rotation[0]))
+    }
+
+    fn composition(&self, input: &[f64]) -> f64 {
+        let (components, deltas): (Vec<Component>, Vec<f64>) = match F {
+            21 => (
+                vec![
+                    Component::Basic(Basic::Rosenbrock, 1.0),
+                    Component::Basic(Basic::Ellips, 1e4 / 1e10),
+                    Component::Basic(Basic::Rastrigin, 1.0),
+                ],
+                vec![10.0, 20.0, 30.0],
+            ),
+            22 => (
+                vec![
+                    Component::Basic(Basic::Rastrigin, 1.0),
+                    Component::Basic(Basic::Griewank, 1e3 / 1e2),
+                    Component::Basic(Basic::Schwefel, 1.0),
+                ],
+                vec![10.0, 20.0, 30.0],
+            ),
+            23 => (
+                vec![
+                    Component::Basic(Basic::Rosenbrock, 1.0),
+                    Component::Basic(Basic::Ackley, 1e3 / 1e2),
+                    Component::Basic(Basic::Schwefel, 1.0),
+                    Component::Basic(Basic::Rastrigin, 1.0),
+                ],
+                vec![10.0, 20.0, 30.0, 40.0],
+            ),
+            24 => (
+                vec![
+                    Component::Basic(Basic::Ackley, 1e3 / 1e2),
+                    Component::Basic(Basic::Ellips, 1e4 / 1e10),
+                    Component::Basic(Basic::Griewank, 1e3 / 1e2),
+                    Component::Basic(Basic::Rastrigin, 1.0),
+                ],
+                vec![10.0, 20.0, 30.0, 40.0],
+            ),
+            25 => (
+                vec![
+                    Component::Basic(Basic::Rastrigin, 1.0),
+                    Component::Basic(Basic::HappyCat, 1.0),
+                    Component::Basic(Basic::Ackley, 1e3 / 1e2),
+                    Component::Basic(Basic::Discus, 1e4 / 1e10),
+                    Component::Basic(Basic::Rosenbrock, 1.0),
+                ],
+                vec![10.0, 20.0, 30.0, 40.0, 50.0],
+            ),
+            26 => (
+                vec![
+                    Component::Basic(Basic::EScaffer6, 1e4 / 2e7),
+                    Component::Basic(Basic::Schwefel, 1.0),
+                    Component::Basic(Basic::Griewank, 1e3 / 1e2),
+                    Component::Basic(Basic::Rosenbrock, 1.0),
+                    Component::Basic(Basic::Rastrigin, 1.0),
+                ],
+                vec![10.0, 20.0, 20.0, 30.0, 40.0],
+            ),
+            27 => (
+                vec![
+                    Component::Basic(Basic::HgBat, 1e4 / 1e3),
+                    Component::Basic(Basic::Rastrigin, 1.0),
+                    Component::Basic(Basic::Schwefel, 1e4 / 4e3),
+                    Component::Basic(Basic::BentCigar, 1e4 / 1e30),
+                    Component::Basic(Basic::Ellips, 1e4 / 1e10),
+                    Component::Basic(Basic::EScaffer6, 1e4 / 2e7),
+                ],
+                vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0],
+            ),
+            28 => (
+                vec![
+                    Component::Basic(Basic::Ackley, 1e3 / 1e2),
+                    Component::Basic(Basic::Griewank, 1e3 / 1e2),
+                    Component::Basic(Basic::Discus, 1e4 / 1e10),
+                    Component::Basic(Basic::Rosenbrock, 1.0),
+                    Component::Basic(Basic::HappyCat, 1.0),
+                    Component::Basic(Basic::EScaffer6, 1e4 / 2e7),
+                ],
+                vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0],
+            ),
+            29 => (
+                vec![
+                    Component::Hybrid(5),
+                    Component::Hybrid(6),
+                    Component::Hybrid(7),
+                ],
+                vec![10.0, 30.0, 50.0],
+            ),
+            _ => (
+                vec![
+                    Component::Hybrid(5),
+                    Component::Hybrid(8),
+                    Component::Hybrid(9),
+                ],
+                vec![10.0, 30.0, 50.0],
+            ),
+        };
+
+        let n = input.len() as f64;
+        let (values, weights): (Vec<f64>, Vec<f64>) = components
+            .iter()
+            .enumerate()
+            .map(|(i, component)| {
+                let shift = &self.shift[i];
+                let value = match component {
+                    Component::Basic(basic, factor) => {
+                        factor * basic.evaluate(input, shift, true, Some(&self.rotation[i]))
+                    }
+                    Component::Hybrid(id) => {
+                        hybrid(*id, input, shift, &self.rotation[i], &self.shuffle[i])
+                    }
+                };
+                let distance: f64 = input
+                    .iter()
+                    .zip(shift.iter())
+                    .map(|(x, o)| (x - o).powi(2))
+                    .sum();
+                let weight = if distance != 0.0 {
+                    (1.0 / distance).sqrt() * (-distance / 2.0 / n / deltas[i].powi(2)).exp()
+                } else {
+                    1e99
+                };
+                (value + 100.0 * i as f64, weight)
+            })
+            .unzip();
+        let weight_sum: f64 = weights.iter().sum();
+        if weights.iter().all(|w| *w == 0.0) {
+            return values.iter().sum::<f64>() / values.len() as f64;
+        }
+        values
+            .iter()
+            .zip(weights.iter())
+            .map(|(value, weight)| weight / weight_sum * value)
+            .sum()
+    }
+}
+
+// the shifted and rotated input is shuffled and split into groups, one for every function
+fn hybrid(
+    id: usize,
+    input: &[f64],
+    shift: &[f64],
+    rotation: &[Vec<f64>],
+    shuffle: &[usize],
+) -> f64 {
+    let (proportions, functions): (&[f64], &[Basic]) = match id {
+        1 => (
+            &[0.2, 0.4, 0.4],
+            &[Basic::Zakharov, Basic::Rosenbrock, Basic::Rastrigin],
+        ),
+        2 => (
+            &[0.3, 0.3, 0.4],
+            &[Basic::Ellips, Basic::Schwefel, Basic::BentCigar],
+        ),
+        3 => (
+            &[0.3, 0.3, 0.4],
+            &[Basic::BentCigar, Basic::Rosenbrock, Basic::BiRastrigin],
+        ),
+        4 => (
+            &[0.2, 0.2, 0.2, 0.4],
+            &[
+                Basic::Ellips,
+                Basic::Ackley,
+                Basic::SchafferF7,
+                Basic::Rastrigin,
+            ],
+        ),
+        5 => (
+            &[0.2, 0.2, 0.3, 0.3],
+            &[
+                Basic::BentCigar,
+                Basic::HgBat,
+                Basic::Rastrigin,
+                Basic::Rosenbrock,
+            ],
+        ),
+        6 => (
+            &[0.2, 0.2, 0.3, 0.3],
+            &[
+                Basic::EScaffer6,
+                Basic::HgBat,
+                Basic::Rosenbrock,
+                Basic::Schwefel,
+            ],
+        ),
+        7 => (
+            &[0.1, 0.2, 0.2, 0.2, 0.3],
+            &[
+                Basic::Katsuura,
+                Basic::Ackley,
+                Basic::GrieRosen,
+                Basic::Schwefel,
+                Basic::Rastrigin,
+            ],
+        ),
+        8 => (
+            &[0.2, 0.2, 0.2, 0.2, 0.2],
+            &[
+                Basic::Ellips,
+                Basic::Ackley,
+                Basic::Rastrigin,
+                Basic::HgBat,
+                Basic::Discus,
+            ],
+        ),
+        9 => (
+            &[0.2, 0.2, 0.2, 0.2, 0.2],
+            &[
+                Basic::BentCigar,
+                Basic::Rastrigin,
+                Basic::GrieRosen,
+                Basic::Weierstrass,
+                Basic::EScaffer6,
+            ],
+        ),
+        _ => (
+            &[0.1, 0.1, 0.2, 0.2, 0.2, 0.2],
+            &[
+                Basic::HgBat,
+                Basic::Katsuura,
+                Basic::Ackley,
+                Basic::Rastrigin,
+                Basic::Schwefel,
+                Basic::SchafferF7,
+            ],
+        ),
+    };
+
+    let z = shift_rotate(input, shift, true, Some(rotation), 1.0);
+    let shuffled: Vec<f64> = shuffle.iter().map(|i| z[*i]).collect();
+    let n = input.len();
+    let mut sizes: Vec<usize> = proportions[..proportions.len() - 1]
+        .iter()
+        .map(|p| (p * n as f64).ceil() as usize)
+        .collect();
+    sizes.push(n.saturating_sub(sizes.iter().sum()));
+
+    let mut start = 0;
+    functions
+        .iter()
+        .zip(sizes)
+        .map(|(function, size)| {
+            let end = (start + size).min(n);
+            let group = &shuffled[start..end];
+            start = end;
+            if group.is_empty() {
+                0.0
+            } else {
+                function.evaluate(group, shift, false, None)
+            }
+        })
+        .sum()
+}
+
+fn read_rows(path: impl AsRef<Path>) -> Result<Vec<Vec<f64>>, TransformError> {
+    fs::read_to_string(path)
+        .map_err(TransformError::Io)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.split_whitespace()
+                .map(|value| {
+                    value
+                        .parse::<f64>()
+                        .map_err(|_| TransformError::Parse(value.to_string()))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn check_size(expected: usize, found: usize) -> Result<(), TransformError> {
+    if found < expected {
+        Err(TransformError::WrongSize { expected, found })
+    } else {
+        Ok(())
+    }
+}
+
+impl<const F: usize> Benchmark for Cec2017<F> {
+    const FUNCTION_NAME: &'static str = FUNCTION_NAMES[F - 1];
+
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min.or(Some(100f32 * F as f32))
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords
+            .as_deref()
+            .or(Some(self.optimum.as_slice()))
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // the data depend on the dimension and are generated again, loaded data keep theirs
+    fn set_dim(mut self, value: usize) -> Self {
+        if !self.official {
+            self.dim = value;
+            self.generate_data();
+        }
+        self
+    }
+
+    fn try_set_dim(self, value: usize) -> Result<Self, BuilderError> {
+        match self.official && value != self.dim {
+            true => Err(BuilderError::WrongDim {
+                expected: self.dim,
+                found: value,
+            }),
+            false => Ok(self.set_dim(value)),
+        }
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        let input: Vec<f64> = input.iter().map(|x| f64::from(*x)).collect();
+        self.cost_function_f64(&input) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        self.evaluate(input) + 100.0 * F as f64
+    }
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-100f32, 100f32))
+    }
+}
+
+impl<const F: usize> Default for Cec2017<F> {
+    fn default() -> Self {
+        assert!((1..=30).contains(&F), "CEC 2017 has functions 1 to 30");
+        let mut default = Self {
+            min: -100f32,
+            max: 100f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+            shift: vec![],
+            rotation: vec![],
+            shuffle: vec![],
+            optimum: vec![],
+            official: false,
+        };
+        default.generate_data();
+        default
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    fn build<const F: usize>(dimensions: usize) -> Cec2017<F> {
+        Cec2017::<F>::builder()
+            .minimum(-100f32)
+            .maximum(100f32)
+            .dimensions(dimensions)
+            .build()
+            .unwrap()
+    }
+
+    fn check_optimum<const F: usize>() {
+        for dimensions in DIMENSIONS {
+            let problem = build::<F>(dimensions);
+            let optimum = problem.get_expected_min_coords().unwrap().to_vec();
+            assert_eq!(optimum.len(), dimensions);
+            let expected = problem.get_expected_min().unwrap();
+            let cost = Benchmark::cost_function(&problem, &optimum);
+            assert!(
+                (cost - expected).abs() < 1e-1,
+                "{}: {} at the optimum",
+                Cec2017::<F>::FUNCTION_NAME,
+                cost
+            );
+            let random = Benchmark::cost_function(&problem, &problem.get_random());
+            assert!(random > expected, "{}", Cec2017::<F>::FUNCTION_NAME);
+        }
+    }
+
+    #[test]
+    fn unimodal_and_multimodal() {
+        check_optimum::<1>();
+        check_optimum::<2>();
+        check_optimum::<3>();
+        check_optimum::<4>();
+        check_optimum::<5>();
+        check_optimum::<6>();
+        check_optimum::<7>();
+        check_optimum::<8>();
+        check_optimum::<9>();
+        check_optimum::<10>();
+    }
+
+    #[test]
+    fn hybrid() {
+        check_optimum::<11>();
+        check_optimum::<12>();
+        check_optimum::<13>();
+        check_optimum::<14>();
+        check_optimum::<15>();
+        check_optimum::<16>();
+        check_optimum::<17>();
+        check_optimum::<18>();
+        check_optimum::<19>();
+        check_optimum::<20>();
+    }
+
+    #[test]
+    fn composition() {
+        check_optimum::<21>();
+        check_optimum::<22>();
+        check_optimum::<23>();
+        check_optimum::<24>();
+        check_optimum::<25>();
+        check_optimum::<26>();
+        check_optimum::<27>();
+        check_optimum::<28>();
+        check_optimum::<29>();
+        check_optimum::<30>();
+    }
+
+    #[test]
+    fn fixed_data() {
+        let a = build::<14>(10);
+        let b = build::<14>(10);
+        let input = a.get_random();
+        assert_eq!(
+            Benchmark::cost_function(&a, &input),
+            Benchmark::cost_function(&b, &input)
+        );
+        assert_ne!(
+            build::<14>(30).get_expected_min_coords().unwrap()[..10],
+            a.get_expected_min_coords().unwrap()[..]
+        );
+    }
+
+    // the expected values follow the definitions in cec17_func.cpp of the reference code, worked
+    // out by hand for a zero shift and no rotation, the data files are in the official format
+    #[test]
+    fn reference_values() {
+        let directory = std::env::temp_dir().join("heuristics_cec2017_reference");
+        fs::create_dir_all(&directory).unwrap();
+        let shift = ["0"; 10].join(" ");
+        let rotation: Vec<String> = (0..10)
+            .map(|i| {
+                (0..10)
+                    .map(|j| if i == j { "1" } else { "0" })
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .collect();
+        for function in [1, 3, 4, 5] {
+            fs::write(
+                directory.join(format!("shift_data_{}.txt", function)),
+                &shift,
+            )
+            .unwrap();
+            fs::write(
+                directory.join(format!("M_{}_D10.txt", function)),
+                rotation.join("\n"),
+            )
+            .unwrap();
+        }
+        let input: Vec<f64> = (0..10).map(f64::from).collect();
+
+        let bent_cigar = build::<1>(10).load_data(&directory).unwrap();
+        assert_eq!(bent_cigar.cost_function_f64(&input), 285000100.0);
+        let zakharov = build::<3>(10).load_data(&directory).unwrap();
+        assert_eq!(zakharov.cost_function_f64(&input), 741228435.0);
+        let rosenbrock = build::<4>(10).load_data(&directory).unwrap();
+        assert!((rosenbrock.cost_function_f64(&input) - 408.029892812474).abs() < 1e-9);
+        let rastrigin = build::<5>(10).load_data(&directory).unwrap();
+        assert!((rastrigin.cost_function_f64(&input) - 593.082617147886).abs() < 1e-9);
+    }
+
+    #[test]
+    fn load_data() {
+        let directory = std::env::temp_dir().join("heuristics_cec2017");
+        fs::create_dir_all(&directory).unwrap();
+        let shift: Vec<String> = (0..100).map(|i| format!("{}", i as f64 / 10.0)).collect();
+        fs::write(directory.join("shift_data_11.txt"), shift.join(" ")).unwrap();
+        let rotation: Vec<String> = (0..10)
+            .map(|i| {
+                (0..10)
+                    .map(|j| if i == j { "1" } else { "0" })
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .collect();
+        fs::write(directory.join("M_11_D10.txt"), rotation.join("\n")).unwrap();
+        let shuffle: Vec<String> = (1..=10).rev().map(|i| i.to_string()).collect();
+        fs::write(directory.join("shuffle_data_11_D10.txt"), shuffle.join(" ")).unwrap();
+
+        assert!(!build::<11>(10).is_official());
+        let problem = build::<11>(10).load_data(&directory).unwrap();
+        assert!(problem.is_official());
+        let optimum: Vec<f32> = (0..10).map(|i| i as f32 / 10f32).collect();
+        assert_eq!(problem.get_expected_min_coords().unwrap(), &optimum[..]);
+        assert!((Benchmark::cost_function(&problem, &optimum) - 1100f32).abs() < 1e-3);
+
+        // the loaded data are for 10 dimensions only
+        assert!(matches!(
+            problem.try_set_dim(30),
+            Err(BuilderError::WrongDim {
+                expected: 10,
+                found: 30
+            })
+        ));
+        let problem = build::<11>(10).load_data(&directory).unwrap().set_dim(30);
+        assert_eq!(problem.get_dim(), 10);
+        assert_eq!(problem.get_expected_min_coords().unwrap(), &optimum[..]);
+        assert!(build::<11>(10).try_set_dim(30).is_ok());
+
+        for wrong in ["0", "11", "2.5"] {
+            let mut shuffle = shuffle.clone();
+            shuffle[3] = wrong.to_string();
+            fs::write(directory.join("shuffle_data_11_D10.txt"), shuffle.join(" ")).unwrap();
+            assert!(matches!(
+                build::<11>(10).load_data(&directory),
+                Err(TransformError::WrongIndex { dim: 10, .. })
+            ));
+        }
+
+        assert!(matches!(
+            build::<11>(30).load_data(&directory),
+            Err(TransformError::Io(_))
+        ));
+    }
+}
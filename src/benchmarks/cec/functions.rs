@@ -0,0 +1,338 @@
+use std::f64::consts::{E, PI};
+
+// basic functions of the CEC suites, each one shrinks its input to its own search range
+// source: https://github.com/P-N-Suganthan/CEC2017-BoundContrained
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Basic {
+    BentCigar,
+    SumDiffPow,
+    Zakharov,
+    Rosenbrock,
+    Rastrigin,
+    SchafferF7,
+    BiRastrigin,
+    StepRastrigin,
+    Levy,
+    Schwefel,
+    Ellips,
+    Discus,
+    HgBat,
+    HappyCat,
+    Griewank,
+    Katsuura,
+    Ackley,
+    Weierstrass,
+    GrieRosen,
+    EScaffer6,
+}
+
+impl Basic {
+    fn shrink_rate(&self) -> f64 {
+        match self {
+            Self::Rosenbrock => 2.048 / 100.0,
+            Self::Rastrigin | Self::StepRastrigin => 5.12 / 100.0,
+            Self::BiRastrigin => 10.0 / 100.0,
+            Self::Schwefel => 1000.0 / 100.0,
+            Self::HgBat | Self::HappyCat | Self::Katsuura | Self::GrieRosen => 5.0 / 100.0,
+            Self::Griewank => 600.0 / 100.0,
+            Self::Weierstrass => 0.5 / 100.0,
+            _ => 1.0,
+        }
+    }
+
+    // shift is also the reference for the sign flip of the bi-Rastrigin function, it is
+    // subtracted only when shifted is set
+    pub(crate) fn evaluate(
+        &self,
+        input: &[f64],
+        shift: &[f64],
+        shifted: bool,
+        rotation: Option<&[Vec<f64>]>,
+    ) -> f64 {
+        match self {
+            Self::BiRastrigin => bi_rastrigin(input, shift, shifted, rotation),
+            Self::StepRastrigin => {
+                let stepped: Vec<f64> = input
+                    .iter()
+                    .zip(shift.iter())
+                    .map(|(x, o)| {
+                        if (x - o).abs() > 0.5 {
+                            o + (2.0 * (x - o) + 0.5).floor() / 2.0
+                        } else {
+                            *x
+                        }
+                    })
+                    .collect();
+                rastrigin(&shift_rotate(
+                    &stepped,
+                    shift,
+                    shifted,
+                    rotation,
+                    self.shrink_rate(),
+                ))
+            }
+            _ => {
+                let z = shift_rotate(input, shift, shifted, rotation, self.shrink_rate());
+                match self {
+                    Self::BentCigar => bent_cigar(&z),
+                    Self::SumDiffPow => sum_diff_pow(&z),
+                    Self::Zakharov => zakharov(&z),
+                    Self::Rosenbrock => rosenbrock(&z),
+                    Self::Rastrigin => rastrigin(&z),
+                    Self::SchafferF7 => schaffer_f7(&z),
+                    Self::Levy => levy(&z),
+                    Self::Schwefel => schwefel(&z),
+                    Self::Ellips => ellips(&z),
+                    Self::Discus => discus(&z),
+                    Self::HgBat => hgbat(&z),
+                    Self::HappyCat => happycat(&z),
+                    Self::Griewank => griewank(&z),
+                    Self::Katsuura => katsuura(&z),
+                    Self::Ackley => ackley(&z),
+                    Self::Weierstrass => weierstrass(&z),
+                    Self::GrieRosen => grie_rosen(&z),
+                    Self::EScaffer6 => escaffer6(&z),
+                    Self::BiRastrigin | Self::StepRastrigin => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn shift_rotate(
+    input: &[f64],
+    shift: &[f64],
+    shifted: bool,
+    rotation: Option<&[Vec<f64>]>,
+    rate: f64,
+) -> Vec<f64> {
+    let moved: Vec<f64> = input
+        .iter()
+        .zip(shift.iter())
+        .map(|(x, o)| if shifted { (x - o) * rate } else { x * rate })
+        .collect();
+    match rotation {
+        Some(rotation) => rotate(&moved, rotation),
+        None => moved,
+    }
+}
+
+pub(crate) fn rotate(input: &[f64], rotation: &[Vec<f64>]) -> Vec<f64> {
+    rotation
+        .iter()
+        .map(|row| row.iter().zip(input.iter()).map(|(m, x)| m * x).sum())
+        .collect()
+}
+
+fn bent_cigar(z: &[f64]) -> f64 {
+    z[0].powi(2) + z[1..].iter().map(|z| 1e6 * z.powi(2)).sum::<f64>()
+}
+
+fn sum_diff_pow(z: &[f64]) -> f64 {
+    z.iter()
+        .enumerate()
+        .map(|(i, z)| z.abs().powi(i as i32 + 1))
+        .sum()
+}
+
+fn zakharov(z: &[f64]) -> f64 {
+    let squares: f64 = z.iter().map(|z| z.powi(2)).sum();
+    let weighted: f64 = z
+        .iter()
+        .enumerate()
+        .map(|(i, z)| 0.5 * (i + 1) as f64 * z)
+        .sum();
+    squares + weighted.powi(2) + weighted.powi(4)
+}
+
+fn rosenbrock(z: &[f64]) -> f64 {
+    let z: Vec<f64> = z.iter().map(|z| z + 1.0).collect();
+    z.windows(2)
+        .map(|pair| 100.0 * (pair[0].powi(2) - pair[1]).powi(2) + (pair[0] - 1.0).powi(2))
+        .sum()
+}
+
+fn rastrigin(z: &[f64]) -> f64 {
+    z.iter()
+        .map(|z| z.powi(2) - 10.0 * (2.0 * PI * z).cos() + 10.0)
+        .sum()
+}
+
+// the reference code reads a stale buffer here, the rotated input is used instead
+fn schaffer_f7(z: &[f64]) -> f64 {
+    if z.len() < 2 {
+        return 0.0;
+    }
+    let sum: f64 = z
+        .windows(2)
+        .map(|pair| {
+            let s = (pair[0].powi(2) + pair[1].powi(2)).sqrt();
+            let t = (50.0 * s.powf(0.2)).sin();
+            s.sqrt() + s.sqrt() * t * t
+        })
+        .sum();
+    let count = (z.len() - 1) as f64;
+    sum * sum / count / count
+}
+
+fn bi_rastrigin(input: &[f64], shift: &[f64], shifted: bool, rotation: Option<&[Vec<f64>]>) -> f64 {
+    let n = input.len() as f64;
+    let (mu0, d) = (2.5, 1.0);
+    let s = 1.0 - 1.0 / (2.0 * (n + 20.0).sqrt() - 8.2);
+    let mu1 = -((mu0 * mu0 - d) / s).sqrt();
+
+    let z: Vec<f64> = input
+        .iter()
+        .zip(shift.iter())
+        .map(|(x, o)| {
+            let y = (if shifted { x - o } else { *x }) * 10.0 / 100.0;
+            if *o < 0.0 {
+                -2.0 * y
+            } else {
+                2.0 * y
+            }
+        })
+        .collect();
+    let first: f64 = z.iter().map(|z| z.powi(2)).sum();
+    let second: f64 = z.iter().map(|z| (z + mu0 - mu1).powi(2)).sum::<f64>() * s + d * n;
+    let rotated = match rotation {
+        Some(rotation) => rotate(&z, rotation),
+        None => z,
+    };
+    let cosines: f64 = rotated.iter().map(|y| (2.0 * PI * y).cos()).sum();
+    first.min(second) + 10.0 * (n - cosines)
+}
+
+fn levy(z: &[f64]) -> f64 {
+    let w: Vec<f64> = z.iter().map(|z| 1.0 + z / 4.0).collect();
+    let last = w[w.len() - 1];
+    (PI * w[0]).sin().powi(2)
+        + w[..w.len() - 1]
+            .iter()
+            .map(|w| (w - 1.0).powi(2) * (1.0 + 10.0 * (PI * w + 1.0).sin().powi(2)))
+            .sum::<f64>()
+        + (last - 1.0).powi(2) * (1.0 + (2.0 * PI * last).sin().powi(2))
+}
+
+fn schwefel(z: &[f64]) -> f64 {
+    let n = z.len() as f64;
+    z.iter()
+        .map(|z| {
+            let z = z + 4.209687462275036e2;
+            if z > 500.0 {
+                let m = 500.0 - z % 500.0;
+                -m * m.sqrt().sin() + ((z - 500.0) / 100.0).powi(2) / n
+            } else if z < -500.0 {
+                let m = z.abs() % 500.0 - 500.0;
+                -m * m.abs().sqrt().sin() + ((z + 500.0) / 100.0).powi(2) / n
+            } else {
+                -z * z.abs().sqrt().sin()
+            }
+        })
+        .sum::<f64>()
+        + 4.189828872724338e2 * n
+}
+
+fn ellips(z: &[f64]) -> f64 {
+    let n = z.len();
+    if n < 2 {
+        return z.iter().map(|z| z.powi(2)).sum();
+    }
+    z.iter()
+        .enumerate()
+        .map(|(i, z)| 10f64.powf(6.0 * i as f64 / (n - 1) as f64) * z.powi(2))
+        .sum()
+}
+
+fn discus(z: &[f64]) -> f64 {
+    1e6 * z[0].powi(2) + z[1..].iter().map(|z| z.powi(2)).sum::<f64>()
+}
+
+fn hgbat(z: &[f64]) -> f64 {
+    let n = z.len() as f64;
+    let squares: f64 = z.iter().map(|z| (z - 1.0).powi(2)).sum();
+    let sum: f64 = z.iter().map(|z| z - 1.0).sum();
+    (squares.powi(2) - sum.powi(2)).abs().powf(0.5) + (0.5 * squares + sum) / n + 0.5
+}
+
+fn happycat(z: &[f64]) -> f64 {
+    let n = z.len() as f64;
+    let squares: f64 = z.iter().map(|z| (z - 1.0).powi(2)).sum();
+    let sum: f64 = z.iter().map(|z| z - 1.0).sum();
+    (squares - n).abs().powf(0.25) + (0.5 * squares + sum) / n + 0.5
+}
+
+fn griewank(z: &[f64]) -> f64 {
+    let sum: f64 = z.iter().map(|z| z.powi(2)).sum::<f64>() / 4000.0;
+    let product: f64 = z
+        .iter()
+        .enumerate()
+        .map(|(i, z)| (z / ((i + 1) as f64).sqrt()).cos())
+        .product();
+    sum - product + 1.0
+}
+
+fn katsuura(z: &[f64]) -> f64 {
+    let n = z.len() as f64;
+    let exponent = 10.0 / n.powf(1.2);
+    let product: f64 = z
+        .iter()
+        .enumerate()
+        .map(|(i, z)| {
+            let sum: f64 = (1..=32)
+                .map(|j| {
+                    let power = 2f64.powi(j);
+                    let scaled = power * z;
+                    (scaled - (scaled + 0.5).floor()).abs() / power
+                })
+                .sum();
+            (1.0 + (i + 1) as f64 * sum).powf(exponent)
+        })
+        .product();
+    let factor = 10.0 / n / n;
+    product * factor - factor
+}
+
+fn ackley(z: &[f64]) -> f64 {
+    let n = z.len() as f64;
+    let squares: f64 = z.iter().map(|z| z.powi(2)).sum();
+    let cosines: f64 = z.iter().map(|z| (2.0 * PI * z).cos()).sum();
+    -20.0 * (-0.2 * (squares / n).sqrt()).exp() - (cosines / n).exp() + 20.0 + E
+}
+
+fn weierstrass(z: &[f64]) -> f64 {
+    let (a, b, k_max) = (0.5f64, 3f64, 20);
+    let offset: f64 = (0..=k_max)
+        .map(|k| a.powi(k) * (2.0 * PI * b.powi(k) * 0.5).cos())
+        .sum();
+    z.iter()
+        .map(|z| {
+            (0..=k_max)
+                .map(|k| a.powi(k) * (2.0 * PI * b.powi(k) * (z + 0.5)).cos())
+                .sum::<f64>()
+        })
+        .sum::<f64>()
+        - z.len() as f64 * offset
+}
+
+fn grie_rosen(z: &[f64]) -> f64 {
+    let z: Vec<f64> = z.iter().map(|z| z + 1.0).collect();
+    (0..z.len())
+        .map(|i| {
+            let next = z[(i + 1) % z.len()];
+            let rosen = 100.0 * (z[i].powi(2) - next).powi(2) + (z[i] - 1.0).powi(2);
+            rosen.powi(2) / 4000.0 - rosen.cos() + 1.0
+        })
+        .sum()
+}
+
+fn escaffer6(z: &[f64]) -> f64 {
+    (0..z.len())
+        .map(|i| {
+            let squares = z[i].powi(2) + z[(i + 1) % z.len()].powi(2);
+            let numerator = squares.sqrt().sin().powi(2) - 0.5;
+            let denominator = (1.0 + 0.001 * squares).powi(2);
+            0.5 + numerator / denominator
+        })
+        .sum()
+}
@@ -0,0 +1,3 @@
+pub mod cec2006;
+pub mod cec2017;
+mod functions;
@@ -0,0 +1,159 @@
+use std::f64::consts::PI;
+
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+
+// source: https://www.sfu.ca/~ssurjano/crossit.html
+#[derive(Debug)]
+pub struct CrossInTray {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<CrossInTray> for CrossInTray {}
+
+const MINIMISERS: [[f32; 2]; 4] = [
+    [1.349_406_6, 1.349_406_6],
+    [-1.349_406_6, 1.349_406_6],
+    [1.349_406_6, -1.349_406_6],
+    [-1.349_406_6, -1.349_406_6],
+];
+
+impl Benchmark for CrossInTray {
+    const FUNCTION_NAME: &'static str = "Cross-in-Tray function";
+    const DIMENSIONS: Option<usize> = Some(2);
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords
+            .as_deref()
+            .or(Some(MINIMISERS[0].as_slice()))
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only in 2 dimensions, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    // the exponential overflows f32 near the minimisers
+    // evaluated in f64, the exponential overflows f32 near the corners of the domain
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        self.cost_function_f64(&[f64::from(input[0]), f64::from(input[1])]) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        let (x, y) = (input[0], input[1]);
+        let distance = (x.powi(2) + y.powi(2)).sqrt();
+        let value = (x.sin() * y.sin() * (100f64 - distance / PI).abs().exp()).abs();
+        -0.0001 * (value + 1f64).powf(0.1)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-10f32, 10f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(-2.062_611_8)
+    }
+
+    fn get_all_expected_min_coords(&self) -> Vec<Vec<f32>> {
+        match &self.expected_min_coords {
+            Some(coords) => vec![coords.clone()],
+            None => MINIMISERS.iter().map(|coords| coords.to_vec()).collect(),
+        }
+    }
+}
+
+impl Default for CrossInTray {
+    fn default() -> Self {
+        Self {
+            min: -10f32,
+            max: 10f32,
+            dim: 2usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::traits::BuilderError;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn optimum() {
+        let cross_in_tray = CrossInTray::builder()
+            .minimum(-10f32)
+            .maximum(10f32)
+            .build()
+            .unwrap();
+        let expected = cross_in_tray.get_expected_min().unwrap();
+        assert_eq!(cross_in_tray.get_all_expected_min_coords().len(), 4);
+        for coords in cross_in_tray.get_all_expected_min_coords() {
+            assert!(
+                (cross_in_tray.cost_function(&coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+        }
+        for _ in 0..100 {
+            assert!(
+                cross_in_tray.cost_function(&cross_in_tray.get_random())
+                    >= expected - 1e-4 * expected.abs()
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_dimensions() {
+        let result = CrossInTray::builder()
+            .minimum(-10f32)
+            .maximum(10f32)
+            .dimensions(3)
+            .build();
+        assert!(matches!(
+            result,
+            Err(BuilderError::WrongDim {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+}
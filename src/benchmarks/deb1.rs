@@ -1,9 +1,5 @@
-use std::{
-    f32::consts:: PI,
-    ops::{Div, Mul,}
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
 
 // source:  https://al-roomi.org/benchmarks/unconstrained/n-dimensions/231-deb-s-function-no-01
 //
@@ -66,11 +62,33 @@ impl Benchmark for Deb1 {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        let d = self.dim as f32;
-        -1f32
-            .div(d)
-            .mul(input.iter().map(|&x| 5f32.mul(PI).mul(x).sin().powi(6)).sum::<f32>())
+        deb1(self.dim, input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        deb1(self.dim, input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-1f32, 1f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(-1f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0.1; dim])
+    }
+}
+
+fn deb1<F: Real>(dim: usize, input: &[F]) -> F {
+    let c = F::from_f64;
+    -input
+        .iter()
+        .map(|&x| (c(5.0) * F::PI() * x).sin().powi(6))
+        .sum::<F>()
+        / c(dim as f64)
 }
 
 impl Default for Deb1 {
@@ -156,4 +174,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Deb1::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
@@ -0,0 +1,200 @@
+use crate::benchmarks::registry::DynBenchmark;
+use crate::benchmarks::traits::{local_next_in, random_in, reflect_into};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+use crate::variables::{local_next_mixed, random_mixed, repair_mixed};
+
+// evaluates a benchmark in f64, the solvers see a problem with Item = f64, the domain is the
+// one of the wrapped benchmark read back as the decimal it was written as, so -5.12 stays
+// -5.12 instead of -5.119999885559082
+#[derive(Debug)]
+pub struct DoublePrecision<B>
+where
+    B: DynBenchmark,
+{
+    benchmark: B,
+}
+
+impl<B> DoublePrecision<B>
+where
+    B: DynBenchmark,
+{
+    pub fn new(benchmark: B) -> Self {
+        Self { benchmark }
+    }
+
+    pub fn get_benchmark(&self) -> &B {
+        &self.benchmark
+    }
+
+    pub fn get_bounds(&self) -> Vec<(f64, f64)> {
+        self.benchmark
+            .get_bounds()
+            .into_iter()
+            .map(|(min, max)| (widen(min), widen(max)))
+            .collect()
+    }
+}
+
+impl<B> ProblemDomain for DoublePrecision<B>
+where
+    B: DynBenchmark,
+{
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        widen(self.benchmark.get_min())
+    }
+
+    fn get_maximum(&self) -> f64 {
+        widen(self.benchmark.get_max())
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.benchmark.get_dim()
+    }
+
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        self.benchmark.cost_function_f64(input)
+    }
+
+    fn repair(&self, input: Vec<f64>) -> Vec<f64> {
+        match self.benchmark.get_variable_types() {
+            Some(types) => repair_mixed(&types, input),
+            None => reflect_into(&self.get_bounds(), input),
+        }
+    }
+
+    fn get_inequality_constraints(&self, input: &[f64]) -> Vec<f64> {
+        self.benchmark.get_inequality_constraints_f64(input)
+    }
+
+    fn get_equality_constraints(&self, input: &[f64]) -> Vec<f64> {
+        self.benchmark.get_equality_constraints_f64(input)
+    }
+}
+
+impl<B> HasRandom for DoublePrecision<B>
+where
+    B: DynBenchmark,
+{
+    fn get_random(&self) -> Vec<f64> {
+        match self.benchmark.get_variable_types() {
+            Some(types) => random_mixed(&types),
+            None => random_in(&self.get_bounds()),
+        }
+    }
+}
+
+impl<B> HasLocal for DoublePrecision<B>
+where
+    B: DynBenchmark,
+{
+    fn get_local_next(&self, input: &[f64]) -> Vec<f64> {
+        match self.benchmark.get_variable_types() {
+            Some(types) => local_next_mixed(&types, input),
+            None => local_next_in(
+                &self.get_bounds(),
+                (self.get_minimum(), self.get_maximum()),
+                input,
+            ),
+        }
+    }
+}
+
+// the shortest decimal that rounds to the f32, parsed in f64
+fn widen(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(f64::from(value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{
+        bbob::noiseless::Bbob, fst_dejong::FstDeJong, qing::Qing, registry, rosenbrock::Rosenbrock,
+        schwefel::Schwefel, styblinsky_and_tang::StyblinskyAndTang, traits::Benchmark,
+        traits::HasBuilder,
+    };
+    use crate::evol_arg::de::{De, Strategy, Variant};
+    use crate::solvers::nelder_mead::NelderMead;
+
+    #[test]
+    fn qing_optimum() {
+        let problem = DoublePrecision::new(Qing::builder().dimensions(50).build().unwrap());
+        let coords: Vec<f64> = (0..50).map(|i| (i as f64).sqrt()).collect();
+        let single: Vec<f32> = coords.iter().map(|x| *x as f32).collect();
+        assert!(problem.cost_function(&coords) < 1e-20);
+        assert!(Benchmark::cost_function(problem.get_benchmark(), &single) > 1e-10);
+    }
+
+    #[test]
+    fn single_and_double() {
+        let styblinsky_and_tang = StyblinskyAndTang::builder().dimensions(3).build().unwrap();
+        let schwefel = Schwefel::builder().dimensions(3).build().unwrap();
+        let bbob = Bbob::<3>::builder().dimensions(3).build().unwrap();
+        let input = [1.5f32, -2f32, 0.25f32];
+        let wide = input.map(f64::from);
+        for (single, double) in [
+            (
+                Benchmark::cost_function(&styblinsky_and_tang, &input),
+                Benchmark::cost_function_f64(&styblinsky_and_tang, &wide),
+            ),
+            (
+                Benchmark::cost_function(&schwefel, &input),
+                Benchmark::cost_function_f64(&schwefel, &wide),
+            ),
+            (
+                Benchmark::cost_function(&bbob, &input),
+                Benchmark::cost_function_f64(&bbob, &wide),
+            ),
+        ] {
+            assert!((f64::from(single) - double).abs() <= 1e-4 * double.abs().max(1f64));
+        }
+        assert_eq!(
+            Benchmark::cost_function_f64(&bbob, &wide),
+            bbob.evaluate(&wide)
+        );
+    }
+
+    #[test]
+    fn double_precision() {
+        // the f32 input rounds 1 + 1e-10 to 1 and misses the difference from the optimum
+        let rosenbrock = Rosenbrock::builder().dimensions(2).build().unwrap();
+        let near = [1f64 + 1e-10, 1f64];
+        assert!(Benchmark::cost_function_f64(&rosenbrock, &near) > 0f64);
+        assert_eq!(
+            Benchmark::cost_function(&rosenbrock, &near.map(|x| x as f32)),
+            0f32
+        );
+
+        let problem = DoublePrecision::new(registry::build("rastrigin", 2).unwrap());
+        assert_eq!(problem.get_minimum(), -5.12);
+        assert_eq!(problem.get_bounds(), vec![(-5.12, 5.12); 2]);
+    }
+
+    #[test]
+    fn random_in_bounds() {
+        let problem = DoublePrecision::new(registry::build("six_hump_camel", 2).unwrap());
+        let bounds = problem.get_bounds();
+        for _ in 0..100 {
+            let random = problem.get_random();
+            let local = problem.get_local_next(&random);
+            for (x, (min, max)) in random.iter().chain(local.iter()).zip(bounds.iter().cycle()) {
+                assert!((*min..=*max).contains(x));
+            }
+        }
+    }
+
+    #[test]
+    fn solvers() {
+        let problem = DoublePrecision::new(FstDeJong::builder().dimensions(3).build().unwrap());
+        let mut nelder_mead = NelderMead::new(5000, problem).set_tolerance(0f32);
+        nelder_mead.run();
+        assert!(nelder_mead.get_best_cost().unwrap() < 1e-20);
+
+        let problem = DoublePrecision::new(FstDeJong::builder().dimensions(3).build().unwrap());
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 2000, 10, 0.8, 0.9, problem);
+        de.run();
+        let best: f64 = de.get_best().unwrap().get_cost();
+        assert!(best < 1f64);
+    }
+}
@@ -0,0 +1,365 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::StandardNormal;
+
+use crate::benchmarks::traits::Benchmark;
+use crate::benchmarks::transformed::pivot;
+use crate::variables::VariableType;
+
+#[derive(Debug, Clone)]
+struct Environment {
+    shift: Vec<f32>,
+    movement: Vec<f32>,
+    // changes caused by the evaluation clock, manual ones are not counted
+    epoch: usize,
+    changes: usize,
+    rng: StdRng,
+}
+
+// wraps a benchmark as f(x - s), the shift s moves every `period` evaluations by a vector of
+// length `severity` whose direction is correlated with the previous move by `correlation`, as
+// the peaks of the moving peaks benchmark (Branke 1999), a move that would take the optimum out
+// of the domain bounces off its bounds, the sequence of moves is given by the seed
+// source: https://doi.org/10.1109/CEC.1999.785502
+#[derive(Debug)]
+pub struct Dynamic<B>
+where
+    B: Benchmark,
+{
+    benchmark: B,
+    period: usize,
+    severity: f32,
+    correlation: f32,
+    evaluations: AtomicUsize,
+    environment: Mutex<Environment>,
+}
+
+impl<B> Clone for Dynamic<B>
+where
+    B: Benchmark + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            benchmark: self.benchmark.clone(),
+            period: self.period,
+            severity: self.severity,
+            correlation: self.correlation,
+            evaluations: AtomicUsize::new(self.get_evaluations()),
+            environment: Mutex::new(self.environment.lock().unwrap().clone()),
+        }
+    }
+}
+
+impl<B> Dynamic<B>
+where
+    B: Benchmark,
+{
+    pub fn new(benchmark: B) -> Self {
+        let dim = benchmark.get_dim();
+        Self {
+            benchmark,
+            period: 1000,
+            severity: 1f32,
+            correlation: 0f32,
+            evaluations: AtomicUsize::new(0),
+            environment: Mutex::new(Environment {
+                shift: vec![0f32; dim],
+                movement: vec![0f32; dim],
+                epoch: 0,
+                changes: 0,
+                rng: StdRng::seed_from_u64(0),
+            }),
+        }
+    }
+
+    // evaluations between two changes, 0 leaves only the changes made by change()
+    pub fn set_period(mut self, period: usize) -> Self {
+        self.period = period;
+        self
+    }
+
+    pub fn set_severity(mut self, severity: f32) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    // 0 moves in random directions, 1 keeps the direction of the first move
+    pub fn set_correlation(mut self, correlation: f32) -> Self {
+        self.correlation = correlation.clamp(0f32, 1f32);
+        self
+    }
+
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.environment.get_mut().unwrap().rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    pub fn get_benchmark(&self) -> &B {
+        &self.benchmark
+    }
+
+    pub fn get_evaluations(&self) -> usize {
+        self.evaluations.load(Ordering::Relaxed)
+    }
+
+    pub fn get_changes(&self) -> usize {
+        self.environment.lock().unwrap().changes
+    }
+
+    pub fn get_shift(&self) -> Vec<f32> {
+        self.environment.lock().unwrap().shift.clone()
+    }
+
+    // where the optimum of the wrapped benchmark is now, when it is known
+    pub fn get_current_optimum(&self) -> Option<Vec<f32>> {
+        let shift = self.get_shift();
+        self.benchmark.get_expected_min_coords().map(|coords| {
+            coords
+                .iter()
+                .zip(shift.iter())
+                .map(|(c, s)| c + s)
+                .collect()
+        })
+    }
+
+    // moves the optimum right away, regardless of the evaluation clock
+    pub fn change(&self) {
+        let mut environment = self.environment.lock().unwrap();
+        self.move_optimum(&mut environment);
+    }
+
+    // counts the evaluation and catches up with the changes due by then
+    fn tick(&self) -> Vec<f32> {
+        let evaluation = self.evaluations.fetch_add(1, Ordering::Relaxed);
+        let mut environment = self.environment.lock().unwrap();
+        if let Some(epoch) = evaluation.checked_div(self.period) {
+            while environment.epoch < epoch {
+                environment.epoch += 1;
+                self.move_optimum(&mut environment);
+            }
+        }
+        environment.shift.clone()
+    }
+
+    fn move_optimum(&self, environment: &mut Environment) {
+        let random: Vec<f32> = (0..environment.shift.len())
+            .map(|_| environment.rng.sample(StandardNormal))
+            .collect();
+        let random = scaled(random, self.severity);
+        let combined = random
+            .iter()
+            .zip(environment.movement.iter())
+            .map(|(r, v)| (1f32 - self.correlation) * r + self.correlation * v)
+            .collect();
+        let mut movement = scaled(combined, self.severity);
+        let optimum = pivot(&self.benchmark);
+        let (min, max) = (self.benchmark.get_min(), self.benchmark.get_max());
+        for (i, step) in movement.iter_mut().enumerate() {
+            let moved = optimum[i] + environment.shift[i] + *step;
+            if moved < min || moved > max {
+                *step = -*step;
+            }
+            environment.shift[i] += *step;
+        }
+        environment.movement = movement;
+        environment.changes += 1;
+    }
+
+    fn moved(&self, input: &[f32], shift: &[f32]) -> Vec<f32> {
+        input.iter().zip(shift.iter()).map(|(x, s)| x - s).collect()
+    }
+}
+
+fn scaled(vector: Vec<f32>, length: f32) -> Vec<f32> {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0f32 {
+        return vector;
+    }
+    vector.into_iter().map(|x| x * length / norm).collect()
+}
+
+impl<B> Benchmark for Dynamic<B>
+where
+    B: Benchmark,
+{
+    const FUNCTION_NAME: &'static str = B::FUNCTION_NAME;
+    const DIMENSIONS: Option<usize> = B::DIMENSIONS;
+
+    fn get_min(&self) -> f32 {
+        self.benchmark.get_min()
+    }
+
+    fn get_max(&self) -> f32 {
+        self.benchmark.get_max()
+    }
+
+    fn get_dim(&self) -> usize {
+        self.benchmark.get_dim()
+    }
+
+    // the shift keeps the optimal value
+    fn get_expected_min(&self) -> Option<f32> {
+        self.benchmark.get_expected_min()
+    }
+
+    // the optimum moves, see get_current_optimum
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        None
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.benchmark = self.benchmark.set_min(value);
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.benchmark = self.benchmark.set_max(value);
+        self
+    }
+
+    // the optimum returns to its unshifted position, fixed-dimension benchmarks keep their own
+    fn set_dim(mut self, value: usize) -> Self {
+        self.benchmark = self.benchmark.set_dim(value);
+        let dim = self.benchmark.get_dim();
+        let environment = self.environment.get_mut().unwrap();
+        environment.shift = vec![0f32; dim];
+        environment.movement = vec![0f32; dim];
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.benchmark = self.benchmark.set_expected_min(value);
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.benchmark = self.benchmark.set_expected_min_coords(value);
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        let shift = self.tick();
+        self.benchmark.cost_function(&self.moved(input, &shift))
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        let shift = self.tick();
+        let moved: Vec<f64> = input
+            .iter()
+            .zip(shift.iter())
+            .map(|(x, s)| x - f64::from(*s))
+            .collect();
+        self.benchmark.cost_function_f64(&moved)
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        self.benchmark.get_bounds()
+    }
+
+    fn get_variable_types(&self) -> Option<Vec<VariableType>> {
+        self.benchmark.get_variable_types()
+    }
+
+    // the constraints move with the optimum
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        let shift = self.get_shift();
+        let moved: Vec<f64> = input
+            .iter()
+            .zip(shift.iter())
+            .map(|(x, s)| x - f64::from(*s))
+            .collect();
+        self.benchmark.get_inequality_constraints_f64(&moved)
+    }
+
+    fn get_equality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        let shift = self.get_shift();
+        let moved: Vec<f64> = input
+            .iter()
+            .zip(shift.iter())
+            .map(|(x, s)| x - f64::from(*s))
+            .collect();
+        self.benchmark.get_equality_constraints_f64(&moved)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{branin::Branin, fst_dejong::FstDeJong, traits::HasBuilder};
+
+    fn sphere() -> FstDeJong {
+        FstDeJong::builder().dimensions(3).build().unwrap()
+    }
+
+    fn distance(a: &[f32], b: &[f32]) -> f32 {
+        a.iter()
+            .zip(b.iter())
+            .map(|(x, y)| (x - y).powi(2))
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    #[test]
+    fn moving_optimum() {
+        let problem = Dynamic::new(sphere()).set_period(10).set_severity(0.5);
+        assert_eq!(problem.get_current_optimum(), Some(vec![0f32; 3]));
+        for _ in 0..10 {
+            assert_eq!(problem.cost_function(&[0f32; 3]), 0f32);
+        }
+        // the 11th evaluation sees the first change
+        assert!(problem.cost_function(&[0f32; 3]) > 0f32);
+        assert_eq!(problem.get_changes(), 1);
+        let optimum = problem.get_current_optimum().unwrap();
+        assert!((distance(&optimum, &[0f32; 3]) - 0.5).abs() < 1e-5);
+        assert!(problem.cost_function(&optimum).abs() < 1e-6);
+
+        problem.change();
+        assert_eq!(problem.get_changes(), 2);
+        let moved = problem.get_current_optimum().unwrap();
+        assert!((distance(&moved, &optimum) - 0.5).abs() < 1e-5);
+        assert_eq!(problem.get_evaluations(), 12);
+        assert!(problem.get_expected_min_coords().is_none());
+    }
+
+    #[test]
+    fn bounded_and_seeded() {
+        let run = |seed: u64| {
+            let problem = Dynamic::new(sphere())
+                .set_period(1)
+                .set_severity(2f32)
+                .set_correlation(0.9)
+                .set_seed(seed);
+            (0..500).for_each(|_| {
+                problem.cost_function(&[0f32; 3]);
+            });
+            problem
+        };
+        let (a, b) = (run(4), run(4));
+        assert_eq!(a.get_changes(), 499);
+        assert_eq!(a.get_shift(), b.get_shift());
+        assert_ne!(a.get_shift(), run(5).get_shift());
+        // the optimum bounces off the bounds
+        let (min, max) = (a.get_min(), a.get_max());
+        assert!(a
+            .get_current_optimum()
+            .unwrap()
+            .iter()
+            .all(|x| *x >= min && *x <= max));
+    }
+
+    #[test]
+    fn fixed_dimensions() {
+        let problem = Dynamic::new(Branin::builder().build().unwrap())
+            .set_period(1)
+            .set_dim(5);
+        assert_eq!(problem.get_shift().len(), 2);
+        (0..10).for_each(|_| {
+            problem.cost_function(&[0f32; 2]);
+        });
+        assert_eq!(problem.get_changes(), 9);
+    }
+}
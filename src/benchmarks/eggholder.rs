@@ -0,0 +1,155 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// source: https://www.sfu.ca/~ssurjano/egg.html
+#[derive(Debug)]
+pub struct Eggholder {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Eggholder> for Eggholder {}
+
+const MINIMISERS: [[f32; 2]; 1] = [[512f32, 404.231_8]];
+
+impl Benchmark for Eggholder {
+    const FUNCTION_NAME: &'static str = "Eggholder function";
+    const DIMENSIONS: Option<usize> = Some(2);
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords
+            .as_deref()
+            .or(Some(MINIMISERS[0].as_slice()))
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only in 2 dimensions, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        eggholder(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        eggholder(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-512f32, 512f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(-959.640_7)
+    }
+
+    fn get_all_expected_min_coords(&self) -> Vec<Vec<f32>> {
+        match &self.expected_min_coords {
+            Some(coords) => vec![coords.clone()],
+            None => MINIMISERS.iter().map(|coords| coords.to_vec()).collect(),
+        }
+    }
+}
+
+fn eggholder<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    let (x, y) = (input[0], input[1]);
+    -(y + c(47.0)) * (x / c(2.0) + y + c(47.0)).abs().sqrt().sin()
+        - x * (x - (y + c(47.0))).abs().sqrt().sin()
+}
+
+impl Default for Eggholder {
+    fn default() -> Self {
+        Self {
+            min: -512f32,
+            max: 512f32,
+            dim: 2usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::traits::BuilderError;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn optimum() {
+        let eggholder = Eggholder::builder()
+            .minimum(-512f32)
+            .maximum(512f32)
+            .build()
+            .unwrap();
+        let expected = eggholder.get_expected_min().unwrap();
+        assert_eq!(eggholder.get_all_expected_min_coords().len(), 1);
+        for coords in eggholder.get_all_expected_min_coords() {
+            assert!(
+                (eggholder.cost_function(&coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+        }
+        for _ in 0..100 {
+            assert!(
+                eggholder.cost_function(&eggholder.get_random())
+                    >= expected - 1e-4 * expected.abs()
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_dimensions() {
+        let result = Eggholder::builder()
+            .minimum(-512f32)
+            .maximum(512f32)
+            .dimensions(3)
+            .build();
+        assert!(matches!(
+            result,
+            Err(BuilderError::WrongDim {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+}
@@ -0,0 +1,749 @@
+use std::f64::consts::PI;
+
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::variables::VariableType;
+
+// constrained engineering design problems, every variable has its own domain kept inside
+// [min, max] and the known values are the best feasible designs reported in the literature
+
+fn bounds_within(bounds: &[(f32, f32)], min: f32, max: f32) -> Vec<(f32, f32)> {
+    bounds
+        .iter()
+        .map(|(lower, upper)| (lower.max(min), upper.min(max)))
+        .collect()
+}
+
+// the listed variables are integers, the rest continuous
+fn variable_types(bounds: &[(f32, f32)], integers: &[usize]) -> Vec<VariableType> {
+    bounds
+        .iter()
+        .enumerate()
+        .map(|(i, (min, max))| {
+            if integers.contains(&i) {
+                VariableType::Integer {
+                    min: min.ceil() as i64,
+                    max: max.floor() as i64,
+                }
+            } else {
+                VariableType::Continuous {
+                    min: f64::from(*min),
+                    max: f64::from(*max),
+                }
+            }
+        })
+        .collect()
+}
+
+fn to_f64(input: &[f32]) -> Vec<f64> {
+    input.iter().map(|x| f64::from(*x)).collect()
+}
+
+// cylindrical vessel with hemispherical heads, the variables are the shell and head thickness
+// as whole multiples of 0.0625 inch, the inner radius and the length of the shell
+#[derive(Debug)]
+pub struct PressureVessel {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<PressureVessel> for PressureVessel {}
+
+impl PressureVessel {
+    const BOUNDS: [(f32, f32); 4] = [
+        (1f32, 99f32),
+        (1f32, 99f32),
+        (10f32, 200f32),
+        (10f32, 200f32),
+    ];
+
+    fn thickness(multiples: f64) -> f64 {
+        0.0625 * multiples.round()
+    }
+}
+
+impl Benchmark for PressureVessel {
+    const FUNCTION_NAME: &'static str = "Pressure vessel design";
+    const DIMENSIONS: Option<usize> = Some(4);
+
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only for DIMENSIONS, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        self.cost_function_f64(&to_f64(input)) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        let (shell, head) = (Self::thickness(input[0]), Self::thickness(input[1]));
+        let (radius, length) = (input[2], input[3]);
+        0.6224 * shell * radius * length
+            + 1.7781 * head * radius.powi(2)
+            + 3.1661 * shell.powi(2) * length
+            + 19.84 * shell.powi(2) * radius
+    }
+
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        let (shell, head) = (Self::thickness(input[0]), Self::thickness(input[1]));
+        let (radius, length) = (input[2], input[3]);
+        vec![
+            -shell + 0.0193 * radius,
+            -head + 0.00954 * radius,
+            -PI * radius.powi(2) * length - 4f64 / 3f64 * PI * radius.powi(3) + 1_296_000f64,
+            length - 240f64,
+        ]
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((1f32, 200f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(6059.714)
+    }
+
+    fn get_known_min_coords(_dim: usize) -> Option<Vec<f32>> {
+        Some(vec![13f32, 7f32, 42.098_446, 176.636_6])
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        bounds_within(&Self::BOUNDS, self.min, self.max)
+    }
+
+    fn get_variable_types(&self) -> Option<Vec<VariableType>> {
+        Some(variable_types(&self.get_bounds(), &[0, 1]))
+    }
+}
+
+impl Default for PressureVessel {
+    fn default() -> Self {
+        Self {
+            min: 1f32,
+            max: 200f32,
+            dim: 4usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+// beam welded to a support, the variables are the weld thickness and length and the height and
+// thickness of the bar
+// source: https://doi.org/10.1016/S0166-3615(99)00046-9
+#[derive(Debug)]
+pub struct WeldedBeam {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<WeldedBeam> for WeldedBeam {}
+
+impl WeldedBeam {
+    const BOUNDS: [(f32, f32); 4] = [(0.1, 2f32), (0.1, 10f32), (0.1, 10f32), (0.1, 2f32)];
+    const LOAD: f64 = 6000f64;
+    const LENGTH: f64 = 14f64;
+    const YOUNG: f64 = 30e6;
+    const SHEAR: f64 = 12e6;
+}
+
+impl Benchmark for WeldedBeam {
+    const FUNCTION_NAME: &'static str = "Welded beam design";
+    const DIMENSIONS: Option<usize> = Some(4);
+
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only for DIMENSIONS, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        self.cost_function_f64(&to_f64(input)) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        let (h, l, t, b) = (input[0], input[1], input[2], input[3]);
+        1.10471 * h.powi(2) * l + 0.04811 * t * b * (14f64 + l)
+    }
+
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        let (h, l, t, b) = (input[0], input[1], input[2], input[3]);
+        let (p, length) = (Self::LOAD, Self::LENGTH);
+
+        let primary = p / (2f64.sqrt() * h * l);
+        let moment = p * (length + l / 2f64);
+        let radius = (l.powi(2) / 4f64 + ((h + t) / 2f64).powi(2)).sqrt();
+        let inertia = 2f64 * (2f64.sqrt() * h * l * (l.powi(2) / 12f64 + ((h + t) / 2f64).powi(2)));
+        let secondary = moment * radius / inertia;
+        let shear_stress = (primary.powi(2)
+            + 2f64 * primary * secondary * l / (2f64 * radius)
+            + secondary.powi(2))
+        .sqrt();
+        let bending_stress = 6f64 * p * length / (b * t.powi(2));
+        let deflection = 4f64 * p * length.powi(3) / (Self::YOUNG * t.powi(3) * b);
+        let buckling_load = 4.013 * Self::YOUNG * (t.powi(2) * b.powi(6) / 36f64).sqrt()
+            / length.powi(2)
+            * (1f64 - t / (2f64 * length) * (Self::YOUNG / (4f64 * Self::SHEAR)).sqrt());
+
+        vec![
+            shear_stress - 13600f64,
+            bending_stress - 30000f64,
+            h - b,
+            0.10471 * h.powi(2) + 0.04811 * t * b * (14f64 + l) - 5f64,
+            0.125 - h,
+            deflection - 0.25,
+            p - buckling_load,
+        ]
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((0.1, 10f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(1.724_852)
+    }
+
+    fn get_known_min_coords(_dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0.205_73, 3.470_489, 9.036_624, 0.205_73])
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        bounds_within(&Self::BOUNDS, self.min, self.max)
+    }
+}
+
+impl Default for WeldedBeam {
+    fn default() -> Self {
+        Self {
+            min: 0.1,
+            max: 10f32,
+            dim: 4usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+// coil spring of minimal weight, the variables are the wire diameter, the mean coil diameter
+// and the number of active coils
+// source: https://doi.org/10.1016/S0166-3615(99)00046-9
+#[derive(Debug)]
+pub struct TensionCompressionSpring {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<TensionCompressionSpring> for TensionCompressionSpring {}
+
+impl TensionCompressionSpring {
+    const BOUNDS: [(f32, f32); 3] = [(0.05, 2f32), (0.25, 1.3), (2f32, 15f32)];
+}
+
+impl Benchmark for TensionCompressionSpring {
+    const FUNCTION_NAME: &'static str = "Tension/compression spring design";
+    const DIMENSIONS: Option<usize> = Some(3);
+
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only for DIMENSIONS, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        self.cost_function_f64(&to_f64(input)) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        let (d, coil, n) = (input[0], input[1], input[2]);
+        (n + 2f64) * coil * d.powi(2)
+    }
+
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        let (d, coil, n) = (input[0], input[1], input[2]);
+        vec![
+            1f64 - coil.powi(3) * n / (71785f64 * d.powi(4)),
+            (4f64 * coil.powi(2) - d * coil) / (12566f64 * (coil * d.powi(3) - d.powi(4)))
+                + 1f64 / (5108f64 * d.powi(2))
+                - 1f64,
+            1f64 - 140.45 * d / (coil.powi(2) * n),
+            (d + coil) / 1.5 - 1f64,
+        ]
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((0.05, 15f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0.012_665_23)
+    }
+
+    fn get_known_min_coords(_dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0.051_689, 0.356_718, 11.288_966])
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        bounds_within(&Self::BOUNDS, self.min, self.max)
+    }
+}
+
+impl Default for TensionCompressionSpring {
+    fn default() -> Self {
+        Self {
+            min: 0.05,
+            max: 15f32,
+            dim: 3usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+// gearbox of minimal weight, the variables are the face width, the module of the teeth, the
+// number of teeth on the pinion, the lengths of both shafts between the bearings and the
+// diameters of both shafts
+#[derive(Debug)]
+pub struct SpeedReducer {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<SpeedReducer> for SpeedReducer {}
+
+impl SpeedReducer {
+    const BOUNDS: [(f32, f32); 7] = [
+        (2.6, 3.6),
+        (0.7, 0.8),
+        (17f32, 28f32),
+        (7.3, 8.3),
+        (7.3, 8.3),
+        (2.9, 3.9),
+        (5f32, 5.5),
+    ];
+}
+
+impl Benchmark for SpeedReducer {
+    const FUNCTION_NAME: &'static str = "Speed reducer design";
+    const DIMENSIONS: Option<usize> = Some(7);
+
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only for DIMENSIONS, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        self.cost_function_f64(&to_f64(input)) as f32
+    }
+
+    // the published coefficient 0.7854 is kept rather than pi / 4
+    #[allow(clippy::approx_constant)]
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        let x = input;
+        let teeth = x[2].round();
+        0.7854 * x[0] * x[1].powi(2) * (3.3333 * teeth.powi(2) + 14.9334 * teeth - 43.0934)
+            - 1.508 * x[0] * (x[5].powi(2) + x[6].powi(2))
+            + 7.4777 * (x[5].powi(3) + x[6].powi(3))
+            + 0.7854 * (x[3] * x[5].powi(2) + x[4] * x[6].powi(2))
+    }
+
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        let x = input;
+        let teeth = x[2].round();
+        vec![
+            27f64 / (x[0] * x[1].powi(2) * teeth) - 1f64,
+            397.5 / (x[0] * x[1].powi(2) * teeth.powi(2)) - 1f64,
+            1.93 * x[3].powi(3) / (x[1] * teeth * x[5].powi(4)) - 1f64,
+            1.93 * x[4].powi(3) / (x[1] * teeth * x[6].powi(4)) - 1f64,
+            ((745f64 * x[3] / (x[1] * teeth)).powi(2) + 16.9e6).sqrt() / (110f64 * x[5].powi(3))
+                - 1f64,
+            ((745f64 * x[4] / (x[1] * teeth)).powi(2) + 157.5e6).sqrt() / (85f64 * x[6].powi(3))
+                - 1f64,
+            x[1] * teeth / 40f64 - 1f64,
+            5f64 * x[1] / x[0] - 1f64,
+            x[0] / (12f64 * x[1]) - 1f64,
+            (1.5 * x[5] + 1.9) / x[3] - 1f64,
+            (1.1 * x[6] + 1.9) / x[4] - 1f64,
+        ]
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((0.7, 28f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(2994.471)
+    }
+
+    fn get_known_min_coords(_dim: usize) -> Option<Vec<f32>> {
+        Some(vec![3.5, 0.7, 17f32, 7.3, 7.715_32, 3.350_215, 5.286_654])
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        bounds_within(&Self::BOUNDS, self.min, self.max)
+    }
+
+    fn get_variable_types(&self) -> Option<Vec<VariableType>> {
+        Some(variable_types(&self.get_bounds(), &[2]))
+    }
+}
+
+impl Default for SpeedReducer {
+    fn default() -> Self {
+        Self {
+            min: 0.7,
+            max: 28f32,
+            dim: 7usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+// truss of three bars under a load, the variables are the cross sections of the outer bars and
+// of the middle one, the volume is minimised subject to the stress in every bar
+#[derive(Debug)]
+pub struct ThreeBarTruss {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<ThreeBarTruss> for ThreeBarTruss {}
+
+impl ThreeBarTruss {
+    const BAR_LENGTH: f64 = 100f64;
+    const LOAD: f64 = 2f64;
+    const STRESS: f64 = 2f64;
+}
+
+impl Benchmark for ThreeBarTruss {
+    const FUNCTION_NAME: &'static str = "Three-bar truss design";
+    const DIMENSIONS: Option<usize> = Some(2);
+
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only for DIMENSIONS, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        self.cost_function_f64(&to_f64(input)) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        (2f64 * 2f64.sqrt() * input[0] + input[1]) * Self::BAR_LENGTH
+    }
+
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        let (outer, middle) = (input[0], input[1]);
+        let (p, sigma) = (Self::LOAD, Self::STRESS);
+        let denominator = 2f64.sqrt() * outer.powi(2) + 2f64 * outer * middle;
+        vec![
+            (2f64.sqrt() * outer + middle) / denominator * p - sigma,
+            middle / denominator * p - sigma,
+            1f64 / (2f64.sqrt() * middle + outer) * p - sigma,
+        ]
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((0f32, 1f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(263.895_84)
+    }
+
+    fn get_known_min_coords(_dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0.788_675_1, 0.408_248_3])
+    }
+}
+
+impl Default for ThreeBarTruss {
+    fn default() -> Self {
+        Self {
+            min: 0f32,
+            max: 1f32,
+            dim: 2usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constraints::total_violation;
+    use crate::problem_definitions::{HasRandom, ProblemDomain};
+
+    fn check<T>(benchmark: T)
+    where
+        T: Benchmark + HasRandom + ProblemDomain<Item = f32>,
+    {
+        let coords = benchmark.get_expected_min_coords().unwrap().to_vec();
+        let expected = benchmark.get_expected_min().unwrap();
+        let cost = Benchmark::cost_function(&benchmark, &coords);
+        let violation = total_violation(&benchmark, &coords);
+        assert!((cost - expected).abs() <= 1e-4 * expected.abs());
+        assert!(violation <= 1e-3);
+        assert!(benchmark
+            .get_bounds()
+            .iter()
+            .zip(coords.iter())
+            .all(|((min, max), x)| min <= x && x <= max));
+        let random = benchmark.get_random();
+        assert_eq!(random.len(), benchmark.get_dim());
+        assert!(benchmark
+            .get_bounds()
+            .iter()
+            .zip(random.iter())
+            .all(|((min, max), x)| min <= x && x <= max));
+    }
+
+    #[test]
+    fn best_known_designs() {
+        check(PressureVessel::builder().build().unwrap());
+        check(WeldedBeam::builder().build().unwrap());
+        check(TensionCompressionSpring::builder().build().unwrap());
+        check(SpeedReducer::builder().build().unwrap());
+        check(ThreeBarTruss::builder().build().unwrap());
+    }
+
+    #[test]
+    fn integer_variables() {
+        let vessel = PressureVessel::builder().build().unwrap();
+        let repaired = ProblemDomain::repair(&vessel, vec![12.6, 0.2, 42.0, 250.0]);
+        assert_eq!(repaired, vec![13f32, 1f32, 42f32, 200f32]);
+        let random = vessel.get_random();
+        assert_eq!(random[0], random[0].round());
+        assert_eq!(random[1], random[1].round());
+
+        let reducer = SpeedReducer::builder().build().unwrap();
+        assert_eq!(reducer.get_random()[2].fract(), 0f32);
+        assert_eq!(
+            Benchmark::cost_function(&reducer, &[3.5, 0.7, 17.2, 7.3, 7.8, 3.4, 5.3]),
+            Benchmark::cost_function(&reducer, &[3.5, 0.7, 17.0, 7.3, 7.8, 3.4, 5.3])
+        );
+    }
+
+    #[test]
+    fn infeasible_design() {
+        let truss = ThreeBarTruss::builder().build().unwrap();
+        assert!(total_violation(&truss, &[0.1, 0.1]) > 0f64);
+        assert_eq!(
+            Benchmark::get_inequality_constraints(&truss, &[0.5, 0.5]).len(),
+            3
+        );
+    }
+}
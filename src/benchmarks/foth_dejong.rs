@@ -1,11 +1,6 @@
-use std::{
-    f32::consts::PI,
-    ops::{Add, Mul, Sub},
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
 use rand::random;
-use rand_distr::num_traits::ToPrimitive;
 
 // source: https://al-roomi.org/benchmarks/unconstrained/n-dimensions/161-quartic-or-modified-4th-de-jong-s-function
 #[derive(Debug)]
@@ -68,12 +63,34 @@ impl Benchmark for FothDejong {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        input
-            .iter()
-            .enumerate()
-            .map(|(i, x)| (i as f32).add(1f32).mul(x.powi(4)).add(random::<f32>().mul(10000000f32)))
-            .sum::<f32>()
+        foth_dejong(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        foth_dejong(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-1.28, 1.28))
+    }
+
+    // without the noise
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn foth_dejong<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| c(i as f64 + 1.0) * x.powi(4) + c(random::<f64>() * 10000000.0))
+        .sum::<F>()
 }
 
 impl Default for FothDejong {
@@ -159,4 +176,18 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn default_domain() {
+        let problem = FothDejong::builder().dimensions(5usize).build().unwrap();
+        assert_eq!(
+            problem.get_min(),
+            FothDejong::get_default_domain(5).unwrap().0
+        );
+        assert_eq!(
+            problem.get_max(),
+            FothDejong::get_default_domain(5).unwrap().1
+        );
+        assert_eq!(problem.get_expected_min(), Some(0f32));
+    }
 }
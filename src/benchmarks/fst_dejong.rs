@@ -1,5 +1,7 @@
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
 
+use crate::problem_definitions::{HasGradient, Real};
+
 #[derive(Debug)]
 pub struct FstDeJong {
     min: f32,
@@ -59,8 +61,34 @@ impl Benchmark for FstDeJong {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        input.iter().map(|x| x * *x).sum()
+        fst_dejong(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        fst_dejong(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5.12, 5.12))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+impl HasGradient for FstDeJong {
+    fn get_gradient(&self, input: &[f32]) -> Vec<f32> {
+        input.iter().map(|x| 2f32 * x).collect()
+    }
+}
+
+fn fst_dejong<F: Real>(input: &[F]) -> F {
+    input.iter().map(|&x| x * x).sum()
 }
 
 impl Default for FstDeJong {
@@ -78,7 +106,7 @@ impl Default for FstDeJong {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::problem_definitions::{HasLocal, HasRandom};
+    use crate::problem_definitions::{numerical_gradient, HasLocal, HasRandom};
 
     #[test]
     fn random() {
@@ -146,4 +174,33 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -5f32 && x < 5f32));
         assert!(new_high.into_iter().all(|x| x > -5f32 && x < 5f32));
     }
+
+    #[test]
+    fn gradient() {
+        let problem = FstDeJong::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(5usize)
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let input = problem.get_random();
+            let analytic = problem.get_gradient(&input);
+            let numerical = numerical_gradient(&problem, &input, 1e-3);
+            assert!(analytic
+                .iter()
+                .zip(numerical.iter())
+                .all(|(a, n)| (a - n).abs() <= 1e-2 * a.abs().max(1f32)));
+        }
+    }
+
+    #[test]
+    fn known_optimum() {
+        let problem = FstDeJong::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
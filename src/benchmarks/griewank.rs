@@ -1,9 +1,7 @@
-use std::
-    ops::Div
-;
+use std::ops::Div;
 
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
-use rand_distr::num_traits::{Float, ToPrimitive};
+use crate::problem_definitions::{HasGradient, Real};
 
 // source: https://gitlab.com/luca.baronti/python_benchmark_functions
 
@@ -66,17 +64,62 @@ impl Benchmark for Griewank {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-       1f32 + input.iter().map(|x| x.powi(2).div(4000f32)).sum::<f32>()
-            - input.iter().enumerate().map(|(i,x)| x.div(
-                        (i as f32 + 1f32)
-                        .sqrt()
-                    )
-                .cos()
-            )
-            .fold(1f32, |acc,cur| acc * cur)
+        griewank(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        griewank(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-600f32, 600f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+impl HasGradient for Griewank {
+    fn get_gradient(&self, input: &[f32]) -> Vec<f32> {
+        let cosines: Vec<f32> = input
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x.div((i as f32 + 1f32).sqrt()).cos())
+            .collect();
+        input
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let root = (i as f32 + 1f32).sqrt();
+                let others = cosines
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .fold(1f32, |acc, (_, cur)| acc * cur);
+                x / 2000f32 + x.div(root).sin() / root * others
+            })
+            .collect()
     }
 }
 
+fn griewank<F: Real>(input: &[F]) -> F {
+    F::one()
+        + input
+            .iter()
+            .map(|x| x.powi(2) / F::from_f64(4000.0))
+            .sum::<F>()
+        - input
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (x / F::from_f64(i as f64 + 1.0).sqrt()).cos())
+            .fold(F::one(), |acc, cur| acc * cur)
+}
+
 impl Default for Griewank {
     fn default() -> Self {
         Self {
@@ -92,7 +135,7 @@ impl Default for Griewank {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::problem_definitions::{HasLocal, HasRandom};
+    use crate::problem_definitions::{numerical_gradient, HasLocal, HasRandom};
 
     #[test]
     fn random() {
@@ -160,4 +203,33 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn gradient() {
+        let problem = Griewank::builder()
+            .minimum(-10f32)
+            .maximum(10f32)
+            .dimensions(5usize)
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let input = problem.get_random();
+            let analytic = problem.get_gradient(&input);
+            let numerical = numerical_gradient(&problem, &input, 1e-3);
+            assert!(analytic
+                .iter()
+                .zip(numerical.iter())
+                .all(|(a, n)| (a - n).abs() <= 1e-2 * a.abs().max(1f32)));
+        }
+    }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Griewank::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
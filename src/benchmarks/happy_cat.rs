@@ -0,0 +1,146 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// alpha = 1/8
+// source: https://doi.org/10.1007/978-3-642-32937-1_1
+#[derive(Debug)]
+pub struct HappyCat {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<HappyCat> for HappyCat {}
+
+impl Benchmark for HappyCat {
+    const FUNCTION_NAME: &'static str = "Happy Cat function";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        happy_cat(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        happy_cat(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-2f32, 2f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![-1f32; dim])
+    }
+}
+
+fn happy_cat<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    let dim = c(input.len() as f64);
+    let squares: F = input.iter().map(|x| x.powi(2)).sum();
+    let sum: F = input.iter().copied().sum();
+    (squares - dim).powi(2).powf(c(0.125)) + (c(0.5) * squares + sum) / dim + c(0.5)
+}
+
+impl Default for HappyCat {
+    fn default() -> Self {
+        Self {
+            min: -2f32,
+            max: 2f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn random_in_range() {
+        let happy_cat = HappyCat::builder()
+            .minimum(-2f32)
+            .maximum(2f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = happy_cat.get_random();
+        let random_2 = happy_cat.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1.into_iter().all(|x| (-2f32..=2f32).contains(&x)));
+    }
+
+    #[test]
+    fn optimum() {
+        for dimensions in [2usize, 5, 10] {
+            let happy_cat = HappyCat::builder()
+                .minimum(-2f32)
+                .maximum(2f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = happy_cat.get_expected_min().unwrap();
+            let coords = happy_cat.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (happy_cat.cost_function(coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(happy_cat.cost_function(&happy_cat.get_random()) >= expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// source: https://www.sfu.ca/~ssurjano/levy.html
+#[derive(Debug)]
+pub struct Levy {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Levy> for Levy {}
+
+impl Benchmark for Levy {
+    const FUNCTION_NAME: &'static str = "Levy function";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        levy(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        levy(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-10f32, 10f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![1f32; dim])
+    }
+}
+
+fn levy<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    let w: Vec<F> = input
+        .iter()
+        .map(|&x| F::one() + (x - F::one()) / c(4.0))
+        .collect();
+    let last = w[w.len() - 1];
+    (F::PI() * w[0]).sin().powi(2)
+        + w[..w.len() - 1]
+            .iter()
+            .map(|&w| {
+                (w - F::one()).powi(2)
+                    * (F::one() + c(10.0) * (F::PI() * w + F::one()).sin().powi(2))
+            })
+            .sum::<F>()
+        + (last - F::one()).powi(2) * (F::one() + (c(2.0) * F::PI() * last).sin().powi(2))
+}
+
+impl Default for Levy {
+    fn default() -> Self {
+        Self {
+            min: -10f32,
+            max: 10f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn random_in_range() {
+        let levy = Levy::builder()
+            .minimum(-10f32)
+            .maximum(10f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = levy.get_random();
+        let random_2 = levy.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1.into_iter().all(|x| (-10f32..=10f32).contains(&x)));
+    }
+
+    #[test]
+    fn optimum() {
+        for dimensions in [2usize, 5, 10] {
+            let levy = Levy::builder()
+                .minimum(-10f32)
+                .maximum(10f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = levy.get_expected_min().unwrap();
+            let coords = levy.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (levy.cost_function(coords) - expected).abs() <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(levy.cost_function(&levy.get_random()) >= expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// source: https://www.sfu.ca/~ssurjano/matya.html
+#[derive(Debug)]
+pub struct Matyas {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Matyas> for Matyas {}
+
+const MINIMISERS: [[f32; 2]; 1] = [[0f32, 0f32]];
+
+impl Benchmark for Matyas {
+    const FUNCTION_NAME: &'static str = "Matyas function";
+    const DIMENSIONS: Option<usize> = Some(2);
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords
+            .as_deref()
+            .or(Some(MINIMISERS[0].as_slice()))
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only in 2 dimensions, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        matyas(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        matyas(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-10f32, 10f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_all_expected_min_coords(&self) -> Vec<Vec<f32>> {
+        match &self.expected_min_coords {
+            Some(coords) => vec![coords.clone()],
+            None => MINIMISERS.iter().map(|coords| coords.to_vec()).collect(),
+        }
+    }
+}
+
+fn matyas<F: Real>(input: &[F]) -> F {
+    let (x, y) = (input[0], input[1]);
+    F::from_f64(0.26) * (x.powi(2) + y.powi(2)) - F::from_f64(0.48) * x * y
+}
+
+impl Default for Matyas {
+    fn default() -> Self {
+        Self {
+            min: -10f32,
+            max: 10f32,
+            dim: 2usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::traits::BuilderError;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn optimum() {
+        let matyas = Matyas::builder()
+            .minimum(-10f32)
+            .maximum(10f32)
+            .build()
+            .unwrap();
+        let expected = matyas.get_expected_min().unwrap();
+        assert_eq!(matyas.get_all_expected_min_coords().len(), 1);
+        for coords in matyas.get_all_expected_min_coords() {
+            assert!(
+                (matyas.cost_function(&coords) - expected).abs() <= 1e-4 * expected.abs().max(1f32)
+            );
+        }
+        for _ in 0..100 {
+            assert!(matyas.cost_function(&matyas.get_random()) >= expected - 1e-4 * expected.abs());
+        }
+    }
+
+    #[test]
+    fn fixed_dimensions() {
+        let result = Matyas::builder()
+            .minimum(-10f32)
+            .maximum(10f32)
+            .dimensions(3)
+            .build();
+        assert!(matches!(
+            result,
+            Err(BuilderError::WrongDim {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+}
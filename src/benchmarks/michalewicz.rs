@@ -1,5 +1,6 @@
-use std::{f32::consts::PI, ops::{Add, Div, Mul}};
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+use std::f32::consts::{FRAC_PI_2, PI};
 
 // source:https://www.sfu.ca/~ssurjano/michal.html
 
@@ -74,25 +75,46 @@ impl Benchmark for Michalewicz {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        println!("inputs: {:?}", input);
-        let result =-input
-            .iter()
-            .enumerate()
-            .map(|(i, x)| {
-                x.sin()
-                    .mul(
-                        (i as f32)
-                        .add(1f32)
-                        .mul(x.powi(2))
-                        .div(PI)
-                        .sin()
-                        .powi(self.m *2)
-                        )
-            })
-            .sum::<f32>();
-        println!("{result}");
-        result
+        michalewicz(self.m, input)
     }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        michalewicz(self.m, input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((0f32, PI))
+    }
+
+    // known for m = 10 only
+    fn get_known_min(dim: usize) -> Option<f32> {
+        match dim {
+            2 => Some(-1.801_303),
+            5 => Some(-4.687_658),
+            10 => Some(-9.660_152),
+            _ => None,
+        }
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        match dim {
+            2 => Some(vec![2.202_906, FRAC_PI_2]),
+            _ => None,
+        }
+    }
+}
+
+fn michalewicz<F: Real>(m: i32, input: &[F]) -> F {
+    -input
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            x.sin()
+                * (F::from_f64(i as f64 + 1.0) * x.powi(2) / F::PI())
+                    .sin()
+                    .powi(m * 2)
+        })
+        .sum::<F>()
 }
 
 impl Default for Michalewicz {
@@ -179,4 +201,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Michalewicz::builder().dimensions(2usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
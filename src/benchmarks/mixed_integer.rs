@@ -0,0 +1,187 @@
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+use crate::variables::{envelope, local_next_mixed, random_mixed, repair_mixed, VariableType};
+
+// mixed-integer variants of sphere and ellipsoid, continuous variables in [-5, 5], integers in
+// [-10, 10] and categorical variables with 4 levels where only level 0 is free of penalty,
+// the optimum is 0 at the origin
+
+fn variable_types(continuous: usize, integer: usize, categorical: usize) -> Vec<VariableType> {
+    std::iter::repeat_n(
+        VariableType::Continuous {
+            min: -5.0,
+            max: 5.0,
+        },
+        continuous,
+    )
+    .chain(std::iter::repeat_n(
+        VariableType::Integer { min: -10, max: 10 },
+        integer,
+    ))
+    .chain(std::iter::repeat_n(
+        VariableType::Categorical { levels: 4 },
+        categorical,
+    ))
+    .collect()
+}
+
+fn categorical_penalty(types: &[VariableType], input: &[f32]) -> f32 {
+    types
+        .iter()
+        .zip(input.iter())
+        .filter(|(kind, &x)| matches!(kind, VariableType::Categorical { .. }) && x != 0f32)
+        .count() as f32
+}
+
+#[derive(Debug, Clone)]
+pub struct MixedSphere {
+    types: Vec<VariableType>,
+}
+
+impl MixedSphere {
+    pub fn new(continuous: usize, integer: usize, categorical: usize) -> Self {
+        Self {
+            types: variable_types(continuous, integer, categorical),
+        }
+    }
+
+    pub fn get_variable_types(&self) -> &[VariableType] {
+        &self.types
+    }
+}
+
+impl ProblemDomain for MixedSphere {
+    type Item = f32;
+
+    fn get_minimum(&self) -> f32 {
+        envelope::<f32>(&self.types).0
+    }
+
+    fn get_maximum(&self) -> f32 {
+        envelope::<f32>(&self.types).1
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.types.len()
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        let input = repair_mixed(&self.types, input.to_vec());
+        let sphere: f32 = self
+            .types
+            .iter()
+            .zip(input.iter())
+            .filter(|(kind, _)| !matches!(kind, VariableType::Categorical { .. }))
+            .map(|(_, x)| x.powi(2))
+            .sum();
+        sphere + categorical_penalty(&self.types, &input)
+    }
+
+    fn repair(&self, input: Vec<f32>) -> Vec<f32> {
+        repair_mixed(&self.types, input)
+    }
+}
+
+impl HasRandom for MixedSphere {
+    fn get_random(&self) -> Vec<f32> {
+        random_mixed(&self.types)
+    }
+}
+
+impl HasLocal for MixedSphere {
+    fn get_local_next(&self, input: &[f32]) -> Vec<f32> {
+        local_next_mixed(&self.types, input)
+    }
+}
+
+// ill-conditioned variant, weights grow from 1 to 10^6 over the ordinal variables
+#[derive(Debug, Clone)]
+pub struct MixedEllipsoid {
+    types: Vec<VariableType>,
+}
+
+impl MixedEllipsoid {
+    pub fn new(continuous: usize, integer: usize, categorical: usize) -> Self {
+        Self {
+            types: variable_types(continuous, integer, categorical),
+        }
+    }
+
+    pub fn get_variable_types(&self) -> &[VariableType] {
+        &self.types
+    }
+}
+
+impl ProblemDomain for MixedEllipsoid {
+    type Item = f32;
+
+    fn get_minimum(&self) -> f32 {
+        envelope::<f32>(&self.types).0
+    }
+
+    fn get_maximum(&self) -> f32 {
+        envelope::<f32>(&self.types).1
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.types.len()
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        let input = repair_mixed(&self.types, input.to_vec());
+        let ordinal: Vec<f32> = self
+            .types
+            .iter()
+            .zip(input.iter())
+            .filter(|(kind, _)| !matches!(kind, VariableType::Categorical { .. }))
+            .map(|(_, &x)| x)
+            .collect();
+        let last = ordinal.len().saturating_sub(1).max(1) as f32;
+        let ellipsoid: f32 = ordinal
+            .iter()
+            .enumerate()
+            .map(|(i, x)| 10f32.powf(6f32 * i as f32 / last) * x.powi(2))
+            .sum();
+        ellipsoid + categorical_penalty(&self.types, &input)
+    }
+
+    fn repair(&self, input: Vec<f32>) -> Vec<f32> {
+        repair_mixed(&self.types, input)
+    }
+}
+
+impl HasRandom for MixedEllipsoid {
+    fn get_random(&self) -> Vec<f32> {
+        random_mixed(&self.types)
+    }
+}
+
+impl HasLocal for MixedEllipsoid {
+    fn get_local_next(&self, input: &[f32]) -> Vec<f32> {
+        local_next_mixed(&self.types, input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn optimum() {
+        let sphere = MixedSphere::new(2, 2, 2);
+        let ellipsoid = MixedEllipsoid::new(2, 2, 2);
+        assert_eq!(sphere.get_dimensions(), 6);
+        assert_eq!(sphere.cost_function(&[0f32; 6]), 0f32);
+        assert_eq!(ellipsoid.cost_function(&[0f32; 6]), 0f32);
+        assert_eq!(sphere.get_minimum(), -10f32);
+        assert_eq!(sphere.get_maximum(), 10f32);
+    }
+
+    #[test]
+    fn rounds_before_evaluation() {
+        let sphere = MixedSphere::new(1, 1, 1);
+        assert_eq!(sphere.cost_function(&[0.5, 1.4, 0.2]), 1.25f32);
+        assert_eq!(sphere.cost_function(&[0.0, 0.0, 2.6]), 1f32);
+        let ellipsoid = MixedEllipsoid::new(1, 1, 0);
+        assert_eq!(ellipsoid.cost_function(&[0.0, 1.0]), 1_000_000f32);
+    }
+}
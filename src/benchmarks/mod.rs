@@ -1,20 +1,51 @@
 pub mod ackley;
 pub mod alpine2;
+pub mod bbob;
+pub mod beale;
+pub mod bohachevsky;
+pub mod booth;
+pub mod branin;
+pub mod cec;
+pub mod cross_in_tray;
+pub mod deb1;
+pub mod dixon_price;
+pub mod drop_wave;
+pub mod easom;
+pub mod eggholder;
 pub mod foth_dejong;
+pub mod goldstein_price;
 pub mod griewank;
+pub mod happy_cat;
+pub mod himmelblau;
+pub mod holder_table;
+pub mod levy;
+pub mod matyas;
 pub mod michalewicz;
 pub mod periodic;
+pub mod powell;
+pub mod qing;
+pub mod quintic;
 pub mod rastrigin;
+pub mod rosenbrock;
 pub mod salomon;
+pub mod schaffer;
 pub mod schwefel;
+pub mod six_hump_camel;
 pub mod styblinsky_and_tang;
+pub mod sum_of_different_powers;
 pub mod trd_dejong;
+pub mod trid;
 pub mod xinsheyang;
-pub mod qing;
-pub mod deb1;
-pub mod quintic;
-
+pub mod zakharov;
 
+pub mod double_precision;
+pub mod dynamic;
+pub mod engineering;
 pub mod fst_dejong;
+pub mod mixed_integer;
+pub mod multi_objective;
+pub mod noisy;
+pub mod registry;
 pub mod snd_dejong;
 pub mod traits;
+pub mod transformed;
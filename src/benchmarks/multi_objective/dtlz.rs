@@ -0,0 +1,245 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+use crate::benchmarks::multi_objective::{
+    clamp_into, concave, non_dominated, simplex_points, sphere_points, unit_grid, HasReferenceFront,
+};
+use crate::benchmarks::traits::random_in;
+use crate::problem_definitions::{HasObjectives, HasRandom, ProblemDomain};
+
+// DTLZ1-7 of Deb, Thiele, Laumanns and Zitzler, M objectives over n = M + k - 1 variables in
+// [0, 1], the first M - 1 set the position on the front and the last k its distance, which
+// is 0 for x_M..x_n = 0.5 (0 in DTLZ6 and DTLZ7)
+// source: https://doi.org/10.1007/1-84628-137-7_6
+
+const FUNCTION_NAMES: [&str; 7] = [
+    "DTLZ1", "DTLZ2", "DTLZ3", "DTLZ4", "DTLZ5", "DTLZ6", "DTLZ7",
+];
+// recommended k
+const DISTANCE_VARIABLES: [usize; 7] = [5, 10, 10, 10, 10, 10, 20];
+const DTLZ4_ALPHA: i32 = 100;
+
+#[derive(Debug, Clone)]
+pub struct Dtlz<const F: usize> {
+    objectives: usize,
+    dimensions: usize,
+}
+
+impl<const F: usize> Dtlz<F> {
+    pub const FUNCTION_NAME: &'static str = FUNCTION_NAMES[F - 1];
+
+    pub fn new(objectives: usize) -> Self {
+        let objectives = objectives.max(2);
+        Self {
+            objectives,
+            dimensions: objectives + DISTANCE_VARIABLES[F - 1] - 1,
+        }
+    }
+
+    // at least one distance variable
+    pub fn set_dimensions(mut self, dimensions: usize) -> Self {
+        self.dimensions = dimensions.max(self.objectives);
+        self
+    }
+
+    pub fn get_bounds(&self) -> Vec<(f64, f64)> {
+        vec![(0f64, 1f64); self.dimensions]
+    }
+
+    fn distance(&self, input: &[f64]) -> f64 {
+        let tail = &input[self.objectives - 1..];
+        match F {
+            1 | 3 => {
+                100f64
+                    * (tail.len() as f64
+                        + tail
+                            .iter()
+                            .map(|x| (x - 0.5).powi(2) - (20f64 * PI * (x - 0.5)).cos())
+                            .sum::<f64>())
+            }
+            6 => tail.iter().map(|x| x.powf(0.1)).sum(),
+            7 => 1f64 + 9f64 * tail.iter().sum::<f64>() / tail.len() as f64,
+            _ => tail.iter().map(|x| (x - 0.5).powi(2)).sum(),
+        }
+    }
+}
+
+// the angles of DTLZ2-6 measured from the last objective axis, the order of the concave shape
+fn angles(position: impl Iterator<Item = f64>) -> Vec<f64> {
+    position.map(|theta| FRAC_PI_2 - theta).collect()
+}
+
+impl<const F: usize> ProblemDomain for Dtlz<F> {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        0f64
+    }
+
+    fn get_maximum(&self) -> f64 {
+        1f64
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    // sum of the objectives
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        self.cost_vector(input).into_iter().sum()
+    }
+
+    fn repair(&self, input: Vec<f64>) -> Vec<f64> {
+        clamp_into(&self.get_bounds(), input)
+    }
+}
+
+impl<const F: usize> HasObjectives for Dtlz<F> {
+    fn get_objectives(&self) -> usize {
+        self.objectives
+    }
+
+    fn cost_vector(&self, input: &[f64]) -> Vec<f64> {
+        let m = self.objectives;
+        let position = &input[..m - 1];
+        let g = self.distance(input);
+        match F {
+            1 => (0..m)
+                .map(|i| {
+                    let free = m - 1 - i;
+                    let product = position[..free].iter().product::<f64>();
+                    match i {
+                        0 => 0.5 * (1f64 + g) * product,
+                        _ => 0.5 * (1f64 + g) * product * (1f64 - position[free]),
+                    }
+                })
+                .collect(),
+            2 | 3 => concave(&angles(position.iter().map(|x| x * FRAC_PI_2)), 1f64 + g),
+            4 => concave(
+                &angles(position.iter().map(|x| x.powi(DTLZ4_ALPHA) * FRAC_PI_2)),
+                1f64 + g,
+            ),
+            5 | 6 => {
+                let theta = position.iter().enumerate().map(|(i, x)| match i {
+                    0 => x * FRAC_PI_2,
+                    _ => FRAC_PI_4 / (1f64 + g) * (1f64 + 2f64 * g * x),
+                });
+                concave(&angles(theta), 1f64 + g)
+            }
+            _ => {
+                let h = m as f64
+                    - position
+                        .iter()
+                        .map(|f| f / (1f64 + g) * (1f64 + (3f64 * PI * f).sin()))
+                        .sum::<f64>();
+                let mut costs = position.to_vec();
+                costs.push((1f64 + g) * h);
+                costs
+            }
+        }
+    }
+}
+
+impl<const F: usize> HasRandom for Dtlz<F> {
+    fn get_random(&self) -> Vec<f64> {
+        random_in(&self.get_bounds())
+    }
+}
+
+impl<const F: usize> HasReferenceFront for Dtlz<F> {
+    fn get_reference_front(&self, size: usize) -> Vec<Vec<f64>> {
+        let m = self.objectives;
+        match F {
+            // the hyperplane sum f = 0.5
+            1 => simplex_points(m, size)
+                .into_iter()
+                .map(|point| point.into_iter().map(|f| 0.5 * f).collect())
+                .collect(),
+            2..=4 => sphere_points(m, size),
+            // a curve on the unit sphere, all but the first angle are pi / 4
+            5 | 6 => (0..size.max(2))
+                .map(|i| {
+                    let first = i as f64 / (size.max(2) - 1) as f64 * FRAC_PI_2;
+                    let theta = std::iter::once(first).chain(vec![FRAC_PI_4; m - 2]);
+                    concave(&angles(theta), 1f64)
+                })
+                .collect(),
+            _ => non_dominated(
+                unit_grid(m - 1, size)
+                    .into_iter()
+                    .map(|mut point| {
+                        let h = m as f64
+                            - point
+                                .iter()
+                                .map(|f| f / 2f64 * (1f64 + (3f64 * PI * f).sin()))
+                                .sum::<f64>();
+                        point.push(2f64 * h);
+                        point
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn optimal<const F: usize>(dtlz: &Dtlz<F>, position: &[f64]) -> Vec<f64> {
+        let distance = match F {
+            6 | 7 => 0f64,
+            _ => 0.5,
+        };
+        let mut input = position.to_vec();
+        input.resize(dtlz.get_dimensions(), distance);
+        input
+    }
+
+    #[test]
+    fn fronts() {
+        let dtlz1 = Dtlz::<1>::new(3);
+        assert_eq!(dtlz1.get_dimensions(), 7);
+        let costs = dtlz1.cost_vector(&optimal(&dtlz1, &[0.3, 0.6]));
+        assert!((costs.iter().sum::<f64>() - 0.5).abs() < 1e-12);
+        assert!((costs[0] - 0.5 * 0.3 * 0.6).abs() < 1e-12);
+
+        let sphere = |costs: Vec<f64>| (costs.iter().map(|f| f * f).sum::<f64>() - 1f64).abs();
+        for position in [[0f64, 0f64], [0.2, 0.9], [1f64, 0.5]] {
+            let dtlz2 = Dtlz::<2>::new(3);
+            assert!(sphere(dtlz2.cost_vector(&optimal(&dtlz2, &position))) < 1e-12);
+            let dtlz3 = Dtlz::<3>::new(3);
+            assert!(sphere(dtlz3.cost_vector(&optimal(&dtlz3, &position))) < 1e-12);
+            let dtlz4 = Dtlz::<4>::new(3);
+            assert!(sphere(dtlz4.cost_vector(&optimal(&dtlz4, &position))) < 1e-12);
+            let dtlz5 = Dtlz::<5>::new(3);
+            assert!(sphere(dtlz5.cost_vector(&optimal(&dtlz5, &position))) < 1e-12);
+            let dtlz6 = Dtlz::<6>::new(3);
+            assert!(sphere(dtlz6.cost_vector(&optimal(&dtlz6, &position))) < 1e-12);
+        }
+
+        // f_1 = cos(x_1 pi / 2) cos(x_2 pi / 2) and f_M = sin(x_1 pi / 2) as in the paper
+        let dtlz2 = Dtlz::<2>::new(3);
+        let costs = dtlz2.cost_vector(&optimal(&dtlz2, &[0.2, 0.9]));
+        assert!((costs[0] - (0.1 * PI).cos() * (0.45 * PI).cos()).abs() < 1e-12);
+        assert!((costs[2] - (0.1 * PI).sin()).abs() < 1e-12);
+
+        let dtlz7 = Dtlz::<7>::new(2);
+        let costs = dtlz7.cost_vector(&optimal(&dtlz7, &[0f64]));
+        assert_eq!(costs, vec![0f64, 4f64]);
+    }
+
+    #[test]
+    fn reference_fronts() {
+        let front = Dtlz::<1>::new(3).get_reference_front(100);
+        assert!(front.len() >= 100);
+        assert!(front
+            .iter()
+            .all(|p| (p.iter().sum::<f64>() - 0.5).abs() < 1e-12));
+        let front = Dtlz::<5>::new(3).get_reference_front(50);
+        assert_eq!(front.len(), 50);
+        assert!(front.iter().all(|p| (p[0] - p[1]).abs() < 1e-12));
+        let front = Dtlz::<7>::new(2).get_reference_front(1000);
+        assert!(front.len() < 1000);
+        assert!(front.iter().any(|p| p[0] == 0f64 && p[1] == 4f64));
+    }
+}
@@ -0,0 +1,99 @@
+pub(crate) use crate::benchmarks::traits::clamp_into;
+use crate::pareto::{non_dominated_sort, simplex_lattice};
+use crate::problem_definitions::HasObjectives;
+
+pub mod dtlz;
+pub mod wfg;
+pub mod zdt;
+
+// multi-objective benchmarks whose pareto front is known analytically, the reference fronts are
+// what the indicators measure the approximations against
+pub trait HasReferenceFront: HasObjectives {
+    // roughly size points spread over the front
+    fn get_reference_front(&self, size: usize) -> Vec<Vec<f64>>;
+}
+
+// the simplex lattice with the fewest divisions that gives at least size points
+pub(crate) fn simplex_points(objectives: usize, size: usize) -> Vec<Vec<f64>> {
+    let mut divisions = 1;
+    loop {
+        let points = simplex_lattice(objectives, divisions);
+        if points.len() >= size {
+            return points;
+        }
+        divisions += 1;
+    }
+}
+
+// lattice points projected onto the positive part of the unit sphere
+pub(crate) fn sphere_points(objectives: usize, size: usize) -> Vec<Vec<f64>> {
+    simplex_points(objectives, size)
+        .into_iter()
+        .map(|point| {
+            let norm = point.iter().map(|x| x * x).sum::<f64>().sqrt();
+            point.into_iter().map(|x| x / norm).collect()
+        })
+        .collect()
+}
+
+// regular grid over [0, 1]^dimensions with at least size points
+pub(crate) fn unit_grid(dimensions: usize, size: usize) -> Vec<Vec<f64>> {
+    let per_axis = ((size as f64).powf(1f64 / dimensions.max(1) as f64).ceil() as usize).max(2);
+    let mut grid = vec![vec![]];
+    for _ in 0..dimensions {
+        grid = grid
+            .into_iter()
+            .flat_map(|point: Vec<f64>| {
+                (0..per_axis).map(move |i| {
+                    let mut point = point.clone();
+                    point.push(i as f64 / (per_axis - 1) as f64);
+                    point
+                })
+            })
+            .collect();
+    }
+    grid
+}
+
+pub(crate) fn non_dominated(points: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    match non_dominated_sort(&points).first() {
+        Some(front) => front.iter().map(|&i| points[i].clone()).collect(),
+        None => vec![],
+    }
+}
+
+// f_1 = r prod sin, ..., f_M = r cos(a_1) of the angles a_i in radians, the first objective
+// takes all the sines, the shape of DTLZ2-6 and of the concave WFG problems
+pub(crate) fn concave(angles: &[f64], radius: f64) -> Vec<f64> {
+    let objectives = angles.len() + 1;
+    (0..objectives)
+        .map(|m| {
+            let free = objectives - 1 - m;
+            let product = angles[..free].iter().map(|a| a.sin()).product::<f64>();
+            match m {
+                0 => radius * product,
+                _ => radius * product * angles[free].cos(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reference_points() {
+        assert!(simplex_points(3, 20).len() >= 20);
+        assert!(sphere_points(3, 20)
+            .iter()
+            .all(|p| (p.iter().map(|x| x * x).sum::<f64>() - 1f64).abs() < 1e-12));
+        let grid = unit_grid(2, 10);
+        assert_eq!(grid.len(), 16);
+        assert!(grid.contains(&vec![1f64, 0f64]));
+        let front = non_dominated(vec![vec![0f64, 1f64], vec![1f64, 1f64], vec![1f64, 0f64]]);
+        assert_eq!(front.len(), 2);
+        let point = concave(&[0.3, 1.1], 2f64);
+        assert!((point.iter().map(|x| x * x).sum::<f64>() - 4f64).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,498 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::benchmarks::multi_objective::{
+    clamp_into, concave, non_dominated, sphere_points, unit_grid, HasReferenceFront,
+};
+use crate::benchmarks::traits::random_in;
+use crate::problem_definitions::{HasObjectives, HasRandom, ProblemDomain};
+
+// WFG1-9 toolkit problems of Huband, Hingston, Barone and While, k position and l distance
+// variables, z_i in [0, 2i], normalised and passed through the transformations of each
+// problem before the shape, f_m = x_M + 2m h_m
+// source: https://doi.org/10.1109/TEVC.2005.861417
+
+const FUNCTION_NAMES: [&str; 9] = [
+    "WFG1", "WFG2", "WFG3", "WFG4", "WFG5", "WFG6", "WFG7", "WFG8", "WFG9",
+];
+const DISTANCE_PARAMETERS: usize = 20;
+// every distance variable transformed into this value puts the solution on the front
+const OPTIMAL_DISTANCE: f64 = 0.35;
+// constants of the parameter dependent bias of WFG7-9
+const PARAM_A: f64 = 0.98 / 49.98;
+const PARAM_B: f64 = 0.02;
+const PARAM_C: f64 = 50f64;
+// rounding errors of the transformations below this are snapped to the bounds, the
+// b_poly(y, 0.02) of WFG1 would blow them up otherwise
+const EPSILON: f64 = 1e-10;
+
+#[derive(Debug, Clone)]
+pub struct Wfg<const F: usize> {
+    objectives: usize,
+    position: usize,
+    distance: usize,
+}
+
+impl<const F: usize> Wfg<F> {
+    pub const FUNCTION_NAME: &'static str = FUNCTION_NAMES[F - 1];
+
+    // k = 2 (M - 1) and l = 20 by default
+    pub fn new(objectives: usize) -> Self {
+        let objectives = objectives.max(2);
+        Self {
+            objectives,
+            position: 2 * (objectives - 1),
+            distance: DISTANCE_PARAMETERS,
+        }
+    }
+
+    // rounded up to a multiple of M - 1
+    pub fn set_position_parameters(mut self, position: usize) -> Self {
+        let groups = self.objectives - 1;
+        self.position = position.max(1).div_ceil(groups) * groups;
+        self
+    }
+
+    // rounded up to an even number in WFG2 and WFG3, which pair the distance variables
+    pub fn set_distance_parameters(mut self, distance: usize) -> Self {
+        self.distance = match F {
+            2 | 3 => distance.max(1).div_ceil(2) * 2,
+            _ => distance.max(1),
+        };
+        self
+    }
+
+    pub fn get_bounds(&self) -> Vec<(f64, f64)> {
+        (1..=self.position + self.distance)
+            .map(|i| (0f64, 2f64 * i as f64))
+            .collect()
+    }
+
+    // pareto optimal solution with the given position parameters from [0, 1]
+    pub fn optimal_solution(&self, position: &[f64]) -> Vec<f64> {
+        let (k, n) = (self.position, self.position + self.distance);
+        let mut y = position.to_vec();
+        y.resize(n, OPTIMAL_DISTANCE);
+        match F {
+            // the bias of a distance variable depends on the mean of all the previous ones
+            8 => {
+                for i in k..n {
+                    let u = y[..i].iter().sum::<f64>() / i as f64;
+                    y[i] = OPTIMAL_DISTANCE.powf(1f64 / param_exponent(u));
+                }
+            }
+            // and on the mean of all the following ones in WFG9
+            9 => {
+                for i in (k..n - 1).rev() {
+                    let u = y[i + 1..].iter().sum::<f64>() / (n - i - 1) as f64;
+                    y[i] = OPTIMAL_DISTANCE.powf(1f64 / param_exponent(u));
+                }
+            }
+            _ => (),
+        }
+        y.iter()
+            .enumerate()
+            .map(|(i, y)| y * 2f64 * (i + 1) as f64)
+            .collect()
+    }
+
+    fn transform(&self, y: Vec<f64>) -> Vec<f64> {
+        let (k, n) = (self.position, self.position + self.distance);
+        let original = y.clone();
+        let mut y = y;
+        match F {
+            1 => {
+                y[k..].iter_mut().for_each(|v| *v = s_linear(*v, 0.35));
+                y[k..]
+                    .iter_mut()
+                    .for_each(|v| *v = b_flat(*v, 0.8, 0.75, 0.85));
+                y.iter_mut().for_each(|v| *v = v.powf(0.02));
+                let weights = (1..=n).map(|i| 2f64 * i as f64).collect::<Vec<_>>();
+                self.reduce_sum(&y, &weights)
+            }
+            2 | 3 => {
+                y[k..].iter_mut().for_each(|v| *v = s_linear(*v, 0.35));
+                let mut reduced = y[..k].to_vec();
+                reduced.extend(y[k..].chunks(2).map(|pair| r_nonsep(pair, 2)));
+                self.reduce_sum(&reduced, &vec![1f64; reduced.len()])
+            }
+            4 => {
+                y.iter_mut()
+                    .for_each(|v| *v = s_multi(*v, 30f64, 10f64, 0.35));
+                self.reduce_sum(&y, &vec![1f64; n])
+            }
+            5 => {
+                y.iter_mut()
+                    .for_each(|v| *v = s_decept(*v, 0.35, 0.001, 0.05));
+                self.reduce_sum(&y, &vec![1f64; n])
+            }
+            6 => {
+                y[k..].iter_mut().for_each(|v| *v = s_linear(*v, 0.35));
+                self.reduce_nonsep(&y)
+            }
+            7 => {
+                for i in 0..k {
+                    y[i] = b_param(y[i], mean(&original[i + 1..]));
+                }
+                y[k..].iter_mut().for_each(|v| *v = s_linear(*v, 0.35));
+                self.reduce_sum(&y, &vec![1f64; n])
+            }
+            8 => {
+                for i in k..n {
+                    y[i] = b_param(y[i], mean(&original[..i]));
+                }
+                y[k..].iter_mut().for_each(|v| *v = s_linear(*v, 0.35));
+                self.reduce_sum(&y, &vec![1f64; n])
+            }
+            _ => {
+                for i in 0..n - 1 {
+                    y[i] = b_param(y[i], mean(&original[i + 1..]));
+                }
+                y[..k]
+                    .iter_mut()
+                    .for_each(|v| *v = s_decept(*v, 0.35, 0.001, 0.05));
+                y[k..]
+                    .iter_mut()
+                    .for_each(|v| *v = s_multi(*v, 30f64, 95f64, 0.35));
+                self.reduce_nonsep(&y)
+            }
+        }
+    }
+
+    // weighted means of the M - 1 groups of position variables and of the distance variables
+    fn reduce_sum(&self, y: &[f64], weights: &[f64]) -> Vec<f64> {
+        let group = self.position / (self.objectives - 1);
+        let mut t = y[..self.position]
+            .chunks(group)
+            .zip(weights.chunks(group))
+            .map(|(y, w)| r_sum(y, w))
+            .collect::<Vec<_>>();
+        t.push(r_sum(&y[self.position..], &weights[self.position..]));
+        t
+    }
+
+    fn reduce_nonsep(&self, y: &[f64]) -> Vec<f64> {
+        let group = self.position / (self.objectives - 1);
+        let mut t = y[..self.position]
+            .chunks(group)
+            .map(|y| r_nonsep(y, group))
+            .collect::<Vec<_>>();
+        t.push(r_nonsep(&y[self.position..], self.distance));
+        t
+    }
+
+    // h_1..h_M of the shape parameters x_1..x_{M-1}
+    fn shape(&self, x: &[f64]) -> Vec<f64> {
+        match F {
+            1 | 2 => {
+                let mut h = product_shape(
+                    x,
+                    |x| 1f64 - (x * FRAC_PI_2).cos(),
+                    |x| 1f64 - (x * FRAC_PI_2).sin(),
+                );
+                let last = h.len() - 1;
+                h[last] = match F {
+                    1 => {
+                        let a = 5f64;
+                        1f64 - x[0] - (2f64 * a * PI * x[0] + FRAC_PI_2).cos() / (2f64 * a * PI)
+                    }
+                    _ => 1f64 - x[0] * (5f64 * x[0] * PI).cos().powi(2),
+                };
+                h
+            }
+            3 => product_shape(x, |x| x, |x| 1f64 - x),
+            _ => concave(&x.iter().map(|x| x * FRAC_PI_2).collect::<Vec<_>>(), 1f64),
+        }
+    }
+
+    fn scale(&self, h: Vec<f64>, distance: f64) -> Vec<f64> {
+        h.into_iter()
+            .enumerate()
+            .map(|(m, h)| distance + 2f64 * (m + 1) as f64 * h)
+            .collect()
+    }
+}
+
+fn mean(y: &[f64]) -> f64 {
+    y.iter().sum::<f64>() / y.len() as f64
+}
+
+// h_m = prod_{i <= M - m} inner(x_i) * outer(x_{M - m + 1}), h_1 takes only the product
+fn product_shape(x: &[f64], inner: impl Fn(f64) -> f64, outer: impl Fn(f64) -> f64) -> Vec<f64> {
+    let objectives = x.len() + 1;
+    (0..objectives)
+        .map(|m| {
+            let free = objectives - 1 - m;
+            let product = x[..free].iter().map(|x| inner(*x)).product::<f64>();
+            match m {
+                0 => product,
+                _ => product * outer(x[free]),
+            }
+        })
+        .collect()
+}
+
+// the transformations keep [0, 1], this only removes rounding errors
+fn correct(value: f64) -> f64 {
+    if value < EPSILON {
+        0f64
+    } else if value > 1f64 - EPSILON {
+        1f64
+    } else {
+        value
+    }
+}
+
+fn b_flat(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let value = a + (y - b).floor().min(0f64) * a * (b - y) / b
+        - (c - y).floor().min(0f64) * (1f64 - a) * (y - c) / (1f64 - c);
+    correct(value)
+}
+
+fn param_exponent(u: f64) -> f64 {
+    PARAM_B
+        + (PARAM_C - PARAM_B) * (PARAM_A - (1f64 - 2f64 * u) * ((0.5 - u).floor() + PARAM_A).abs())
+}
+
+fn b_param(y: f64, u: f64) -> f64 {
+    correct(y.powf(param_exponent(u)))
+}
+
+fn s_linear(y: f64, a: f64) -> f64 {
+    correct((y - a).abs() / ((a - y).floor() + a).abs())
+}
+
+fn s_decept(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let value = 1f64
+        + ((y - a).abs() - b)
+            * ((y - a + b).floor() * (1f64 - c + (a - b) / b) / (a - b)
+                + (a + b - y).floor() * (1f64 - c + (1f64 - a - b) / b) / (1f64 - a - b)
+                + 1f64 / b);
+    correct(value)
+}
+
+fn s_multi(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let t = (y - c).abs() / (2f64 * ((c - y).floor() + c));
+    let value = (1f64 + ((4f64 * a + 2f64) * PI * (0.5 - t)).cos() + 4f64 * b * t * t) / (b + 2f64);
+    correct(value)
+}
+
+fn r_sum(y: &[f64], weights: &[f64]) -> f64 {
+    let value = y
+        .iter()
+        .zip(weights.iter())
+        .map(|(y, w)| y * w)
+        .sum::<f64>()
+        / weights.iter().sum::<f64>();
+    correct(value)
+}
+
+fn r_nonsep(y: &[f64], a: usize) -> f64 {
+    let n = y.len();
+    let numerator = (0..n)
+        .map(|j| {
+            y[j] + (0..a.saturating_sub(1))
+                .map(|k| (y[j] - y[(j + k + 1) % n]).abs())
+                .sum::<f64>()
+        })
+        .sum::<f64>();
+    let half = a.div_ceil(2) as f64;
+    let denominator = n as f64 / a as f64 * half * (1f64 + 2f64 * a as f64 - 2f64 * half);
+    correct(numerator / denominator)
+}
+
+impl<const F: usize> ProblemDomain for Wfg<F> {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        0f64
+    }
+
+    fn get_maximum(&self) -> f64 {
+        2f64 * (self.position + self.distance) as f64
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.position + self.distance
+    }
+
+    // sum of the objectives
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        self.cost_vector(input).into_iter().sum()
+    }
+
+    fn repair(&self, input: Vec<f64>) -> Vec<f64> {
+        clamp_into(&self.get_bounds(), input)
+    }
+}
+
+impl<const F: usize> HasObjectives for Wfg<F> {
+    fn get_objectives(&self) -> usize {
+        self.objectives
+    }
+
+    fn cost_vector(&self, input: &[f64]) -> Vec<f64> {
+        let y = input
+            .iter()
+            .enumerate()
+            .map(|(i, z)| (z / (2f64 * (i + 1) as f64)).clamp(0f64, 1f64))
+            .collect();
+        let t = self.transform(y);
+        let distance = t[self.objectives - 1];
+        // WFG3 is degenerate, all but the first shape parameter collapse to 0.5 on the front
+        let x = t[..self.objectives - 1]
+            .iter()
+            .enumerate()
+            .map(|(i, t)| {
+                let a = if F == 3 && i > 0 { 0f64 } else { 1f64 };
+                distance.max(a) * (t - 0.5) + 0.5
+            })
+            .collect::<Vec<_>>();
+        self.scale(self.shape(&x), distance)
+    }
+}
+
+impl<const F: usize> HasRandom for Wfg<F> {
+    fn get_random(&self) -> Vec<f64> {
+        random_in(&self.get_bounds())
+    }
+}
+
+impl<const F: usize> HasReferenceFront for Wfg<F> {
+    fn get_reference_front(&self, size: usize) -> Vec<Vec<f64>> {
+        let m = self.objectives;
+        match F {
+            1 | 2 => non_dominated(
+                unit_grid(m - 1, size)
+                    .into_iter()
+                    .map(|x| self.scale(self.shape(&x), 0f64))
+                    .collect(),
+            ),
+            3 => (0..size.max(2))
+                .map(|i| {
+                    let mut x = vec![0.5; m - 1];
+                    x[0] = i as f64 / (size.max(2) - 1) as f64;
+                    self.scale(self.shape(&x), 0f64)
+                })
+                .collect(),
+            _ => sphere_points(m, size)
+                .into_iter()
+                .map(|h| self.scale(h, 0f64))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pareto::dominates;
+
+    fn on_front<const F: usize>(wfg: &Wfg<F>) {
+        let front = wfg.get_reference_front(2000);
+        for position in [0f64, 0.2, 0.5, 0.7, 1f64] {
+            let optimal = wfg.optimal_solution(&vec![position; wfg.position]);
+            let costs = wfg.cost_vector(&optimal);
+            // WFG2 is disconnected, parts of its optimal set are dominated by the rest
+            if F != 2 {
+                assert!(
+                    front.iter().all(|p| !dominates(p, &costs)),
+                    "{} {:?}",
+                    Wfg::<F>::FUNCTION_NAME,
+                    costs
+                );
+            }
+            let closest = front
+                .iter()
+                .map(|p| {
+                    p.iter()
+                        .zip(&costs)
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<f64>()
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert!(
+                F == 2 || closest.sqrt() < 0.1,
+                "{} {:?}",
+                Wfg::<F>::FUNCTION_NAME,
+                costs
+            );
+
+            // moving a distance variable off its optimum adds to every objective, the degenerate
+            // WFG3 moves the shape parameters as well
+            if F == 3 {
+                continue;
+            }
+            let mut moved = optimal.clone();
+            let last = moved.len() - 1;
+            moved[last] *= 0.5;
+            let worse = wfg.cost_vector(&moved);
+            assert!(costs.iter().zip(&worse).all(|(a, b)| a < b));
+        }
+    }
+
+    #[test]
+    fn optimal_solutions() {
+        on_front(&Wfg::<1>::new(2));
+        on_front(&Wfg::<2>::new(2));
+        on_front(&Wfg::<3>::new(2));
+        on_front(&Wfg::<1>::new(3));
+        on_front(&Wfg::<2>::new(3));
+        on_front(&Wfg::<3>::new(3));
+
+        // the concave ones lie on the ellipsoid sum (f_m / 2m)^2 = 1
+        fn ellipsoid<const F: usize>(objectives: usize) {
+            let wfg = Wfg::<F>::new(objectives);
+            for position in [0f64, 0.3, 0.8, 1f64] {
+                let costs = wfg.cost_vector(&wfg.optimal_solution(&vec![position; wfg.position]));
+                let radius = costs
+                    .iter()
+                    .enumerate()
+                    .map(|(m, f)| (f / (2f64 * (m + 1) as f64)).powi(2))
+                    .sum::<f64>();
+                assert!((radius - 1f64).abs() < 1e-6, "{}", Wfg::<F>::FUNCTION_NAME);
+            }
+        }
+        for objectives in [2, 3] {
+            ellipsoid::<4>(objectives);
+            ellipsoid::<5>(objectives);
+            ellipsoid::<6>(objectives);
+            ellipsoid::<7>(objectives);
+            ellipsoid::<8>(objectives);
+            ellipsoid::<9>(objectives);
+        }
+    }
+
+    #[test]
+    fn parameters() {
+        let wfg = Wfg::<2>::new(3)
+            .set_position_parameters(5)
+            .set_distance_parameters(7);
+        assert_eq!(wfg.get_dimensions(), 14);
+        assert_eq!(wfg.get_bounds()[13], (0f64, 28f64));
+        assert_eq!(Wfg::<1>::new(2).get_dimensions(), 22);
+        let random = wfg.get_random();
+        assert!(random
+            .iter()
+            .zip(wfg.get_bounds())
+            .all(|(x, (min, max))| (min..=max).contains(x)));
+        // dominated by the front everywhere but on it
+        let costs = wfg.cost_vector(&random);
+        assert!(wfg
+            .get_reference_front(500)
+            .iter()
+            .any(|p| dominates(p, &costs)));
+    }
+
+    #[test]
+    fn transformations() {
+        assert_eq!(s_linear(0.35, 0.35), 0f64);
+        assert!((s_linear(1f64, 0.35) - 1f64).abs() < 1e-12);
+        assert!(s_decept(0.35, 0.35, 0.001, 0.05).abs() < 1e-12);
+        assert!(s_multi(0.35, 30f64, 10f64, 0.35).abs() < 1e-12);
+        assert!((b_flat(0.8, 0.8, 0.75, 0.85) - 0.8).abs() < 1e-12);
+        // the differences count as well, a constant vector scores below its value
+        assert!((r_nonsep(&[0.2, 0.2, 0.2], 3) - 0.1).abs() < 1e-12);
+        assert!((r_nonsep(&[0.2, 0.4], 1) - 0.3).abs() < 1e-12);
+        assert!((r_sum(&[0.2, 0.6], &[1f64, 3f64]) - 0.5).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,248 @@
+use std::f64::consts::PI;
+
+use crate::benchmarks::multi_objective::{clamp_into, non_dominated, HasReferenceFront};
+use crate::benchmarks::traits::random_in;
+use crate::combinatorial::binary::{count_ones, random_bits};
+use crate::problem_definitions::{HasObjectives, HasRandom, ProblemDomain};
+
+// ZDT1-6, two objectives of Zitzler, Deb and Thiele (2000), the fronts are reached with
+// x_2..x_n = 0, in ZDT5 with all the bits of the 5 bit substrings set
+// source: https://doi.org/10.1162/106365600568202
+
+const FUNCTION_NAMES: [&str; 6] = ["ZDT1", "ZDT2", "ZDT3", "ZDT4", "ZDT5", "ZDT6"];
+const DIMENSIONS: [usize; 6] = [30, 30, 30, 10, 80, 10];
+
+// ZDT5 works on bits, a 30 bit substring for f1 followed by 5 bit substrings for g
+const FIRST_SUBSTRING: usize = 30;
+const SUBSTRING: usize = 5;
+
+// smallest f1 of ZDT6, 1 - exp(-4x) sin^6(6 pi x) is minimal around x = 0.0815
+const ZDT6_MIN_F1: f64 = 0.2807753188;
+
+#[derive(Debug, Clone)]
+pub struct Zdt<const F: usize> {
+    dimensions: usize,
+}
+
+impl<const F: usize> Default for Zdt<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const F: usize> Zdt<F> {
+    pub const FUNCTION_NAME: &'static str = FUNCTION_NAMES[F - 1];
+
+    pub fn new() -> Self {
+        Self {
+            dimensions: DIMENSIONS[F - 1],
+        }
+    }
+
+    // at least two variables, ZDT5 is rounded down to whole 5 bit substrings
+    pub fn set_dimensions(mut self, dimensions: usize) -> Self {
+        self.dimensions = match F {
+            5 => {
+                let substrings = dimensions.saturating_sub(FIRST_SUBSTRING) / SUBSTRING;
+                FIRST_SUBSTRING + SUBSTRING * substrings.max(1)
+            }
+            _ => dimensions.max(2),
+        };
+        self
+    }
+
+    pub fn get_bounds(&self) -> Vec<(f64, f64)> {
+        let mut bounds = vec![(self.get_minimum(), self.get_maximum()); self.dimensions];
+        bounds[0] = (0f64, 1f64);
+        bounds
+    }
+
+    fn zdt5(&self, input: &[f64]) -> Vec<f64> {
+        let f1 = 1f64 + count_ones(&input[..FIRST_SUBSTRING]) as f64;
+        let g = input[FIRST_SUBSTRING..]
+            .chunks(SUBSTRING)
+            .map(|substring| match count_ones(substring) {
+                SUBSTRING => 1f64,
+                ones => 2f64 + ones as f64,
+            })
+            .sum::<f64>();
+        vec![f1, g / f1]
+    }
+}
+
+impl<const F: usize> ProblemDomain for Zdt<F> {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        match F {
+            4 => -5f64,
+            _ => 0f64,
+        }
+    }
+
+    fn get_maximum(&self) -> f64 {
+        match F {
+            4 => 5f64,
+            _ => 1f64,
+        }
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    // sum of the objectives
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        self.cost_vector(input).into_iter().sum()
+    }
+
+    fn repair(&self, input: Vec<f64>) -> Vec<f64> {
+        let input = clamp_into(&self.get_bounds(), input);
+        match F {
+            5 => input.into_iter().map(f64::round).collect(),
+            _ => input,
+        }
+    }
+}
+
+impl<const F: usize> HasObjectives for Zdt<F> {
+    fn get_objectives(&self) -> usize {
+        2
+    }
+
+    fn cost_vector(&self, input: &[f64]) -> Vec<f64> {
+        if F == 5 {
+            return self.zdt5(input);
+        }
+        let rest = &input[1..];
+        let mean = rest.iter().sum::<f64>() / rest.len() as f64;
+        let f1 = match F {
+            6 => 1f64 - (-4f64 * input[0]).exp() * (6f64 * PI * input[0]).sin().powi(6),
+            _ => input[0],
+        };
+        let g = match F {
+            4 => {
+                1f64 + 10f64 * rest.len() as f64
+                    + rest
+                        .iter()
+                        .map(|x| x * x - 10f64 * (4f64 * PI * x).cos())
+                        .sum::<f64>()
+            }
+            6 => 1f64 + 9f64 * mean.powf(0.25),
+            _ => 1f64 + 9f64 * mean,
+        };
+        let ratio = f1 / g;
+        let h = match F {
+            2 | 6 => 1f64 - ratio.powi(2),
+            3 => 1f64 - ratio.sqrt() - ratio * (10f64 * PI * f1).sin(),
+            _ => 1f64 - ratio.sqrt(),
+        };
+        vec![f1, g * h]
+    }
+}
+
+impl<const F: usize> HasRandom for Zdt<F> {
+    fn get_random(&self) -> Vec<f64> {
+        match F {
+            5 => random_bits(self.dimensions),
+            _ => random_in(&self.get_bounds()),
+        }
+    }
+}
+
+impl<const F: usize> HasReferenceFront for Zdt<F> {
+    fn get_reference_front(&self, size: usize) -> Vec<Vec<f64>> {
+        let size = size.max(2);
+        let steps = (0..size).map(|i| i as f64 / (size - 1) as f64);
+        match F {
+            // ZDT3 is disconnected, only the non-dominated part of its curve is kept
+            3 => non_dominated(
+                steps
+                    .map(|f1| vec![f1, 1f64 - f1.sqrt() - f1 * (10f64 * PI * f1).sin()])
+                    .collect(),
+            ),
+            5 => {
+                let g = ((self.dimensions - FIRST_SUBSTRING) / SUBSTRING) as f64;
+                (0..=FIRST_SUBSTRING)
+                    .map(|ones| {
+                        let f1 = 1f64 + ones as f64;
+                        vec![f1, g / f1]
+                    })
+                    .collect()
+            }
+            6 => steps
+                .map(|t| {
+                    let f1 = ZDT6_MIN_F1 + (1f64 - ZDT6_MIN_F1) * t;
+                    vec![f1, 1f64 - f1 * f1]
+                })
+                .collect(),
+            2 => steps.map(|f1| vec![f1, 1f64 - f1 * f1]).collect(),
+            _ => steps.map(|f1| vec![f1, 1f64 - f1.sqrt()]).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn optimal<const F: usize>(zdt: &Zdt<F>, first: f64) -> Vec<f64> {
+        let mut input = vec![0f64; zdt.get_dimensions()];
+        input[0] = first;
+        input
+    }
+
+    #[test]
+    fn fronts() {
+        for first in [0f64, 0.25, 0.64, 1f64] {
+            let zdt1 = Zdt::<1>::new();
+            assert_eq!(
+                zdt1.cost_vector(&optimal(&zdt1, first)),
+                vec![first, 1f64 - first.sqrt()]
+            );
+            let zdt2 = Zdt::<2>::new();
+            assert_eq!(
+                zdt2.cost_vector(&optimal(&zdt2, first)),
+                vec![first, 1f64 - first * first]
+            );
+            let zdt4 = Zdt::<4>::new();
+            let costs = zdt4.cost_vector(&optimal(&zdt4, first));
+            assert!((costs[1] - (1f64 - first.sqrt())).abs() < 1e-12);
+        }
+        let zdt6 = Zdt::<6>::new();
+        let costs = zdt6.cost_vector(&optimal(&zdt6, 0.0814577971));
+        assert!((costs[0] - ZDT6_MIN_F1).abs() < 1e-9);
+        assert!((costs[1] - (1f64 - costs[0] * costs[0])).abs() < 1e-12);
+        assert_eq!(Zdt::<1>::FUNCTION_NAME, "ZDT1");
+    }
+
+    #[test]
+    fn zdt5() {
+        let zdt5 = Zdt::<5>::new();
+        assert_eq!(zdt5.get_dimensions(), 80);
+        let mut input = vec![1f64; 80];
+        input[..10].iter_mut().for_each(|bit| *bit = 0f64);
+        assert_eq!(zdt5.cost_vector(&input), vec![21f64, 10f64 / 21f64]);
+        let front = zdt5.get_reference_front(100);
+        assert_eq!(front.len(), 31);
+        assert!(front.contains(&zdt5.cost_vector(&input)));
+        assert_eq!(zdt5.repair(vec![0.7; 80]), vec![1f64; 80]);
+        assert_eq!(Zdt::<5>::new().set_dimensions(52).get_dimensions(), 50);
+    }
+
+    #[test]
+    fn reference_fronts() {
+        let front = Zdt::<3>::new().get_reference_front(1000);
+        assert!(front.len() < 1000 && front.len() > 100);
+        assert!(front.iter().any(|p| p[1] < -0.77));
+        assert_eq!(Zdt::<1>::new().get_reference_front(50).len(), 50);
+        let bounds = Zdt::<4>::new().get_bounds();
+        assert_eq!(bounds[0], (0f64, 1f64));
+        assert_eq!(bounds[1], (-5f64, 5f64));
+        assert!(Zdt::<4>::new()
+            .get_random()
+            .iter()
+            .zip(bounds.iter())
+            .all(|(x, (min, max))| (min..=max).contains(&x)));
+    }
+}
@@ -0,0 +1,228 @@
+use rand::Rng;
+use rand_distr::{Cauchy, Distribution, StandardNormal};
+
+use crate::benchmarks::traits::Benchmark;
+use crate::variables::VariableType;
+
+// noise models of the BBOB 2009 noisy testbed (Hansen et al.) in simplified form, every
+// evaluation draws anew
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Noise {
+    // f + sigma * N(0, 1)
+    Gaussian(f32),
+    // f * (1 + sigma * N(0, 1)), vanishes at a zero optimum
+    Multiplicative(f32),
+    // f + scale * C with C standard Cauchy, rare but huge outliers
+    Cauchy(f32),
+}
+
+impl Noise {
+    fn apply<R: Rng>(&self, cost: f64, rng: &mut R) -> f64 {
+        match *self {
+            Self::Gaussian(sigma) => cost + f64::from(sigma) * rng.sample::<f64, _>(StandardNormal),
+            Self::Multiplicative(sigma) => {
+                cost * (1f64 + f64::from(sigma) * rng.sample::<f64, _>(StandardNormal))
+            }
+            // a scale that is not positive means no noise
+            Self::Cauchy(scale) => match Cauchy::new(0f64, f64::from(scale)) {
+                Ok(cauchy) => cost + cauchy.sample(rng),
+                Err(_) => cost,
+            },
+        }
+    }
+}
+
+// wraps a benchmark so that its cost is disturbed by the noise, with the given probability per
+// evaluation, everything else is the wrapped benchmark's
+#[derive(Debug, Clone)]
+pub struct Noisy<B>
+where
+    B: Benchmark,
+{
+    benchmark: B,
+    noise: Noise,
+    probability: f32,
+}
+
+impl<B> Noisy<B>
+where
+    B: Benchmark,
+{
+    pub fn new(benchmark: B, noise: Noise) -> Self {
+        Self {
+            benchmark,
+            noise,
+            probability: 1f32,
+        }
+    }
+
+    // e.g. Cauchy noise with a small probability gives occasional outliers
+    pub fn set_probability(mut self, probability: f32) -> Self {
+        self.probability = probability.clamp(0f32, 1f32);
+        self
+    }
+
+    pub fn get_benchmark(&self) -> &B {
+        &self.benchmark
+    }
+
+    pub fn get_noise(&self) -> Noise {
+        self.noise
+    }
+
+    pub fn get_probability(&self) -> f32 {
+        self.probability
+    }
+
+    // cost without the noise, the true quality of a solution
+    pub fn noiseless_cost(&self, input: &[f32]) -> f32 {
+        self.benchmark.cost_function(input)
+    }
+
+    fn disturb(&self, cost: f64) -> f64 {
+        let mut rng = rand::thread_rng();
+        match rng.gen::<f32>() < self.probability {
+            true => self.noise.apply(cost, &mut rng),
+            false => cost,
+        }
+    }
+}
+
+impl<B> Benchmark for Noisy<B>
+where
+    B: Benchmark,
+{
+    const FUNCTION_NAME: &'static str = B::FUNCTION_NAME;
+    const DIMENSIONS: Option<usize> = B::DIMENSIONS;
+
+    fn get_min(&self) -> f32 {
+        self.benchmark.get_min()
+    }
+
+    fn get_max(&self) -> f32 {
+        self.benchmark.get_max()
+    }
+
+    fn get_dim(&self) -> usize {
+        self.benchmark.get_dim()
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.benchmark.get_expected_min()
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.benchmark.get_expected_min_coords()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.benchmark = self.benchmark.set_min(value);
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.benchmark = self.benchmark.set_max(value);
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.benchmark = self.benchmark.set_dim(value);
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.benchmark = self.benchmark.set_expected_min(value);
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.benchmark = self.benchmark.set_expected_min_coords(value);
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        self.disturb(f64::from(self.benchmark.cost_function(input))) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        self.disturb(self.benchmark.cost_function_f64(input))
+    }
+
+    fn get_all_expected_min_coords(&self) -> Vec<Vec<f32>> {
+        self.benchmark.get_all_expected_min_coords()
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        self.benchmark.get_bounds()
+    }
+
+    fn get_variable_types(&self) -> Option<Vec<VariableType>> {
+        self.benchmark.get_variable_types()
+    }
+
+    // the constraints stay exact
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        self.benchmark.get_inequality_constraints_f64(input)
+    }
+
+    fn get_equality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        self.benchmark.get_equality_constraints_f64(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{fst_dejong::FstDeJong, traits::HasBuilder};
+
+    fn sphere() -> FstDeJong {
+        FstDeJong::builder().dimensions(3).build().unwrap()
+    }
+
+    fn samples<B: Benchmark>(problem: &Noisy<B>, input: &[f32]) -> Vec<f32> {
+        (0..10000).map(|_| problem.cost_function(input)).collect()
+    }
+
+    #[test]
+    fn noise_models() {
+        let input = [1f32, 1f32, 1f32];
+        let gaussian = Noisy::new(sphere(), Noise::Gaussian(0.5));
+        let values = samples(&gaussian, &input);
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32;
+        assert!((mean - 3f32).abs() < 0.05);
+        assert!((variance.sqrt() - 0.5).abs() < 0.05);
+        assert_eq!(gaussian.noiseless_cost(&input), 3f32);
+
+        let multiplicative = Noisy::new(sphere(), Noise::Multiplicative(0.1));
+        assert_eq!(multiplicative.cost_function(&[0f32; 3]), 0f32);
+        assert!(samples(&multiplicative, &input)
+            .iter()
+            .any(|v| (v - 3f32).abs() > 0.1));
+
+        // most Cauchy samples are close, a few are far off
+        let cauchy = Noisy::new(sphere(), Noise::Cauchy(0.01));
+        let values = samples(&cauchy, &input);
+        let close = values.iter().filter(|v| (*v - 3f32).abs() < 0.1).count();
+        assert!(close > 9000 && close < 10000);
+        assert!(values.iter().any(|v| (v - 3f32).abs() > 1f32));
+    }
+
+    #[test]
+    fn probability() {
+        let input = [1f32, 2f32, 0f32];
+        let never = Noisy::new(sphere(), Noise::Cauchy(10f32)).set_probability(0f32);
+        assert!(samples(&never, &input).iter().all(|v| *v == 5f32));
+        let unscaled = Noisy::new(sphere(), Noise::Cauchy(0f32));
+        assert!(samples(&unscaled, &input).iter().all(|v| *v == 5f32));
+        let sometimes = Noisy::new(sphere(), Noise::Gaussian(1f32)).set_probability(0.2);
+        let disturbed = samples(&sometimes, &input)
+            .iter()
+            .filter(|v| **v != 5f32)
+            .count();
+        assert!((1500..2500).contains(&disturbed));
+        assert_eq!(sometimes.get_probability(), 0.2);
+        assert_eq!(sometimes.get_expected_min(), Some(0f32));
+        assert_eq!(Noisy::<FstDeJong>::FUNCTION_NAME, FstDeJong::FUNCTION_NAME);
+    }
+}
@@ -1,10 +1,5 @@
-use std::{
-    f32::consts::{E, PI},
-    ops::{Add, Div, Mul, Sub},
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
-use rand_distr::num_traits::{Float, ToPrimitive};
+use crate::problem_definitions::Real;
 
 // source:  https://benchmarkfcns.info/doc/periodicfcn.html
 //
@@ -67,9 +62,30 @@ impl Benchmark for Periodic {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        1f32.add(input.iter().map(|&x| x.sin().powi(2)).sum::<f32>())
-            .sub(0.1.mul(E).powf(input.iter().map(|&x| x.powi(2)).sum::<f32>()))
+        periodic(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        periodic(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-10f32, 10f32))
+    }
+
+    // the exponential term is 1 at the origin, so the minimum is 0 rather than 0.9
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn periodic<F: Real>(input: &[F]) -> F {
+    F::one() + input.iter().map(|x| x.sin().powi(2)).sum::<F>()
+        - (F::from_f64(0.1) * F::E()).powf(input.iter().map(|x| x.powi(2)).sum::<F>())
 }
 
 impl Default for Periodic {
@@ -155,4 +171,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Periodic::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
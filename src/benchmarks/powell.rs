@@ -0,0 +1,180 @@
+use crate::benchmarks::traits::{Benchmark, BuilderError, HasBuilder};
+use crate::problem_definitions::Real;
+
+// defined for a multiple of four dimensions, others are reported by try_set_dim
+// source: https://www.sfu.ca/~ssurjano/powell.html
+#[derive(Debug)]
+pub struct Powell {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Powell> for Powell {}
+
+impl Benchmark for Powell {
+    const FUNCTION_NAME: &'static str = "Powell function";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    // a variable past the last group of four would have no effect, the closest smaller
+    // multiple of four is the one expected
+    fn try_set_dim(self, value: usize) -> Result<Self, BuilderError> {
+        match value == 0 || !value.is_multiple_of(4) {
+            true => Err(BuilderError::WrongDim {
+                expected: (value - value % 4).max(4),
+                found: value,
+            }),
+            false => Ok(self.set_dim(value)),
+        }
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        powell(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        powell(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-4f32, 5f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn powell<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    input
+        .chunks_exact(4)
+        .map(|x| {
+            (x[0] + c(10.0) * x[1]).powi(2)
+                + c(5.0) * (x[2] - x[3]).powi(2)
+                + (x[1] - c(2.0) * x[2]).powi(4)
+                + c(10.0) * (x[0] - x[3]).powi(4)
+        })
+        .sum()
+}
+
+impl Default for Powell {
+    fn default() -> Self {
+        Self {
+            min: -4f32,
+            max: 5f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn random_in_range() {
+        let powell = Powell::builder()
+            .minimum(-4f32)
+            .maximum(5f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = powell.get_random();
+        let random_2 = powell.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1.into_iter().all(|x| (-4f32..=5f32).contains(&x)));
+    }
+
+    #[test]
+    fn optimum() {
+        for dimensions in [4usize, 8, 12] {
+            let powell = Powell::builder()
+                .minimum(-4f32)
+                .maximum(5f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = powell.get_expected_min().unwrap();
+            let coords = powell.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (powell.cost_function(coords) - expected).abs() <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(powell.cost_function(&powell.get_random()) >= expected);
+            }
+        }
+    }
+
+    #[test]
+    fn groups_of_four() {
+        for (dimensions, expected) in [(0usize, 4usize), (2, 4), (5, 4), (11, 8)] {
+            assert!(matches!(
+                Powell::builder().dimensions(dimensions).build(),
+                Err(BuilderError::WrongDim { expected: e, found }) if e == expected && found == dimensions
+            ));
+        }
+        assert_eq!(
+            Powell::builder()
+                .dimensions(8usize)
+                .build()
+                .unwrap()
+                .get_dim(),
+            8
+        );
+    }
+}
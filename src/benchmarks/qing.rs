@@ -1,9 +1,7 @@
-use std::{
-    f32::consts::{E, PI},
-    ops::{Add, Div, Mul, Sub},
-};
+use std::f32::consts::{E, PI};
 
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
 use rand::random;
 use rand_distr::num_traits::{Float, ToPrimitive};
 
@@ -68,12 +66,33 @@ impl Benchmark for Qing {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        input
-            .iter()
-            .enumerate()
-            .map(|(i, &x)| x.powi(2).sub(i as f32).powi(2))
-            .sum::<f32>()
+        qing(input)
     }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        qing(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-500f32, 500f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    // x_i = sqrt(i), indexed from 0
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some((0..dim).map(|i| (i as f32).sqrt()).collect())
+    }
+}
+
+fn qing<F: Real>(input: &[F]) -> F {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| x.powi(2).sub(F::from_f64(i as f64)).powi(2))
+        .sum::<F>()
 }
 
 impl Default for Qing {
@@ -159,4 +178,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Qing::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
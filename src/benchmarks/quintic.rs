@@ -1,8 +1,5 @@
-use std::{
-    ops::{Add, Mul, Sub},
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
 
 // source:  https://al-roomi.org/benchmarks/unconstrained/n-dimensions/240-quintic-function
 //
@@ -65,19 +62,40 @@ impl Benchmark for Quintic {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        input
-            .iter()
-            .map(|&x| {
-                x.powi(5)
-                    .sub(3f32.mul(x.powi(4)))
-                    .add(4f32.mul(x.powi(3)))
-                    .add(2f32.mul(x.powi(2)))
-                    .sub(10f32.mul(x))
-                    .sub(4f32)
-                    .abs()
-            })
-            .sum::<f32>()
+        quintic(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        quintic(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-10f32, 10f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![-1f32; dim])
+    }
+}
+
+fn quintic<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    input
+        .iter()
+        .map(|&x| {
+            x.powi(5)
+                .sub(c(3.0).mul(x.powi(4)))
+                .add(c(4.0).mul(x.powi(3)))
+                .add(c(2.0).mul(x.powi(2)))
+                .sub(c(10.0).mul(x))
+                .sub(c(4.0))
+                .abs()
+        })
+        .sum::<F>()
 }
 
 impl Default for Quintic {
@@ -163,4 +181,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Quintic::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
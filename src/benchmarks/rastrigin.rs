@@ -1,7 +1,7 @@
-use std::{f32::consts::PI, ops::{Mul, Sub}};
+use std::f32::consts::PI;
 
-use rand_distr::num_traits::ToPrimitive;
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::{HasGradient, Real};
 
 // source: https://gitlab.com/luca.baronti/python_benchmark_functions
 
@@ -64,22 +64,44 @@ impl Benchmark for Rastrigin {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        let dimensions = self.dim.to_f32().unwrap();
-        10f32 * dimensions
-            + input
-                .iter()
-                .map(|x| 
-                    x.powi(2)
-                    .sub(
-                        10f32.mul(
-                            (2f32 * PI * x)
-                            .cos()
-                            )
-                        ))
-                .sum::<f32>()
+        rastrigin(self.dim, input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        rastrigin(self.dim, input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5.12, 5.12))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+impl HasGradient for Rastrigin {
+    fn get_gradient(&self, input: &[f32]) -> Vec<f32> {
+        input
+            .iter()
+            .map(|x| 2f32 * x + 20f32 * PI * (2f32 * PI * x).sin())
+            .collect()
     }
 }
 
+fn rastrigin<F: Real>(dim: usize, input: &[F]) -> F {
+    let c = F::from_f64;
+    c(10.0) * c(dim as f64)
+        + input
+            .iter()
+            .map(|&x| x.powi(2).sub(c(10.0).mul((c(2.0) * F::PI() * x).cos())))
+            .sum::<F>()
+}
+
 impl Default for Rastrigin {
     fn default() -> Self {
         Self {
@@ -95,7 +117,7 @@ impl Default for Rastrigin {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::problem_definitions::{HasLocal, HasRandom};
+    use crate::problem_definitions::{numerical_gradient, HasLocal, HasRandom};
 
     #[test]
     fn random() {
@@ -163,4 +185,33 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn gradient() {
+        let problem = Rastrigin::builder()
+            .minimum(-5.12)
+            .maximum(5.12)
+            .dimensions(5usize)
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let input = problem.get_random();
+            let analytic = problem.get_gradient(&input);
+            let numerical = numerical_gradient(&problem, &input, 1e-3);
+            assert!(analytic
+                .iter()
+                .zip(numerical.iter())
+                .all(|(a, n)| (a - n).abs() <= 1e-2 * a.abs().max(1f32)));
+        }
+    }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Rastrigin::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
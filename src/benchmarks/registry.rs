@@ -0,0 +1,590 @@
+use std::fmt::Display;
+
+use crate::benchmarks::{
+    ackley::Ackley,
+    alpine2::Alpine2,
+    bbob::noiseless::Bbob,
+    beale::Beale,
+    bohachevsky::Bohachevsky,
+    booth::Booth,
+    branin::Branin,
+    cec::{cec2006::Cec2006, cec2017::Cec2017},
+    cross_in_tray::CrossInTray,
+    deb1::Deb1,
+    dixon_price::DixonPrice,
+    drop_wave::DropWave,
+    easom::Easom,
+    eggholder::Eggholder,
+    engineering::{
+        PressureVessel, SpeedReducer, TensionCompressionSpring, ThreeBarTruss, WeldedBeam,
+    },
+    foth_dejong::FothDejong,
+    fst_dejong::FstDeJong,
+    goldstein_price::GoldsteinPrice,
+    griewank::Griewank,
+    happy_cat::HappyCat,
+    himmelblau::Himmelblau,
+    holder_table::HolderTable,
+    levy::Levy,
+    matyas::Matyas,
+    michalewicz::Michalewicz,
+    periodic::Periodic,
+    powell::Powell,
+    qing::Qing,
+    quintic::Quintic,
+    rastrigin::Rastrigin,
+    rosenbrock::Rosenbrock,
+    salomon::Salomon,
+    schaffer::{SchafferN2, SchafferN4},
+    schwefel::Schwefel,
+    six_hump_camel::SixHumpCamel,
+    snd_dejong::SndDeJong,
+    styblinsky_and_tang::StyblinskyAndTang,
+    sum_of_different_powers::SumOfDifferentPowers,
+    traits::{local_next_in, random_in, reflect_into, Benchmark, BuilderError, HasBuilder},
+    trd_dejong::TrdDejong,
+    trid::Trid,
+    xinsheyang::XinSheYang,
+    zakharov::Zakharov,
+};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+use crate::variables::{local_next_mixed, random_mixed, repair_mixed, VariableType};
+
+// object safe view of a benchmark, every Benchmark is one, boxed it is a problem on its own so
+// heterogeneous benchmarks can live in one Vec<Box<dyn DynBenchmark>> and go to any solver
+pub trait DynBenchmark {
+    fn get_name(&self) -> &'static str;
+    fn get_min(&self) -> f32;
+    fn get_max(&self) -> f32;
+    fn get_dim(&self) -> usize;
+    fn get_expected_min(&self) -> Option<f32>;
+    fn get_expected_min_coords(&self) -> Option<&[f32]>;
+    fn get_bounds(&self) -> Vec<(f32, f32)>;
+    fn cost_function(&self, input: &[f32]) -> f32;
+    fn cost_function_f64(&self, input: &[f64]) -> f64;
+    fn get_variable_types(&self) -> Option<Vec<VariableType>>;
+    fn get_inequality_constraints(&self, input: &[f32]) -> Vec<f32>;
+    fn get_equality_constraints(&self, input: &[f32]) -> Vec<f32>;
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64>;
+    fn get_equality_constraints_f64(&self, input: &[f64]) -> Vec<f64>;
+}
+
+impl<T> DynBenchmark for T
+where
+    T: Benchmark,
+{
+    fn get_name(&self) -> &'static str {
+        T::FUNCTION_NAME
+    }
+
+    fn get_min(&self) -> f32 {
+        Benchmark::get_min(self)
+    }
+
+    fn get_max(&self) -> f32 {
+        Benchmark::get_max(self)
+    }
+
+    fn get_dim(&self) -> usize {
+        Benchmark::get_dim(self)
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        Benchmark::get_expected_min(self)
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        Benchmark::get_expected_min_coords(self)
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        Benchmark::get_bounds(self)
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        Benchmark::cost_function(self, input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        Benchmark::cost_function_f64(self, input)
+    }
+
+    fn get_variable_types(&self) -> Option<Vec<VariableType>> {
+        Benchmark::get_variable_types(self)
+    }
+
+    fn get_inequality_constraints(&self, input: &[f32]) -> Vec<f32> {
+        Benchmark::get_inequality_constraints(self, input)
+    }
+
+    fn get_equality_constraints(&self, input: &[f32]) -> Vec<f32> {
+        Benchmark::get_equality_constraints(self, input)
+    }
+
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        Benchmark::get_inequality_constraints_f64(self, input)
+    }
+
+    fn get_equality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        Benchmark::get_equality_constraints_f64(self, input)
+    }
+}
+
+impl DynBenchmark for Box<dyn DynBenchmark> {
+    fn get_name(&self) -> &'static str {
+        self.as_ref().get_name()
+    }
+
+    fn get_min(&self) -> f32 {
+        self.as_ref().get_min()
+    }
+
+    fn get_max(&self) -> f32 {
+        self.as_ref().get_max()
+    }
+
+    fn get_dim(&self) -> usize {
+        self.as_ref().get_dim()
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.as_ref().get_expected_min()
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.as_ref().get_expected_min_coords()
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        self.as_ref().get_bounds()
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        self.as_ref().cost_function(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        self.as_ref().cost_function_f64(input)
+    }
+
+    fn get_variable_types(&self) -> Option<Vec<VariableType>> {
+        self.as_ref().get_variable_types()
+    }
+
+    fn get_inequality_constraints(&self, input: &[f32]) -> Vec<f32> {
+        self.as_ref().get_inequality_constraints(input)
+    }
+
+    fn get_equality_constraints(&self, input: &[f32]) -> Vec<f32> {
+        self.as_ref().get_equality_constraints(input)
+    }
+
+    fn get_inequality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        self.as_ref().get_inequality_constraints_f64(input)
+    }
+
+    fn get_equality_constraints_f64(&self, input: &[f64]) -> Vec<f64> {
+        self.as_ref().get_equality_constraints_f64(input)
+    }
+}
+
+// boxed benchmarks can be passed to the solvers directly
+impl ProblemDomain for Box<dyn DynBenchmark> {
+    type Item = f32;
+
+    fn get_minimum(&self) -> Self::Item {
+        self.as_ref().get_min()
+    }
+
+    fn get_maximum(&self) -> Self::Item {
+        self.as_ref().get_max()
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.as_ref().get_dim()
+    }
+
+    fn cost_function(&self, input: &[Self::Item]) -> Self::Item {
+        self.as_ref().cost_function(input)
+    }
+
+    fn repair(&self, input: Vec<f32>) -> Vec<f32> {
+        match self.as_ref().get_variable_types() {
+            Some(types) => repair_mixed(&types, input),
+            None => reflect_into(&self.as_ref().get_bounds(), input),
+        }
+    }
+
+    fn get_inequality_constraints(&self, input: &[f32]) -> Vec<f32> {
+        self.as_ref().get_inequality_constraints(input)
+    }
+
+    fn get_equality_constraints(&self, input: &[f32]) -> Vec<f32> {
+        self.as_ref().get_equality_constraints(input)
+    }
+}
+
+impl HasRandom for Box<dyn DynBenchmark> {
+    fn get_random(&self) -> Vec<f32> {
+        match self.as_ref().get_variable_types() {
+            Some(types) => random_mixed(&types),
+            None => random_in(&self.as_ref().get_bounds()),
+        }
+    }
+}
+
+impl HasLocal for Box<dyn DynBenchmark> {
+    fn get_local_next(&self, input: &[f32]) -> Vec<f32> {
+        match self.as_ref().get_variable_types() {
+            Some(types) => local_next_mixed(&types, input),
+            None => local_next_in(
+                &self.as_ref().get_bounds(),
+                (self.as_ref().get_min(), self.as_ref().get_max()),
+                input,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modality {
+    Unimodal,
+    Multimodal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separability {
+    Separable,
+    NonSeparable,
+}
+
+#[derive(Debug)]
+pub enum RegistryError {
+    Unknown(String),
+    Builder(BuilderError),
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(id) => write!(f, "Unknown benchmark: {}", id),
+            Self::Builder(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+// metadata and constructor of one benchmark
+#[derive(Clone, Copy)]
+pub struct BenchmarkInfo {
+    id: &'static str,
+    name: &'static str,
+    modality: Modality,
+    separability: Separability,
+    dimensions: Option<usize>,
+    default_domain: fn(usize) -> Option<(f32, f32)>,
+    known_min: fn(usize) -> Option<f32>,
+    known_min_coords: fn(usize) -> Option<Vec<f32>>,
+    constructor: fn(usize) -> Result<Box<dyn DynBenchmark>, BuilderError>,
+}
+
+impl BenchmarkInfo {
+    fn new<T>(id: &'static str, modality: Modality, separability: Separability) -> Self
+    where
+        T: Benchmark + Default + HasBuilder<T> + 'static,
+    {
+        Self {
+            id,
+            name: T::FUNCTION_NAME,
+            modality,
+            separability,
+            dimensions: T::DIMENSIONS,
+            default_domain: T::get_default_domain,
+            known_min: T::get_known_min,
+            known_min_coords: T::get_known_min_coords,
+            constructor: |dim| {
+                T::builder()
+                    .dimensions(dim)
+                    .build()
+                    .map(|benchmark| Box::new(benchmark) as Box<dyn DynBenchmark>)
+            },
+        }
+    }
+
+    pub fn get_id(&self) -> &'static str {
+        self.id
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn get_modality(&self) -> Modality {
+        self.modality
+    }
+
+    pub fn get_separability(&self) -> Separability {
+        self.separability
+    }
+
+    // Some for the functions defined only for one number of dimensions
+    pub fn get_dimensions(&self) -> Option<usize> {
+        self.dimensions
+    }
+
+    pub fn get_default_domain(&self, dim: usize) -> Option<(f32, f32)> {
+        (self.default_domain)(dim)
+    }
+
+    pub fn get_known_min(&self, dim: usize) -> Option<f32> {
+        (self.known_min)(dim)
+    }
+
+    pub fn get_known_min_coords(&self, dim: usize) -> Option<Vec<f32>> {
+        (self.known_min_coords)(dim)
+    }
+
+    // built on the default domain with the known optimum filled in
+    pub fn build(&self, dim: usize) -> Result<Box<dyn DynBenchmark>, BuilderError> {
+        (self.constructor)(dim)
+    }
+}
+
+impl std::fmt::Debug for BenchmarkInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BenchmarkInfo")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("modality", &self.modality)
+            .field("separability", &self.separability)
+            .field("dimensions", &self.dimensions)
+            .finish()
+    }
+}
+
+macro_rules! suite {
+    ($list:ident, $family:ident, $prefix:literal, $($f:literal: $modality:ident, $separability:ident);+ $(;)?) => {
+        $(
+            $list.push(BenchmarkInfo::new::<$family<$f>>(
+                concat!($prefix, $f),
+                Modality::$modality,
+                Separability::$separability,
+            ));
+        )+
+    };
+}
+
+// every benchmark of the crate, the identifiers are stable
+pub fn benchmarks() -> Vec<BenchmarkInfo> {
+    use Modality::{Multimodal, Unimodal};
+    use Separability::{NonSeparable, Separable};
+
+    let mut list = vec![
+        BenchmarkInfo::new::<Ackley>("ackley", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Alpine2>("alpine2", Multimodal, Separable),
+        BenchmarkInfo::new::<Beale>("beale", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Bohachevsky>("bohachevsky", Multimodal, Separable),
+        BenchmarkInfo::new::<Booth>("booth", Unimodal, NonSeparable),
+        BenchmarkInfo::new::<Branin>("branin", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<CrossInTray>("cross_in_tray", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Deb1>("deb1", Multimodal, Separable),
+        BenchmarkInfo::new::<DixonPrice>("dixon_price", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<DropWave>("drop_wave", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Easom>("easom", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Eggholder>("eggholder", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<FothDejong>("foth_dejong", Unimodal, Separable),
+        BenchmarkInfo::new::<FstDeJong>("fst_dejong", Unimodal, Separable),
+        BenchmarkInfo::new::<GoldsteinPrice>("goldstein_price", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Griewank>("griewank", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<HappyCat>("happy_cat", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Himmelblau>("himmelblau", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<HolderTable>("holder_table", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Levy>("levy", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Matyas>("matyas", Unimodal, NonSeparable),
+        BenchmarkInfo::new::<Michalewicz>("michalewicz", Multimodal, Separable),
+        BenchmarkInfo::new::<Periodic>("periodic", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Powell>("powell", Unimodal, NonSeparable),
+        BenchmarkInfo::new::<Qing>("qing", Multimodal, Separable),
+        BenchmarkInfo::new::<Quintic>("quintic", Multimodal, Separable),
+        BenchmarkInfo::new::<Rastrigin>("rastrigin", Multimodal, Separable),
+        // the Rosenbrock valley has a second local minimum from 4 dimensions on
+        BenchmarkInfo::new::<Rosenbrock>("rosenbrock", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Salomon>("salomon", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<SchafferN2>("schaffer_n2", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<SchafferN4>("schaffer_n4", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<Schwefel>("schwefel", Multimodal, Separable),
+        BenchmarkInfo::new::<SixHumpCamel>("six_hump_camel", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<SndDeJong>("snd_dejong", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<StyblinskyAndTang>("styblinsky_and_tang", Multimodal, Separable),
+        BenchmarkInfo::new::<SumOfDifferentPowers>("sum_of_different_powers", Unimodal, Separable),
+        BenchmarkInfo::new::<TrdDejong>("trd_dejong", Unimodal, Separable),
+        BenchmarkInfo::new::<Trid>("trid", Unimodal, NonSeparable),
+        BenchmarkInfo::new::<XinSheYang>("xinsheyang", Multimodal, Separable),
+        BenchmarkInfo::new::<Zakharov>("zakharov", Unimodal, NonSeparable),
+        // the constrained problems are listed as multimodal and non-separable, their feasible
+        // regions are neither convex nor separable in general
+        BenchmarkInfo::new::<PressureVessel>("pressure_vessel", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<WeldedBeam>("welded_beam", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<TensionCompressionSpring>(
+            "tension_compression_spring",
+            Multimodal,
+            NonSeparable,
+        ),
+        BenchmarkInfo::new::<SpeedReducer>("speed_reducer", Multimodal, NonSeparable),
+        BenchmarkInfo::new::<ThreeBarTruss>("three_bar_truss", Multimodal, NonSeparable),
+    ];
+    // source: https://numbbo.github.io/coco/testsuites/bbob
+    suite!(list, Bbob, "bbob_f",
+        1: Unimodal, Separable; 2: Unimodal, Separable; 3: Multimodal, Separable;
+        4: Multimodal, Separable; 5: Unimodal, Separable; 6: Unimodal, NonSeparable;
+        7: Unimodal, NonSeparable; 8: Multimodal, NonSeparable; 9: Multimodal, NonSeparable;
+        10: Unimodal, NonSeparable; 11: Unimodal, NonSeparable; 12: Unimodal, NonSeparable;
+        13: Unimodal, NonSeparable; 14: Unimodal, NonSeparable; 15: Multimodal, NonSeparable;
+        16: Multimodal, NonSeparable; 17: Multimodal, NonSeparable; 18: Multimodal, NonSeparable;
+        19: Multimodal, NonSeparable; 20: Multimodal, NonSeparable; 21: Multimodal, NonSeparable;
+        22: Multimodal, NonSeparable; 23: Multimodal, NonSeparable; 24: Multimodal, NonSeparable;
+    );
+    suite!(list, Cec2017, "cec2017_f",
+        1: Unimodal, NonSeparable; 2: Unimodal, NonSeparable; 3: Unimodal, NonSeparable;
+        4: Multimodal, NonSeparable; 5: Multimodal, NonSeparable; 6: Multimodal, NonSeparable;
+        7: Multimodal, NonSeparable; 8: Multimodal, NonSeparable; 9: Multimodal, NonSeparable;
+        10: Multimodal, NonSeparable; 11: Multimodal, NonSeparable; 12: Multimodal, NonSeparable;
+        13: Multimodal, NonSeparable; 14: Multimodal, NonSeparable; 15: Multimodal, NonSeparable;
+        16: Multimodal, NonSeparable; 17: Multimodal, NonSeparable; 18: Multimodal, NonSeparable;
+        19: Multimodal, NonSeparable; 20: Multimodal, NonSeparable; 21: Multimodal, NonSeparable;
+        22: Multimodal, NonSeparable; 23: Multimodal, NonSeparable; 24: Multimodal, NonSeparable;
+        25: Multimodal, NonSeparable; 26: Multimodal, NonSeparable; 27: Multimodal, NonSeparable;
+        28: Multimodal, NonSeparable; 29: Multimodal, NonSeparable; 30: Multimodal, NonSeparable;
+    );
+    suite!(list, Cec2006, "cec2006_g",
+        1: Multimodal, NonSeparable; 2: Multimodal, NonSeparable; 3: Multimodal, NonSeparable;
+        4: Multimodal, NonSeparable; 5: Multimodal, NonSeparable; 6: Multimodal, NonSeparable;
+        7: Multimodal, NonSeparable; 8: Multimodal, NonSeparable; 9: Multimodal, NonSeparable;
+        10: Multimodal, NonSeparable; 11: Multimodal, NonSeparable; 12: Multimodal, NonSeparable;
+        13: Multimodal, NonSeparable; 14: Multimodal, NonSeparable; 15: Multimodal, NonSeparable;
+        16: Multimodal, NonSeparable; 17: Multimodal, NonSeparable; 18: Multimodal, NonSeparable;
+        19: Multimodal, NonSeparable; 20: Multimodal, NonSeparable; 21: Multimodal, NonSeparable;
+        22: Multimodal, NonSeparable; 23: Multimodal, NonSeparable; 24: Multimodal, NonSeparable;
+    );
+    list
+}
+
+pub fn find(id: &str) -> Option<BenchmarkInfo> {
+    benchmarks().into_iter().find(|info| info.id == id)
+}
+
+pub fn build(id: &str, dim: usize) -> Result<Box<dyn DynBenchmark>, RegistryError> {
+    find(id)
+        .ok_or_else(|| RegistryError::Unknown(id.to_string()))?
+        .build(dim)
+        .map_err(RegistryError::Builder)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solvers::hill_climber::HillClimber;
+    use std::collections::HashSet;
+
+    #[test]
+    fn unique_ids() {
+        let list = benchmarks();
+        let ids: HashSet<&str> = list.iter().map(|info| info.get_id()).collect();
+        assert_eq!(ids.len(), list.len());
+    }
+
+    #[test]
+    fn known_optima() {
+        for info in benchmarks() {
+            // the ones with noise never hit their noise-free minimum
+            if ["foth_dejong", "xinsheyang"].contains(&info.get_id()) {
+                continue;
+            }
+            let dim = info.get_dimensions().unwrap_or(4);
+            let benchmark = info.build(dim).unwrap();
+            assert_eq!(benchmark.get_dim(), dim, "{}", info.get_id());
+            assert_eq!(benchmark.get_name(), info.get_name());
+            let (Some(expected), Some(coords)) = (
+                benchmark.get_expected_min(),
+                benchmark.get_expected_min_coords(),
+            ) else {
+                continue;
+            };
+            assert!(
+                (benchmark.as_ref().cost_function(coords) - expected).abs()
+                    <= 1e-3 * expected.abs().max(1f32),
+                "{}",
+                info.get_id()
+            );
+        }
+    }
+
+    #[test]
+    fn by_name() {
+        let schwefel = build("schwefel", 3).unwrap();
+        assert_eq!(schwefel.get_name(), "Schwefel function");
+        assert_eq!(schwefel.get_min(), -500f32);
+        assert_eq!(schwefel.get_random().len(), 3);
+        assert_eq!(
+            find("trid").unwrap().get_default_domain(3),
+            Some((-9f32, 9f32))
+        );
+        assert!(matches!(
+            build("unknown", 3),
+            Err(RegistryError::Unknown(_))
+        ));
+        let vessel = build("pressure_vessel", 4).unwrap();
+        let g13 = build("cec2006_g13", 5).unwrap();
+        assert_eq!(
+            ProblemDomain::get_inequality_constraints(&vessel, &[13.0, 7.0, 42.1, 176.6]).len(),
+            4
+        );
+        assert_eq!(
+            vessel.repair(vec![12.6, 7.2, 42.1, 176.6])[..2],
+            [13f32, 7f32]
+        );
+        assert_eq!(
+            ProblemDomain::get_equality_constraints(&g13, &[0f32; 5]).len(),
+            3
+        );
+        assert_eq!(
+            find("rosenbrock").unwrap().get_modality(),
+            Modality::Multimodal
+        );
+        assert!(matches!(
+            build("himmelblau", 3),
+            Err(RegistryError::Builder(BuilderError::WrongDim { .. }))
+        ));
+    }
+
+    #[test]
+    fn delegates() {
+        let benchmark = SndDeJong::builder().dimensions(4).build().unwrap();
+        let input = vec![0.5f32; 4];
+        let expected = Benchmark::cost_function(&benchmark, &input);
+        let boxed: Box<dyn DynBenchmark> = Box::new(benchmark);
+        assert_eq!(ProblemDomain::get_dimensions(&boxed), 4);
+        assert_eq!(ProblemDomain::get_minimum(&boxed), -2.048);
+        assert_eq!(ProblemDomain::get_maximum(&boxed), 2.048);
+        assert_eq!(ProblemDomain::cost_function(&boxed, &input), expected);
+        let random = HasRandom::get_random(&boxed);
+        assert_eq!(random.len(), 4);
+        assert!(random.iter().all(|x| (-2.048..=2.048).contains(x)));
+        assert_eq!(HasLocal::get_local_next(&boxed, &random).len(), 4);
+    }
+
+    #[test]
+    fn heterogeneous_grid() {
+        let grid: Vec<Box<dyn DynBenchmark>> = vec![
+            Box::new(Schwefel::builder().dimensions(3).build().unwrap()),
+            Box::new(SndDeJong::builder().dimensions(4).build().unwrap()),
+            Box::new(Bbob::<1>::builder().dimensions(2).build().unwrap()),
+            build("cec2017_f1", 10).unwrap(),
+        ];
+        for benchmark in grid {
+            let dim = benchmark.get_dim();
+            let mut solver = HillClimber::new(20, 10, benchmark);
+            solver.run();
+            assert_eq!(solver.get_best_coords().unwrap().len(), dim);
+        }
+    }
+}
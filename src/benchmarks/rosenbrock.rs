@@ -0,0 +1,147 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// the same function as SndDeJong, with the usual domain [-5, 10] and a known optimum
+// source: https://www.sfu.ca/~ssurjano/rosen.html
+#[derive(Debug)]
+pub struct Rosenbrock {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Rosenbrock> for Rosenbrock {}
+
+impl Benchmark for Rosenbrock {
+    const FUNCTION_NAME: &'static str = "Rosenbrock function";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        rosenbrock(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        rosenbrock(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5f32, 10f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![1f32; dim])
+    }
+}
+
+fn rosenbrock<F: Real>(input: &[F]) -> F {
+    input
+        .windows(2)
+        .map(|pair| {
+            F::from_f64(100.0) * (pair[1] - pair[0].powi(2)).powi(2) + (pair[0] - F::one()).powi(2)
+        })
+        .sum()
+}
+
+impl Default for Rosenbrock {
+    fn default() -> Self {
+        Self {
+            min: -5f32,
+            max: 10f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn random_in_range() {
+        let rosenbrock = Rosenbrock::builder()
+            .minimum(-5f32)
+            .maximum(10f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = rosenbrock.get_random();
+        let random_2 = rosenbrock.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1.into_iter().all(|x| (-5f32..=10f32).contains(&x)));
+    }
+
+    #[test]
+    fn optimum() {
+        for dimensions in [2usize, 5, 10] {
+            let rosenbrock = Rosenbrock::builder()
+                .minimum(-5f32)
+                .maximum(10f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = rosenbrock.get_expected_min().unwrap();
+            let coords = rosenbrock.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (rosenbrock.cost_function(coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(rosenbrock.cost_function(&rosenbrock.get_random()) >= expected);
+            }
+        }
+    }
+}
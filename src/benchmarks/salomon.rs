@@ -1,11 +1,5 @@
-use std::{
-    f32::consts::PI,
-    ops::{Add, Mul, Sub},
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
-use rand::random;
-use rand_distr::num_traits::{Float, ToPrimitive};
+use crate::problem_definitions::Real;
 
 // source: https://benchmarkfcns.info/doc/salomonfcn.html
 #[derive(Debug)]
@@ -68,25 +62,29 @@ impl Benchmark for Salomon {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        (1 as f32)
-            .sub(
-                input
-                    .iter()
-                    .map(|x| x.powi(2))
-                    .sum::<f32>()
-                    .sqrt()
-                    .mul(2f32.mul(PI))
-                    .cos(),
-            )
-            .add(
-                input
-                    .iter()
-                    .map(|x| x.powi(2))
-                    .sum::<f32>()
-                    .sqrt()
-                    .mul(0.1),
-            )
+        salomon(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        salomon(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-100f32, 100f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn salomon<F: Real>(input: &[F]) -> F {
+    let norm = input.iter().map(|x| x.powi(2)).sum::<F>().sqrt();
+    F::one() - (F::from_f64(2.0) * F::PI() * norm).cos() + F::from_f64(0.1) * norm
 }
 
 impl Default for Salomon {
@@ -172,4 +170,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Salomon::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
@@ -0,0 +1,300 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// Schaffer functions N. 2 and N. 4, generalised to d dimensions over consecutive pairs
+// source: https://www.sfu.ca/~ssurjano/schaffer2.html
+// source: https://www.sfu.ca/~ssurjano/schaffer4.html
+#[derive(Debug)]
+pub struct SchafferN2 {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<SchafferN2> for SchafferN2 {}
+
+impl Benchmark for SchafferN2 {
+    const FUNCTION_NAME: &'static str = "Schaffer function N. 2";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        schaffer_n2(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        schaffer_n2(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-100f32, 100f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn schaffer_n2<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    input
+        .windows(2)
+        .map(|pair| {
+            let (x, y) = (pair[0].powi(2), pair[1].powi(2));
+            c(0.5) + ((x - y).sin().powi(2) - c(0.5)) / (F::one() + c(0.001) * (x + y)).powi(2)
+        })
+        .sum()
+}
+
+impl Default for SchafferN2 {
+    fn default() -> Self {
+        Self {
+            min: -100f32,
+            max: 100f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+// the minimum of one pair is at (0, 1.25313), the optimum alternates between both values
+#[derive(Debug)]
+pub struct SchafferN4 {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<SchafferN4> for SchafferN4 {}
+
+impl Benchmark for SchafferN4 {
+    const FUNCTION_NAME: &'static str = "Schaffer function N. 4";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        schaffer_n4(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        schaffer_n4(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-100f32, 100f32))
+    }
+
+    fn get_known_min(dim: usize) -> Option<f32> {
+        Some(0.292_578_63 * dim.saturating_sub(1) as f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(
+            (0..dim)
+                .map(|i| if i % 2 == 0 { 0f32 } else { 1.253_131_8 })
+                .collect(),
+        )
+    }
+}
+
+fn schaffer_n4<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    input
+        .windows(2)
+        .map(|pair| {
+            let (x, y) = (pair[0].powi(2), pair[1].powi(2));
+            c(0.5)
+                + ((x - y).abs().sin().cos().powi(2) - c(0.5))
+                    / (F::one() + c(0.001) * (x + y)).powi(2)
+        })
+        .sum()
+}
+
+impl Default for SchafferN4 {
+    fn default() -> Self {
+        Self {
+            min: -100f32,
+            max: 100f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn n2_random_in_range() {
+        let schaffer = SchafferN2::builder()
+            .minimum(-100f32)
+            .maximum(100f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = schaffer.get_random();
+        let random_2 = schaffer.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1
+            .into_iter()
+            .all(|x| (-100f32..=100f32).contains(&x)));
+    }
+
+    #[test]
+    fn n2_optimum() {
+        for dimensions in [2usize, 5, 10] {
+            let schaffer = SchafferN2::builder()
+                .minimum(-100f32)
+                .maximum(100f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = schaffer.get_expected_min().unwrap();
+            let coords = schaffer.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (schaffer.cost_function(coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(schaffer.cost_function(&schaffer.get_random()) >= expected);
+            }
+        }
+    }
+
+    #[test]
+    fn n4_random_in_range() {
+        let schaffer = SchafferN4::builder()
+            .minimum(-100f32)
+            .maximum(100f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = schaffer.get_random();
+        let random_2 = schaffer.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1
+            .into_iter()
+            .all(|x| (-100f32..=100f32).contains(&x)));
+    }
+
+    #[test]
+    fn n4_optimum() {
+        for dimensions in [2usize, 5, 10] {
+            let schaffer = SchafferN4::builder()
+                .minimum(-100f32)
+                .maximum(100f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = schaffer.get_expected_min().unwrap();
+            let coords = schaffer.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (schaffer.cost_function(coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(schaffer.cost_function(&schaffer.get_random()) >= expected);
+            }
+        }
+    }
+}
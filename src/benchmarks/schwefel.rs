@@ -1,6 +1,5 @@
-use rand_distr::num_traits::ToPrimitive;
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
 
 #[derive(Debug)]
 pub struct Schwefel {
@@ -61,13 +60,28 @@ impl Benchmark for Schwefel {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        let dimensions = self.dim.to_f32().unwrap();
-        418.9829 * dimensions
-            - input
-                .iter()
-                .map(|x| x * (x.abs().sqrt().sin()))
-                .sum::<f32>()
+        schwefel(self.dim, input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        schwefel(self.dim, input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-500f32, 500f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![420.968_75; dim])
+    }
+}
+
+fn schwefel<F: Real>(dim: usize, input: &[F]) -> F {
+    F::from_f64(418.9829 * dim as f64) - input.iter().map(|&x| x * x.abs().sqrt().sin()).sum::<F>()
 }
 
 impl Default for Schwefel {
@@ -153,4 +167,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = Schwefel::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
@@ -0,0 +1,178 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// x1 is in [-3, 3] and x2 in [-2, 2]
+// source: https://www.sfu.ca/~ssurjano/camel6.html
+#[derive(Debug)]
+pub struct SixHumpCamel {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<SixHumpCamel> for SixHumpCamel {}
+
+const MINIMISERS: [[f32; 2]; 2] = [[0.089_842_01, -0.712_656_4], [-0.089_842_01, 0.712_656_4]];
+
+impl Benchmark for SixHumpCamel {
+    const FUNCTION_NAME: &'static str = "Six-Hump Camel function";
+    const DIMENSIONS: Option<usize> = Some(2);
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords
+            .as_deref()
+            .or(Some(MINIMISERS[0].as_slice()))
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    // defined only in 2 dimensions, other values are ignored and reported by try_set_dim
+    fn set_dim(self, _value: usize) -> Self {
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        six_hump_camel(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        six_hump_camel(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-3f32, 3f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(-1.031_628_5)
+    }
+
+    fn get_all_expected_min_coords(&self) -> Vec<Vec<f32>> {
+        match &self.expected_min_coords {
+            Some(coords) => vec![coords.clone()],
+            None => MINIMISERS.iter().map(|coords| coords.to_vec()).collect(),
+        }
+    }
+
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        [(-3f32, 3f32), (-2f32, 2f32)]
+            .into_iter()
+            .map(|(min, max)| (min.max(self.min), max.min(self.max)))
+            .collect()
+    }
+}
+
+fn six_hump_camel<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    let (x, y) = (input[0], input[1]);
+    (c(4.0) - c(2.1) * x.powi(2) + x.powi(4) / c(3.0)) * x.powi(2)
+        + x * y
+        + (c(-4.0) + c(4.0) * y.powi(2)) * y.powi(2)
+}
+
+impl Default for SixHumpCamel {
+    fn default() -> Self {
+        Self {
+            min: -3f32,
+            max: 3f32,
+            dim: 2usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::traits::BuilderError;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn optimum() {
+        let six_hump_camel = SixHumpCamel::builder()
+            .minimum(-3f32)
+            .maximum(3f32)
+            .build()
+            .unwrap();
+        let expected = six_hump_camel.get_expected_min().unwrap();
+        assert_eq!(six_hump_camel.get_all_expected_min_coords().len(), 2);
+        for coords in six_hump_camel.get_all_expected_min_coords() {
+            assert!(
+                (six_hump_camel.cost_function(&coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+        }
+        for _ in 0..100 {
+            assert!(
+                six_hump_camel.cost_function(&six_hump_camel.get_random())
+                    >= expected - 1e-4 * expected.abs()
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_dimensions() {
+        let result = SixHumpCamel::builder()
+            .minimum(-3f32)
+            .maximum(3f32)
+            .dimensions(3)
+            .build();
+        assert!(matches!(
+            result,
+            Err(BuilderError::WrongDim {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn random_in_bounds() {
+        let six_hump_camel = SixHumpCamel::builder()
+            .minimum(-3f32)
+            .maximum(3f32)
+            .build()
+            .unwrap();
+        for _ in 0..100 {
+            let random = six_hump_camel.get_random();
+            assert!((-3f32..=3f32).contains(&random[0]));
+            assert!((-2f32..=2f32).contains(&random[1]));
+        }
+    }
+}
@@ -2,6 +2,8 @@ use std::iter::zip;
 
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
 
+use crate::problem_definitions::{HasGradient, Real};
+
 #[derive(Debug)]
 pub struct SndDeJong {
     min: f32,
@@ -61,15 +63,54 @@ impl Benchmark for SndDeJong {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
+        snd_dejong(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        snd_dejong(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-2.048, 2.048))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![1f32; dim])
+    }
+}
+
+impl HasGradient for SndDeJong {
+    fn get_gradient(&self, input: &[f32]) -> Vec<f32> {
         let len = input.len();
-        let fst = &input[0..len - 1usize];
-        let snd = &input[1..len];
-        zip(fst, snd)
-            .map(|(&x1, &x2)| 100.0 * (x2 - x1.powf(2.0)).powf(2.0) + (1.0 - x1).powf(2.0))
-            .sum()
+        (0..len)
+            .map(|i| {
+                let mut derivative = 0f32;
+                if i + 1 < len {
+                    derivative += -400f32 * input[i] * (input[i + 1] - input[i].powi(2))
+                        - 2f32 * (1f32 - input[i]);
+                }
+                if i > 0 {
+                    derivative += 200f32 * (input[i] - input[i - 1].powi(2));
+                }
+                derivative
+            })
+            .collect()
     }
 }
 
+fn snd_dejong<F: Real>(input: &[F]) -> F {
+    let len = input.len();
+    let fst = &input[0..len - 1usize];
+    let snd = &input[1..len];
+    zip(fst, snd)
+        .map(|(&x1, &x2)| F::from_f64(100.0) * (x2 - x1.powi(2)).powi(2) + (F::one() - x1).powi(2))
+        .sum()
+}
+
 impl Default for SndDeJong {
     fn default() -> Self {
         Self {
@@ -85,7 +126,7 @@ impl Default for SndDeJong {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::problem_definitions::{HasLocal, HasRandom};
+    use crate::problem_definitions::{numerical_gradient, HasLocal, HasRandom};
 
     #[test]
     fn random() {
@@ -153,4 +194,33 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -5f32 && x < 5f32));
         assert!(new_high.into_iter().all(|x| x > -5f32 && x < 5f32));
     }
+
+    #[test]
+    fn gradient() {
+        let problem = SndDeJong::builder()
+            .minimum(-1f32)
+            .maximum(1f32)
+            .dimensions(5usize)
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let input = problem.get_random();
+            let analytic = problem.get_gradient(&input);
+            let numerical = numerical_gradient(&problem, &input, 1e-3);
+            assert!(analytic
+                .iter()
+                .zip(numerical.iter())
+                .all(|(a, n)| (a - n).abs() <= 5e-2 * a.abs().max(1f32)));
+        }
+    }
+
+    #[test]
+    fn known_optimum() {
+        let problem = SndDeJong::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
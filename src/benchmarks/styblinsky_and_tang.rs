@@ -1,9 +1,6 @@
-use std::{
-    f32::consts::PI,
-    ops::{Div, Mul, Sub, Add},
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+
+use crate::problem_definitions::{HasGradient, Real};
 use rand_distr::num_traits::{Float, ToPrimitive};
 
 // source: https://gitlab.com/luca.baronti/python_benchmark_functions
@@ -67,17 +64,44 @@ impl Benchmark for StyblinskyAndTang {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-      0.5 * input.iter().map(|x| 
-          x.powi(4)
-          .sub(
-              16f32.mul(x.powi(2))
-          )
-          .add(x.mul(5f32))
-      )
-          .sum::<f32>()
+        styblinsky_and_tang(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        styblinsky_and_tang(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5f32, 5f32))
+    }
+
+    fn get_known_min(dim: usize) -> Option<f32> {
+        Some(-39.166_165 * dim as f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![-2.903_534; dim])
     }
 }
 
+impl HasGradient for StyblinskyAndTang {
+    fn get_gradient(&self, input: &[f32]) -> Vec<f32> {
+        input
+            .iter()
+            .map(|x| 2f32 * x.powi(3) - 16f32 * x + 2.5)
+            .collect()
+    }
+}
+
+fn styblinsky_and_tang<F: Real>(input: &[F]) -> F {
+    let c = F::from_f64;
+    c(0.5)
+        * input
+            .iter()
+            .map(|&x| x.powi(4).sub(c(16.0).mul(x.powi(2))).add(x.mul(c(5.0))))
+            .sum::<F>()
+}
+
 impl Default for StyblinskyAndTang {
     fn default() -> Self {
         Self {
@@ -93,7 +117,7 @@ impl Default for StyblinskyAndTang {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::problem_definitions::{HasLocal, HasRandom};
+    use crate::problem_definitions::{numerical_gradient, HasLocal, HasRandom};
 
     #[test]
     fn random() {
@@ -154,11 +178,44 @@ mod test {
             .unwrap();
         let random_1 = styblinsky_and_tang.get_random();
         let new_local = styblinsky_and_tang.get_local_next(&random_1);
-        let new_low = styblinsky_and_tang.get_local_next(&vec![-500.0, -500.0, -500.0, -500.0, -500.0]);
+        let new_low =
+            styblinsky_and_tang.get_local_next(&vec![-500.0, -500.0, -500.0, -500.0, -500.0]);
         let new_high = styblinsky_and_tang.get_local_next(&vec![500.0, 500.0, 500.0, 500.0, 500.0]);
 
         assert!(new_local.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn gradient() {
+        let problem = StyblinskyAndTang::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(5usize)
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let input = problem.get_random();
+            let analytic = problem.get_gradient(&input);
+            let numerical = numerical_gradient(&problem, &input, 1e-3);
+            assert!(analytic
+                .iter()
+                .zip(numerical.iter())
+                .all(|(a, n)| (a - n).abs() <= 1e-2 * a.abs().max(1f32)));
+        }
+    }
+
+    #[test]
+    fn known_optimum() {
+        let problem = StyblinskyAndTang::builder()
+            .dimensions(5usize)
+            .build()
+            .unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
@@ -0,0 +1,148 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// source: https://www.sfu.ca/~ssurjano/sumpow.html
+#[derive(Debug)]
+pub struct SumOfDifferentPowers {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<SumOfDifferentPowers> for SumOfDifferentPowers {}
+
+impl Benchmark for SumOfDifferentPowers {
+    const FUNCTION_NAME: &'static str = "Sum of Different Powers function";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        sum_of_different_powers(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        sum_of_different_powers(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-1f32, 1f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn sum_of_different_powers<F: Real>(input: &[F]) -> F {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| x.abs().powi(i as i32 + 2))
+        .sum()
+}
+
+impl Default for SumOfDifferentPowers {
+    fn default() -> Self {
+        Self {
+            min: -1f32,
+            max: 1f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn random_in_range() {
+        let sum_of_different_powers = SumOfDifferentPowers::builder()
+            .minimum(-1f32)
+            .maximum(1f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = sum_of_different_powers.get_random();
+        let random_2 = sum_of_different_powers.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1.into_iter().all(|x| (-1f32..=1f32).contains(&x)));
+    }
+
+    #[test]
+    fn optimum() {
+        for dimensions in [2usize, 5, 10] {
+            let sum_of_different_powers = SumOfDifferentPowers::builder()
+                .minimum(-1f32)
+                .maximum(1f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = sum_of_different_powers.get_expected_min().unwrap();
+            let coords = sum_of_different_powers.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (sum_of_different_powers.cost_function(coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(
+                    sum_of_different_powers.cost_function(&sum_of_different_powers.get_random())
+                        >= expected
+                );
+            }
+        }
+    }
+}
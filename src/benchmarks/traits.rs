@@ -3,12 +3,15 @@ use std::{
     marker::{self, PhantomData},
 };
 
-use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain, Real};
+use crate::variables::{local_next_mixed, random_mixed, repair_mixed, VariableType};
 use rand::distributions::{Distribution, Uniform};
-use rand_distr::Normal;
 
 pub trait Benchmark {
     const FUNCTION_NAME: &'static str;
+    // functions defined only for one number of dimensions
+    const DIMENSIONS: Option<usize> = None;
+
     fn get_min(&self) -> f32;
     fn get_max(&self) -> f32;
     fn get_dim(&self) -> usize;
@@ -18,10 +21,88 @@ pub trait Benchmark {
     fn set_min(self, value: f32) -> Self;
     fn set_max(self, value: f32) -> Self;
     fn set_dim(self, value: usize) -> Self;
+    // set_dim that reports a number of dimensions the benchmark cannot take instead of keeping
+    // the current one
+    fn try_set_dim(self, value: usize) -> Result<Self, BuilderError>
+    where
+        Self: Sized,
+    {
+        match Self::DIMENSIONS {
+            Some(expected) if expected != value => Err(BuilderError::WrongDim {
+                expected,
+                found: value,
+            }),
+            _ => Ok(self.set_dim(value)),
+        }
+    }
     fn set_expected_min(self, value: Option<f32>) -> Self;
     fn set_expected_min_coords(self, value: Option<Vec<f32>>) -> Self;
 
     fn cost_function(&self, input: &[f32]) -> f32;
+
+    // double precision evaluation, every benchmark computes it in f64 rather than rounding the
+    // input to f32
+    fn cost_function_f64(&self, input: &[f64]) -> f64;
+
+    // canonical search domain, used by the builder when no minimum or maximum is given
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        None
+    }
+
+    // known global minimum for the number of dimensions, used by the builder when no expected
+    // minimum is given
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        None
+    }
+
+    fn get_known_min_coords(_dim: usize) -> Option<Vec<f32>> {
+        None
+    }
+
+    // every global minimiser, functions with several of them list all
+    fn get_all_expected_min_coords(&self) -> Vec<Vec<f32>> {
+        self.get_expected_min_coords()
+            .map(|coords| vec![coords.to_vec()])
+            .unwrap_or_default()
+    }
+
+    // domain of every variable, functions with their own domain per variable keep it inside
+    // [min, max]
+    fn get_bounds(&self) -> Vec<(f32, f32)> {
+        vec![(self.get_min(), self.get_max()); self.get_dim()]
+    }
+
+    // Some for the benchmarks with discrete variables, they are rounded by repair and sampled
+    // as whole numbers
+    fn get_variable_types(&self) -> Option<Vec<VariableType>> {
+        None
+    }
+
+    // constraints g(x) <= 0 and h(x) = 0 of the constrained benchmarks, evaluated in f64 like
+    // cost_function_f64, the f32 versions convert from them
+    fn get_inequality_constraints_f64(&self, _input: &[f64]) -> Vec<f64> {
+        vec![]
+    }
+
+    fn get_equality_constraints_f64(&self, _input: &[f64]) -> Vec<f64> {
+        vec![]
+    }
+
+    fn get_inequality_constraints(&self, input: &[f32]) -> Vec<f32> {
+        let input: Vec<f64> = input.iter().map(|x| f64::from(*x)).collect();
+        self.get_inequality_constraints_f64(&input)
+            .into_iter()
+            .map(|g| g as f32)
+            .collect()
+    }
+
+    fn get_equality_constraints(&self, input: &[f32]) -> Vec<f32> {
+        let input: Vec<f64> = input.iter().map(|x| f64::from(*x)).collect();
+        self.get_equality_constraints_f64(&input)
+            .into_iter()
+            .map(|h| h as f32)
+            .collect()
+    }
 }
 
 impl<T> ProblemDomain for T
@@ -45,6 +126,23 @@ where
     fn cost_function(&self, input: &[Self::Item]) -> Self::Item {
         T::cost_function(self, input)
     }
+
+    fn repair(&self, input: Vec<Self::Item>) -> Vec<Self::Item> {
+        match self.get_variable_types() {
+            Some(types) => repair_mixed(&types, input),
+            // operators only keep the points inside [min, max], the own domains of the
+            // variables are enforced here
+            None => reflect_into(&self.get_bounds(), input),
+        }
+    }
+
+    fn get_inequality_constraints(&self, input: &[Self::Item]) -> Vec<Self::Item> {
+        Benchmark::get_inequality_constraints(self, input)
+    }
+
+    fn get_equality_constraints(&self, input: &[Self::Item]) -> Vec<Self::Item> {
+        Benchmark::get_equality_constraints(self, input)
+    }
 }
 
 impl<T> HasRandom for T
@@ -52,9 +150,10 @@ where
     T: Benchmark,
 {
     fn get_random(&self) -> Vec<<Self as ProblemDomain>::Item> {
-        let range = Uniform::new_inclusive(self.get_min(), self.get_max());
-        let mut rng = rand::thread_rng();
-        range.sample_iter(&mut rng).take(self.get_dim()).collect()
+        match self.get_variable_types() {
+            Some(types) => random_mixed(&types),
+            None => random_in(&self.get_bounds()),
+        }
     }
 }
 
@@ -66,23 +165,66 @@ where
         &self,
         input: &[<Self as ProblemDomain>::Item],
     ) -> Vec<<Self as ProblemDomain>::Item> {
-        let bounds = (self.get_max() - self.get_min()) / 10f32;
-        let std_dev = bounds / 6f32;
-        let range = Normal::new(0f32, std_dev).unwrap();
-        let mut rng = rand::thread_rng();
-        input
-            .into_iter()
-            .map(|x| {
-                let mut new_x = x + range.sample(&mut rng);
-                while new_x < self.get_min() || new_x > self.get_max() {
-                    new_x = x + range.sample(&mut rng)
-                }
-                new_x
-            })
-            .collect()
+        match self.get_variable_types() {
+            Some(types) => local_next_mixed(&types, input),
+            None => local_next_in(&self.get_bounds(), (self.get_min(), self.get_max()), input),
+        }
     }
 }
 
+pub(crate) fn random_in<F: Real>(bounds: &[(F, F)]) -> Vec<F> {
+    let mut rng = rand::thread_rng();
+    bounds
+        .iter()
+        .map(|(min, max)| Uniform::new_inclusive(min, max).sample(&mut rng))
+        .collect()
+}
+
+// mirrors every variable that left its bounds back inside and clamps whatever still does not
+// fit, unlike clamping alone it does not pile a population up on a bound
+pub(crate) fn reflect_into<F: Real>(bounds: &[(F, F)], input: Vec<F>) -> Vec<F> {
+    let reflected = input
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| match bounds.get(i) {
+            Some(&(min, _)) if x < min => min + (min - x),
+            Some(&(_, max)) if x > max => max - (x - max),
+            _ => x,
+        })
+        .collect();
+    clamp_into(bounds, reflected)
+}
+
+// variables past the given bounds are left as they are
+pub(crate) fn clamp_into<F: Real>(bounds: &[(F, F)], input: Vec<F>) -> Vec<F> {
+    input
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| match bounds.get(i) {
+            Some(&(min, max)) => x.clamp(min, max),
+            None => x,
+        })
+        .collect()
+}
+
+// variables past the given bounds use the fallback domain
+pub(crate) fn local_next_in<F: Real>(bounds: &[(F, F)], fallback: (F, F), input: &[F]) -> Vec<F> {
+    let mut rng = rand::thread_rng();
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let (min, max) = bounds.get(i).copied().unwrap_or(fallback);
+            let std_dev = (max - min) / F::from_f64(60.0);
+            let mut new_x = x + std_dev * F::standard_normal(&mut rng);
+            while new_x < min || new_x > max {
+                new_x = x + std_dev * F::standard_normal(&mut rng)
+            }
+            new_x
+        })
+        .collect()
+}
+
 pub trait HasBuilder<T>
 where
     T: Benchmark + Default,
@@ -104,6 +246,7 @@ pub enum BuilderError {
     NoMin,
     NoMax,
     NoDim,
+    WrongDim { expected: usize, found: usize },
 }
 
 impl Display for BuilderError {
@@ -115,6 +258,11 @@ impl Display for BuilderError {
                 f,
                 "Error while building object: Number of dimensions is missing"
             ),
+            Self::WrongDim { expected, found } => write!(
+                f,
+                "Error while building object: Function is defined for {} dimensions, not {}",
+                expected, found
+            ),
         }
     }
 }
@@ -166,30 +314,126 @@ where
         self
     }
 
+    // domain and expected minimum fall back to the known values of the benchmark, the number
+    // of dimensions goes through try_set_dim
     pub fn build(&self) -> Result<T, BuilderError> {
-        Ok(T::default()
-            .set_expected_min(self.expected_min.clone())
-            .set_expected_min_coords(self.expected_min_coords.clone()))
-        .and_then(|fd| {
-            if let Some(min) = self.min {
-                Ok(fd.set_min(min))
-            } else {
-                Err(BuilderError::NoMin)
-            }
-        })
-        .and_then(|fd| {
-            if let Some(max) = self.max {
-                Ok(fd.set_max(max))
-            } else {
-                Err(BuilderError::NoMax)
-            }
-        })
-        .and_then(|fd| {
-            if let Some(dim) = self.dim {
-                Ok(fd.set_dim(dim))
-            } else {
-                Err(BuilderError::NoDim)
-            }
-        })
+        let dim = self.dim.or(T::DIMENSIONS).ok_or(BuilderError::NoDim)?;
+        let domain = T::get_default_domain(dim);
+        let min = self
+            .min
+            .or(domain.map(|(min, _)| min))
+            .ok_or(BuilderError::NoMin)?;
+        let max = self
+            .max
+            .or(domain.map(|(_, max)| max))
+            .ok_or(BuilderError::NoMax)?;
+        T::default()
+            .set_expected_min(self.expected_min.or(T::get_known_min(dim)))
+            .set_expected_min_coords(
+                self.expected_min_coords
+                    .clone()
+                    .or(T::get_known_min_coords(dim)),
+            )
+            .set_min(min)
+            .set_max(max)
+            .try_set_dim(dim)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{
+        branin::Branin, himmelblau::Himmelblau, schwefel::Schwefel,
+        styblinsky_and_tang::StyblinskyAndTang,
+    };
+
+    #[test]
+    fn known_values() {
+        let schwefel = Schwefel::builder().dimensions(3usize).build().unwrap();
+        assert_eq!(schwefel.get_min(), -500f32);
+        assert_eq!(schwefel.get_max(), 500f32);
+        assert_eq!(schwefel.get_expected_min(), Some(0f32));
+        assert_eq!(
+            schwefel.get_expected_min_coords(),
+            Some([420.968_75; 3].as_slice())
+        );
+
+        let styblinsky_and_tang = StyblinskyAndTang::builder()
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        assert_eq!(
+            styblinsky_and_tang.get_expected_min(),
+            Some(-39.166_165 * 4f32)
+        );
+    }
+
+    #[test]
+    fn overridden_values() {
+        let schwefel = Schwefel::builder()
+            .minimum(-100f32)
+            .maximum(100f32)
+            .dimensions(3usize)
+            .expected_min(1f32)
+            .expected_min_coords(vec![0f32; 3])
+            .build()
+            .unwrap();
+        assert_eq!(schwefel.get_min(), -100f32);
+        assert_eq!(schwefel.get_max(), 100f32);
+        assert_eq!(schwefel.get_expected_min(), Some(1f32));
+        assert_eq!(
+            schwefel.get_expected_min_coords(),
+            Some([0f32; 3].as_slice())
+        );
+    }
+
+    #[test]
+    fn missing_dimensions() {
+        assert!(matches!(
+            Schwefel::builder().build(),
+            Err(BuilderError::NoDim)
+        ));
+    }
+
+    #[test]
+    fn fixed_dimensions() {
+        assert!(matches!(
+            Himmelblau::builder().dimensions(3usize).build(),
+            Err(BuilderError::WrongDim {
+                expected: 2,
+                found: 3
+            })
+        ));
+        let himmelblau = Himmelblau::builder().build().unwrap();
+        assert!(matches!(
+            Himmelblau::default().try_set_dim(3),
+            Err(BuilderError::WrongDim {
+                expected: 2,
+                found: 3
+            })
+        ));
+        assert_eq!(himmelblau.set_dim(3).get_dim(), 2);
+        assert_eq!(Schwefel::default().try_set_dim(3).unwrap().get_dim(), 3);
+    }
+
+    #[test]
+    fn repair_into_bounds() {
+        let branin = Branin::builder().build().unwrap();
+        // [min, max] is [-5, 15], x0 is defined on [-5, 10] and x1 on [0, 15] only, the variables
+        // are mirrored on the bound they crossed
+        assert_eq!(
+            ProblemDomain::repair(&branin, vec![12f32, -3f32]),
+            vec![8f32, 3f32]
+        );
+        assert_eq!(
+            ProblemDomain::repair(&branin, vec![1f32, 2f32]),
+            vec![1f32, 2f32]
+        );
+        // and is clamped when the mirror image is outside as well
+        assert_eq!(
+            ProblemDomain::repair(&branin, vec![-30f32, 15f32]),
+            vec![10f32, 15f32]
+        );
     }
 }
@@ -0,0 +1,509 @@
+use std::{fmt::Display, fs, path::Path, str::FromStr};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::StandardNormal;
+
+use crate::benchmarks::traits::Benchmark;
+use crate::problem_definitions::{HasGradient, ProblemDomain, Real};
+
+#[derive(Debug)]
+pub enum TransformError {
+    Io(std::io::Error),
+    Parse(String),
+    WrongSize { expected: usize, found: usize },
+    WrongIndex { index: f64, dim: usize },
+    NotOrthogonal,
+}
+
+impl Display for TransformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Error while loading transformation: {}", error),
+            Self::Parse(value) => write!(
+                f,
+                "Error while loading transformation: {} is not a number",
+                value
+            ),
+            Self::WrongSize { expected, found } => write!(
+                f,
+                "Error while setting transformation: expected {} values, found {}",
+                expected, found
+            ),
+            Self::WrongIndex { index, dim } => write!(
+                f,
+                "Error while loading transformation: {} is not an index from 1 to {}",
+                index, dim
+            ),
+            Self::NotOrthogonal => write!(
+                f,
+                "Error while setting transformation: rotation matrix is not orthogonal"
+            ),
+        }
+    }
+}
+
+// wraps a benchmark as f(c + R * S * (x - c - o)), where o is the shift, R an orthogonal
+// rotation, S a diagonal scaling and c the optimum of the wrapped benchmark (or the centre
+// of the domain when it is not known), so the optimum moves from c to c + o
+// source: https://www.al-roomi.org/multimedia/CEC_Database/CEC2017/CEC2017_TechnicalReport.pdf
+#[derive(Debug)]
+pub struct Transformed<B>
+where
+    B: Benchmark,
+{
+    benchmark: B,
+    shift: Option<Vec<f32>>,
+    rotation: Option<Vec<Vec<f32>>>,
+    scaling: Option<Vec<f32>>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl<B> Transformed<B>
+where
+    B: Benchmark,
+{
+    pub fn new(benchmark: B) -> Self {
+        let expected_min_coords = benchmark.get_expected_min_coords().map(|x| x.to_vec());
+        Self {
+            benchmark,
+            shift: None,
+            rotation: None,
+            scaling: None,
+            expected_min_coords,
+        }
+    }
+
+    pub fn set_shift(mut self, shift: Vec<f32>) -> Result<Self, TransformError> {
+        self.check_size(shift.len())?;
+        self.shift = Some(shift);
+        self.update_expected_min_coords();
+        Ok(self)
+    }
+
+    // moves the optimum to a random point in the inner 80 % of the domain
+    pub fn random_shift(self, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let min = self.benchmark.get_min();
+        let max = self.benchmark.get_max();
+        let margin = (max - min) / 10f32;
+        let shift = self
+            .get_pivot()
+            .iter()
+            .map(|c| rng.gen_range(min + margin..=max - margin) - c)
+            .collect();
+        self.set_shift(shift).unwrap()
+    }
+
+    pub fn set_rotation(mut self, rotation: Vec<Vec<f32>>) -> Result<Self, TransformError> {
+        self.check_size(rotation.len())?;
+        for row in rotation.iter() {
+            self.check_size(row.len())?;
+        }
+        if !is_orthogonal(&rotation) {
+            return Err(TransformError::NotOrthogonal);
+        }
+        self.rotation = Some(rotation);
+        self.update_expected_min_coords();
+        Ok(self)
+    }
+
+    pub fn random_rotation(self, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let rotation = random_orthogonal(self.benchmark.get_dim(), &mut rng);
+        self.set_rotation(rotation).unwrap()
+    }
+
+    pub fn set_scaling(mut self, scaling: Vec<f32>) -> Result<Self, TransformError> {
+        self.check_size(scaling.len())?;
+        self.scaling = Some(scaling);
+        self.update_expected_min_coords();
+        Ok(self)
+    }
+
+    // whitespace separated values, as in the CEC shift data files
+    pub fn load_shift(self, path: impl AsRef<Path>) -> Result<Self, TransformError> {
+        let values = read_values(path)?;
+        let dim = self.benchmark.get_dim();
+        if values.len() < dim {
+            return Err(TransformError::WrongSize {
+                expected: dim,
+                found: values.len(),
+            });
+        }
+        self.set_shift(values.into_iter().take(dim).collect())
+    }
+
+    // one row per line, as in the CEC rotation data files
+    pub fn load_rotation(self, path: impl AsRef<Path>) -> Result<Self, TransformError> {
+        let values = read_values(path)?;
+        let dim = self.benchmark.get_dim();
+        if values.len() != dim * dim {
+            return Err(TransformError::WrongSize {
+                expected: dim * dim,
+                found: values.len(),
+            });
+        }
+        self.set_rotation(values.chunks(dim).map(|row| row.to_vec()).collect())
+    }
+
+    pub fn get_benchmark(&self) -> &B {
+        &self.benchmark
+    }
+
+    pub fn get_shift(&self) -> Option<&[f32]> {
+        self.shift.as_deref()
+    }
+
+    pub fn get_rotation(&self) -> Option<&[Vec<f32>]> {
+        self.rotation.as_deref()
+    }
+
+    // point of the wrapped benchmark evaluated for the input
+    pub fn transform(&self, input: &[f32]) -> Vec<f32> {
+        self.transform_in(input)
+    }
+
+    // the transformation is kept in f32 and widened for inputs in f64
+    fn transform_in<F: Real>(&self, input: &[F]) -> Vec<F> {
+        let wide = |x: f32| F::from_f64(f64::from(x));
+        let pivot = self.get_pivot();
+        let moved: Vec<F> = input
+            .iter()
+            .zip(pivot.iter())
+            .enumerate()
+            .map(|(i, (&x, &c))| {
+                let shifted =
+                    x - wide(c) - wide(self.shift.as_ref().map_or(0f32, |shift| shift[i]));
+                shifted * wide(self.scaling.as_ref().map_or(1f32, |scaling| scaling[i]))
+            })
+            .collect();
+        let rotated: Vec<F> = match &self.rotation {
+            Some(rotation) => rotation
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(moved.iter())
+                        .map(|(&r, &z)| wide(r) * z)
+                        .sum()
+                })
+                .collect(),
+            None => moved,
+        };
+        rotated
+            .iter()
+            .zip(pivot.iter())
+            .map(|(&z, &c)| z + wide(c))
+            .collect()
+    }
+
+    fn get_pivot(&self) -> Vec<f32> {
+        pivot(&self.benchmark)
+    }
+
+    // the optimum is only known to move to c + o when c is the optimum of the wrapped benchmark
+    fn update_expected_min_coords(&mut self) {
+        self.expected_min_coords = self.benchmark.get_expected_min_coords().map(|coords| {
+            coords
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c + self.shift.as_ref().map_or(0f32, |shift| shift[i]))
+                .collect()
+        });
+    }
+
+    fn check_size(&self, found: usize) -> Result<(), TransformError> {
+        let expected = self.benchmark.get_dim();
+        if found == expected {
+            Ok(())
+        } else {
+            Err(TransformError::WrongSize { expected, found })
+        }
+    }
+}
+
+impl<B> Benchmark for Transformed<B>
+where
+    B: Benchmark,
+{
+    const FUNCTION_NAME: &'static str = B::FUNCTION_NAME;
+
+    fn get_min(&self) -> f32 {
+        self.benchmark.get_min()
+    }
+
+    fn get_max(&self) -> f32 {
+        self.benchmark.get_max()
+    }
+
+    fn get_dim(&self) -> usize {
+        self.benchmark.get_dim()
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.benchmark.get_expected_min()
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.benchmark = self.benchmark.set_min(value);
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.benchmark = self.benchmark.set_max(value);
+        self
+    }
+
+    // the transformation does not fit the new dimension and is dropped
+    fn set_dim(mut self, value: usize) -> Self {
+        self.benchmark = self.benchmark.set_dim(value);
+        self.shift = None;
+        self.rotation = None;
+        self.scaling = None;
+        self.update_expected_min_coords();
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.benchmark = self.benchmark.set_expected_min(value);
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        Benchmark::cost_function(&self.benchmark, &self.transform(input))
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        self.benchmark.cost_function_f64(&self.transform_in(input))
+    }
+}
+
+// chain rule, the gradient of the wrapped benchmark is mapped back by S * R^T
+impl<B> HasGradient for Transformed<B>
+where
+    B: Benchmark + HasGradient + ProblemDomain<Item = f32>,
+{
+    fn get_gradient(&self, input: &[f32]) -> Vec<f32> {
+        let gradient = self.benchmark.get_gradient(&self.transform(input));
+        let unrotated: Vec<f32> = match &self.rotation {
+            Some(rotation) => (0..gradient.len())
+                .map(|j| {
+                    rotation
+                        .iter()
+                        .zip(gradient.iter())
+                        .map(|(row, g)| row[j] * g)
+                        .sum()
+                })
+                .collect(),
+            None => gradient,
+        };
+        unrotated
+            .iter()
+            .enumerate()
+            .map(|(i, g)| g * self.scaling.as_ref().map_or(1f32, |scaling| scaling[i]))
+            .collect()
+    }
+}
+
+// optimum of the benchmark, or the centre of the domain when it is not known
+pub(crate) fn pivot<B: Benchmark>(benchmark: &B) -> Vec<f32> {
+    match benchmark.get_expected_min_coords() {
+        Some(coords) => coords.to_vec(),
+        None => {
+            let centre = (benchmark.get_min() + benchmark.get_max()) / 2f32;
+            vec![centre; benchmark.get_dim()]
+        }
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn is_orthogonal(matrix: &[Vec<f32>]) -> bool {
+    matrix.iter().enumerate().all(|(i, a)| {
+        matrix.iter().enumerate().all(|(j, b)| {
+            let expected = if i == j { 1f32 } else { 0f32 };
+            (dot(a, b) - expected).abs() < 1e-3
+        })
+    })
+}
+
+// random orthogonal matrix, gaussian rows orthonormalised by Gram-Schmidt
+pub(crate) fn random_orthogonal(dim: usize, rng: &mut impl Rng) -> Vec<Vec<f32>> {
+    let mut rotation: Vec<Vec<f32>> = vec![];
+    while rotation.len() < dim {
+        let mut row: Vec<f32> = (0..dim).map(|_| rng.sample(StandardNormal)).collect();
+        for previous in rotation.iter() {
+            let projection = dot(&row, previous);
+            row.iter_mut()
+                .zip(previous.iter())
+                .for_each(|(x, p)| *x -= projection * p);
+        }
+        let norm = dot(&row, &row).sqrt();
+        // a nearly dependent row is drawn again
+        if norm > 1e-3 {
+            rotation.push(row.into_iter().map(|x| x / norm).collect());
+        }
+    }
+    rotation
+}
+
+pub(crate) fn read_values<F: FromStr>(path: impl AsRef<Path>) -> Result<Vec<F>, TransformError> {
+    fs::read_to_string(path)
+        .map_err(TransformError::Io)?
+        .split_whitespace()
+        .map(|value| {
+            value
+                .parse::<F>()
+                .map_err(|_| TransformError::Parse(value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{
+        fst_dejong::FstDeJong, rastrigin::Rastrigin, schwefel::Schwefel, traits::HasBuilder,
+    };
+    use crate::problem_definitions::{numerical_gradient, HasLocal, HasRandom};
+
+    fn sphere() -> FstDeJong {
+        FstDeJong::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(4)
+            .expected_min(0f32)
+            .expected_min_coords(vec![0f32; 4])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn shifted_optimum() {
+        let problem = Transformed::new(sphere())
+            .random_shift(7)
+            .random_rotation(7)
+            .set_scaling(vec![1f32, 2f32, 3f32, 4f32])
+            .unwrap();
+        let coords = problem.get_expected_min_coords().unwrap().to_vec();
+        assert!(coords.iter().all(|x| *x >= -4f32 && *x <= 4f32));
+        assert_eq!(coords, problem.get_shift().unwrap());
+        assert!(Benchmark::cost_function(&problem, &coords).abs() < 1e-5);
+        assert!(Benchmark::cost_function(&problem, &[0f32; 4]) > 0f32);
+    }
+
+    #[test]
+    fn seeded_rotation() {
+        let a = Transformed::new(sphere()).random_rotation(1);
+        let b = Transformed::new(sphere()).random_rotation(1);
+        assert_eq!(a.get_rotation(), b.get_rotation());
+        assert!(is_orthogonal(a.get_rotation().unwrap()));
+        // a rotation around the optimum keeps the distance to it
+        let input = a.get_random();
+        assert!(
+            (Benchmark::cost_function(&a, &input) - Benchmark::cost_function(&sphere(), &input))
+                .abs()
+                < 1e-3
+        );
+    }
+
+    #[test]
+    fn schwefel_corner() {
+        let schwefel = Schwefel::builder()
+            .minimum(-500f32)
+            .maximum(500f32)
+            .dimensions(3)
+            .expected_min_coords(vec![420.9687; 3])
+            .build()
+            .unwrap();
+        let problem = Transformed::new(schwefel)
+            .random_rotation(3)
+            .set_shift(vec![-400f32, -500f32, -600f32])
+            .unwrap();
+        assert!(problem
+            .get_expected_min_coords()
+            .unwrap()
+            .iter()
+            .zip([20.9687, -79.0313, -179.0313].iter())
+            .all(|(a, b)| (a - b).abs() < 1e-3));
+        let optimum = Benchmark::cost_function(problem.get_benchmark(), &[420.9687; 3]);
+        assert!(
+            (Benchmark::cost_function(&problem, &[20.9687, -79.0313, -179.0313]) - optimum).abs()
+                < 1e-2
+        );
+    }
+
+    #[test]
+    fn wrong_sizes() {
+        assert!(matches!(
+            Transformed::new(sphere()).set_shift(vec![0f32; 3]),
+            Err(TransformError::WrongSize {
+                expected: 4,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            Transformed::new(sphere()).set_rotation(vec![vec![1f32; 4]; 4]),
+            Err(TransformError::NotOrthogonal)
+        ));
+        assert!(matches!(
+            Transformed::new(sphere()).load_shift("/nonexistent/shift_data.txt"),
+            Err(TransformError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn load_from_files() {
+        let directory = std::env::temp_dir();
+        let shift_path = directory.join("heuristics_transformed_shift.txt");
+        let rotation_path = directory.join("heuristics_transformed_rotation.txt");
+        fs::write(&shift_path, "1.0 -1.0 0.5 2.0 9.0 9.0\n").unwrap();
+        fs::write(&rotation_path, "0 1 0 0\n1 0 0 0\n0 0 0 -1\n0 0 1 0\n").unwrap();
+        let problem = Transformed::new(sphere())
+            .load_shift(&shift_path)
+            .unwrap()
+            .load_rotation(&rotation_path)
+            .unwrap();
+        assert_eq!(problem.get_shift().unwrap(), &[1f32, -1f32, 0.5, 2f32]);
+        assert_eq!(
+            problem.transform(&[2f32, -1f32, 0.5, 2f32]),
+            vec![0f32, 1f32, 0f32, 0f32]
+        );
+    }
+
+    #[test]
+    fn blanket_impls() {
+        let rastrigin = Rastrigin::builder()
+            .minimum(-5.12)
+            .maximum(5.12)
+            .dimensions(5)
+            .build()
+            .unwrap();
+        let problem = Transformed::new(rastrigin)
+            .random_shift(2)
+            .random_rotation(2);
+        let input = problem.get_random();
+        assert_eq!(input.len(), 5);
+        assert!(problem
+            .get_local_next(&input)
+            .iter()
+            .all(|x| *x >= -5.12 && *x <= 5.12));
+        assert_eq!(problem.get_dim(), 5);
+        let analytic = problem.get_gradient(&input);
+        let numerical = numerical_gradient(&problem, &input, 1e-3);
+        assert!(analytic
+            .iter()
+            .zip(numerical.iter())
+            .all(|(a, n)| (a - n).abs() <= 5e-2 * a.abs().max(1f32)));
+    }
+}
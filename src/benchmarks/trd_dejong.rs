@@ -1,10 +1,5 @@
-use std::{
-    f32::consts::PI,
-    ops::{Mul, Sub},
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
-use rand_distr::num_traits::ToPrimitive;
+use crate::problem_definitions::Real;
 
 // source: https://gitlab.com/luca.baronti/python_benchmark_functions
 
@@ -67,9 +62,29 @@ impl Benchmark for TrdDejong {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        let dimensions = self.dim.to_f32().unwrap();
-        10f32 * dimensions + input.iter().map(|x| x.floor()).sum::<f32>()
+        trd_dejong(self.dim, input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        trd_dejong(self.dim, input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5.12, 5.12))
+    }
+
+    // every variable at the floor of -6
+    fn get_known_min(dim: usize) -> Option<f32> {
+        Some(4f32 * dim as f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![-5.12; dim])
+    }
+}
+
+fn trd_dejong<F: Real>(dim: usize, input: &[F]) -> F {
+    F::from_f64(10.0 * dim as f64) + input.iter().map(|x| x.floor()).sum::<F>()
 }
 
 impl Default for TrdDejong {
@@ -155,4 +170,14 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn known_optimum() {
+        let problem = TrdDejong::builder().dimensions(5usize).build().unwrap();
+        let expected = problem.get_expected_min().unwrap();
+        let coords = problem.get_expected_min_coords().unwrap();
+        assert!(
+            (problem.cost_function(coords) - expected).abs() <= 1e-3 * expected.abs().max(1f32)
+        );
+    }
 }
@@ -0,0 +1,154 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// the domain is [-d^2, d^2]
+// source: https://www.sfu.ca/~ssurjano/trid.html
+#[derive(Debug)]
+pub struct Trid {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Trid> for Trid {}
+
+impl Benchmark for Trid {
+    const FUNCTION_NAME: &'static str = "Trid function";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        trid(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        trid(input)
+    }
+
+    fn get_default_domain(dim: usize) -> Option<(f32, f32)> {
+        let bound = (dim * dim) as f32;
+        Some((-bound, bound))
+    }
+
+    fn get_known_min(dim: usize) -> Option<f32> {
+        let dim = dim as f32;
+        Some(-dim * (dim + 4f32) * (dim - 1f32) / 6f32)
+    }
+
+    // x_i = i * (d + 1 - i)
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some((1..=dim).map(|i| (i * (dim + 1 - i)) as f32).collect())
+    }
+}
+
+fn trid<F: Real>(input: &[F]) -> F {
+    input.iter().map(|&x| (x - F::one()).powi(2)).sum::<F>()
+        - input.windows(2).map(|pair| pair[0] * pair[1]).sum::<F>()
+}
+
+impl Default for Trid {
+    fn default() -> Self {
+        Self {
+            min: -100f32,
+            max: 100f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn random_in_range() {
+        let trid = Trid::builder()
+            .minimum(-100f32)
+            .maximum(100f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = trid.get_random();
+        let random_2 = trid.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1
+            .into_iter()
+            .all(|x| (-100f32..=100f32).contains(&x)));
+    }
+
+    #[test]
+    fn optimum() {
+        for dimensions in [2usize, 6, 10] {
+            let trid = Trid::builder()
+                .minimum(-100f32)
+                .maximum(100f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = trid.get_expected_min().unwrap();
+            let coords = trid.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (trid.cost_function(coords) - expected).abs() <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(trid.cost_function(&trid.get_random()) >= expected);
+            }
+        }
+    }
+
+    #[test]
+    fn known_minimum() {
+        assert_eq!(Trid::get_known_min(6), Some(-50f32));
+        assert_eq!(Trid::get_known_min(10), Some(-210f32));
+        assert_eq!(Trid::get_default_domain(10), Some((-100f32, 100f32)));
+    }
+}
@@ -1,11 +1,6 @@
-use std::{
-    f32::consts::{E, PI},
-    ops::{Add, Div, Mul, Sub},
-};
-
 use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
 use rand::random;
-use rand_distr::num_traits::{Float, ToPrimitive};
 
 // source:  https://benchmarkfcns.info/doc/xinsheyangn1fcn.html
 //
@@ -68,14 +63,33 @@ impl Benchmark for XinSheYang {
     }
 
     fn cost_function(&self, input: &[f32]) -> f32 {
-        input.iter().enumerate().map(|(i,&x)| random::<f32>().mul(
-                x.abs()
-                .powi(i as i32)
-                )
-            )
-            .sum::<f32>()
-        
+        xinsheyang(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        xinsheyang(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5f32, 5f32))
+    }
+
+    // without the noise
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
     }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn xinsheyang<F: Real>(input: &[F]) -> F {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| F::from_f64(random::<f64>()) * x.abs().powi(i as i32))
+        .sum::<F>()
 }
 
 impl Default for XinSheYang {
@@ -161,4 +175,18 @@ mod test {
         assert!(new_low.into_iter().all(|x| x > -500f32 && x < 500f32));
         assert!(new_high.into_iter().all(|x| x > -500f32 && x < 500f32));
     }
+
+    #[test]
+    fn default_domain() {
+        let problem = XinSheYang::builder().dimensions(5usize).build().unwrap();
+        assert_eq!(
+            problem.get_min(),
+            XinSheYang::get_default_domain(5).unwrap().0
+        );
+        assert_eq!(
+            problem.get_max(),
+            XinSheYang::get_default_domain(5).unwrap().1
+        );
+        assert_eq!(problem.get_expected_min(), Some(0f32));
+    }
 }
@@ -0,0 +1,146 @@
+use crate::benchmarks::traits::{Benchmark, HasBuilder};
+use crate::problem_definitions::Real;
+
+// source: https://www.sfu.ca/~ssurjano/zakharov.html
+#[derive(Debug)]
+pub struct Zakharov {
+    min: f32,
+    max: f32,
+    dim: usize,
+    expected_min: Option<f32>,
+    expected_min_coords: Option<Vec<f32>>,
+}
+
+impl HasBuilder<Zakharov> for Zakharov {}
+
+impl Benchmark for Zakharov {
+    const FUNCTION_NAME: &'static str = "Zakharov function";
+    fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    fn get_dim(&self) -> usize {
+        self.dim
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.expected_min
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.expected_min_coords.as_deref()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.min = value;
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.max = value;
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.dim = value;
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.expected_min = value;
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.expected_min_coords = value;
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        zakharov(input)
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        zakharov(input)
+    }
+
+    fn get_default_domain(_dim: usize) -> Option<(f32, f32)> {
+        Some((-5f32, 10f32))
+    }
+
+    fn get_known_min(_dim: usize) -> Option<f32> {
+        Some(0f32)
+    }
+
+    fn get_known_min_coords(dim: usize) -> Option<Vec<f32>> {
+        Some(vec![0f32; dim])
+    }
+}
+
+fn zakharov<F: Real>(input: &[F]) -> F {
+    let weighted: F = input
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| F::from_f64(0.5 * (i + 1) as f64) * x)
+        .sum();
+    input.iter().map(|x| x.powi(2)).sum::<F>() + weighted.powi(2) + weighted.powi(4)
+}
+
+impl Default for Zakharov {
+    fn default() -> Self {
+        Self {
+            min: -5f32,
+            max: 10f32,
+            dim: 0usize,
+            expected_min: None,
+            expected_min_coords: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::HasRandom;
+
+    #[test]
+    fn random_in_range() {
+        let zakharov = Zakharov::builder()
+            .minimum(-5f32)
+            .maximum(10f32)
+            .dimensions(4usize)
+            .build()
+            .unwrap();
+        let random_1 = zakharov.get_random();
+        let random_2 = zakharov.get_random();
+
+        assert_ne!(random_1, random_2);
+        assert!(random_1.into_iter().all(|x| (-5f32..=10f32).contains(&x)));
+    }
+
+    #[test]
+    fn optimum() {
+        for dimensions in [2usize, 5, 10] {
+            let zakharov = Zakharov::builder()
+                .minimum(-5f32)
+                .maximum(10f32)
+                .dimensions(dimensions)
+                .build()
+                .unwrap();
+            let expected = zakharov.get_expected_min().unwrap();
+            let coords = zakharov.get_expected_min_coords().unwrap();
+            assert_eq!(coords.len(), dimensions);
+            assert!(
+                (zakharov.cost_function(coords) - expected).abs()
+                    <= 1e-4 * expected.abs().max(1f32)
+            );
+            for _ in 0..100 {
+                assert!(zakharov.cost_function(&zakharov.get_random()) >= expected);
+            }
+        }
+    }
+}
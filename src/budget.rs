@@ -0,0 +1,445 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::problem_definitions::{HasGradient, HasLocal, HasObjectives, HasRandom, ProblemDomain};
+
+// a new best-so-far, found by the given evaluation (counted from 1) this long after the start
+#[derive(Debug, Clone, PartialEq)]
+pub struct Improvement<I> {
+    evaluation: usize,
+    elapsed: Duration,
+    cost: I,
+}
+
+impl<I: Clone> Improvement<I> {
+    pub fn get_evaluation(&self) -> usize {
+        self.evaluation
+    }
+
+    pub fn get_elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn get_cost(&self) -> I {
+        self.cost.clone()
+    }
+}
+
+#[derive(Debug)]
+struct Record<I> {
+    best: Option<I>,
+    worst: Option<I>,
+    // per objective, for the evaluations through cost_vector
+    worst_costs: Option<Vec<I>>,
+    best_history: Option<Vec<I>>,
+    improvements: Vec<Improvement<I>>,
+    // evaluations reserved but not finished yet
+    pending: usize,
+}
+
+impl<I> Record<I> {
+    fn new(best_history: bool) -> Self {
+        Self {
+            best: None,
+            worst: None,
+            worst_costs: None,
+            best_history: best_history.then(Vec::new),
+            improvements: vec![],
+            pending: 0,
+        }
+    }
+}
+
+// wraps a problem to count its evaluations and keep the best-so-far cost and the time of each
+// improvement, optionally with a hard budget, the caller hands the same wrapper to solvers of
+// different kinds to compare them at an equal number of evaluations, the solvers stop once it
+// is_exhausted
+#[derive(Debug)]
+pub struct Counted<T>
+where
+    T: ProblemDomain,
+{
+    budget: Option<usize>,
+    started: Instant,
+    evaluations: AtomicUsize,
+    refused: AtomicUsize,
+    record: Mutex<Record<T::Item>>,
+    finished: Condvar,
+    problem: T,
+}
+
+impl<T> Counted<T>
+where
+    T: ProblemDomain,
+{
+    pub fn new(problem: T) -> Self {
+        Self {
+            budget: None,
+            started: Instant::now(),
+            evaluations: AtomicUsize::new(0),
+            refused: AtomicUsize::new(0),
+            record: Mutex::new(Record::new(false)),
+            finished: Condvar::new(),
+            problem,
+        }
+    }
+
+    // evaluations past the budget are refused, the first one is always made so that there is
+    // a cost to refuse them with, the same holds for the first cost_vector
+    pub fn set_budget(mut self, budget: usize) -> Self {
+        self.budget = Some(budget.max(1));
+        self
+    }
+
+    // keeps the best cost after every evaluation, one value per evaluation, so it is off by
+    // default
+    pub fn set_best_history(mut self, best_history: bool) -> Self {
+        self.record.get_mut().unwrap().best_history = best_history.then(Vec::new);
+        self
+    }
+
+    pub fn get_problem(&self) -> &T {
+        &self.problem
+    }
+
+    pub fn get_budget(&self) -> Option<usize> {
+        self.budget
+    }
+
+    // evaluations made, refused ones are not counted
+    pub fn get_evaluations(&self) -> usize {
+        self.evaluations.load(Ordering::Relaxed)
+    }
+
+    pub fn get_refused(&self) -> usize {
+        self.refused.load(Ordering::Relaxed)
+    }
+
+    pub fn get_remaining(&self) -> Option<usize> {
+        self.budget
+            .map(|budget| budget.saturating_sub(self.get_evaluations()))
+    }
+
+    // the best cost after every evaluation, index i holds the best of the first i + 1, empty
+    // unless set_best_history
+    pub fn get_best_history(&self) -> Vec<T::Item> {
+        self.record
+            .lock()
+            .unwrap()
+            .best_history
+            .clone()
+            .unwrap_or_default()
+    }
+
+    pub fn get_best_cost(&self) -> Option<T::Item> {
+        self.record.lock().unwrap().best.clone()
+    }
+
+    pub fn get_improvements(&self) -> Vec<Improvement<T::Item>> {
+        self.record.lock().unwrap().improvements.clone()
+    }
+
+    // starts the count, the records and the clock anew, e.g. before the next solver
+    pub fn reset(&mut self) {
+        self.started = Instant::now();
+        *self.evaluations.get_mut() = 0;
+        *self.refused.get_mut() = 0;
+        let record = self.record.get_mut().unwrap();
+        *record = Record::new(record.best_history.is_some());
+    }
+
+    // reserves an evaluation of the budget, past it the record is returned once the evaluations
+    // in progress have finished, so that their costs can be refused with
+    fn reserve(&self) -> Result<usize, MutexGuard<'_, Record<T::Item>>> {
+        let mut record = self.record.lock().unwrap();
+        let evaluations = self.evaluations.load(Ordering::Relaxed);
+        if self.budget.is_some_and(|budget| evaluations >= budget) {
+            self.refused.fetch_add(1, Ordering::Relaxed);
+            return Err(self
+                .finished
+                .wait_while(record, |record| record.pending > 0)
+                .unwrap());
+        }
+        self.evaluations.store(evaluations + 1, Ordering::Relaxed);
+        record.pending += 1;
+        Ok(evaluations)
+    }
+
+    // the record of a finished evaluation, the refused ones waiting for it are woken
+    fn finish(&self) -> MutexGuard<'_, Record<T::Item>> {
+        let mut record = self.record.lock().unwrap();
+        record.pending -= 1;
+        self.finished.notify_all();
+        record
+    }
+}
+
+impl<T> ProblemDomain for Counted<T>
+where
+    T: ProblemDomain,
+{
+    type Item = T::Item;
+
+    fn get_minimum(&self) -> T::Item {
+        self.problem.get_minimum()
+    }
+
+    fn get_maximum(&self) -> T::Item {
+        self.problem.get_maximum()
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.problem.get_dimensions()
+    }
+
+    // past the budget the problem is not evaluated, the worst cost seen so far is returned
+    // instead so that the solver gains nothing from it, a problem evaluated only through
+    // cost_vector so far has no such cost, it is then computed under the lock
+    fn cost_function(&self, input: &[T::Item]) -> T::Item {
+        let previous = match self.reserve() {
+            Ok(previous) => previous,
+            Err(mut record) => {
+                return record
+                    .worst
+                    .get_or_insert_with(|| self.problem.cost_function(input))
+                    .clone();
+            }
+        };
+        // the problem is evaluated without the lock, it may take long or count evaluations itself
+        let cost = self.problem.cost_function(input);
+        let mut record = self.finish();
+        if record.worst.as_ref().is_none_or(|worst| cost > *worst) {
+            record.worst = Some(cost.clone());
+        }
+        if record.best.as_ref().is_none_or(|best| cost < *best) {
+            record.best = Some(cost.clone());
+            record.improvements.push(Improvement {
+                evaluation: previous + 1,
+                elapsed: self.started.elapsed(),
+                cost: cost.clone(),
+            });
+        }
+        let best = record.best.clone().unwrap();
+        if let Some(history) = record.best_history.as_mut() {
+            history.push(best);
+        }
+        cost
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.get_remaining() == Some(0)
+    }
+
+    fn repair(&self, input: Vec<T::Item>) -> Vec<T::Item> {
+        self.problem.repair(input)
+    }
+
+    fn get_inequality_constraints(&self, input: &[T::Item]) -> Vec<T::Item> {
+        self.problem.get_inequality_constraints(input)
+    }
+
+    fn get_equality_constraints(&self, input: &[T::Item]) -> Vec<T::Item> {
+        self.problem.get_equality_constraints(input)
+    }
+}
+
+impl<T> HasRandom for Counted<T>
+where
+    T: ProblemDomain + HasRandom,
+{
+    fn get_random(&self) -> Vec<T::Item> {
+        self.problem.get_random()
+    }
+}
+
+impl<T> HasLocal for Counted<T>
+where
+    T: ProblemDomain + HasLocal,
+{
+    fn get_local_next(&self, input: &[T::Item]) -> Vec<T::Item> {
+        self.problem.get_local_next(input)
+    }
+}
+
+// the cost vectors are counted and refused as the costs, the best cost and its history follow
+// cost_function only
+impl<T> HasObjectives for Counted<T>
+where
+    T: HasObjectives,
+{
+    fn get_objectives(&self) -> usize {
+        self.problem.get_objectives()
+    }
+
+    fn cost_vector(&self, input: &[T::Item]) -> Vec<T::Item> {
+        if let Err(mut record) = self.reserve() {
+            return record
+                .worst_costs
+                .get_or_insert_with(|| self.problem.cost_vector(input))
+                .clone();
+        }
+        let costs = self.problem.cost_vector(input);
+        let mut record = self.finish();
+        let worst = match record.worst_costs.take() {
+            Some(worst) => worst
+                .into_iter()
+                .zip(costs.iter())
+                .map(|(w, c)| match *c > w {
+                    true => c.clone(),
+                    false => w,
+                })
+                .collect(),
+            None => costs.clone(),
+        };
+        record.worst_costs = Some(worst);
+        costs
+    }
+}
+
+// analytic gradients are not counted as evaluations
+impl<T> HasGradient for Counted<T>
+where
+    T: ProblemDomain + HasGradient,
+{
+    fn get_gradient(&self, input: &[T::Item]) -> Vec<T::Item> {
+        self.problem.get_gradient(input)
+    }
+
+    fn get_gradient_cost(&self) -> i32 {
+        self.problem.get_gradient_cost()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::FnProblem;
+
+    fn sphere() -> Counted<FnProblem<impl Fn(&[f64]) -> f64, f64>> {
+        Counted::new(FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| {
+            x.iter().map(|v| v * v).sum()
+        }))
+    }
+
+    #[test]
+    fn counts_and_records() {
+        let problem = sphere().set_best_history(true);
+        for input in [
+            [2f64, 0f64],
+            [3f64, 0f64],
+            [1f64, 0f64],
+            [1f64, 1f64],
+            [0f64, 0f64],
+        ] {
+            problem.cost_function(&input);
+        }
+        assert_eq!(problem.get_evaluations(), 5);
+        assert_eq!(
+            problem.get_best_history(),
+            vec![4f64, 4f64, 1f64, 1f64, 0f64]
+        );
+        assert_eq!(problem.get_best_cost(), Some(0f64));
+        let improvements = problem.get_improvements();
+        assert_eq!(
+            improvements
+                .iter()
+                .map(|i| (i.get_evaluation(), i.get_cost()))
+                .collect::<Vec<_>>(),
+            vec![(1, 4f64), (3, 1f64), (5, 0f64)]
+        );
+        assert!(improvements
+            .windows(2)
+            .all(|w| w[0].get_elapsed() <= w[1].get_elapsed()));
+        assert_eq!(problem.get_remaining(), None);
+        assert!(!problem.is_exhausted());
+    }
+
+    #[test]
+    fn no_history_by_default() {
+        let problem = sphere();
+        problem.cost_function(&[1f64, 0f64]);
+        problem.cost_function(&[0f64, 0f64]);
+        assert!(problem.get_best_history().is_empty());
+        assert_eq!(problem.get_best_cost(), Some(0f64));
+        assert_eq!(problem.get_improvements().len(), 2);
+    }
+
+    #[test]
+    fn refuses_past_budget() {
+        let mut problem = sphere().set_budget(3).set_best_history(true);
+        problem.cost_function(&[1f64, 0f64]);
+        problem.cost_function(&[2f64, 0f64]);
+        assert_eq!(problem.get_remaining(), Some(1));
+        problem.cost_function(&[0f64, 0f64]);
+        assert!(problem.is_exhausted());
+        // the worst cost seen is returned and nothing is recorded
+        assert_eq!(problem.cost_function(&[0.5f64, 0f64]), 4f64);
+        assert_eq!(problem.get_evaluations(), 3);
+        assert_eq!(problem.get_refused(), 1);
+        assert_eq!(problem.get_best_history().len(), 3);
+
+        problem.reset();
+        assert_eq!(problem.get_evaluations(), 0);
+        assert!(!problem.is_exhausted());
+        assert!(problem.get_improvements().is_empty());
+        assert_eq!(problem.cost_function(&[0.5f64, 0f64]), 0.25f64);
+        assert_eq!(problem.get_best_history(), vec![0.25f64]);
+    }
+
+    #[test]
+    fn counts_cost_vectors() {
+        let problem = Counted::new(
+            FnProblem::new(-5f64, 5f64, 1, |x: &[f64]| x[0])
+                .set_objectives(2, |x: &[f64]| vec![x[0], -x[0]]),
+        )
+        .set_budget(2);
+        assert_eq!(problem.get_objectives(), 2);
+        problem.cost_vector(&[1f64]);
+        problem.cost_vector(&[-2f64]);
+        assert!(problem.is_exhausted());
+        // the worst value of every objective
+        assert_eq!(problem.cost_vector(&[0f64]), vec![1f64, 2f64]);
+        assert_eq!(problem.get_evaluations(), 2);
+        assert_eq!(problem.get_refused(), 1);
+        assert_eq!(problem.get_best_cost(), None);
+    }
+
+    struct Slow;
+
+    impl ProblemDomain for Slow {
+        type Item = f64;
+
+        fn get_minimum(&self) -> f64 {
+            -1f64
+        }
+
+        fn get_maximum(&self) -> f64 {
+            1f64
+        }
+
+        fn get_dimensions(&self) -> usize {
+            1
+        }
+
+        fn cost_function(&self, input: &[f64]) -> f64 {
+            std::thread::sleep(Duration::from_millis(100));
+            input[0]
+        }
+    }
+
+    #[test]
+    fn refused_waits_for_pending() {
+        let problem = Counted::new(Slow).set_budget(1);
+        std::thread::scope(|scope| {
+            let first = scope.spawn(|| problem.cost_function(&[0.5f64]));
+            while problem.get_evaluations() == 0 {
+                std::thread::yield_now();
+            }
+            // the only evaluation is still running, its cost is the one to refuse with
+            assert_eq!(problem.cost_function(&[-0.5f64]), 0.5f64);
+            assert_eq!(first.join().unwrap(), 0.5f64);
+        });
+        assert_eq!(problem.get_refused(), 1);
+    }
+}
@@ -0,0 +1,120 @@
+use rand::seq::index::sample;
+use rand::Rng;
+
+use crate::problem_definitions::{ProblemDomain, Real};
+use crate::solvers::neighbourhood::Neighbourhood;
+
+// bit strings are encoded as 0 and 1 in the problem's float type, like the discrete variables
+// of the mixed-integer problems, so the continuous solvers can run on them as well
+pub fn random_bits<F: Real>(n: usize) -> Vec<F> {
+    let mut rng = rand::thread_rng();
+    (0..n)
+        .map(|_| {
+            if rng.gen_bool(0.5) {
+                F::one()
+            } else {
+                F::zero()
+            }
+        })
+        .collect()
+}
+
+// values above one half are read as set bits
+pub fn is_set<F: Real>(bit: F) -> bool {
+    bit > F::from_f64(0.5)
+}
+
+pub fn count_ones<F: Real>(input: &[F]) -> usize {
+    input.iter().filter(|bit| is_set(**bit)).count()
+}
+
+pub fn flip<F: Real>(input: &[F], positions: &[usize]) -> Vec<F> {
+    let mut output = input.to_vec();
+    for &i in positions {
+        output[i] = if is_set(output[i]) {
+            F::zero()
+        } else {
+            F::one()
+        };
+    }
+    output
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BitMove {
+    // flips a single random bit
+    #[default]
+    Flip,
+    // flips k distinct random bits
+    KFlip(usize),
+    // flips every bit with the probability 1/n, at least one bit is flipped
+    Uniform,
+}
+
+impl BitMove {
+    pub fn apply<F: Real, R: Rng + ?Sized>(&self, input: &[F], rng: &mut R) -> Vec<F> {
+        let n = input.len();
+        if n == 0 {
+            return vec![];
+        }
+        let positions: Vec<usize> = match self {
+            BitMove::Flip => vec![rng.gen_range(0..n)],
+            BitMove::KFlip(k) => sample(rng, n, (*k).clamp(1, n)).into_vec(),
+            BitMove::Uniform => {
+                let positions: Vec<usize> =
+                    (0..n).filter(|_| rng.gen_bool(1f64 / n as f64)).collect();
+                if positions.is_empty() {
+                    vec![rng.gen_range(0..n)]
+                } else {
+                    positions
+                }
+            }
+        };
+        flip(input, &positions)
+    }
+}
+
+impl<T> Neighbourhood<T> for BitMove
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    fn get_neighbour(&mut self, _problem: &T, input: &[T::Item]) -> Vec<T::Item> {
+        self.apply(input, &mut rand::thread_rng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hamming(a: &[f64], b: &[f64]) -> usize {
+        a.iter().zip(b.iter()).filter(|(x, y)| x != y).count()
+    }
+
+    #[test]
+    fn random() {
+        let bits: Vec<f64> = random_bits(100);
+        assert!(bits.iter().all(|bit| *bit == 0f64 || *bit == 1f64));
+        assert!(count_ones(&bits) > 0 && count_ones(&bits) < 100);
+    }
+
+    #[test]
+    fn moves() {
+        let mut rng = rand::thread_rng();
+        let input: Vec<f64> = random_bits(20);
+        assert_eq!(flip(&[0f64, 1f64, 1f64], &[0, 2]), vec![1f64, 1f64, 0f64]);
+        for _ in 0..100 {
+            assert_eq!(hamming(&input, &BitMove::Flip.apply(&input, &mut rng)), 1);
+            assert_eq!(
+                hamming(&input, &BitMove::KFlip(3).apply(&input, &mut rng)),
+                3
+            );
+            assert_eq!(
+                hamming(&input, &BitMove::KFlip(50).apply(&input, &mut rng)),
+                20
+            );
+            assert!(hamming(&input, &BitMove::Uniform.apply(&input, &mut rng)) >= 1);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::{fs, path::Path};
+
+use crate::combinatorial::binary::{is_set, random_bits, BitMove};
+use crate::combinatorial::{parse_number, InstanceError};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+
+// 0/1 knapsack, bit i packs the item i, a packing within the capacity costs minus its value,
+// an overfull one costs its excess weight, so every feasible packing beats every infeasible one
+#[derive(Debug, Clone)]
+pub struct Knapsack {
+    name: String,
+    capacity: i64,
+    values: Vec<i64>,
+    weights: Vec<i64>,
+    local_move: BitMove,
+}
+
+impl Knapsack {
+    pub fn new(capacity: i64, values: Vec<i64>, weights: Vec<i64>) -> Result<Self, InstanceError> {
+        if values.len() != weights.len() {
+            return Err(InstanceError::Format(format!(
+                "{} values do not match {} weights",
+                values.len(),
+                weights.len()
+            )));
+        }
+        Ok(Self {
+            name: String::new(),
+            capacity,
+            values,
+            weights,
+            local_move: BitMove::Flip,
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InstanceError> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self::parse(&fs::read_to_string(path).map_err(InstanceError::Io)?)?.set_name(name))
+    }
+
+    // the first line holds the number of items and the capacity, every further line the value
+    // and the weight of one item, as in the low-dimensional instances of Ortega
+    // source: http://artemisa.unicauca.edu.co/~johnyortega/instances_01_KP/
+    pub fn parse(content: &str) -> Result<Self, InstanceError> {
+        let mut tokens = content.split_whitespace();
+        let mut next = |what: &str| {
+            tokens
+                .next()
+                .ok_or_else(|| InstanceError::Format(format!("{} is missing", what)))
+        };
+        let n: usize = parse_number(next("number of items")?)?;
+        let capacity = parse_number(next("capacity")?)?;
+        let mut values = Vec::with_capacity(n);
+        let mut weights = Vec::with_capacity(n);
+        for _ in 0..n {
+            values.push(parse_number(next("value")?)?);
+            weights.push(parse_number(next("weight")?)?);
+        }
+        Self::new(capacity, values, weights)
+    }
+
+    pub fn set_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn set_local_move(mut self, local_move: BitMove) -> Self {
+        self.local_move = local_move;
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_capacity(&self) -> i64 {
+        self.capacity
+    }
+
+    // total value and weight of the packed items
+    pub fn evaluate(&self, input: &[f64]) -> (i64, i64) {
+        input
+            .iter()
+            .zip(self.values.iter().zip(self.weights.iter()))
+            .filter(|(bit, _)| is_set(**bit))
+            .fold((0, 0), |(value, weight), (_, (v, w))| {
+                (value + v, weight + w)
+            })
+    }
+}
+
+impl ProblemDomain for Knapsack {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        0f64
+    }
+
+    fn get_maximum(&self) -> f64 {
+        1f64
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.values.len()
+    }
+
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        let (value, weight) = self.evaluate(input);
+        if weight <= self.capacity {
+            -(value as f64)
+        } else {
+            (weight - self.capacity) as f64
+        }
+    }
+}
+
+impl HasRandom for Knapsack {
+    fn get_random(&self) -> Vec<f64> {
+        random_bits(self.values.len())
+    }
+}
+
+impl HasLocal for Knapsack {
+    fn get_local_next(&self, input: &[f64]) -> Vec<f64> {
+        self.local_move.apply(input, &mut rand::thread_rng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // f1_l-d_kp_10_269, the optimal value is 295
+    const INSTANCE: &str = "10 269
+55 95
+10 4
+47 60
+5 32
+4 23
+50 72
+8 80
+61 62
+85 65
+87 46
+";
+
+    #[test]
+    fn optimum() {
+        let knapsack = Knapsack::parse(INSTANCE).unwrap();
+        assert_eq!(knapsack.get_dimensions(), 10);
+        assert_eq!(knapsack.get_capacity(), 269);
+        let best = (0..1 << 10)
+            .map(|code: u32| {
+                let bits: Vec<f64> = (0..10).map(|i| ((code >> i) & 1) as f64).collect();
+                knapsack.cost_function(&bits)
+            })
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(best, -295f64);
+    }
+
+    #[test]
+    fn infeasible() {
+        let knapsack = Knapsack::new(10, vec![5, 7], vec![6, 8]).unwrap();
+        assert_eq!(knapsack.evaluate(&[1.0, 1.0]), (12, 14));
+        assert_eq!(knapsack.cost_function(&[1.0, 1.0]), 4f64);
+        assert_eq!(knapsack.cost_function(&[0.0, 1.0]), -7f64);
+        assert!(matches!(
+            Knapsack::parse("2 10\n5 6\n7"),
+            Err(InstanceError::Format(_))
+        ));
+        assert!(Knapsack::new(10, vec![5], vec![6, 8]).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use std::{fs, path::Path};
+
+use crate::combinatorial::binary::{is_set, random_bits, BitMove};
+use crate::combinatorial::{parse_number, InstanceError};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+
+// MAX-SAT, bit i is the value of the variable i + 1, the cost is the number of unsatisfied
+// clauses
+#[derive(Debug, Clone)]
+pub struct MaxSat {
+    name: String,
+    variables: usize,
+    // literals as in DIMACS, a positive number is the variable itself, a negative its negation
+    clauses: Vec<Vec<i64>>,
+    local_move: BitMove,
+}
+
+impl MaxSat {
+    pub fn new(variables: usize, clauses: Vec<Vec<i64>>) -> Result<Self, InstanceError> {
+        if let Some(literal) = clauses
+            .iter()
+            .flatten()
+            .find(|literal| **literal == 0 || literal.unsigned_abs() as usize > variables)
+        {
+            return Err(InstanceError::Format(format!(
+                "literal {} is out of range",
+                literal
+            )));
+        }
+        Ok(Self {
+            name: String::new(),
+            variables,
+            clauses,
+            local_move: BitMove::Flip,
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InstanceError> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self::parse(&fs::read_to_string(path).map_err(InstanceError::Io)?)?.set_name(name))
+    }
+
+    // DIMACS CNF, comment lines start with c, the problem line is "p cnf <variables> <clauses>"
+    // and every clause is a list of literals ended by 0, SATLIB files end with a % line
+    // source: https://www.cs.ubc.ca/~hoos/SATLIB/Benchmarks/SAT/satformat.ps
+    pub fn parse(content: &str) -> Result<Self, InstanceError> {
+        let mut header = None;
+        let mut clauses = vec![];
+        let mut clause = vec![];
+
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('%') {
+                break;
+            }
+            if line.is_empty() || line.starts_with('c') {
+                continue;
+            }
+            if line.starts_with('p') {
+                let tokens: Vec<&str> = line.split_whitespace().collect();
+                if tokens.len() != 4 || tokens[1] != "cnf" {
+                    return Err(InstanceError::Format(format!(
+                        "{} is not a cnf problem line",
+                        line
+                    )));
+                }
+                header = Some((
+                    parse_number::<usize>(tokens[2])?,
+                    parse_number::<usize>(tokens[3])?,
+                ));
+                continue;
+            }
+            for token in line.split_whitespace() {
+                match parse_number::<i64>(token)? {
+                    0 => clauses.push(std::mem::take(&mut clause)),
+                    literal => clause.push(literal),
+                }
+            }
+        }
+        if !clause.is_empty() {
+            clauses.push(clause);
+        }
+
+        let (variables, expected) =
+            header.ok_or_else(|| InstanceError::Format(String::from("problem line is missing")))?;
+        if clauses.len() != expected {
+            return Err(InstanceError::Format(format!(
+                "expected {} clauses, found {}",
+                expected,
+                clauses.len()
+            )));
+        }
+        Self::new(variables, clauses)
+    }
+
+    pub fn set_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn set_local_move(mut self, local_move: BitMove) -> Self {
+        self.local_move = local_move;
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_clauses(&self) -> &[Vec<i64>] {
+        &self.clauses
+    }
+
+    pub fn unsatisfied(&self, input: &[f64]) -> usize {
+        self.clauses
+            .iter()
+            .filter(|clause| {
+                !clause.iter().any(|&literal| {
+                    is_set(input[literal.unsigned_abs() as usize - 1]) == (literal > 0)
+                })
+            })
+            .count()
+    }
+}
+
+impl ProblemDomain for MaxSat {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        0f64
+    }
+
+    fn get_maximum(&self) -> f64 {
+        1f64
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.variables
+    }
+
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        self.unsatisfied(input) as f64
+    }
+}
+
+impl HasRandom for MaxSat {
+    fn get_random(&self) -> Vec<f64> {
+        random_bits(self.variables)
+    }
+}
+
+impl HasLocal for MaxSat {
+    fn get_local_next(&self, input: &[f64]) -> Vec<f64> {
+        self.local_move.apply(input, &mut rand::thread_rng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INSTANCE: &str = "c small example
+c
+p cnf 3 4
+1 -2 0
+2 3
+0
+-1 -3 0
+-2 0
+%
+0
+";
+
+    #[test]
+    fn parse() {
+        let problem = MaxSat::parse(INSTANCE).unwrap();
+        assert_eq!(problem.get_dimensions(), 3);
+        assert_eq!(
+            problem.get_clauses(),
+            vec![vec![1, -2], vec![2, 3], vec![-1, -3], vec![-2]]
+        );
+        assert_eq!(problem.cost_function(&[0.0, 0.0, 1.0]), 0f64);
+        assert_eq!(problem.cost_function(&[1.0, 0.0, 0.0]), 1f64);
+        assert_eq!(problem.cost_function(&[0.0, 1.0, 1.0]), 2f64);
+        assert_eq!(problem.cost_function(&[1.0, 1.0, 1.0]), 2f64);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            MaxSat::parse("p cnf 2 2\n1 2 0\n"),
+            Err(InstanceError::Format(_))
+        ));
+        assert!(matches!(
+            MaxSat::parse("p cnf 2 1\n1 3 0\n"),
+            Err(InstanceError::Format(_))
+        ));
+        assert!(matches!(
+            MaxSat::parse("p wcnf 2 1\n1 2 0\n"),
+            Err(InstanceError::Format(_))
+        ));
+        assert!(matches!(
+            MaxSat::parse("p cnf 2 1\n1 x 0\n"),
+            Err(InstanceError::Parse(_))
+        ));
+    }
+}
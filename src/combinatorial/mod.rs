@@ -0,0 +1,36 @@
+use std::{fmt::Display, str::FromStr};
+
+pub mod binary;
+pub mod knapsack;
+pub mod max_sat;
+pub mod nk_landscape;
+pub mod permutation;
+pub mod pseudo_boolean;
+pub mod qap;
+pub mod tsp;
+
+// errors of every instance loader, permutation and bit-string problems alike
+#[derive(Debug)]
+pub enum InstanceError {
+    Io(std::io::Error),
+    Parse(String),
+    Format(String),
+}
+
+impl Display for InstanceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Error while loading instance: {}", error),
+            Self::Parse(value) => {
+                write!(f, "Error while loading instance: {} is not a number", value)
+            }
+            Self::Format(message) => write!(f, "Error while loading instance: {}", message),
+        }
+    }
+}
+
+pub(crate) fn parse_number<N: FromStr>(token: &str) -> Result<N, InstanceError> {
+    token
+        .parse::<N>()
+        .map_err(|_| InstanceError::Parse(token.to_string()))
+}
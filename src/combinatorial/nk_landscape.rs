@@ -0,0 +1,139 @@
+use rand::{rngs::StdRng, seq::index::sample, Rng, SeedableRng};
+
+use crate::combinatorial::binary::{is_set, random_bits, BitMove};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+
+// NK landscape with random neighbourhoods, bit i contributes a value from its own table indexed
+// by itself and k other random bits, the fitness is the mean contribution, the cost is one
+// minus the fitness, the same seed always gives the same landscape
+// source: https://doi.org/10.1016/S0022-5193(89)80019-0
+#[derive(Debug, Clone)]
+pub struct NkLandscape {
+    k: usize,
+    links: Vec<Vec<usize>>,
+    tables: Vec<Vec<f64>>,
+    local_move: BitMove,
+}
+
+impl NkLandscape {
+    pub fn new(n: usize, k: usize, seed: u64) -> Self {
+        let k = k.min(n.saturating_sub(1));
+        let mut rng = StdRng::seed_from_u64(seed);
+        let links = (0..n)
+            .map(|i| {
+                let mut links = vec![i];
+                links.extend(sample(&mut rng, n - 1, k).into_iter().map(|j| {
+                    if j >= i {
+                        j + 1
+                    } else {
+                        j
+                    }
+                }));
+                links
+            })
+            .collect();
+        let tables = (0..n)
+            .map(|_| (0..1 << (k + 1)).map(|_| rng.gen::<f64>()).collect())
+            .collect();
+        Self {
+            k,
+            links,
+            tables,
+            local_move: BitMove::Flip,
+        }
+    }
+
+    pub fn set_local_move(mut self, local_move: BitMove) -> Self {
+        self.local_move = local_move;
+        self
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    pub fn fitness(&self, input: &[f64]) -> f64 {
+        let n = self.links.len();
+        if n == 0 {
+            return 0f64;
+        }
+        self.links
+            .iter()
+            .zip(self.tables.iter())
+            .map(|(links, table)| {
+                let index = links
+                    .iter()
+                    .fold(0usize, |index, &j| (index << 1) | is_set(input[j]) as usize);
+                table[index]
+            })
+            .sum::<f64>()
+            / n as f64
+    }
+}
+
+impl ProblemDomain for NkLandscape {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        0f64
+    }
+
+    fn get_maximum(&self) -> f64 {
+        1f64
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.links.len()
+    }
+
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        1f64 - self.fitness(input)
+    }
+}
+
+impl HasRandom for NkLandscape {
+    fn get_random(&self) -> Vec<f64> {
+        random_bits(self.links.len())
+    }
+}
+
+impl HasLocal for NkLandscape {
+    fn get_local_next(&self, input: &[f64]) -> Vec<f64> {
+        self.local_move.apply(input, &mut rand::thread_rng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let a = NkLandscape::new(20, 3, 7);
+        let b = NkLandscape::new(20, 3, 7);
+        let c = NkLandscape::new(20, 3, 8);
+        let input = a.get_random();
+        assert_eq!(a.cost_function(&input), b.cost_function(&input));
+        assert_ne!(a.cost_function(&input), c.cost_function(&input));
+        assert!((0f64..=1f64).contains(&a.cost_function(&input)));
+        assert!(a.links.iter().enumerate().all(|(i, links)| {
+            links.len() == 4 && links[0] == i && links[1..].iter().all(|j| *j != i && *j < 20)
+        }));
+    }
+
+    // without interactions every bit can be set to the better value of its own table
+    #[test]
+    fn separable_optimum() {
+        let landscape = NkLandscape::new(10, 0, 1);
+        let optimum: Vec<f64> = landscape
+            .tables
+            .iter()
+            .map(|table| if table[1] > table[0] { 1f64 } else { 0f64 })
+            .collect();
+        let best = landscape.cost_function(&optimum);
+        for _ in 0..100 {
+            assert!(best <= landscape.cost_function(&landscape.get_random()));
+        }
+        assert_eq!(NkLandscape::new(3, 5, 1).get_k(), 2);
+    }
+}
@@ -0,0 +1,153 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::combinatorial::InstanceError;
+use crate::problem_definitions::ProblemDomain;
+use crate::solvers::neighbourhood::Neighbourhood;
+
+// the loading errors are shared with the bit-string instances, the old name is kept for callers
+#[deprecated(note = "use combinatorial::InstanceError instead")]
+pub type PermutationError = InstanceError;
+
+// solutions of the permutation problems are orderings of 0..n, stored in the same i64 type
+// as their integral costs
+pub fn random_permutation(n: usize) -> Vec<i64> {
+    let mut permutation: Vec<i64> = (0..n as i64).collect();
+    permutation.shuffle(&mut rand::thread_rng());
+    permutation
+}
+
+pub fn is_permutation(input: &[i64]) -> bool {
+    let mut seen = vec![false; input.len()];
+    input.iter().all(|&x| {
+        let valid = x >= 0 && (x as usize) < seen.len() && !seen[x as usize];
+        if valid {
+            seen[x as usize] = true;
+        }
+        valid
+    })
+}
+
+// reverses the segment between the positions i and j, both included
+pub fn two_opt(input: &[i64], i: usize, j: usize) -> Vec<i64> {
+    let mut output = input.to_vec();
+    output[i.min(j)..=i.max(j)].reverse();
+    output
+}
+
+pub fn swap(input: &[i64], i: usize, j: usize) -> Vec<i64> {
+    let mut output = input.to_vec();
+    output.swap(i, j);
+    output
+}
+
+// moves the item at the position i to the position j
+pub fn insertion(input: &[i64], i: usize, j: usize) -> Vec<i64> {
+    let mut output = input.to_vec();
+    let item = output.remove(i);
+    output.insert(j, item);
+    output
+}
+
+// moves the segment of the given length starting at i so that it starts at the position j of
+// the remaining items
+pub fn or_opt(input: &[i64], i: usize, length: usize, j: usize) -> Vec<i64> {
+    let mut output = input.to_vec();
+    let segment: Vec<i64> = output.drain(i..i + length).collect();
+    output.splice(j..j, segment);
+    output
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PermutationMove {
+    #[default]
+    TwoOpt,
+    Swap,
+    Insertion,
+    // relocates a segment of one to three items
+    OrOpt,
+}
+
+impl PermutationMove {
+    pub fn apply<R: Rng + ?Sized>(&self, input: &[i64], rng: &mut R) -> Vec<i64> {
+        let n = input.len();
+        if n < 2 {
+            return input.to_vec();
+        }
+        match self {
+            PermutationMove::TwoOpt => {
+                let (i, j) = distinct_pair(n, rng);
+                two_opt(input, i, j)
+            }
+            PermutationMove::Swap => {
+                let (i, j) = distinct_pair(n, rng);
+                swap(input, i, j)
+            }
+            PermutationMove::Insertion => {
+                let (i, j) = distinct_pair(n, rng);
+                insertion(input, i, j)
+            }
+            PermutationMove::OrOpt => {
+                let length = rng.gen_range(1..=3.min(n - 1));
+                let i = rng.gen_range(0..=n - length);
+                // the segment stays in place for j == i
+                let j = (i + rng.gen_range(1..=n - length)) % (n - length + 1);
+                or_opt(input, i, length, j)
+            }
+        }
+    }
+}
+
+fn distinct_pair<R: Rng + ?Sized>(n: usize, rng: &mut R) -> (usize, usize) {
+    let i = rng.gen_range(0..n);
+    let j = (i + rng.gen_range(1..n)) % n;
+    (i, j)
+}
+
+impl<T> Neighbourhood<T> for PermutationMove
+where
+    T: ProblemDomain<Item = i64>,
+{
+    fn get_neighbour(&mut self, _problem: &T, input: &[i64]) -> Vec<i64> {
+        self.apply(input, &mut rand::thread_rng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn moves() {
+        let input = [0, 1, 2, 3, 4, 5];
+        assert_eq!(two_opt(&input, 4, 1), vec![0, 4, 3, 2, 1, 5]);
+        assert_eq!(swap(&input, 0, 5), vec![5, 1, 2, 3, 4, 0]);
+        assert_eq!(insertion(&input, 1, 4), vec![0, 2, 3, 4, 1, 5]);
+        assert_eq!(insertion(&input, 4, 0), vec![4, 0, 1, 2, 3, 5]);
+        assert_eq!(or_opt(&input, 1, 2, 3), vec![0, 3, 4, 1, 2, 5]);
+        assert_eq!(or_opt(&input, 3, 3, 0), vec![3, 4, 5, 0, 1, 2]);
+    }
+
+    #[test]
+    fn random_moves_keep_permutation() {
+        let mut rng = rand::thread_rng();
+        assert!(is_permutation(&random_permutation(20)));
+        assert!(!is_permutation(&[0, 2, 2]));
+        assert!(!is_permutation(&[0, 3, 1]));
+        for kind in [
+            PermutationMove::TwoOpt,
+            PermutationMove::Swap,
+            PermutationMove::Insertion,
+            PermutationMove::OrOpt,
+        ] {
+            for n in [2, 3, 10] {
+                for _ in 0..100 {
+                    let input = random_permutation(n);
+                    let output = kind.apply(&input, &mut rng);
+                    assert!(is_permutation(&output));
+                    assert_ne!(output, input);
+                }
+            }
+        }
+    }
+}
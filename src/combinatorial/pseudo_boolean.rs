@@ -0,0 +1,209 @@
+use crate::combinatorial::binary::{count_ones, is_set, random_bits, BitMove};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+
+// classic pseudo-boolean benchmarks, they are maximised in the literature, the costs here are
+// the distances of the fitness from its optimum, so every optimum costs 0
+
+// number of set bits
+#[derive(Debug, Clone)]
+pub struct OneMax {
+    dimensions: usize,
+    local_move: BitMove,
+}
+
+impl OneMax {
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            local_move: BitMove::Flip,
+        }
+    }
+
+    pub fn set_local_move(mut self, local_move: BitMove) -> Self {
+        self.local_move = local_move;
+        self
+    }
+}
+
+impl ProblemDomain for OneMax {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        0f64
+    }
+
+    fn get_maximum(&self) -> f64 {
+        1f64
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        (self.dimensions - count_ones(input)) as f64
+    }
+}
+
+impl HasRandom for OneMax {
+    fn get_random(&self) -> Vec<f64> {
+        random_bits(self.dimensions)
+    }
+}
+
+impl HasLocal for OneMax {
+    fn get_local_next(&self, input: &[f64]) -> Vec<f64> {
+        self.local_move.apply(input, &mut rand::thread_rng())
+    }
+}
+
+// length of the prefix of set bits
+#[derive(Debug, Clone)]
+pub struct LeadingOnes {
+    dimensions: usize,
+    local_move: BitMove,
+}
+
+impl LeadingOnes {
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            local_move: BitMove::Flip,
+        }
+    }
+
+    pub fn set_local_move(mut self, local_move: BitMove) -> Self {
+        self.local_move = local_move;
+        self
+    }
+}
+
+impl ProblemDomain for LeadingOnes {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        0f64
+    }
+
+    fn get_maximum(&self) -> f64 {
+        1f64
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        let leading = input.iter().take_while(|bit| is_set(**bit)).count();
+        (self.dimensions - leading) as f64
+    }
+}
+
+impl HasRandom for LeadingOnes {
+    fn get_random(&self) -> Vec<f64> {
+        random_bits(self.dimensions)
+    }
+}
+
+impl HasLocal for LeadingOnes {
+    fn get_local_next(&self, input: &[f64]) -> Vec<f64> {
+        self.local_move.apply(input, &mut rand::thread_rng())
+    }
+}
+
+// concatenated deceptive traps, a block of k bits with u set bits scores k when u = k and
+// k - 1 - u otherwise, so the local slope leads away from the optimum
+#[derive(Debug, Clone)]
+pub struct Trap {
+    blocks: usize,
+    block_size: usize,
+    local_move: BitMove,
+}
+
+impl Trap {
+    pub fn new(blocks: usize, block_size: usize) -> Self {
+        Self {
+            blocks,
+            block_size: block_size.max(1),
+            local_move: BitMove::Flip,
+        }
+    }
+
+    pub fn set_local_move(mut self, local_move: BitMove) -> Self {
+        self.local_move = local_move;
+        self
+    }
+}
+
+impl ProblemDomain for Trap {
+    type Item = f64;
+
+    fn get_minimum(&self) -> f64 {
+        0f64
+    }
+
+    fn get_maximum(&self) -> f64 {
+        1f64
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.blocks * self.block_size
+    }
+
+    fn cost_function(&self, input: &[f64]) -> f64 {
+        let k = self.block_size;
+        input
+            .chunks(k)
+            .map(|block| {
+                let ones = count_ones(block);
+                let score = if ones == k { k } else { k - 1 - ones };
+                (k - score) as f64
+            })
+            .sum()
+    }
+}
+
+impl HasRandom for Trap {
+    fn get_random(&self) -> Vec<f64> {
+        random_bits(self.get_dimensions())
+    }
+}
+
+impl HasLocal for Trap {
+    fn get_local_next(&self, input: &[f64]) -> Vec<f64> {
+        self.local_move.apply(input, &mut rand::thread_rng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn one_max() {
+        let problem = OneMax::new(5);
+        assert_eq!(problem.cost_function(&[1.0, 1.0, 1.0, 1.0, 1.0]), 0f64);
+        assert_eq!(problem.cost_function(&[0.0, 1.0, 0.0, 1.0, 1.0]), 2f64);
+    }
+
+    #[test]
+    fn leading_ones() {
+        let problem = LeadingOnes::new(5);
+        assert_eq!(problem.cost_function(&[1.0, 1.0, 1.0, 1.0, 1.0]), 0f64);
+        assert_eq!(problem.cost_function(&[1.0, 1.0, 0.0, 1.0, 1.0]), 3f64);
+        assert_eq!(problem.cost_function(&[0.0, 1.0, 1.0, 1.0, 1.0]), 5f64);
+    }
+
+    #[test]
+    fn trap() {
+        let problem = Trap::new(2, 4);
+        assert_eq!(problem.get_dimensions(), 8);
+        assert_eq!(problem.cost_function(&[1f64; 8]), 0f64);
+        // the all-zero block is the deceptive attractor, one step from it costs more
+        assert_eq!(problem.cost_function(&[0f64; 8]), 2f64);
+        assert_eq!(
+            problem.cost_function(&[1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0]),
+            6f64
+        );
+    }
+}
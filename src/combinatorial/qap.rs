@@ -0,0 +1,170 @@
+use std::{fs, path::Path};
+
+use crate::combinatorial::permutation::{random_permutation, PermutationMove};
+use crate::combinatorial::{parse_number, InstanceError};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+
+// quadratic assignment problem in the QAPLIB form, the cost of the permutation p is
+// sum_ij a_ij * b_p(i)p(j)
+// source: https://coral.ise.lehigh.edu/data-sets/qaplib/
+#[derive(Debug, Clone)]
+pub struct Qap {
+    name: String,
+    a: Vec<Vec<i64>>,
+    b: Vec<Vec<i64>>,
+    local_move: PermutationMove,
+}
+
+impl Qap {
+    pub fn new(a: Vec<Vec<i64>>, b: Vec<Vec<i64>>) -> Result<Self, InstanceError> {
+        let n = a.len();
+        if b.len() != n || a.iter().chain(b.iter()).any(|row| row.len() != n) {
+            return Err(InstanceError::Format(String::from(
+                "matrices are not square or differ in size",
+            )));
+        }
+        Ok(Self {
+            name: String::new(),
+            a,
+            b,
+            local_move: PermutationMove::Swap,
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InstanceError> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self::parse(&fs::read_to_string(path).map_err(InstanceError::Io)?)?.set_name(name))
+    }
+
+    // content of a QAPLIB .dat file, the size n followed by both n x n matrices
+    pub fn parse(content: &str) -> Result<Self, InstanceError> {
+        let mut tokens = content.split_whitespace();
+        let n: usize = parse_number(
+            tokens
+                .next()
+                .ok_or_else(|| InstanceError::Format(String::from("size is missing")))?,
+        )?;
+        let values = tokens
+            .map(parse_number::<i64>)
+            .collect::<Result<Vec<i64>, InstanceError>>()?;
+        if values.len() != 2 * n * n {
+            return Err(InstanceError::Format(format!(
+                "expected {} matrix entries, found {}",
+                2 * n * n,
+                values.len()
+            )));
+        }
+        let mut rows = values.chunks(n.max(1)).map(|row| row.to_vec());
+        let a = rows.by_ref().take(n).collect();
+        let b = rows.collect();
+        Self::new(a, b)
+    }
+
+    pub fn set_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn set_local_move(mut self, local_move: PermutationMove) -> Self {
+        self.local_move = local_move;
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_matrices(&self) -> (&[Vec<i64>], &[Vec<i64>]) {
+        (&self.a, &self.b)
+    }
+}
+
+impl ProblemDomain for Qap {
+    type Item = i64;
+
+    fn get_minimum(&self) -> i64 {
+        0
+    }
+
+    fn get_maximum(&self) -> i64 {
+        self.a.len() as i64 - 1
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.a.len()
+    }
+
+    fn cost_function(&self, input: &[i64]) -> i64 {
+        self.a
+            .iter()
+            .zip(input.iter())
+            .map(|(row, &p_i)| {
+                row.iter()
+                    .zip(input.iter())
+                    .map(|(a_ij, &p_j)| a_ij * self.b[p_i as usize][p_j as usize])
+                    .sum::<i64>()
+            })
+            .sum()
+    }
+}
+
+impl HasRandom for Qap {
+    fn get_random(&self) -> Vec<i64> {
+        random_permutation(self.a.len())
+    }
+}
+
+impl HasLocal for Qap {
+    fn get_local_next(&self, input: &[i64]) -> Vec<i64> {
+        self.local_move.apply(input, &mut rand::thread_rng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::combinatorial::permutation::is_permutation;
+
+    const INSTANCE: &str = "3
+
+0 2 1
+2 0 3
+1 3 0
+
+0 5 2
+5 0 1
+2 1 0
+";
+
+    #[test]
+    fn parse() {
+        let qap = Qap::parse(INSTANCE).unwrap();
+        let (a, b) = qap.get_matrices();
+        assert_eq!(a[1], vec![2, 0, 3]);
+        assert_eq!(b[2], vec![2, 1, 0]);
+        // 2 * (2 * 5 + 1 * 2 + 3 * 1)
+        assert_eq!(qap.cost_function(&[0, 1, 2]), 30);
+        // 2 * (2 * 1 + 1 * 2 + 3 * 5)
+        assert_eq!(qap.cost_function(&[2, 1, 0]), 38);
+        assert!(matches!(
+            Qap::parse("3\n0 1 2\n"),
+            Err(InstanceError::Format(_))
+        ));
+        assert!(matches!(
+            Qap::parse("1\n0 y\n"),
+            Err(InstanceError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn random_and_local() {
+        let qap = Qap::parse(INSTANCE).unwrap();
+        let random = qap.get_random();
+        assert!(is_permutation(&random));
+        assert!(is_permutation(&qap.get_local_next(&random)));
+    }
+}
@@ -0,0 +1,399 @@
+use std::{fs, path::Path};
+
+use crate::combinatorial::permutation::{random_permutation, PermutationMove};
+use crate::combinatorial::{parse_number, InstanceError};
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeWeightType {
+    Euc2d,
+    Geo,
+    Explicit,
+}
+
+// symmetric travelling salesman problem, a solution is the order in which the cities are
+// visited and its cost the length of the closed tour
+// source: http://comopt.ifi.uni-heidelberg.de/software/TSPLIB95/tsp95.pdf
+#[derive(Debug, Clone)]
+pub struct Tsp {
+    name: String,
+    distances: Vec<Vec<i64>>,
+    local_move: PermutationMove,
+}
+
+impl Tsp {
+    pub fn from_matrix(distances: Vec<Vec<i64>>) -> Result<Self, InstanceError> {
+        let n = distances.len();
+        if distances.iter().any(|row| row.len() != n) {
+            return Err(InstanceError::Format(String::from(
+                "distance matrix is not square",
+            )));
+        }
+        Ok(Self {
+            name: String::new(),
+            distances,
+            local_move: PermutationMove::TwoOpt,
+        })
+    }
+
+    pub fn from_coordinates(
+        coordinates: &[(f64, f64)],
+        edge_weight_type: EdgeWeightType,
+    ) -> Result<Self, InstanceError> {
+        let distance = match edge_weight_type {
+            EdgeWeightType::Euc2d => euc_2d,
+            EdgeWeightType::Geo => geo,
+            EdgeWeightType::Explicit => {
+                return Err(InstanceError::Format(String::from(
+                    "explicit distances can not be computed from coordinates",
+                )))
+            }
+        };
+        Self::from_matrix(
+            coordinates
+                .iter()
+                .map(|a| coordinates.iter().map(|b| distance(*a, *b)).collect())
+                .collect(),
+        )
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InstanceError> {
+        Self::parse(&fs::read_to_string(path).map_err(InstanceError::Io)?)
+    }
+
+    // content of a TSPLIB .tsp file with EUC_2D, GEO or EXPLICIT edge weights
+    pub fn parse(content: &str) -> Result<Self, InstanceError> {
+        let mut name = String::new();
+        let mut dimension = None;
+        let mut edge_weight_type = None;
+        let mut edge_weight_format = String::from("FULL_MATRIX");
+        let mut section = String::new();
+        let mut coordinates = vec![];
+        let mut weights = vec![];
+
+        for line in content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            if line == "EOF" {
+                break;
+            }
+            if let Some((key, value)) = line.split_once(':') {
+                let value = value.trim();
+                match key.trim() {
+                    "NAME" => name = value.to_string(),
+                    "TYPE" if value != "TSP" => {
+                        return Err(InstanceError::Format(format!(
+                            "TYPE {} is not supported",
+                            value
+                        )))
+                    }
+                    "DIMENSION" => dimension = Some(parse_number::<usize>(value)?),
+                    "EDGE_WEIGHT_TYPE" => {
+                        edge_weight_type = Some(match value {
+                            "EUC_2D" => EdgeWeightType::Euc2d,
+                            "GEO" => EdgeWeightType::Geo,
+                            "EXPLICIT" => EdgeWeightType::Explicit,
+                            other => {
+                                return Err(InstanceError::Format(format!(
+                                    "EDGE_WEIGHT_TYPE {} is not supported",
+                                    other
+                                )))
+                            }
+                        })
+                    }
+                    "EDGE_WEIGHT_FORMAT" => edge_weight_format = value.to_string(),
+                    _ => {}
+                }
+                section.clear();
+            } else if line.ends_with("_SECTION") {
+                section = line.to_string();
+            } else if section == "NODE_COORD_SECTION" {
+                let tokens: Vec<&str> = line.split_whitespace().collect();
+                if tokens.len() != 3 {
+                    return Err(InstanceError::Format(format!(
+                        "{} is not a node coordinate",
+                        line
+                    )));
+                }
+                coordinates.push((parse_number(tokens[1])?, parse_number(tokens[2])?));
+            } else if section == "EDGE_WEIGHT_SECTION" {
+                for token in line.split_whitespace() {
+                    weights.push(parse_number::<i64>(token)?);
+                }
+            }
+        }
+
+        let dimension =
+            dimension.ok_or_else(|| InstanceError::Format(String::from("DIMENSION is missing")))?;
+        let edge_weight_type = edge_weight_type
+            .ok_or_else(|| InstanceError::Format(String::from("EDGE_WEIGHT_TYPE is missing")))?;
+        let tsp = match edge_weight_type {
+            EdgeWeightType::Explicit => {
+                Self::from_matrix(explicit_matrix(&weights, dimension, &edge_weight_format)?)?
+            }
+            _ => {
+                if coordinates.len() != dimension {
+                    return Err(InstanceError::Format(format!(
+                        "expected {} node coordinates, found {}",
+                        dimension,
+                        coordinates.len()
+                    )));
+                }
+                Self::from_coordinates(&coordinates, edge_weight_type)?
+            }
+        };
+        Ok(tsp.set_name(name))
+    }
+
+    pub fn set_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn set_local_move(mut self, local_move: PermutationMove) -> Self {
+        self.local_move = local_move;
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_distances(&self) -> &[Vec<i64>] {
+        &self.distances
+    }
+
+    pub fn tour_length(&self, tour: &[i64]) -> i64 {
+        tour.iter()
+            .zip(tour.iter().cycle().skip(1))
+            .map(|(&a, &b)| self.distances[a as usize][b as usize])
+            .sum()
+    }
+}
+
+// TSPLIB rounds every distance to the nearest integer
+fn nint(value: f64) -> i64 {
+    (value + 0.5) as i64
+}
+
+fn euc_2d(a: (f64, f64), b: (f64, f64)) -> i64 {
+    nint(((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt())
+}
+
+// coordinates are given as DDD.MM, degrees and minutes
+#[allow(clippy::approx_constant)]
+fn geo_radians(value: f64) -> f64 {
+    // TSPLIB fixes pi to this precision, the reference optima depend on it
+    let pi = 3.141592;
+    let degrees = value.trunc();
+    let minutes = value - degrees;
+    pi * (degrees + 5.0 * minutes / 3.0) / 180.0
+}
+
+fn geo(a: (f64, f64), b: (f64, f64)) -> i64 {
+    let radius = 6378.388;
+    let (latitude_a, longitude_a) = (geo_radians(a.0), geo_radians(a.1));
+    let (latitude_b, longitude_b) = (geo_radians(b.0), geo_radians(b.1));
+    let q1 = (longitude_a - longitude_b).cos();
+    let q2 = (latitude_a - latitude_b).cos();
+    let q3 = (latitude_a + latitude_b).cos();
+    (radius * (0.5 * ((1.0 + q1) * q2 - (1.0 - q1) * q3)).acos() + 1.0) as i64
+}
+
+// the column formats list the same values as the transposed row formats
+fn explicit_matrix(
+    weights: &[i64],
+    n: usize,
+    format: &str,
+) -> Result<Vec<Vec<i64>>, InstanceError> {
+    let (upper, diagonal) = match format {
+        "FULL_MATRIX" => {
+            if weights.len() != n * n {
+                return Err(InstanceError::Format(format!(
+                    "expected {} edge weights, found {}",
+                    n * n,
+                    weights.len()
+                )));
+            }
+            return Ok(weights.chunks(n).map(|row| row.to_vec()).collect());
+        }
+        "UPPER_ROW" | "LOWER_COL" => (true, false),
+        "LOWER_ROW" | "UPPER_COL" => (false, false),
+        "UPPER_DIAG_ROW" | "LOWER_DIAG_COL" => (true, true),
+        "LOWER_DIAG_ROW" | "UPPER_DIAG_COL" => (false, true),
+        other => {
+            return Err(InstanceError::Format(format!(
+                "EDGE_WEIGHT_FORMAT {} is not supported",
+                other
+            )))
+        }
+    };
+    let pairs: Vec<(usize, usize)> = (0..n)
+        .flat_map(|i| {
+            let columns = match (upper, diagonal) {
+                (true, true) => i..n,
+                (true, false) => i + 1..n,
+                (false, true) => 0..i + 1,
+                (false, false) => 0..i,
+            };
+            columns.map(move |j| (i, j))
+        })
+        .collect();
+    if weights.len() != pairs.len() {
+        return Err(InstanceError::Format(format!(
+            "expected {} edge weights, found {}",
+            pairs.len(),
+            weights.len()
+        )));
+    }
+    let mut matrix = vec![vec![0; n]; n];
+    for (&(i, j), &weight) in pairs.iter().zip(weights.iter()) {
+        matrix[i][j] = weight;
+        matrix[j][i] = weight;
+    }
+    Ok(matrix)
+}
+
+impl ProblemDomain for Tsp {
+    type Item = i64;
+
+    fn get_minimum(&self) -> i64 {
+        0
+    }
+
+    fn get_maximum(&self) -> i64 {
+        self.distances.len() as i64 - 1
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.distances.len()
+    }
+
+    fn cost_function(&self, input: &[i64]) -> i64 {
+        self.tour_length(input)
+    }
+}
+
+impl HasRandom for Tsp {
+    fn get_random(&self) -> Vec<i64> {
+        random_permutation(self.distances.len())
+    }
+}
+
+impl HasLocal for Tsp {
+    fn get_local_next(&self, input: &[i64]) -> Vec<i64> {
+        self.local_move.apply(input, &mut rand::thread_rng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::combinatorial::permutation::is_permutation;
+
+    const BURMA14: &str = "NAME: burma14
+TYPE: TSP
+COMMENT: 14-Staedte in Burma (Zaw Win)
+DIMENSION: 14
+EDGE_WEIGHT_TYPE: GEO
+EDGE_WEIGHT_FORMAT: FUNCTION
+DISPLAY_DATA_TYPE: COORD_DISPLAY
+NODE_COORD_SECTION
+   1  16.47       96.10
+   2  16.47       94.44
+   3  20.09       92.54
+   4  22.39       93.37
+   5  25.23       97.24
+   6  22.00       96.05
+   7  20.47       97.02
+   8  17.20       96.29
+   9  16.30       97.38
+  10  14.05       98.12
+  11  16.53       97.38
+  12  21.52       95.59
+  13  19.41       97.13
+  14  20.09       94.55
+EOF
+";
+
+    #[test]
+    fn geo_optimum() {
+        let tsp = Tsp::parse(BURMA14).unwrap();
+        assert_eq!(tsp.get_name(), "burma14");
+        assert_eq!(tsp.get_dimensions(), 14);
+        let tour: Vec<i64> = [1, 2, 14, 3, 4, 5, 6, 12, 7, 13, 8, 11, 9, 10]
+            .iter()
+            .map(|city| city - 1)
+            .collect();
+        assert_eq!(tsp.cost_function(&tour), 3323);
+    }
+
+    #[test]
+    fn euc_2d() {
+        let content = "NAME : square
+TYPE : TSP
+DIMENSION : 4
+EDGE_WEIGHT_TYPE : EUC_2D
+NODE_COORD_SECTION
+1 0 0
+2 0 3
+3 4 3
+4 4 0
+EOF";
+        let tsp = Tsp::parse(content).unwrap();
+        assert_eq!(tsp.get_distances()[0][2], 5);
+        assert_eq!(tsp.cost_function(&[0, 1, 2, 3]), 14);
+        assert_eq!(tsp.cost_function(&[0, 2, 1, 3]), 18);
+    }
+
+    #[test]
+    fn explicit_formats() {
+        let full = vec![vec![0, 1, 2], vec![1, 0, 3], vec![2, 3, 0]];
+        for (format, weights) in [
+            ("FULL_MATRIX", "0 1 2\n1 0 3\n2 3 0"),
+            ("UPPER_ROW", "1 2\n3"),
+            ("LOWER_ROW", "1\n2 3"),
+            ("UPPER_DIAG_ROW", "0 1 2 0 3 0"),
+            ("LOWER_DIAG_ROW", "0 1 0 2 3 0"),
+            ("LOWER_COL", "1 2 3"),
+        ] {
+            let content = format!(
+                "TYPE: TSP\nDIMENSION: 3\nEDGE_WEIGHT_TYPE: EXPLICIT\nEDGE_WEIGHT_FORMAT: {}\nEDGE_WEIGHT_SECTION\n{}\nEOF\n",
+                format, weights
+            );
+            assert_eq!(Tsp::parse(&content).unwrap().get_distances(), full);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            Tsp::parse("TYPE: TSP\nEDGE_WEIGHT_TYPE: EUC_2D\n"),
+            Err(InstanceError::Format(_))
+        ));
+        assert!(matches!(
+            Tsp::parse("DIMENSION: 3\nEDGE_WEIGHT_TYPE: ATT\n"),
+            Err(InstanceError::Format(_))
+        ));
+        assert!(matches!(
+            Tsp::parse("DIMENSION: 2\nEDGE_WEIGHT_TYPE: EXPLICIT\nEDGE_WEIGHT_SECTION\n0 x\n1 0\n"),
+            Err(InstanceError::Parse(_))
+        ));
+        assert!(matches!(
+            Tsp::load("/nonexistent/instance.tsp"),
+            Err(InstanceError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn random_and_local() {
+        let tsp = Tsp::parse(BURMA14)
+            .unwrap()
+            .set_local_move(PermutationMove::OrOpt);
+        let random = tsp.get_random();
+        assert!(is_permutation(&random));
+        assert!(is_permutation(&tsp.get_local_next(&random)));
+    }
+}
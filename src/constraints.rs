@@ -0,0 +1,266 @@
+use std::collections::VecDeque;
+
+use rand_distr::num_traits::ToPrimitive;
+
+use crate::problem_definitions::ProblemDomain;
+
+// equality constraints count as satisfied within this distance from zero
+pub const EQUALITY_TOLERANCE: f64 = 1e-4;
+
+// sum of the violations of every constraint, 0 for feasible inputs
+pub fn total_violation<T>(problem: &T, input: &[T::Item]) -> f64
+where
+    T: ProblemDomain + ?Sized,
+{
+    let value = |constraint: &T::Item| constraint.to_f64().unwrap_or(f64::INFINITY);
+    let inequality: f64 = problem
+        .get_inequality_constraints(input)
+        .iter()
+        .map(|g| value(g).max(0f64))
+        .sum();
+    let equality: f64 = problem
+        .get_equality_constraints(input)
+        .iter()
+        .map(|h| (value(h).abs() - EQUALITY_TOLERANCE).max(0f64))
+        .sum();
+    inequality + equality
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ConstraintHandling {
+    // cost + weight * violation
+    StaticPenalty {
+        weight: f64,
+    },
+    // the weight is divided by decrease after `generations` generations with a feasible best and
+    // multiplied by increase after as many with an infeasible one
+    // source: https://doi.org/10.1287/opre.45.1.92
+    AdaptivePenalty {
+        weight: f64,
+        decrease: f64,
+        increase: f64,
+        generations: usize,
+    },
+    // feasible beats infeasible, two feasible compare by cost, two infeasible by violation
+    // source: https://doi.org/10.1016/S0045-7825(99)00389-8
+    #[default]
+    FeasibilityRules,
+    // feasibility rules where violations up to epsilon count as feasible, epsilon falls to 0 as
+    // (1 - t / control_generations)^exponent
+    // source: https://doi.org/10.1109/CEC.2006.1688283
+    EpsilonConstrained {
+        epsilon: f64,
+        control_generations: usize,
+        exponent: f64,
+    },
+    // unless both are feasible, compares by cost with the probability and by violation otherwise
+    // source: https://doi.org/10.1109/4235.873238
+    StochasticRanking {
+        probability: f64,
+    },
+}
+
+// applies a technique to pairs of (cost, violation), the solvers call next_generation once per
+// generation or temperature step so the adaptive parameters can follow the search
+#[derive(Debug, Clone)]
+pub struct ConstraintHandler {
+    technique: ConstraintHandling,
+    weight: f64,
+    epsilon: f64,
+    generation: usize,
+    best_feasibility: VecDeque<bool>,
+}
+
+impl ConstraintHandler {
+    pub fn new(technique: ConstraintHandling) -> Self {
+        let (weight, epsilon) = match technique {
+            ConstraintHandling::StaticPenalty { weight }
+            | ConstraintHandling::AdaptivePenalty { weight, .. } => (weight, 0f64),
+            ConstraintHandling::EpsilonConstrained { epsilon, .. } => (0f64, epsilon),
+            _ => (0f64, 0f64),
+        };
+        Self {
+            technique,
+            weight,
+            epsilon,
+            generation: 0,
+            best_feasibility: VecDeque::new(),
+        }
+    }
+
+    pub fn get_technique(&self) -> ConstraintHandling {
+        self.technique
+    }
+
+    pub fn get_weight(&self) -> f64 {
+        self.weight
+    }
+
+    pub fn get_epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    // negative when a = (cost, violation) is better than b, used as is by the metropolis rule
+    pub fn difference(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        let by_rules = |tolerance: f64| {
+            if (a.1 <= tolerance && b.1 <= tolerance) || a.1 == b.1 {
+                a.0 - b.0
+            } else {
+                a.1 - b.1
+            }
+        };
+        match self.technique {
+            ConstraintHandling::StaticPenalty { .. }
+            | ConstraintHandling::AdaptivePenalty { .. } => {
+                (a.0 + self.weight * a.1) - (b.0 + self.weight * b.1)
+            }
+            ConstraintHandling::FeasibilityRules => by_rules(0f64),
+            ConstraintHandling::EpsilonConstrained { .. } => by_rules(self.epsilon),
+            ConstraintHandling::StochasticRanking { probability } => {
+                if (a.1 == 0f64 && b.1 == 0f64) || rand::random::<f64>() < probability {
+                    a.0 - b.0
+                } else {
+                    a.1 - b.1
+                }
+            }
+        }
+    }
+
+    pub fn is_better(&self, a: (f64, f64), b: (f64, f64)) -> bool {
+        self.difference(a, b) < 0f64
+    }
+
+    // comparison for the best found so far, stochastic ranking only biases the selection, so the
+    // reported best follows the feasibility rules instead of being replaced at random
+    pub fn is_new_best(&self, a: (f64, f64), b: (f64, f64)) -> bool {
+        match self.technique {
+            ConstraintHandling::StochasticRanking { .. } => {
+                ConstraintHandler::new(ConstraintHandling::FeasibilityRules).is_better(a, b)
+            }
+            _ => self.is_better(a, b),
+        }
+    }
+
+    pub fn next_generation(&mut self, best_feasible: bool) {
+        self.generation += 1;
+        match self.technique {
+            ConstraintHandling::AdaptivePenalty {
+                decrease,
+                increase,
+                generations,
+                ..
+            } => {
+                self.best_feasibility.push_back(best_feasible);
+                if self.best_feasibility.len() > generations {
+                    self.best_feasibility.pop_front();
+                }
+                if self.best_feasibility.len() == generations {
+                    if self.best_feasibility.iter().all(|feasible| *feasible) {
+                        self.weight /= decrease;
+                    } else if self.best_feasibility.iter().all(|feasible| !feasible) {
+                        self.weight *= increase;
+                    }
+                }
+            }
+            ConstraintHandling::EpsilonConstrained {
+                epsilon,
+                control_generations,
+                exponent,
+            } => {
+                self.epsilon = if self.generation < control_generations {
+                    epsilon
+                        * (1f64 - self.generation as f64 / control_generations as f64)
+                            .powf(exponent)
+                } else {
+                    0f64
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Default for ConstraintHandler {
+    fn default() -> Self {
+        Self::new(ConstraintHandling::default())
+    }
+}
+
+// (cost, violation) of an input in the form taken by the handler
+pub(crate) fn evaluation<C: ToPrimitive>(cost: &C, violation: f64) -> (f64, f64) {
+    (cost.to_f64().unwrap_or(f64::INFINITY), violation)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::FnProblem;
+
+    #[test]
+    fn violation() {
+        let problem = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| x[0] + x[1])
+            .set_inequality_constraints(|x: &[f64]| vec![x[0] - 1f64, -x[1]])
+            .set_equality_constraints(|x: &[f64]| vec![x[0] + x[1] - 1f64]);
+        assert_eq!(total_violation(&problem, &[0.5, 0.5]), 0f64);
+        assert!((total_violation(&problem, &[2.0, -1.0]) - 2f64).abs() < 1e-3);
+        assert!((total_violation(&problem, &[0.0, 0.0]) - 1f64).abs() < 1e-3);
+    }
+
+    #[test]
+    fn techniques() {
+        let feasible = (10f64, 0f64);
+        let infeasible = (1f64, 0.5f64);
+        let rules = ConstraintHandler::new(ConstraintHandling::FeasibilityRules);
+        assert!(rules.is_better(feasible, infeasible));
+        assert!(rules.is_better((1f64, 0.1), (0f64, 0.2)));
+        assert!(rules.is_better((1f64, 0f64), (2f64, 0f64)));
+
+        let penalty = ConstraintHandler::new(ConstraintHandling::StaticPenalty { weight: 10f64 });
+        assert!(penalty.is_better(infeasible, feasible));
+        let penalty = ConstraintHandler::new(ConstraintHandling::StaticPenalty { weight: 100f64 });
+        assert!(penalty.is_better(feasible, infeasible));
+
+        let ranking =
+            ConstraintHandler::new(ConstraintHandling::StochasticRanking { probability: 0f64 });
+        assert!(ranking.is_better(feasible, infeasible));
+        let ranking =
+            ConstraintHandler::new(ConstraintHandling::StochasticRanking { probability: 1f64 });
+        assert!(ranking.is_better(infeasible, feasible));
+        assert!(ranking.is_new_best(feasible, infeasible));
+    }
+
+    #[test]
+    fn epsilon_falls() {
+        let mut handler = ConstraintHandler::new(ConstraintHandling::EpsilonConstrained {
+            epsilon: 1f64,
+            control_generations: 4,
+            exponent: 2f64,
+        });
+        assert!(handler.is_better((1f64, 0.5), (2f64, 0f64)));
+        handler.next_generation(false);
+        assert_eq!(handler.get_epsilon(), 0.5625);
+        for _ in 0..3 {
+            handler.next_generation(false);
+        }
+        assert_eq!(handler.get_epsilon(), 0f64);
+        assert!(handler.is_better((2f64, 0f64), (1f64, 0.5)));
+    }
+
+    #[test]
+    fn adaptive_weight() {
+        let mut handler = ConstraintHandler::new(ConstraintHandling::AdaptivePenalty {
+            weight: 8f64,
+            decrease: 2f64,
+            increase: 4f64,
+            generations: 2,
+        });
+        handler.next_generation(true);
+        assert_eq!(handler.get_weight(), 8f64);
+        handler.next_generation(true);
+        assert_eq!(handler.get_weight(), 4f64);
+        handler.next_generation(false);
+        assert_eq!(handler.get_weight(), 4f64);
+        handler.next_generation(false);
+        assert_eq!(handler.get_weight(), 16f64);
+    }
+}
@@ -1,5 +1,8 @@
-use crate::problem_definitions::{HasRandom, ProblemDomain};
+use crate::constraints::{evaluation, total_violation, ConstraintHandler, ConstraintHandling};
+use crate::problem_definitions::{HasRandom, ProblemDomain, Real};
+use crate::solvers::neighbourhood::fit_in_bounds;
 use rand::seq::SliceRandom;
+use rand_distr::num_traits::{Float, ToPrimitive};
 
 pub enum Variant {
     Rnd,
@@ -13,19 +16,23 @@ pub enum Strategy {
 #[derive(Debug)]
 pub struct Member<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
-    cost: f32,
+    cost: T::Item,
+    violation: f64,
     coordinates: Vec<T::Item>,
 }
 
 impl<T> Clone for Member<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
     fn clone(&self) -> Self {
         Self {
             cost: self.cost.clone(),
+            violation: self.violation,
             coordinates: self.coordinates.clone(),
         }
     }
@@ -33,24 +40,42 @@ where
 
 impl<T> Member<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
-    pub fn new(cost: f32, coordinates: Vec<T::Item>) -> Self {
-        Self { cost, coordinates }
+    pub fn new(cost: T::Item, coordinates: Vec<T::Item>) -> Self {
+        Self {
+            cost,
+            violation: 0f64,
+            coordinates,
+        }
     }
 
     pub fn get_coordinates(&self) -> &[T::Item] {
         &self.coordinates
     }
 
-    pub fn get_cost(&self) -> f32 {
+    pub fn get_cost(&self) -> T::Item {
         self.cost
     }
+
+    pub fn get_violation(&self) -> f64 {
+        self.violation
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.violation == 0f64
+    }
+
+    fn evaluation(&self) -> (f64, f64) {
+        evaluation(&self.cost, self.violation)
+    }
 }
 
 pub struct De<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
     // DE parameters
     max_cf: i32,
@@ -60,19 +85,28 @@ where
     difference_vectors: i32,
     variant: Variant,
     strategy: Strategy,
+    constraint_handler: ConstraintHandler,
+    samples: usize,
+    reevaluation: bool,
+    change_tolerance: Option<f64>,
+    reseeding: f64,
 
     // results
     current_best: Option<Member<T>>,
     generations_history: Vec<Vec<Member<T>>>,
     current_generation: usize,
     cost_function_evaluations: i32,
+    detected_changes: Vec<i32>,
+    // the best member as read at the last check for changes
+    sentinel: Option<Member<T>>,
 
     problem: T,
 }
 
 impl<T> De<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
     pub fn new(
         variant: Variant,
@@ -87,44 +121,210 @@ where
         Self {
             max_cf,
             population_size,
-            scaling_factor,
+            scaling_factor: T::Item::from_f64(f64::from(scaling_factor)),
             crossover_probability,
             difference_vectors,
             variant,
             strategy,
+            constraint_handler: ConstraintHandler::default(),
+            samples: 1,
+            reevaluation: false,
+            change_tolerance: None,
+            reseeding: 0.5,
             current_best: None,
             generations_history: Vec::new(),
             current_generation: 0usize,
             cost_function_evaluations: 0,
+            detected_changes: Vec::new(),
+            sentinel: None,
             problem,
         }
     }
 
+    // decides the selection and the best member on constrained problems, feasibility rules
+    // by default, which reduce to comparing costs when nothing is violated
+    pub fn set_constraint_handling(mut self, technique: ConstraintHandling) -> Self {
+        self.constraint_handler = ConstraintHandler::new(technique);
+        self
+    }
+
+    // every cost is the mean of as many evaluations, each of them counted, to average out noise
+    pub fn set_resampling(mut self, samples: usize) -> Self {
+        self.samples = samples.max(1);
+        self
+    }
+
+    // the best member is evaluated again every generation, so that a lucky noisy cost does not
+    // stay the best for good
+    pub fn set_reevaluation(mut self, reevaluation: bool) -> Self {
+        self.reevaluation = reevaluation;
+        self
+    }
+
+    // the best member of the previous check is evaluated again every generation, when its cost
+    // moves by more than the tolerance the problem is taken as changed, a share of the members
+    // is replaced by random ones to win back the diversity, see set_reseeding, the rest is
+    // evaluated again and the best forgotten
+    pub fn set_change_detection(mut self, tolerance: f64) -> Self {
+        self.change_tolerance = Some(tolerance);
+        self
+    }
+
+    // the share of the members replaced by random ones on a detected change, spread evenly over
+    // the population, half of them by default
+    pub fn set_reseeding(mut self, fraction: f64) -> Self {
+        self.reseeding = fraction.clamp(0f64, 1f64);
+        self
+    }
+
     pub fn run(&mut self) -> () {
-        let new_gen = self.get_random_generation();
+        self.initialise();
+        while self.cost_function_evaluations < self.max_cf && !self.problem.is_exhausted() {
+            self.next_generation(self.max_cf);
+        }
+    }
+
+    pub fn initialise(&mut self) {
+        self.populate(self.max_cf);
+    }
+
+    // evaluates the random population within max_cf, counted from the start
+    pub(crate) fn populate(&mut self, max_cf: i32) {
+        let new_gen = self.get_random_generation(max_cf);
         self.add_new_generation(new_gen);
         self.update_best();
-        while self.cost_function_evaluations < self.max_cf {
-            let new_generation = self.generations_history[self.current_generation]
-                .clone()
+    }
+
+    // evolves a single generation, used when the run is driven from outside
+    pub fn step(&mut self) {
+        self.next_generation(self.max_cf);
+    }
+
+    // members past the evaluation limit are carried over unchanged
+    pub(crate) fn next_generation(&mut self, max_cf: i32) {
+        self.track_changes(max_cf);
+        let new_generation = self.generations_history[self.current_generation]
+            .clone()
+            .into_iter()
+            .enumerate()
+            .map(|(index, member)| {
+                if self.cost_function_evaluations < max_cf {
+                    self.mutate(&member, index, max_cf)
+                } else {
+                    member
+                }
+            })
+            .collect();
+        self.add_new_generation(new_generation);
+        self.update_best();
+        let best_feasible = self.get_current_gen_best().is_feasible();
+        self.constraint_handler.next_generation(best_feasible);
+    }
+
+    pub fn get_members(&self) -> &[Member<T>] {
+        self.get_current_generation()
+    }
+
+    // replaces a member of the current generation, e.g. with a migrant from another population
+    pub fn inject_member(&mut self, index: usize, mut member: Member<T>) {
+        member.violation = total_violation(&self.problem, &member.coordinates);
+        self.get_mut_current_generation()[index] = member;
+        self.update_best();
+    }
+
+    // the samples are cut to the remaining budget, once it or the problem is exhausted nothing is
+    // evaluated and the cost is infinite, e.g. for the rest of a population larger than the budget
+    fn run_cost_fn(&mut self, input: &[T::Item], max_cf: i32) -> T::Item {
+        let remaining = (max_cf - self.cost_function_evaluations).max(0) as usize;
+        let samples = self.samples.min(remaining);
+        if samples == 0 || self.problem.is_exhausted() {
+            return T::Item::infinity();
+        }
+        self.cost_function_evaluations += samples as i32;
+        (0..samples)
+            .map(|_| self.problem.cost_function(input))
+            .sum::<T::Item>()
+            / T::Item::from_f64(samples as f64)
+    }
+
+    fn evaluate(&mut self, coordinates: Vec<T::Item>, max_cf: i32) -> Member<T> {
+        Member {
+            cost: self.run_cost_fn(&coordinates, max_cf),
+            violation: total_violation(&self.problem, &coordinates),
+            coordinates,
+        }
+    }
+
+    // evaluates the best member again, to replace its noisy cost or to find out that the
+    // problem changed
+    fn track_changes(&mut self, max_cf: i32) {
+        if (!self.reevaluation && self.change_tolerance.is_none())
+            || self.cost_function_evaluations >= max_cf
+        {
+            return;
+        }
+        let Some(best) = self.current_best.clone() else {
+            return;
+        };
+        // a best found after the change would not show it, the one read before does
+        let sentinel = self
+            .sentinel
+            .take()
+            .filter(|_| self.change_tolerance.is_some())
+            .unwrap_or_else(|| best.clone());
+        let cost = self.run_cost_fn(&sentinel.coordinates, max_cf);
+        let changed = self
+            .change_tolerance
+            .is_some_and(|tolerance| (cost - sentinel.cost).to_f64().unwrap().abs() > tolerance);
+        if changed {
+            self.detected_changes.push(self.cost_function_evaluations);
+            let members = self.get_current_generation().to_vec();
+            let refreshed = members
                 .into_iter()
                 .enumerate()
-                .map(|(index, member)| self.mutate(&member, index))
+                .map(
+                    |(index, member)| match self.cost_function_evaluations < max_cf {
+                        true if self.is_reseeded(index) => {
+                            self.evaluate(self.problem.get_random(), max_cf)
+                        }
+                        true => self.evaluate(member.coordinates, max_cf),
+                        false => member,
+                    },
+                )
                 .collect();
-            self.add_new_generation(new_generation);
+            self.generations_history[self.current_generation] = refreshed;
+            self.current_best = None;
             self.update_best();
+        } else if self.reevaluation {
+            let cost = if sentinel.coordinates == best.coordinates {
+                cost
+            } else if self.cost_function_evaluations < max_cf {
+                self.run_cost_fn(&best.coordinates, max_cf)
+            } else {
+                best.cost
+            };
+            self.current_best = Some(Member { cost, ..best });
         }
+        self.sentinel = self.current_best.clone();
     }
 
-    fn run_cost_fn(&mut self, input: &[T::Item]) -> f32 {
-        self.cost_function_evaluations += 1;
-        self.problem.cost_function(input).into()
+    fn is_reseeded(&self, index: usize) -> bool {
+        ((index + 1) as f64 * self.reseeding).floor() > (index as f64 * self.reseeding).floor()
     }
 
     fn get_current_gen_best(&self) -> Member<T> {
         self.get_current_generation()
             .iter()
-            .max_by(|a, b| a.cost.partial_cmp(&b.cost).unwrap())
+            .reduce(|best, member| {
+                if self
+                    .constraint_handler
+                    .is_new_best(member.evaluation(), best.evaluation())
+                {
+                    member
+                } else {
+                    best
+                }
+            })
             .unwrap()
             .clone()
     }
@@ -132,7 +332,10 @@ where
     fn update_best(&mut self) -> () {
         let current_gen_best = self.get_current_gen_best();
         if let Some(member) = self.current_best.clone() {
-            if member.cost > current_gen_best.cost {
+            if self
+                .constraint_handler
+                .is_new_best(current_gen_best.evaluation(), member.evaluation())
+            {
                 self.current_best = Some(current_gen_best);
             }
         } else {
@@ -140,15 +343,12 @@ where
         }
     }
 
-    fn get_random_generation(&mut self) -> Vec<Member<T>> {
+    fn get_random_generation(&mut self, max_cf: i32) -> Vec<Member<T>> {
         (0..self.population_size)
             .into_iter()
             .map(|_| {
                 let coords = self.problem.get_random();
-                Member {
-                    cost: self.run_cost_fn(&coords),
-                    coordinates: coords,
-                }
+                self.evaluate(coords, max_cf)
             })
             .collect()
     }
@@ -176,14 +376,25 @@ where
         self.cost_function_evaluations
     }
 
-    fn mutate(&mut self, member: &Member<T>, index: usize) -> Member<T> {
-        let mut bias = 0f32;
-        let bias_increment =
-            (self.crossover_probability + 0.001) / self.problem.get_dimensions() as f32;
+    pub fn get_problem(&self) -> &T {
+        &self.problem
+    }
+
+    // evaluations of the random population made by initialise
+    pub fn get_initialisation_cost(&self) -> i32 {
+        (self.population_size * self.samples) as i32
+    }
+
+    // evaluations done when the changes were detected
+    pub fn get_detected_changes(&self) -> &[i32] {
+        &self.detected_changes
+    }
+
+    fn mutate(&mut self, member: &Member<T>, index: usize, max_cf: i32) -> Member<T> {
         if self.difference_vectors != 1 {
             todo!()
         }
-        let mut current_gen = self
+        let current_gen = self
             .get_current_generation()
             .iter()
             .enumerate()
@@ -191,67 +402,94 @@ where
             .map(|(_, member)| member.to_owned())
             .collect::<Vec<Member<T>>>();
         let mut selected_vectors = current_gen.choose_multiple(&mut rand::thread_rng(), 3);
+        let first = selected_vectors.next().unwrap().coordinates.clone();
+        let second = selected_vectors.next().unwrap().coordinates.clone();
+        let base = match self.variant {
+            Variant::Rnd => selected_vectors.next().unwrap().coordinates.clone(),
+            Variant::Best => self.get_current_gen_best().coordinates,
+        };
 
-        let trial_vector = self.reflect(selected_vectors
-            .next()
-            .unwrap()
-            .coordinates
-            .iter()
-            .zip(selected_vectors.next().unwrap().coordinates.iter())
-            .map(|(a, b)| (a.clone() - b.clone()) * self.scaling_factor.clone())
-            .zip(match self.variant {
-                Variant::Rnd => selected_vectors
-                    .next()
-                    .unwrap()
-                    .coordinates
-                    .clone()
-                    .into_iter(),
-                Variant::Best => self.get_current_gen_best().coordinates.into_iter(),
-            })
-            .map(|(a, b)| a.clone() + b.clone())
-            .zip(member.coordinates.iter())
-            .map(|(a, b)| {
-                if rand::random::<f32>() + bias > self.crossover_probability {
-                    bias = 0f32;
-                    *b
-                } else {
-                    bias += bias_increment;
-                    a
-                }
-            })
-            .collect::<Vec<T::Item>>());
-        let cost = self.run_cost_fn(&trial_vector).into();
-        if cost < member.cost {
-            Member {
-                cost,
-                coordinates: trial_vector,
-            }
+        let trial_vector = reflect(
+            &self.problem,
+            binomial_trial(
+                &base,
+                &first,
+                &second,
+                &member.coordinates,
+                self.scaling_factor,
+                self.crossover_probability,
+            ),
+        );
+        let trial = self.evaluate(trial_vector, max_cf);
+        if self
+            .constraint_handler
+            .is_better(trial.evaluation(), member.evaluation())
+        {
+            trial
         } else {
             member.to_owned()
         }
     }
+}
 
-    fn reflect(&self, vec: Vec<f32>) -> Vec<f32> {
-        let min = self.problem.get_minimum();
-        let max = self.problem.get_maximum();
-        vec.iter()
-            .map(|&x| match (x < min, x > max) {
-                (true, _) => min + (min - x),
-                (_, true) => max + (x - max),
-                (false, false) => x,
-            })
-            .collect::<Vec<f32>>()
-    }
+// base + F * (first - second) crossed with the target, the bias makes the runs of taken
+// mutant coordinates short, shared with the multi-objective solvers
+pub(crate) fn binomial_trial<F: Real>(
+    base: &[F],
+    first: &[F],
+    second: &[F],
+    target: &[F],
+    scaling_factor: F,
+    crossover_probability: f32,
+) -> Vec<F> {
+    let mut bias = 0f32;
+    let bias_increment = (crossover_probability + 0.001) / target.len() as f32;
+    first
+        .iter()
+        .zip(second.iter())
+        .map(|(&a, &b)| (a - b) * scaling_factor)
+        .zip(base.iter())
+        .map(|(a, &b)| a + b)
+        .zip(target.iter())
+        .map(|(a, &b)| {
+            if rand::random::<f32>() + bias > crossover_probability {
+                bias = 0f32;
+                b
+            } else {
+                bias += bias_increment;
+                a
+            }
+        })
+        .collect()
+}
+
+// mirrors the coordinates that left [min, max] back inside and lets the problem repair the rest
+pub(crate) fn reflect<T>(problem: &T, vec: Vec<T::Item>) -> Vec<T::Item>
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    let reflected = vec.iter().map(|&x| fit_in_bounds(problem, x)).collect();
+    problem.repair(reflected)
 }
 
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use crate::benchmarks::branin::Branin;
+    use crate::benchmarks::noisy::{Noise, Noisy};
+    use crate::benchmarks::{
+        dynamic::Dynamic,
+        fst_dejong::FstDeJong,
+        traits::{Benchmark, HasBuilder},
+    };
+    use crate::problem_definitions::FnProblem;
     use mockall::predicate::*;
     use mockall::*;
     use rand::distributions::Uniform;
     use rand_distr::Distribution;
+    use std::sync::atomic::{AtomicI32, Ordering};
 
     mock! {
         Problem {}
@@ -307,4 +545,128 @@ mod test {
         assert_eq!(de_rng_1_bin.generations_history.len(), 500);
         assert_eq!(de_rng_1_bin.generations_history[0].len(), 10);
     }
+
+    #[test]
+    fn reflection() {
+        let problem = FnProblem::new(-5f64, 5f64, 3, |x: &[f64]| x.iter().sum());
+        // each coordinate that left the domain is mirrored on the bound it crossed
+        assert_eq!(
+            reflect(&problem, vec![6f64, -7f64, 1f64]),
+            vec![4f64, -3f64, 1f64]
+        );
+        // and is clamped when the mirror image is outside as well
+        assert_eq!(
+            reflect(&problem, vec![20f64, -20f64, 5f64]),
+            vec![-5f64, 5f64, 5f64]
+        );
+    }
+
+    #[test]
+    fn constrained() {
+        // the disk x^2 + y^2 <= 4 cuts off the unconstrained optimum (-5, -5)
+        let problem = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| x[0] + x[1])
+            .set_inequality_constraints(|x: &[f64]| vec![x[0].powi(2) + x[1].powi(2) - 4f64]);
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 4000, 20, 0.8, 0.9, problem);
+        de.run();
+        let best = de.get_best().unwrap();
+        assert!(best.is_feasible());
+        assert!((best.get_cost() + 8f64.sqrt()).abs() < 1e-2);
+    }
+
+    #[test]
+    fn noisy_resampling() {
+        let calls = AtomicI32::new(0);
+        // sphere with uniform noise in [-0.5, 0.5]
+        let problem = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            x.iter().map(|v| v * v).sum::<f64>() + rand::random::<f64>() - 0.5
+        });
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 4003, 10, 0.8, 0.9, problem)
+            .set_resampling(4)
+            .set_reevaluation(true);
+        de.run();
+        assert_eq!(de.get_cost_function_evaluations(), 4003);
+        assert_eq!(calls.load(Ordering::Relaxed), 4003);
+        let best = de.get_best().unwrap();
+        assert!(best.get_cost() > -0.5);
+        assert!(best.get_coordinates().iter().map(|v| v * v).sum::<f64>() < 0.5);
+        assert!(de.get_detected_changes().is_empty());
+    }
+
+    #[test]
+    fn change_detection() {
+        let sphere = FstDeJong::builder().dimensions(3).build().unwrap();
+        let problem = Dynamic::new(sphere).set_period(2000);
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 8000, 20, 0.8, 0.9, problem)
+            .set_change_detection(1e-6);
+        de.run();
+        let changes = de.get_detected_changes();
+        assert_eq!(changes.len(), 3);
+        assert!(changes
+            .iter()
+            .zip([2000, 4000, 6000])
+            .all(|(detected, changed)| *detected > changed && *detected < changed + 50));
+        // the optimum moved by 1, a best left at the old one would cost 1
+        assert!(de.get_best().unwrap().get_cost() < 0.5);
+    }
+    #[test]
+    fn per_variable_bounds() {
+        let branin = Branin::builder().build().unwrap();
+        let bounds = branin.get_bounds();
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 5000, 20, 0.8, 0.9, branin);
+        de.run();
+        // every point evaluated, not only the best, stays inside the domain of its variables
+        assert!(de.generations_history.iter().flatten().all(|member| member
+            .get_coordinates()
+            .iter()
+            .zip(bounds.iter())
+            .all(|(x, (min, max))| x >= min && x <= max)));
+    }
+
+    #[test]
+    fn spent_budget() {
+        let calls = AtomicI32::new(0);
+        let problem = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            x.iter().map(|v| v * v).sum()
+        });
+        // the population is larger than the budget, the members past it are not evaluated
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 6, 10, 0.8, 0.9, problem);
+        de.run();
+        assert_eq!(calls.load(Ordering::Relaxed), 6);
+        assert_eq!(de.get_cost_function_evaluations(), 6);
+        assert_eq!(
+            de.get_members()
+                .iter()
+                .filter(|member| member.get_cost().is_infinite())
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn reseeding() {
+        let problem = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| x.iter().sum());
+        let de = De::new(Variant::Rnd, 1, Strategy::Bin, 100, 8, 0.8, 0.9, problem);
+        let reseeded = |de: &De<_>| (0..8).filter(|&i| de.is_reseeded(i)).collect::<Vec<_>>();
+        assert_eq!(reseeded(&de), vec![1, 3, 5, 7]);
+        let de = de.set_reseeding(0.25);
+        assert_eq!(reseeded(&de), vec![3, 7]);
+        let de = de.set_reseeding(0f64);
+        assert!(reseeded(&de).is_empty());
+        let de = de.set_reseeding(2f64);
+        assert_eq!(reseeded(&de).len(), 8);
+    }
+
+    #[test]
+    fn noise_is_no_change() {
+        let sphere = FstDeJong::builder().dimensions(3).build().unwrap();
+        let problem = Noisy::new(sphere, Noise::Gaussian(0.01));
+        // the mean of 16 samples moves by far less than the tolerance
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 8000, 20, 0.8, 0.9, problem)
+            .set_resampling(16)
+            .set_change_detection(0.05);
+        de.run();
+        assert!(de.get_detected_changes().is_empty());
+    }
 }
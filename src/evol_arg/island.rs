@@ -0,0 +1,458 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use rand::Rng;
+
+use crate::evol_arg::de::{De, Member};
+use crate::evol_arg::pso::Pso;
+use crate::problem_definitions::{HasRandom, ProblemDomain, Real};
+
+// population based solver which can be driven generation by generation by the island model
+pub trait Island<F: Real = f32> {
+    // the model pays the initialisation from its own budget, the max_cf of the solver plays no
+    // part once it is an island
+    fn initialise(&mut self);
+
+    // evaluations made by initialise
+    fn get_initialisation_cost(&self) -> i32;
+
+    // evolves one generation using at most max_cf evaluations
+    fn step(&mut self, max_cf: i32);
+
+    // costs and coordinates of the current population
+    fn get_members(&self) -> Vec<(F, Vec<F>)>;
+
+    fn inject(&mut self, index: usize, cost: F, coordinates: Vec<F>);
+
+    fn get_cost_function_evaluations(&self) -> i32;
+
+    // the problem has no evaluation left, see ProblemDomain::is_exhausted
+    fn is_exhausted(&self) -> bool;
+}
+
+impl<T> Island<T::Item> for De<T>
+where
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
+{
+    fn initialise(&mut self) {
+        let limit = self.get_cost_function_evaluations() + self.get_initialisation_cost();
+        self.populate(limit);
+    }
+
+    fn get_initialisation_cost(&self) -> i32 {
+        De::get_initialisation_cost(self)
+    }
+
+    fn step(&mut self, max_cf: i32) {
+        let limit = self.get_cost_function_evaluations() + max_cf;
+        self.next_generation(limit);
+    }
+
+    fn get_members(&self) -> Vec<(T::Item, Vec<T::Item>)> {
+        De::get_members(self)
+            .iter()
+            .map(|member| (member.get_cost(), member.get_coordinates().to_vec()))
+            .collect()
+    }
+
+    fn inject(&mut self, index: usize, cost: T::Item, coordinates: Vec<T::Item>) {
+        self.inject_member(index, Member::new(cost, coordinates));
+    }
+
+    fn get_cost_function_evaluations(&self) -> i32 {
+        De::get_cost_function_evaluations(self)
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.get_problem().is_exhausted()
+    }
+}
+
+impl<T> Island<T::Item> for Pso<T>
+where
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
+{
+    fn initialise(&mut self) {
+        let limit = self.get_cost_function_evaluations() + self.get_initialisation_cost();
+        self.populate(limit);
+    }
+
+    fn get_initialisation_cost(&self) -> i32 {
+        Pso::get_initialisation_cost(self)
+    }
+
+    fn step(&mut self, max_cf: i32) {
+        let limit = self.get_cost_function_evaluations() + max_cf;
+        self.next_generation(limit);
+    }
+
+    fn get_members(&self) -> Vec<(T::Item, Vec<T::Item>)> {
+        self.get_particles()
+            .iter()
+            .map(|particle| (particle.current_cost, particle.get_coordinates().to_vec()))
+            .collect()
+    }
+
+    fn inject(&mut self, index: usize, cost: T::Item, coordinates: Vec<T::Item>) {
+        self.inject_particle(index, cost, coordinates);
+    }
+
+    fn get_cost_function_evaluations(&self) -> i32 {
+        Pso::get_cost_function_evaluations(self)
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.get_problem().is_exhausted()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IslandError {
+    NoIslands,
+    // the islands cannot even be initialised within the shared budget
+    BudgetTooSmall { needed: i32, budget: i32 },
+}
+
+impl Display for IslandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoIslands => write!(f, "Error while building island model: no island given"),
+            Self::BudgetTooSmall { needed, budget } => write!(
+                f,
+                "Error while building island model: initialisation needs {} evaluations, the budget is {}",
+                needed, budget
+            ),
+        }
+    }
+}
+
+pub enum Topology {
+    // every island sends its migrants to the next one
+    Ring,
+    FullyConnected,
+    // every island sends its migrants to one randomly chosen island
+    Random,
+}
+
+pub enum Replacement {
+    // migrants replace the worst members, but only when they are better
+    Worst,
+    Random,
+}
+
+// source: https://en.wikipedia.org/wiki/Population_model_(evolutionary_algorithm)#Island_models
+pub struct IslandModel<F: Real = f32> {
+    max_cf: i32,
+    migration_interval: usize,
+    migrants: usize,
+    topology: Topology,
+    replacement: Replacement,
+    islands: Vec<Box<dyn Island<F>>>,
+
+    current_best: Option<F>,
+    current_best_coords: Option<Vec<F>>,
+    cost_history: Vec<F>,
+    migrations: usize,
+    cost_function_evaluations: i32,
+}
+
+impl<F: Real> IslandModel<F> {
+    // the initialisation of every island is paid from max_cf as well
+    pub fn new(
+        max_cf: i32,
+        migration_interval: usize,
+        islands: Vec<Box<dyn Island<F>>>,
+    ) -> Result<Self, IslandError> {
+        if islands.is_empty() {
+            return Err(IslandError::NoIslands);
+        }
+        let needed = islands
+            .iter()
+            .map(|island| island.get_initialisation_cost())
+            .sum();
+        if needed > max_cf {
+            return Err(IslandError::BudgetTooSmall {
+                needed,
+                budget: max_cf,
+            });
+        }
+        Ok(Self {
+            max_cf,
+            migration_interval: migration_interval.max(1),
+            migrants: 1,
+            topology: Topology::Ring,
+            replacement: Replacement::Worst,
+            islands,
+            current_best: None,
+            current_best_coords: None,
+            cost_history: vec![],
+            migrations: 0,
+            cost_function_evaluations: 0,
+        })
+    }
+
+    pub fn set_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn set_replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    pub fn set_migrants(mut self, migrants: usize) -> Self {
+        self.migrants = migrants;
+        self
+    }
+
+    // the initialisation fits in the budget, see new, every generation is cut to what is left
+    pub fn run(&mut self) {
+        let start_evaluations = self.get_island_evaluations();
+        self.islands
+            .iter_mut()
+            .for_each(|island| island.initialise());
+        self.cost_function_evaluations = self.get_island_evaluations() - start_evaluations;
+        self.update_best();
+
+        let mut generation = 0;
+        while self.cost_function_evaluations < self.max_cf
+            && !self.islands.iter().all(|island| island.is_exhausted())
+        {
+            for index in 0..self.islands.len() {
+                let remaining = self.max_cf - self.cost_function_evaluations;
+                if remaining <= 0 {
+                    break;
+                }
+                self.islands[index].step(remaining);
+                self.cost_function_evaluations = self.get_island_evaluations() - start_evaluations;
+            }
+            generation += 1;
+            self.update_best();
+
+            if generation % self.migration_interval == 0 && self.islands.len() > 1 {
+                self.migrate();
+            }
+        }
+    }
+
+    fn migrate(&mut self) {
+        let emigrants: Vec<Vec<(F, Vec<F>)>> = self
+            .islands
+            .iter()
+            .map(|island| {
+                let mut members = island.get_members();
+                members.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+                members.truncate(self.migrants);
+                members
+            })
+            .collect();
+
+        let mut rng = rand::thread_rng();
+        let count = self.islands.len();
+        for (source, migrants) in emigrants.into_iter().enumerate() {
+            let targets: Vec<usize> = match self.topology {
+                Topology::Ring => vec![(source + 1) % count],
+                Topology::FullyConnected => (0..count).filter(|i| *i != source).collect(),
+                Topology::Random => {
+                    let target = rng.gen_range(0..count - 1);
+                    vec![if target >= source { target + 1 } else { target }]
+                }
+            };
+            for target in targets {
+                for (cost, coordinates) in migrants.iter() {
+                    let members = self.islands[target].get_members();
+                    let index = match self.replacement {
+                        Replacement::Worst => {
+                            let (index, (worst, _)) = members
+                                .iter()
+                                .enumerate()
+                                .max_by(|a, b| {
+                                    a.1 .0.partial_cmp(&b.1 .0).unwrap_or(Ordering::Equal)
+                                })
+                                .unwrap();
+                            if cost >= worst {
+                                continue;
+                            }
+                            index
+                        }
+                        Replacement::Random => rng.gen_range(0..members.len()),
+                    };
+                    self.islands[target].inject(index, *cost, coordinates.clone());
+                }
+            }
+        }
+        self.migrations += 1;
+    }
+
+    fn update_best(&mut self) {
+        for island in self.islands.iter() {
+            for (cost, coordinates) in island.get_members() {
+                if self.current_best.is_none_or(|best| cost < best) {
+                    self.current_best = Some(cost);
+                    self.current_best_coords = Some(coordinates);
+                }
+            }
+        }
+        if let Some(best) = self.current_best {
+            self.cost_history.push(best);
+        }
+    }
+
+    fn get_island_evaluations(&self) -> i32 {
+        self.islands
+            .iter()
+            .map(|island| island.get_cost_function_evaluations())
+            .sum()
+    }
+
+    pub fn get_history(&self) -> &[F] {
+        &self.cost_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<F> {
+        self.current_best
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[F]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_migrations(&self) -> usize {
+        self.migrations
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{rastrigin::Rastrigin, traits::HasBuilder};
+    use crate::budget::Counted;
+    use crate::evol_arg::de::{Strategy, Variant};
+
+    fn problem() -> Rastrigin {
+        Rastrigin::builder()
+            .minimum(-5.12)
+            .maximum(5.12)
+            .dimensions(5)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn shared_budget() {
+        let islands: Vec<Box<dyn Island>> = vec![
+            Box::new(De::new(
+                Variant::Rnd,
+                1,
+                Strategy::Bin,
+                1000,
+                10,
+                0.8,
+                0.5,
+                problem(),
+            )),
+            Box::new(De::new(
+                Variant::Best,
+                1,
+                Strategy::Bin,
+                1000,
+                10,
+                0.5,
+                0.9,
+                problem(),
+            )),
+            Box::new(Pso::new(1000, 10, 0.5, 0.8, 0.9, problem())),
+        ];
+        let mut model = IslandModel::new(3005, 5, islands).unwrap();
+        model.run();
+        assert_eq!(model.get_cost_function_evaluations(), 3005);
+        assert!(model.get_migrations() > 0);
+        assert!(model
+            .get_history()
+            .windows(2)
+            .all(|costs| costs[1] <= costs[0]));
+    }
+
+    #[test]
+    fn exhausted_problems() {
+        // the wrapped problems run out long before the model budget
+        let islands: Vec<Box<dyn Island>> = vec![
+            Box::new(Pso::new(
+                0,
+                10,
+                0.5,
+                0.8,
+                0.9,
+                Counted::new(problem()).set_budget(200),
+            )),
+            Box::new(Pso::new(
+                0,
+                10,
+                0.5,
+                0.8,
+                0.9,
+                Counted::new(problem()).set_budget(300),
+            )),
+        ];
+        let mut model = IslandModel::new(3000, 5, islands).unwrap();
+        model.run();
+        assert_eq!(model.get_cost_function_evaluations(), 500);
+    }
+
+    #[test]
+    fn migrants_replace_worst() {
+        let islands: Vec<Box<dyn Island>> = vec![
+            Box::new(Pso::new(0, 5, 0.5, 0.8, 0.9, problem())),
+            Box::new(Pso::new(0, 5, 0.5, 0.8, 0.9, problem())),
+        ];
+        // the budget covers the initialisation only
+        let mut model = IslandModel::new(10, 1, islands)
+            .unwrap()
+            .set_migrants(2)
+            .set_topology(Topology::FullyConnected);
+        model.run();
+        assert_eq!(model.get_cost_function_evaluations(), 10);
+        let best: Vec<f32> = model
+            .islands
+            .iter()
+            .map(|island| {
+                island
+                    .get_members()
+                    .iter()
+                    .fold(f32::INFINITY, |acc, member| acc.min(member.0))
+            })
+            .collect();
+        model.migrate();
+        // both islands now hold the best member found so far
+        assert!(model.islands.iter().all(|island| island
+            .get_members()
+            .iter()
+            .any(|member| member.0 == best[0].min(best[1]))));
+    }
+
+    #[test]
+    fn rejected_models() {
+        assert_eq!(
+            IslandModel::<f32>::new(100, 1, vec![]).err(),
+            Some(IslandError::NoIslands)
+        );
+        let islands: Vec<Box<dyn Island>> = vec![
+            Box::new(Pso::new(100, 20, 0.5, 0.8, 0.9, problem())),
+            Box::new(Pso::new(100, 20, 0.5, 0.8, 0.9, problem())),
+        ];
+        assert_eq!(
+            IslandModel::new(30, 1, islands).err(),
+            Some(IslandError::BudgetTooSmall {
+                needed: 40,
+                budget: 30
+            })
+        );
+    }
+}
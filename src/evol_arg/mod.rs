@@ -1,2 +1,7 @@
 pub mod de;
+pub mod island;
+pub mod moead;
+pub mod nsga2;
 pub mod pso;
+pub mod spea2;
+pub mod variation;
@@ -0,0 +1,392 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::constraints::total_violation;
+use crate::evol_arg::variation::Variation;
+use crate::pareto::{simplex_lattice, sort_solutions, ParetoArchive, Solution};
+use crate::problem_definitions::{HasObjectives, HasRandom, Real};
+
+// smallest weight of the Tchebycheff scalarisation, keeps the axis subproblems meaningful
+const MIN_WEIGHT: f64 = 1e-6;
+
+// how a subproblem turns a cost vector into a single value, given its weight vector and the
+// ideal point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scalarisation {
+    WeightedSum,
+    Tchebycheff,
+    // penalty-based boundary intersection, theta weighs the distance from the weight direction
+    PenaltyBoundaryIntersection(f64),
+}
+
+impl Scalarisation {
+    pub fn scalarise(&self, costs: &[f64], weight: &[f64], ideal: &[f64]) -> f64 {
+        match *self {
+            Self::WeightedSum => costs.iter().zip(weight).map(|(f, w)| w * f).sum(),
+            Self::Tchebycheff => costs
+                .iter()
+                .zip(weight.iter().zip(ideal))
+                .map(|(f, (w, z))| w.max(MIN_WEIGHT) * (f - z).abs())
+                .fold(f64::NEG_INFINITY, f64::max),
+            Self::PenaltyBoundaryIntersection(theta) => {
+                let norm = weight.iter().map(|w| w * w).sum::<f64>().sqrt();
+                let shifted = costs.iter().zip(ideal).map(|(f, z)| f - z);
+                let along = shifted.clone().zip(weight).map(|(d, w)| d * w).sum::<f64>() / norm;
+                let across = shifted
+                    .zip(weight)
+                    .map(|(d, w)| (d - along * w / norm).powi(2))
+                    .sum::<f64>()
+                    .sqrt();
+                along.abs() + theta * across
+            }
+        }
+    }
+}
+
+// MOEA/D (Zhang and Li 2007), one subproblem per weight vector of the simplex lattice, a child
+// of a subproblem's neighbourhood may replace the solutions of the neighbouring subproblems it
+// scalarises better, the defaults follow MOEA/D-DE (Li and Zhang 2009)
+// source: https://doi.org/10.1109/TEVC.2007.892759
+pub struct Moead<T>
+where
+    T: HasObjectives + HasRandom,
+    T::Item: Real,
+{
+    // MOEA/D parameters
+    max_cf: i32,
+    scalarisation: Scalarisation,
+    variation: Variation,
+    neighbourhood_size: usize,
+    neighbourhood_probability: f32,
+    replacement_limit: usize,
+    weights: Vec<Vec<f64>>,
+    neighbourhoods: Vec<Vec<usize>>,
+    ideal: Vec<f64>,
+
+    // results
+    archive: ParetoArchive<T::Item>,
+    generations_history: Vec<Vec<Solution<T::Item>>>,
+    fronts_history: Vec<Vec<Solution<T::Item>>>,
+    current_generation: usize,
+    cost_function_evaluations: i32,
+
+    problem: T,
+}
+
+impl<T> Moead<T>
+where
+    T: HasObjectives + HasRandom,
+    T::Item: Real,
+{
+    // the population is as large as the lattice with the given divisions, e.g. 99 divisions give
+    // 100 subproblems for two objectives and 13 give 105 for three
+    pub fn new(max_cf: i32, divisions: usize, problem: T) -> Self {
+        let weights = simplex_lattice(problem.get_objectives(), divisions);
+        Self {
+            max_cf,
+            scalarisation: Scalarisation::Tchebycheff,
+            variation: Variation::DifferentialEvolution {
+                scaling_factor: 0.5,
+                crossover_probability: 1f32,
+            },
+            neighbourhood_size: 20,
+            neighbourhood_probability: 0.9,
+            replacement_limit: 2,
+            archive: ParetoArchive::new(weights.len()),
+            weights,
+            neighbourhoods: Vec::new(),
+            ideal: Vec::new(),
+            generations_history: Vec::new(),
+            fronts_history: Vec::new(),
+            current_generation: 0usize,
+            cost_function_evaluations: 0,
+            problem,
+        }
+    }
+
+    pub fn set_scalarisation(mut self, scalarisation: Scalarisation) -> Self {
+        self.scalarisation = scalarisation;
+        self
+    }
+
+    pub fn set_variation(mut self, variation: Variation) -> Self {
+        self.variation = variation;
+        self
+    }
+
+    // subproblems with the closest weight vectors, the subproblem itself included
+    pub fn set_neighbourhood_size(mut self, neighbourhood_size: usize) -> Self {
+        self.neighbourhood_size = neighbourhood_size.max(1);
+        self
+    }
+
+    // chance that the parents and the replaced solutions come from the neighbourhood rather than
+    // the whole population
+    pub fn set_neighbourhood_probability(mut self, neighbourhood_probability: f32) -> Self {
+        self.neighbourhood_probability = neighbourhood_probability;
+        self
+    }
+
+    // most solutions a single child may replace
+    pub fn set_replacement_limit(mut self, replacement_limit: usize) -> Self {
+        self.replacement_limit = replacement_limit.max(1);
+        self
+    }
+
+    pub fn set_archive_capacity(mut self, capacity: usize) -> Self {
+        self.archive = ParetoArchive::new(capacity);
+        self
+    }
+
+    pub fn run(&mut self) {
+        self.initialise();
+        while self.cost_function_evaluations < self.max_cf && !self.problem.is_exhausted() {
+            self.step();
+        }
+    }
+
+    pub fn initialise(&mut self) {
+        self.neighbourhoods = self
+            .weights
+            .iter()
+            .map(|weight| {
+                let mut order = (0..self.weights.len()).collect::<Vec<_>>();
+                order.sort_by(|&a, &b| {
+                    distance(weight, &self.weights[a])
+                        .total_cmp(&distance(weight, &self.weights[b]))
+                });
+                order.truncate(self.neighbourhood_size);
+                order
+            })
+            .collect();
+        self.ideal = vec![f64::INFINITY; self.problem.get_objectives()];
+        let population = (0..self.weights.len())
+            .map(|_| {
+                let coords = self.problem.get_random();
+                self.evaluate(coords)
+            })
+            .collect();
+        self.add_new_generation(population);
+    }
+
+    // gives every subproblem one child, used when the run is driven from outside
+    pub fn step(&mut self) {
+        let mut rng = rand::thread_rng();
+        let mut population = self.get_members().to_vec();
+        for index in 0..population.len() {
+            if self.cost_function_evaluations >= self.max_cf || self.problem.is_exhausted() {
+                break;
+            }
+            let mut mating = match rng.gen::<f32>() < self.neighbourhood_probability {
+                true => self.neighbourhoods[index].clone(),
+                false => (0..population.len()).collect(),
+            };
+            let pool = mating
+                .iter()
+                .filter(|&&i| i != index)
+                .map(|&i| population[i].get_coordinates())
+                .collect::<Vec<_>>();
+            let coords =
+                self.variation
+                    .offspring(&self.problem, population[index].get_coordinates(), &pool);
+            let child = self.evaluate(coords);
+            mating.shuffle(&mut rng);
+            let mut replaced = 0usize;
+            for i in mating {
+                if replaced == self.replacement_limit {
+                    break;
+                }
+                if self.improves(&child, &population[i], i) {
+                    population[i] = child.clone();
+                    replaced += 1;
+                }
+            }
+        }
+        self.add_new_generation(population);
+    }
+
+    // feasible beats infeasible and two infeasible solutions compare by their violation
+    fn improves(
+        &self,
+        child: &Solution<T::Item>,
+        current: &Solution<T::Item>,
+        index: usize,
+    ) -> bool {
+        match (child.is_feasible(), current.is_feasible()) {
+            (true, true) => self.scalarise(child, index) < self.scalarise(current, index),
+            (true, false) => true,
+            (false, true) => false,
+            (false, false) => child.get_violation() < current.get_violation(),
+        }
+    }
+
+    fn scalarise(&self, solution: &Solution<T::Item>, index: usize) -> f64 {
+        self.scalarisation.scalarise(
+            &to_f64(solution.get_costs()),
+            &self.weights[index],
+            &self.ideal,
+        )
+    }
+
+    fn evaluate(&mut self, coordinates: Vec<T::Item>) -> Solution<T::Item> {
+        self.cost_function_evaluations += 1;
+        let costs = self.problem.cost_vector(&coordinates);
+        let violation = total_violation(&self.problem, &coordinates);
+        self.ideal
+            .iter_mut()
+            .zip(to_f64(&costs))
+            .for_each(|(z, f)| *z = z.min(f));
+        let solution = Solution::new(coordinates, costs, violation);
+        self.archive.insert(solution.clone());
+        solution
+    }
+
+    fn add_new_generation(&mut self, new_generation: Vec<Solution<T::Item>>) {
+        if !self.generations_history.is_empty() {
+            self.current_generation += 1;
+        }
+        let front = sort_solutions(&new_generation)
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| new_generation[i].clone())
+            .collect();
+        self.fronts_history.push(front);
+        self.generations_history.push(new_generation);
+    }
+
+    // the solution of subproblem i is the i-th member
+    pub fn get_members(&self) -> &[Solution<T::Item>] {
+        &self.generations_history[self.current_generation]
+    }
+
+    pub fn get_weights(&self) -> &[Vec<f64>] {
+        &self.weights
+    }
+
+    // smallest value of every objective met so far
+    pub fn get_ideal_point(&self) -> &[f64] {
+        &self.ideal
+    }
+
+    // non-dominated members of the final population
+    pub fn get_front(&self) -> &[Solution<T::Item>] {
+        &self.fronts_history[self.current_generation]
+    }
+
+    // first front of every generation, the initial one included
+    pub fn get_fronts_history(&self) -> &[Vec<Solution<T::Item>>] {
+        &self.fronts_history
+    }
+
+    pub fn get_archive(&self) -> &ParetoArchive<T::Item> {
+        &self.archive
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn to_f64<F: Real>(costs: &[F]) -> Vec<f64> {
+    costs.iter().map(|cost| cost.to_f64().unwrap()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pareto::dominates;
+    use crate::problem_definitions::FnProblem;
+
+    // Schaffer's problem, the pareto set is x in [0, 2]
+    fn schaffer() -> FnProblem<impl Fn(&[f64]) -> f64, f64> {
+        FnProblem::new(-10f64, 10f64, 1, |x: &[f64]| x[0].powi(2))
+            .set_objectives(2, |x: &[f64]| vec![x[0].powi(2), (x[0] - 2f64).powi(2)])
+    }
+
+    #[test]
+    fn scalarisations() {
+        let ideal = [0f64, 0f64];
+        let weight = [0.5, 0.5];
+        assert_eq!(
+            Scalarisation::WeightedSum.scalarise(&[1f64, 3f64], &weight, &ideal),
+            2f64
+        );
+        assert_eq!(
+            Scalarisation::Tchebycheff.scalarise(&[1f64, 3f64], &weight, &ideal),
+            1.5
+        );
+        // on the weight direction only the distance along it counts
+        let pbi = Scalarisation::PenaltyBoundaryIntersection(5f64);
+        assert!((pbi.scalarise(&[1f64, 1f64], &weight, &ideal) - 2f64.sqrt()).abs() < 1e-12);
+        let off = pbi.scalarise(&[2f64, 0f64], &weight, &ideal);
+        assert!((off - 6f64 * 2f64.sqrt()).abs() < 1e-12);
+        // a zero weight still counts a little
+        let axis = Scalarisation::Tchebycheff.scalarise(&[0f64, 3f64], &[1f64, 0f64], &ideal);
+        assert!(axis > 0f64);
+    }
+
+    #[test]
+    fn expected_cost_calls() {
+        let mut moead = Moead::new(1010, 19, schaffer());
+        moead.run();
+        assert_eq!(moead.get_cost_function_evaluations(), 1010);
+        assert_eq!(moead.get_fronts_history().len(), 51);
+        assert_eq!(moead.get_members().len(), 20);
+        assert_eq!(moead.get_weights().len(), 20);
+        assert!(moead.get_ideal_point().iter().all(|z| *z >= 0f64));
+    }
+
+    #[test]
+    fn schaffer_front() {
+        let scalarisations = [
+            Scalarisation::WeightedSum,
+            Scalarisation::Tchebycheff,
+            Scalarisation::PenaltyBoundaryIntersection(5f64),
+        ];
+        for scalarisation in scalarisations {
+            let mut moead = Moead::new(16000, 39, schaffer()).set_scalarisation(scalarisation);
+            moead.run();
+            let front = moead.get_front();
+            assert!(front.len() > 10);
+            assert!(front
+                .iter()
+                .all(|s| (-0.05..=2.05).contains(&s.get_coordinates()[0])));
+            for a in front {
+                assert!(front
+                    .iter()
+                    .all(|b| !dominates(b.get_costs(), a.get_costs())));
+            }
+            assert!(moead.get_ideal_point().iter().all(|z| *z < 1e-3));
+        }
+    }
+
+    #[test]
+    fn sbx_constrained() {
+        // x >= 1 removes the left part of the pareto set
+        let problem = schaffer().set_inequality_constraints(|x: &[f64]| vec![1f64 - x[0]]);
+        let mut moead = Moead::new(3000, 29, problem)
+            .set_variation(Variation::SimulatedBinary {
+                crossover_probability: 0.9,
+                crossover_index: 20f64,
+                mutation_index: 20f64,
+            })
+            .set_neighbourhood_size(5)
+            .set_replacement_limit(1);
+        moead.run();
+        assert!(moead.get_front().iter().all(|s| s.is_feasible()));
+        assert!(moead
+            .get_archive()
+            .get_solutions()
+            .iter()
+            .all(|s| (1f64..=2.05).contains(&s.get_coordinates()[0])));
+    }
+}
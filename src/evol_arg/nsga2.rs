@@ -0,0 +1,287 @@
+use std::cmp::Ordering;
+
+use crate::constraints::total_violation;
+use crate::evol_arg::de::{binomial_trial, reflect};
+use crate::pareto::{crowding_distance, sort_solutions, ParetoArchive, Solution};
+use crate::problem_definitions::{HasObjectives, HasRandom, Real};
+use rand::seq::SliceRandom;
+
+// NSGA-II (Deb et al. 2002) with the rand/1/bin offspring of De, parents and offspring are
+// merged and the next generation is filled front by front, the last front by crowding distance,
+// constraints are handled by constrained dominance
+pub struct Nsga2<T>
+where
+    T: HasObjectives + HasRandom,
+    T::Item: Real,
+{
+    // NSGA-II parameters
+    max_cf: i32,
+    population_size: usize,
+    scaling_factor: T::Item,
+    crossover_probability: f32,
+
+    // results
+    archive: ParetoArchive<T::Item>,
+    generations_history: Vec<Vec<Solution<T::Item>>>,
+    fronts_history: Vec<Vec<Solution<T::Item>>>,
+    current_generation: usize,
+    cost_function_evaluations: i32,
+
+    problem: T,
+}
+
+impl<T> Nsga2<T>
+where
+    T: HasObjectives + HasRandom,
+    T::Item: Real,
+{
+    pub fn new(max_cf: i32, population_size: usize, problem: T) -> Self {
+        // an empty population never spends the budget
+        let population_size = population_size.max(1);
+        Self {
+            max_cf,
+            population_size,
+            scaling_factor: T::Item::from_f64(0.5),
+            crossover_probability: 0.9,
+            archive: ParetoArchive::new(population_size),
+            generations_history: Vec::new(),
+            fronts_history: Vec::new(),
+            current_generation: 0usize,
+            cost_function_evaluations: 0,
+            problem,
+        }
+    }
+
+    pub fn set_scaling_factor(mut self, scaling_factor: f32) -> Self {
+        self.scaling_factor = T::Item::from_f64(f64::from(scaling_factor));
+        self
+    }
+
+    pub fn set_crossover_probability(mut self, crossover_probability: f32) -> Self {
+        self.crossover_probability = crossover_probability;
+        self
+    }
+
+    // the archive collects every non-dominated point met during the run, by default it is as
+    // large as the population
+    pub fn set_archive_capacity(mut self, capacity: usize) -> Self {
+        self.archive = ParetoArchive::new(capacity);
+        self
+    }
+
+    pub fn run(&mut self) {
+        self.initialise();
+        while self.cost_function_evaluations < self.max_cf && !self.problem.is_exhausted() {
+            self.step();
+        }
+    }
+
+    pub fn initialise(&mut self) {
+        let population = (0..self.population_size)
+            .map(|_| {
+                let coords = self.problem.get_random();
+                self.evaluate(coords)
+            })
+            .collect();
+        self.add_new_generation(population);
+    }
+
+    // evolves a single generation, used when the run is driven from outside
+    pub fn step(&mut self) {
+        let population = self.get_members().to_vec();
+        let remaining = (self.max_cf - self.cost_function_evaluations).max(0) as usize;
+        let offspring = (0..population.len().min(remaining))
+            .map_while(|index| {
+                // a shared budget may run out within the generation
+                if self.problem.is_exhausted() {
+                    return None;
+                }
+                let trial = self.offspring(&population, index);
+                Some(self.evaluate(trial))
+            })
+            .collect::<Vec<_>>();
+        let survivors = self.select(population.into_iter().chain(offspring).collect());
+        self.add_new_generation(survivors);
+    }
+
+    fn offspring(&self, population: &[Solution<T::Item>], index: usize) -> Vec<T::Item> {
+        let others = population
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, member)| member)
+            .collect::<Vec<_>>();
+        // populations too small for three distinct parents draw them with replacement
+        let mut rng = rand::thread_rng();
+        let target = &population[index];
+        let parents: Vec<&Solution<T::Item>> = match others.len() {
+            3.. => others.choose_multiple(&mut rng, 3).copied().collect(),
+            _ => (0..3)
+                .map(|_| others.choose(&mut rng).copied().unwrap_or(target))
+                .collect(),
+        };
+        let first = parents[0].get_coordinates();
+        let second = parents[1].get_coordinates();
+        let base = parents[2].get_coordinates();
+        reflect(
+            &self.problem,
+            binomial_trial(
+                base,
+                first,
+                second,
+                target.get_coordinates(),
+                self.scaling_factor,
+                self.crossover_probability,
+            ),
+        )
+    }
+
+    fn select(&self, merged: Vec<Solution<T::Item>>) -> Vec<Solution<T::Item>> {
+        let mut survivors = Vec::with_capacity(self.population_size);
+        for front in sort_solutions(&merged) {
+            if survivors.len() + front.len() <= self.population_size {
+                survivors.extend(front.into_iter().map(|i| merged[i].clone()));
+                continue;
+            }
+            let costs = front
+                .iter()
+                .map(|&i| merged[i].get_costs().to_vec())
+                .collect::<Vec<_>>();
+            let distances = crowding_distance(&costs);
+            let mut order = (0..front.len()).collect::<Vec<_>>();
+            order.sort_by(|&a, &b| {
+                distances[b]
+                    .partial_cmp(&distances[a])
+                    .unwrap_or(Ordering::Equal)
+            });
+            let missing = self.population_size - survivors.len();
+            survivors.extend(
+                order
+                    .into_iter()
+                    .take(missing)
+                    .map(|i| merged[front[i]].clone()),
+            );
+            break;
+        }
+        survivors
+    }
+
+    fn evaluate(&mut self, coordinates: Vec<T::Item>) -> Solution<T::Item> {
+        self.cost_function_evaluations += 1;
+        let costs = self.problem.cost_vector(&coordinates);
+        let violation = total_violation(&self.problem, &coordinates);
+        let solution = Solution::new(coordinates, costs, violation);
+        self.archive.insert(solution.clone());
+        solution
+    }
+
+    fn add_new_generation(&mut self, new_generation: Vec<Solution<T::Item>>) {
+        if !self.generations_history.is_empty() {
+            self.current_generation += 1;
+        }
+        let front = sort_solutions(&new_generation)
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| new_generation[i].clone())
+            .collect();
+        self.fronts_history.push(front);
+        self.generations_history.push(new_generation);
+    }
+
+    pub fn get_members(&self) -> &[Solution<T::Item>] {
+        &self.generations_history[self.current_generation]
+    }
+
+    // non-dominated members of the final population
+    pub fn get_front(&self) -> &[Solution<T::Item>] {
+        &self.fronts_history[self.current_generation]
+    }
+
+    // first front of every generation, the initial one included
+    pub fn get_fronts_history(&self) -> &[Vec<Solution<T::Item>>] {
+        &self.fronts_history
+    }
+
+    pub fn get_archive(&self) -> &ParetoArchive<T::Item> {
+        &self.archive
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::budget::Counted;
+    use crate::pareto::dominates;
+    use crate::problem_definitions::FnProblem;
+
+    // Schaffer's problem, the pareto set is x in [0, 2]
+    fn schaffer() -> FnProblem<impl Fn(&[f64]) -> f64, f64> {
+        FnProblem::new(-10f64, 10f64, 1, |x: &[f64]| x[0].powi(2))
+            .set_objectives(2, |x: &[f64]| vec![x[0].powi(2), (x[0] - 2f64).powi(2)])
+    }
+
+    #[test]
+    fn expected_cost_calls() {
+        let mut nsga2 = Nsga2::new(1010, 20, schaffer());
+        nsga2.run();
+        assert_eq!(nsga2.get_cost_function_evaluations(), 1010);
+        assert_eq!(nsga2.get_fronts_history().len(), 51);
+        assert!(nsga2.get_members().iter().all(|m| m.get_costs().len() == 2));
+        assert_eq!(nsga2.get_members().len(), 20);
+    }
+
+    #[test]
+    fn shared_budget() {
+        let mut nsga2 = Nsga2::new(1000, 20, Counted::new(schaffer()).set_budget(110));
+        nsga2.run();
+        assert_eq!(nsga2.get_cost_function_evaluations(), 110);
+        assert_eq!(nsga2.problem.get_refused(), 0);
+    }
+
+    #[test]
+    fn small_populations() {
+        for population_size in 0..4 {
+            let mut nsga2 = Nsga2::new(50, population_size, schaffer());
+            nsga2.run();
+            assert_eq!(nsga2.get_cost_function_evaluations(), 50);
+            assert_eq!(nsga2.get_members().len(), population_size.max(1));
+        }
+    }
+
+    #[test]
+    fn schaffer_front() {
+        let mut nsga2 = Nsga2::new(4000, 40, schaffer());
+        nsga2.run();
+        let front = nsga2.get_front();
+        assert_eq!(front.len(), 40);
+        assert!(front
+            .iter()
+            .all(|s| (-0.05..=2.05).contains(&s.get_coordinates()[0])));
+        for a in front {
+            assert!(front
+                .iter()
+                .all(|b| !dominates(b.get_costs(), a.get_costs())));
+        }
+        assert!(nsga2.get_archive().len() <= 40);
+    }
+
+    #[test]
+    fn constrained() {
+        // x >= 1 removes the left part of the pareto set
+        let problem = schaffer().set_inequality_constraints(|x: &[f64]| vec![1f64 - x[0]]);
+        let mut nsga2 = Nsga2::new(3000, 30, problem);
+        nsga2.run();
+        assert!(nsga2.get_front().iter().all(|s| s.is_feasible()));
+        assert!(nsga2
+            .get_archive()
+            .get_solutions()
+            .iter()
+            .all(|s| (1f64..=2.05).contains(&s.get_coordinates()[0])));
+    }
+}
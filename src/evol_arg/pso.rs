@@ -1,13 +1,19 @@
-use crate::problem_definitions::{HasRandom, ProblemDomain};
+use crate::constraints::{evaluation, total_violation, ConstraintHandler, ConstraintHandling};
+use crate::evol_arg::de::reflect;
+use crate::problem_definitions::{HasRandom, ProblemDomain, Real};
 use rand::{random, seq::SliceRandom};
+use rand_distr::num_traits::{Float, ToPrimitive};
 #[derive(Debug)]
 pub struct Particle<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
-    pub current_cost: f32,
+    pub current_cost: T::Item,
     pub best_coords: usize,
-    best_cost: f32,
+    best_cost: T::Item,
+    current_violation: f64,
+    best_violation: f64,
     pub current_coordinates: usize,
     pub coordinates_history: Vec<Vec<T::Item>>,
     pub velocity: Vec<T::Item>,
@@ -15,12 +21,15 @@ where
 
 impl<T> Clone for Particle<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
     fn clone(&self) -> Self {
         Self {
             current_cost: self.current_cost,
             best_cost: self.best_cost,
+            current_violation: self.current_violation,
+            best_violation: self.best_violation,
             best_coords: self.best_coords,
             current_coordinates: self.current_coordinates,
             coordinates_history: self.coordinates_history.clone(),
@@ -31,12 +40,15 @@ where
 
 impl<T> Particle<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
-    pub fn new(cost: f32, coordinates: Vec<T::Item>, velocity: Vec<T::Item>) -> Self {
+    pub fn new(cost: T::Item, coordinates: Vec<T::Item>, velocity: Vec<T::Item>) -> Self {
         Self {
             current_cost: cost,
             best_cost: cost,
+            current_violation: 0f64,
+            best_violation: 0f64,
             best_coords: 0usize,
             coordinates_history: vec![coordinates],
             current_coordinates: 0usize,
@@ -44,44 +56,92 @@ where
         }
     }
 
-    fn update_particle(&mut self, coordinates: Vec<T::Item>, velocity: Vec<T::Item>, cost: f32) {
+    pub fn get_coordinates(&self) -> &[T::Item] {
+        &self.coordinates_history[self.current_coordinates]
+    }
+
+    pub fn get_violation(&self) -> f64 {
+        self.current_violation
+    }
+
+    fn update_particle(
+        &mut self,
+        coordinates: Vec<T::Item>,
+        velocity: Vec<T::Item>,
+        cost: T::Item,
+        violation: f64,
+        constraint_handler: &ConstraintHandler,
+    ) {
         self.coordinates_history.push(coordinates);
         self.velocity = velocity;
         let len = self.coordinates_history.len();
         self.current_coordinates += 1;
-        self.update_cost(cost);
+        self.update_cost(cost, violation, constraint_handler);
     }
 
-    fn update_cost(&mut self, cost: f32) {
+    fn update_cost(
+        &mut self,
+        cost: T::Item,
+        violation: f64,
+        constraint_handler: &ConstraintHandler,
+    ) {
         self.current_cost = cost;
-        if cost < self.best_cost {
+        self.current_violation = violation;
+        if constraint_handler.is_better(
+            evaluation(&cost, violation),
+            evaluation(&self.best_cost, self.best_violation),
+        ) {
             self.best_cost = cost;
+            self.best_violation = violation;
             self.best_coords = self.current_coordinates;
         }
     }
+
+    // the personal best starts at the given point
+    fn set_violation(&mut self, violation: f64) {
+        self.current_violation = violation;
+        self.best_violation = violation;
+    }
+
+    // the personal best restarts at the current position, e.g. after the problem changed
+    fn forget_best(&mut self) {
+        self.best_cost = self.current_cost;
+        self.best_violation = self.current_violation;
+        self.best_coords = self.current_coordinates;
+    }
 }
 
 pub struct Pso<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
     max_cf: i32,
     population_size: usize,
     inertia_weight: f32,
     personal_priority: f32,
     social_priority: f32,
+    constraint_handler: ConstraintHandler,
+    samples: usize,
+    reevaluation: bool,
+    change_tolerance: Option<f64>,
 
     current_best: Option<T::Item>,
+    current_best_violation: f64,
     current_best_coordinates: Option<Vec<T::Item>>,
     particles: Vec<Particle<T>>,
     cost_function_evaluations: i32,
+    detected_changes: Vec<i32>,
+    // the global best as read at the last check for changes
+    sentinel: Option<(Vec<T::Item>, T::Item)>,
 
     problem: T,
 }
 
 impl<T> Pso<T>
 where
-    T: ProblemDomain<Item = f32> + HasRandom,
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
 {
     pub fn new(
         max_cf: i32,
@@ -97,100 +157,284 @@ where
             inertia_weight,
             personal_priority,
             social_priority,
+            constraint_handler: ConstraintHandler::default(),
+            samples: 1,
+            reevaluation: false,
+            change_tolerance: None,
             current_best: None,
+            current_best_violation: 0f64,
             current_best_coordinates: None,
             particles: Vec::new(),
             cost_function_evaluations: 0,
+            detected_changes: Vec::new(),
+            sentinel: None,
             problem,
         }
     }
 
+    // decides the personal and global bests on constrained problems, feasibility rules by
+    // default, which reduce to comparing costs when nothing is violated
+    pub fn set_constraint_handling(mut self, technique: ConstraintHandling) -> Self {
+        self.constraint_handler = ConstraintHandler::new(technique);
+        self
+    }
+
+    // every cost is the mean of as many evaluations, each of them counted, to average out noise
+    pub fn set_resampling(mut self, samples: usize) -> Self {
+        self.samples = samples.max(1);
+        self
+    }
+
+    // the global and personal bests are evaluated again every generation, so that lucky noisy
+    // costs do not steer the swarm for good
+    pub fn set_reevaluation(mut self, reevaluation: bool) -> Self {
+        self.reevaluation = reevaluation;
+        self
+    }
+
+    // the global best of the previous check is evaluated again every generation, when its cost
+    // moves by more than the tolerance the problem is taken as changed, the particles are evaluated again, all the bests
+    // restart from their current positions and the velocities are drawn anew so that a converged
+    // swarm can follow the optimum
+    pub fn set_change_detection(mut self, tolerance: f64) -> Self {
+        self.change_tolerance = Some(tolerance);
+        self
+    }
+
     pub fn run(&mut self) {
+        self.initialise();
+        while self.cost_function_evaluations < self.max_cf && !self.problem.is_exhausted() {
+            self.next_generation(self.max_cf);
+        }
+    }
+
+    pub fn initialise(&mut self) {
+        self.populate(self.max_cf);
+    }
+
+    // evaluates the random swarm within max_cf, counted from the start
+    pub(crate) fn populate(&mut self, max_cf: i32) {
         let new_pop: Vec<Particle<T>> = (0..self.population_size)
             .into_iter()
             .map(|_| {
                 let coords = self.problem.get_random();
                 let velocity = self.problem.get_random();
-                let cost = self.run_cost_fn(&coords);
-
-                Particle::new(cost, coords, velocity)
+                let cost = self.run_cost_fn(&coords, max_cf);
+                let mut particle = Particle::new(cost, coords, velocity);
+                particle.set_violation(total_violation(&self.problem, particle.get_coordinates()));
+                particle
             })
             .collect();
         self.particles = new_pop.clone();
         self.update_best();
+    }
 
-        while self.cost_function_evaluations < self.max_cf {
-            self.particles = self
-                .particles
-                .clone()
-                .iter()
-                .map(|particle| self.move_particle(particle))
-                .collect();
-            self.update_best();
-        }
+    // moves every particle once, used when the run is driven from outside
+    pub fn step(&mut self) {
+        self.next_generation(self.max_cf);
+    }
+
+    // particles past the evaluation limit are left where they are
+    pub(crate) fn next_generation(&mut self, max_cf: i32) {
+        self.track_changes(max_cf);
+        self.particles = self
+            .particles
+            .clone()
+            .iter()
+            .map(|particle| {
+                if self.cost_function_evaluations < max_cf {
+                    self.move_particle(particle, max_cf)
+                } else {
+                    particle.clone()
+                }
+            })
+            .collect();
+        self.update_best();
+        self.constraint_handler
+            .next_generation(self.current_best_violation == 0f64);
+    }
+
+    // replaces a particle, e.g. with a migrant from another swarm, its velocity is kept
+    pub fn inject_particle(&mut self, index: usize, cost: T::Item, coordinates: Vec<T::Item>) {
+        let velocity = self.particles[index].velocity.clone();
+        let violation = total_violation(&self.problem, &coordinates);
+        self.particles[index] = Particle::new(cost, coordinates, velocity);
+        self.particles[index].set_violation(violation);
+        self.update_best();
     }
 
-    pub fn get_best(&self) -> Option<f32> {
+    pub fn get_best(&self) -> Option<T::Item> {
         self.current_best
     }
 
+    pub fn get_best_violation(&self) -> f64 {
+        self.current_best_violation
+    }
+
+    pub fn is_best_feasible(&self) -> bool {
+        self.current_best_violation == 0f64
+    }
+
+    pub fn get_best_coordinates(&self) -> Option<&[T::Item]> {
+        self.current_best_coordinates.as_deref()
+    }
+
     pub fn get_cost_function_evaluations(&self) -> i32 {
         self.cost_function_evaluations
     }
 
-    fn run_cost_fn(&mut self, input: &[T::Item]) -> f32 {
-        self.cost_function_evaluations += 1;
-        self.problem.cost_function(input)
+    pub fn get_problem(&self) -> &T {
+        &self.problem
+    }
+
+    // evaluations of the random population made by initialise
+    pub fn get_initialisation_cost(&self) -> i32 {
+        (self.population_size * self.samples) as i32
     }
 
-    fn get_current_gen_best(&self) -> (f32, Vec<T::Item>) {
+    // evaluations done when the changes were detected
+    pub fn get_detected_changes(&self) -> &[i32] {
+        &self.detected_changes
+    }
+
+    // the samples are cut to the remaining budget, once it or the problem is exhausted nothing is
+    // evaluated and the cost is infinite, e.g. for the rest of a population larger than the budget
+    fn run_cost_fn(&mut self, input: &[T::Item], max_cf: i32) -> T::Item {
+        let remaining = (max_cf - self.cost_function_evaluations).max(0) as usize;
+        let samples = self.samples.min(remaining);
+        if samples == 0 || self.problem.is_exhausted() {
+            return T::Item::infinity();
+        }
+        self.cost_function_evaluations += samples as i32;
+        (0..samples)
+            .map(|_| self.problem.cost_function(input))
+            .sum::<T::Item>()
+            / T::Item::from_f64(samples as f64)
+    }
+
+    // evaluates the global best again, to replace the noisy costs of the bests or to find out
+    // that the problem changed
+    fn track_changes(&mut self, max_cf: i32) {
+        if (!self.reevaluation && self.change_tolerance.is_none())
+            || self.cost_function_evaluations >= max_cf
+        {
+            return;
+        }
+        let (Some(best), Some(coordinates)) =
+            (self.current_best, self.current_best_coordinates.clone())
+        else {
+            return;
+        };
+        // a best found after the change would not show it, the one read before does
+        let (sentinel, sentinel_cost) = self
+            .sentinel
+            .take()
+            .filter(|_| self.change_tolerance.is_some())
+            .unwrap_or_else(|| (coordinates.clone(), best));
+        let cost = self.run_cost_fn(&sentinel, max_cf);
+        let changed = self
+            .change_tolerance
+            .is_some_and(|tolerance| (cost - sentinel_cost).to_f64().unwrap().abs() > tolerance);
+        let mut particles = std::mem::take(&mut self.particles);
+        if changed {
+            self.detected_changes.push(self.cost_function_evaluations);
+            for particle in particles.iter_mut() {
+                if self.cost_function_evaluations < max_cf {
+                    let coordinates = particle.get_coordinates().to_vec();
+                    particle.current_cost = self.run_cost_fn(&coordinates, max_cf);
+                    particle.current_violation = total_violation(&self.problem, &coordinates);
+                }
+                particle.velocity = self.problem.get_random();
+                particle.forget_best();
+            }
+        } else if self.reevaluation {
+            self.current_best = if sentinel == coordinates {
+                Some(cost)
+            } else if self.cost_function_evaluations < max_cf {
+                Some(self.run_cost_fn(&coordinates, max_cf))
+            } else {
+                Some(best)
+            };
+            for particle in particles.iter_mut() {
+                if self.cost_function_evaluations < max_cf {
+                    let coordinates = particle.coordinates_history[particle.best_coords].clone();
+                    particle.best_cost = self.run_cost_fn(&coordinates, max_cf);
+                }
+            }
+        }
+        self.particles = particles;
+        if changed {
+            self.current_best = None;
+            self.update_best();
+        }
+        self.sentinel = self
+            .current_best
+            .zip(self.current_best_coordinates.clone())
+            .map(|(cost, coordinates)| (coordinates, cost));
+    }
+
+    fn get_current_gen_best(&self) -> (T::Item, f64, Vec<T::Item>) {
         let best = self
             .get_particles()
             .iter()
-            .max_by(|a, b| a.current_cost.partial_cmp(&b.current_cost).unwrap())
+            .reduce(|best, particle| {
+                if self.constraint_handler.is_new_best(
+                    evaluation(&particle.current_cost, particle.current_violation),
+                    evaluation(&best.current_cost, best.current_violation),
+                ) {
+                    particle
+                } else {
+                    best
+                }
+            })
             .unwrap();
         (
             best.current_cost,
+            best.current_violation,
             best.coordinates_history[best.current_coordinates].clone(),
         )
     }
 
-    fn get_particles(&self) -> &[Particle<T>] {
+    pub fn get_particles(&self) -> &[Particle<T>] {
         &self.particles
     }
 
     fn update_best(&mut self) {
-        let (current_gen_best, current_gen_best_coords) = self.get_current_gen_best();
-        if let Some(current_best) = self.current_best {
-            if current_gen_best < current_best {
-                self.current_best = Some(current_gen_best);
-                self.current_best_coordinates = Some(current_gen_best_coords);
-            }
-        } else {
+        let (current_gen_best, violation, current_gen_best_coords) = self.get_current_gen_best();
+        let improved = self.current_best.is_none_or(|current_best| {
+            self.constraint_handler.is_new_best(
+                evaluation(&current_gen_best, violation),
+                evaluation(&current_best, self.current_best_violation),
+            )
+        });
+        if improved {
             self.current_best = Some(current_gen_best);
+            self.current_best_violation = violation;
             self.current_best_coordinates = Some(current_gen_best_coords);
         }
     }
 
-    fn move_particle(&mut self, particle: &Particle<T>) -> Particle<T> {
+    fn move_particle(&mut self, particle: &Particle<T>, max_cf: i32) -> Particle<T> {
         let mut particle = particle.clone();
+        let inertia_weight = T::Item::from_f64(f64::from(self.inertia_weight));
+        let personal_priority = T::Item::from_f64(f64::from(self.personal_priority));
         let weighted_velocity = particle
             .velocity
             .clone()
             .into_iter()
-            .map(|a| a * self.inertia_weight);
+            .map(|a| a * inertia_weight);
         let personal_velocity = particle.coordinates_history[particle.best_coords]
             .clone()
             .into_iter()
             .zip(particle.coordinates_history[particle.current_coordinates].iter())
-            .map(|(a, b)| a - b)
+            .map(|(a, &b)| a - b)
             .zip(
                 (0..self.problem.get_dimensions())
                     .into_iter()
-                    .map(|_| random::<f32>()),
+                    .map(|_| T::Item::from_f64(random::<f64>())),
             )
             .map(|(a, b)| a * b)
-            .map(|a| a * self.personal_priority);
+            .map(|a| a * personal_priority);
 
         let social_velocity = self
             .current_best_coordinates
@@ -198,51 +442,53 @@ where
             .unwrap()
             .into_iter()
             .zip(particle.coordinates_history[particle.current_coordinates].iter())
-            .map(|(a, b)| a - b)
+            .map(|(&a, &b)| a - b)
             .zip(
                 (0..self.problem.get_dimensions())
                     .into_iter()
-                    .map(|_| random::<f32>()),
+                    .map(|_| T::Item::from_f64(random::<f64>())),
             )
             .map(|(a, b)| a * b)
-            .map(|a| a * self.personal_priority);
-        let new_velocity: Vec<f32> = weighted_velocity
+            .map(|a| a * personal_priority);
+        let new_velocity: Vec<T::Item> = weighted_velocity
             .zip(social_velocity)
             .map(|(a, b)| a + b)
             .zip(personal_velocity)
             .map(|(a, b)| a + b)
             .collect();
-        let new_coords: Vec<T::Item> = self.reflect(particle.coordinates_history[particle.current_coordinates]
-            .iter()
-            .zip(new_velocity.iter())
-            .map(|(a, b)| a + b)
-            .collect());
-        let new_cost = self.run_cost_fn(&new_coords);
-        particle.update_particle(new_coords, new_velocity, new_cost);
+        let new_coords: Vec<T::Item> = reflect(
+            &self.problem,
+            particle.coordinates_history[particle.current_coordinates]
+                .iter()
+                .zip(new_velocity.iter())
+                .map(|(&a, &b)| a + b)
+                .collect(),
+        );
+        let new_cost = self.run_cost_fn(&new_coords, max_cf);
+        let violation = total_violation(&self.problem, &new_coords);
+        particle.update_particle(
+            new_coords,
+            new_velocity,
+            new_cost,
+            violation,
+            &self.constraint_handler,
+        );
         particle
     }
-
-    fn reflect(&self, vec: Vec<f32>) -> Vec<f32> {
-        let min = self.problem.get_minimum();
-        let max = self.problem.get_maximum();
-        vec.iter()
-            .map(|&x| match (x < min, x > max) {
-                (true, _) => min + (min - x),
-                (_, true) => max + (x - max),
-                (false, false) => x,
-            })
-            .collect::<Vec<f32>>()
-    }
 }
 
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use crate::benchmarks::noisy::{Noise, Noisy};
+    use crate::benchmarks::{dynamic::Dynamic, fst_dejong::FstDeJong, traits::HasBuilder};
+    use crate::problem_definitions::FnProblem;
     use mockall::predicate::*;
     use mockall::*;
     use rand::distributions::Uniform;
     use rand_distr::Distribution;
+    use std::sync::atomic::{AtomicI32, Ordering};
 
     mock! {
         Problem {}
@@ -284,4 +530,64 @@ mod test {
         assert!(pso.current_best_coordinates.is_some());
         assert_eq!(pso.cost_function_evaluations, expected_calls as i32);
     }
+
+    #[test]
+    fn noisy_reevaluation() {
+        let calls = AtomicI32::new(0);
+        // sphere with uniform noise in [-0.5, 0.5]
+        let problem = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            x.iter().map(|v| v * v).sum::<f64>() + rand::random::<f64>() - 0.5
+        });
+        let mut pso = Pso::new(4003, 10, 0.7, 1.5, 1.5, problem)
+            .set_resampling(3)
+            .set_reevaluation(true);
+        pso.run();
+        assert_eq!(pso.get_cost_function_evaluations(), 4003);
+        assert_eq!(calls.load(Ordering::Relaxed), 4003);
+        assert!(pso.get_best().unwrap() > -0.5);
+        assert!(pso.get_detected_changes().is_empty());
+    }
+
+    #[test]
+    fn change_detection() {
+        let sphere = FstDeJong::builder().dimensions(3).build().unwrap();
+        let problem = Dynamic::new(sphere).set_period(2000);
+        let mut pso = Pso::new(8000, 20, 0.7, 1.5, 1.5, problem).set_change_detection(1e-6);
+        pso.run();
+        let changes = pso.get_detected_changes();
+        assert_eq!(changes.len(), 3);
+        assert!(changes
+            .iter()
+            .zip([2000, 4000, 6000])
+            .all(|(detected, changed)| *detected > changed && *detected < changed + 50));
+        // the optimum moved by 1, a best left at the old one would cost 1
+        assert!(pso.get_best().unwrap() < 0.5);
+    }
+    #[test]
+    fn spent_budget() {
+        let calls = AtomicI32::new(0);
+        let problem = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            x.iter().map(|v| v * v).sum()
+        });
+        // the swarm is larger than the budget, the particles past it are not evaluated
+        let mut pso = Pso::new(6, 10, 0.7, 1.5, 1.5, problem);
+        pso.run();
+        assert_eq!(calls.load(Ordering::Relaxed), 6);
+        assert_eq!(pso.get_cost_function_evaluations(), 6);
+        assert!(pso.get_best().unwrap().is_finite());
+    }
+
+    #[test]
+    fn noise_is_no_change() {
+        let sphere = FstDeJong::builder().dimensions(3).build().unwrap();
+        let problem = Noisy::new(sphere, Noise::Gaussian(0.01));
+        // the mean of 16 samples moves by far less than the tolerance
+        let mut pso = Pso::new(8000, 20, 0.7, 1.5, 1.5, problem)
+            .set_resampling(16)
+            .set_change_detection(0.05);
+        pso.run();
+        assert!(pso.get_detected_changes().is_empty());
+    }
 }
@@ -0,0 +1,378 @@
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use crate::constraints::total_violation;
+use crate::evol_arg::variation::Variation;
+use crate::pareto::{sort_solutions, ParetoArchive, Solution};
+use crate::problem_definitions::{HasObjectives, HasRandom, Real};
+
+// SPEA2 (Zitzler, Laumanns and Thiele 2001), the members are the environmental archive filled
+// with the non-dominated solutions of the archive and the offspring, by fitness when there are
+// too few and truncated by the distances to the nearest neighbours when there are too many, the
+// mating pool is drawn from it by binary tournaments, constraints are handled by constrained
+// dominance
+// source: https://doi.org/10.3929/ethz-a-004284029
+pub struct Spea2<T>
+where
+    T: HasObjectives + HasRandom,
+    T::Item: Real,
+{
+    // SPEA2 parameters
+    max_cf: i32,
+    population_size: usize,
+    members_size: usize,
+    variation: Variation,
+
+    // results
+    archive: ParetoArchive<T::Item>,
+    generations_history: Vec<Vec<Solution<T::Item>>>,
+    fronts_history: Vec<Vec<Solution<T::Item>>>,
+    fitness: Vec<f64>,
+    current_generation: usize,
+    cost_function_evaluations: i32,
+
+    problem: T,
+}
+
+impl<T> Spea2<T>
+where
+    T: HasObjectives + HasRandom,
+    T::Item: Real,
+{
+    pub fn new(max_cf: i32, population_size: usize, problem: T) -> Self {
+        Self {
+            max_cf,
+            population_size,
+            members_size: population_size,
+            variation: Variation::SimulatedBinary {
+                crossover_probability: 0.9,
+                crossover_index: 20f64,
+                mutation_index: 20f64,
+            },
+            archive: ParetoArchive::new(population_size),
+            generations_history: Vec::new(),
+            fronts_history: Vec::new(),
+            fitness: Vec::new(),
+            current_generation: 0usize,
+            cost_function_evaluations: 0,
+            problem,
+        }
+    }
+
+    // size of the environmental archive, by default it is as large as the population
+    pub fn set_members_size(mut self, members_size: usize) -> Self {
+        self.members_size = members_size.max(1);
+        self
+    }
+
+    pub fn set_variation(mut self, variation: Variation) -> Self {
+        self.variation = variation;
+        self
+    }
+
+    // the archive collects every non-dominated point met during the run, by default it is as
+    // large as the population
+    pub fn set_archive_capacity(mut self, capacity: usize) -> Self {
+        self.archive = ParetoArchive::new(capacity);
+        self
+    }
+
+    pub fn run(&mut self) {
+        self.initialise();
+        while self.cost_function_evaluations < self.max_cf && !self.problem.is_exhausted() {
+            self.step();
+        }
+    }
+
+    pub fn initialise(&mut self) {
+        let population = (0..self.population_size)
+            .map(|_| {
+                let coords = self.problem.get_random();
+                self.evaluate(coords)
+            })
+            .collect();
+        let members = self.select(population);
+        self.add_new_generation(members);
+    }
+
+    // evolves a single generation, used when the run is driven from outside
+    pub fn step(&mut self) {
+        let members = self.get_members().to_vec();
+        let remaining = (self.max_cf - self.cost_function_evaluations).max(0) as usize;
+        let mating_pool = (0..self.population_size)
+            .map(|_| self.tournament())
+            .collect::<Vec<_>>();
+        let offspring = (0..self.population_size.min(remaining))
+            .map_while(|i| {
+                // a shared budget may run out within the generation
+                if self.problem.is_exhausted() {
+                    return None;
+                }
+                let pool = mating_pool
+                    .iter()
+                    .filter(|&&parent| parent != mating_pool[i])
+                    .map(|&parent| members[parent].get_coordinates())
+                    .collect::<Vec<_>>();
+                let coords = self.variation.offspring(
+                    &self.problem,
+                    members[mating_pool[i]].get_coordinates(),
+                    &pool,
+                );
+                Some(self.evaluate(coords))
+            })
+            .collect::<Vec<_>>();
+        let members = self.select(members.into_iter().chain(offspring).collect());
+        self.add_new_generation(members);
+    }
+
+    // the fitter of two random members
+    fn tournament(&self) -> usize {
+        let mut rng = rand::thread_rng();
+        let a = rng.gen_range(0..self.fitness.len());
+        let b = rng.gen_range(0..self.fitness.len());
+        match self.fitness[b] < self.fitness[a] {
+            true => b,
+            false => a,
+        }
+    }
+
+    // environmental selection, keeps the fitness of the chosen solutions for the tournaments
+    fn select(&mut self, merged: Vec<Solution<T::Item>>) -> Vec<Solution<T::Item>> {
+        let fitness = strength_fitness(&merged);
+        let mut order = (0..merged.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| fitness[a].total_cmp(&fitness[b]));
+        let non_dominated = order
+            .iter()
+            .copied()
+            .filter(|&i| fitness[i] < 1f64)
+            .collect::<Vec<_>>();
+        let chosen = match non_dominated.len() > self.members_size {
+            true => truncate(&merged, non_dominated, self.members_size),
+            false => {
+                order.truncate(self.members_size);
+                order
+            }
+        };
+        self.fitness = chosen.iter().map(|&i| fitness[i]).collect();
+        chosen.into_iter().map(|i| merged[i].clone()).collect()
+    }
+
+    fn evaluate(&mut self, coordinates: Vec<T::Item>) -> Solution<T::Item> {
+        self.cost_function_evaluations += 1;
+        let costs = self.problem.cost_vector(&coordinates);
+        let violation = total_violation(&self.problem, &coordinates);
+        let solution = Solution::new(coordinates, costs, violation);
+        self.archive.insert(solution.clone());
+        solution
+    }
+
+    fn add_new_generation(&mut self, new_generation: Vec<Solution<T::Item>>) {
+        if !self.generations_history.is_empty() {
+            self.current_generation += 1;
+        }
+        let front = sort_solutions(&new_generation)
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| new_generation[i].clone())
+            .collect();
+        self.fronts_history.push(front);
+        self.generations_history.push(new_generation);
+    }
+
+    // the environmental archive of the current generation
+    pub fn get_members(&self) -> &[Solution<T::Item>] {
+        &self.generations_history[self.current_generation]
+    }
+
+    // SPEA2 fitness of the members, below 1 for the non-dominated ones
+    pub fn get_fitness(&self) -> &[f64] {
+        &self.fitness
+    }
+
+    // non-dominated members of the final environmental archive
+    pub fn get_front(&self) -> &[Solution<T::Item>] {
+        &self.fronts_history[self.current_generation]
+    }
+
+    // first front of every generation, the initial one included
+    pub fn get_fronts_history(&self) -> &[Vec<Solution<T::Item>>] {
+        &self.fronts_history
+    }
+
+    pub fn get_archive(&self) -> &ParetoArchive<T::Item> {
+        &self.archive
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+fn distance<F: Real>(a: &Solution<F>, b: &Solution<F>) -> f64 {
+    a.get_costs()
+        .iter()
+        .zip(b.get_costs().iter())
+        .map(|(x, y)| (*x - *y).to_f64().unwrap().powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+// raw fitness, the summed strengths (number of dominated solutions) of the dominators, plus the
+// density 1 / (distance to the k-th nearest neighbour + 2) with k = sqrt(count)
+fn strength_fitness<F: Real>(solutions: &[Solution<F>]) -> Vec<f64> {
+    let count = solutions.len();
+    let dominance = (0..count)
+        .map(|a| {
+            (0..count)
+                .map(|b| solutions[a].dominates(&solutions[b]))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let strength = dominance
+        .iter()
+        .map(|row| row.iter().filter(|&&dominated| dominated).count())
+        .collect::<Vec<_>>();
+    let k = ((count as f64).sqrt() as usize).max(1);
+    (0..count)
+        .map(|i| {
+            let raw = (0..count)
+                .filter(|&j| dominance[j][i])
+                .map(|j| strength[j])
+                .sum::<usize>();
+            let mut distances = (0..count)
+                .filter(|&j| j != i)
+                .map(|j| distance(&solutions[i], &solutions[j]))
+                .collect::<Vec<_>>();
+            distances.sort_by(f64::total_cmp);
+            let kth = distances.get(k - 1).copied().unwrap_or(0f64);
+            raw as f64 + 1f64 / (kth + 2f64)
+        })
+        .collect()
+}
+
+// drops the solution closest to its neighbours until size remain, ties in the nearest distance
+// are broken by the second nearest and so on
+fn truncate<F: Real>(solutions: &[Solution<F>], mut kept: Vec<usize>, size: usize) -> Vec<usize> {
+    let mut neighbours = kept
+        .iter()
+        .map(|&i| {
+            let mut row = kept
+                .iter()
+                .filter(|&&j| j != i)
+                .map(|&j| (distance(&solutions[i], &solutions[j]), j))
+                .collect::<Vec<_>>();
+            row.sort_by(|a, b| a.0.total_cmp(&b.0));
+            row
+        })
+        .collect::<Vec<_>>();
+    while kept.len() > size {
+        let closest = (0..kept.len())
+            .min_by(|&a, &b| {
+                neighbours[a]
+                    .iter()
+                    .zip(neighbours[b].iter())
+                    .map(|(x, y)| x.0.total_cmp(&y.0))
+                    .find(|ordering| *ordering != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        let removed = kept.remove(closest);
+        neighbours.remove(closest);
+        neighbours
+            .iter_mut()
+            .for_each(|row| row.retain(|(_, j)| *j != removed));
+    }
+    kept
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pareto::dominates;
+    use crate::problem_definitions::FnProblem;
+
+    // Schaffer's problem, the pareto set is x in [0, 2]
+    fn schaffer() -> FnProblem<impl Fn(&[f64]) -> f64, f64> {
+        FnProblem::new(-10f64, 10f64, 1, |x: &[f64]| x[0].powi(2))
+            .set_objectives(2, |x: &[f64]| vec![x[0].powi(2), (x[0] - 2f64).powi(2)])
+    }
+
+    fn solutions(costs: &[[f64; 2]]) -> Vec<Solution<f64>> {
+        costs
+            .iter()
+            .map(|cost| Solution::new(vec![], cost.to_vec(), 0f64))
+            .collect()
+    }
+
+    #[test]
+    fn fitness_and_truncation() {
+        let solutions = solutions(&[
+            [0f64, 4f64],
+            [1f64, 1f64],
+            [4f64, 0f64],
+            [2f64, 2f64],
+            [3f64, 3f64],
+        ]);
+        let fitness = strength_fitness(&solutions);
+        assert!(fitness[..3].iter().all(|f| *f < 1f64));
+        // (2, 2) is dominated by (1, 1) which dominates two solutions
+        assert!((2f64..3f64).contains(&fitness[3]));
+        // (3, 3) is dominated by (1, 1) and (2, 2) with the strengths 2 and 1
+        assert!((3f64..4f64).contains(&fitness[4]));
+
+        let line = self::solutions(&[[0f64, 4f64], [1f64, 3f64], [1.2, 2.8], [4f64, 0f64]]);
+        let mut kept = truncate(&line, vec![0, 1, 2, 3], 3);
+        kept.sort();
+        assert_eq!(kept.len(), 3);
+        assert!(kept.contains(&0) && kept.contains(&3));
+        assert_eq!(truncate(&line, vec![0, 1, 2, 3], 2), vec![0, 3]);
+    }
+
+    #[test]
+    fn expected_cost_calls() {
+        let mut spea2 = Spea2::new(1010, 20, schaffer()).set_members_size(10);
+        spea2.run();
+        assert_eq!(spea2.get_cost_function_evaluations(), 1010);
+        assert_eq!(spea2.get_fronts_history().len(), 51);
+        assert_eq!(spea2.get_members().len(), 10);
+        assert_eq!(spea2.get_fitness().len(), 10);
+    }
+
+    #[test]
+    fn schaffer_front() {
+        let mut spea2 =
+            Spea2::new(4000, 40, schaffer()).set_variation(Variation::DifferentialEvolution {
+                scaling_factor: 0.5,
+                crossover_probability: 0.9,
+            });
+        spea2.run();
+        let front = spea2.get_front();
+        assert_eq!(front.len(), 40);
+        assert!(spea2.get_fitness().iter().all(|f| *f < 1f64));
+        assert!(front
+            .iter()
+            .all(|s| (-0.05..=2.05).contains(&s.get_coordinates()[0])));
+        for a in front {
+            assert!(front
+                .iter()
+                .all(|b| !dominates(b.get_costs(), a.get_costs())));
+        }
+    }
+
+    #[test]
+    fn constrained() {
+        // x >= 1 removes the left part of the pareto set
+        let problem = schaffer().set_inequality_constraints(|x: &[f64]| vec![1f64 - x[0]]);
+        let mut spea2 = Spea2::new(3000, 30, problem);
+        spea2.run();
+        assert!(spea2.get_front().iter().all(|s| s.is_feasible()));
+        assert!(spea2
+            .get_archive()
+            .get_solutions()
+            .iter()
+            .all(|s| (1f64..=2.05).contains(&s.get_coordinates()[0])));
+    }
+}
@@ -0,0 +1,207 @@
+use rand::{seq::SliceRandom, Rng};
+use rand_distr::num_traits::ToPrimitive;
+
+use crate::evol_arg::de::{binomial_trial, reflect};
+use crate::problem_definitions::{ProblemDomain, Real};
+
+// variation operators of the multi-objective solvers, a child is built from a target and parents
+// drawn from a mating pool
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Variation {
+    // rand/1/bin of De
+    DifferentialEvolution {
+        scaling_factor: f32,
+        crossover_probability: f32,
+    },
+    // simulated binary crossover of the target with one parent followed by polynomial mutation
+    // of every coordinate with the probability 1/n (Deb and Agrawal 1995), larger distribution
+    // indices keep the child closer to its parents
+    SimulatedBinary {
+        crossover_probability: f32,
+        crossover_index: f64,
+        mutation_index: f64,
+    },
+}
+
+impl Variation {
+    // the pool should not contain the target, DE takes three parents from it and SBX one, a
+    // smaller pool is sampled with replacement
+    pub(crate) fn offspring<T>(
+        &self,
+        problem: &T,
+        target: &[T::Item],
+        pool: &[&[T::Item]],
+    ) -> Vec<T::Item>
+    where
+        T: ProblemDomain,
+        T::Item: Real,
+    {
+        let mut rng = rand::thread_rng();
+        let parents: Vec<&[T::Item]> = match (self, pool.len()) {
+            (Self::DifferentialEvolution { .. }, 3..) => {
+                pool.choose_multiple(&mut rng, 3).copied().collect()
+            }
+            _ => (0..3)
+                .map(|_| pool.choose(&mut rng).copied().unwrap_or(target))
+                .collect(),
+        };
+        match *self {
+            Self::DifferentialEvolution {
+                scaling_factor,
+                crossover_probability,
+            } => reflect(
+                problem,
+                binomial_trial(
+                    parents[2],
+                    parents[0],
+                    parents[1],
+                    target,
+                    T::Item::from_f64(f64::from(scaling_factor)),
+                    crossover_probability,
+                ),
+            ),
+            Self::SimulatedBinary {
+                crossover_probability,
+                crossover_index,
+                mutation_index,
+            } => {
+                let child = if rng.gen::<f32>() < crossover_probability {
+                    simulated_binary_crossover(target, parents[0], crossover_index, &mut rng)
+                } else {
+                    target.to_vec()
+                };
+                reflect(
+                    problem,
+                    polynomial_mutation(problem, child, mutation_index, &mut rng),
+                )
+            }
+        }
+    }
+}
+
+// every coordinate is taken from the target or, with the probability 1/2, from one of the two
+// SBX children whose spread around the parents follows the distribution index
+fn simulated_binary_crossover<F: Real, R: Rng>(
+    target: &[F],
+    parent: &[F],
+    index: f64,
+    rng: &mut R,
+) -> Vec<F> {
+    target
+        .iter()
+        .zip(parent.iter())
+        .map(|(&x, &y)| {
+            if rng.gen::<bool>() {
+                return x;
+            }
+            let u = rng.gen::<f64>();
+            let beta = match u <= 0.5 {
+                true => (2f64 * u).powf(1f64 / (index + 1f64)),
+                false => (0.5 / (1f64 - u)).powf(1f64 / (index + 1f64)),
+            };
+            let (x, y) = (x.to_f64().unwrap(), y.to_f64().unwrap());
+            let (near, far) = match rng.gen::<bool>() {
+                true => (x, y),
+                false => (y, x),
+            };
+            F::from_f64(0.5 * ((1f64 + beta) * near + (1f64 - beta) * far))
+        })
+        .collect()
+}
+
+// shifts a coordinate by at most the width of the domain, small shifts are the likely ones
+fn polynomial_mutation<T, R>(
+    problem: &T,
+    child: Vec<T::Item>,
+    index: f64,
+    rng: &mut R,
+) -> Vec<T::Item>
+where
+    T: ProblemDomain,
+    T::Item: Real,
+    R: Rng,
+{
+    let probability = 1f64 / child.len() as f64;
+    let width = (problem.get_maximum() - problem.get_minimum())
+        .to_f64()
+        .unwrap();
+    child
+        .into_iter()
+        .map(|x| {
+            if rng.gen::<f64>() >= probability {
+                return x;
+            }
+            let u = rng.gen::<f64>();
+            let delta = match u < 0.5 {
+                true => (2f64 * u).powf(1f64 / (index + 1f64)) - 1f64,
+                false => 1f64 - (2f64 * (1f64 - u)).powf(1f64 / (index + 1f64)),
+            };
+            x + T::Item::from_f64(delta * width)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::problem_definitions::{FnProblem, HasRandom};
+
+    fn problem() -> FnProblem<impl Fn(&[f64]) -> f64, f64> {
+        FnProblem::new(-1f64, 1f64, 10, |x: &[f64]| x.iter().sum())
+    }
+
+    #[test]
+    fn offspring_in_bounds() {
+        let problem = problem();
+        let parents = (0..4).map(|_| problem.get_random()).collect::<Vec<_>>();
+        let pool = parents[1..]
+            .iter()
+            .map(|p| p.as_slice())
+            .collect::<Vec<_>>();
+        let variations = [
+            Variation::DifferentialEvolution {
+                scaling_factor: 0.9,
+                crossover_probability: 1f32,
+            },
+            Variation::SimulatedBinary {
+                crossover_probability: 1f32,
+                crossover_index: 1f64,
+                mutation_index: 1f64,
+            },
+        ];
+        for variation in variations {
+            for _ in 0..200 {
+                let child = variation.offspring(&problem, &parents[0], &pool);
+                assert_eq!(child.len(), 10);
+                assert!(child.iter().all(|x| (-1f64..=1f64).contains(x)));
+            }
+            // a lone target is recombined with itself
+            let child = variation.offspring(&problem, &parents[0], &[]);
+            assert_eq!(child.len(), 10);
+        }
+    }
+
+    #[test]
+    fn sbx_spread() {
+        let mut rng = rand::thread_rng();
+        let target = vec![0.2f64; 1000];
+        let parent = vec![0.4f64; 1000];
+        // a large index keeps every coordinate next to one of the parents
+        let child = simulated_binary_crossover(&target, &parent, 1000f64, &mut rng);
+        assert!(child
+            .iter()
+            .all(|x| (x - 0.2).abs() < 0.01 || (x - 0.4).abs() < 0.01));
+        assert!(child.iter().any(|x| (x - 0.4).abs() < 0.01));
+        let mean = child.iter().sum::<f64>() / 1000f64;
+        // half the coordinates are kept and the other half lands next to either parent
+        assert!((0.23..0.27).contains(&mean));
+
+        // about one coordinate in n is shifted
+        let mutated = (0..100)
+            .flat_map(|_| polynomial_mutation(&problem(), vec![0.2; 10], 20f64, &mut rng))
+            .collect::<Vec<_>>();
+        let changed = mutated.iter().filter(|x| **x != 0.2).count();
+        assert!((40..=200).contains(&changed));
+        assert!(mutated.iter().all(|x| (x - 0.2).abs() <= 2f64));
+    }
+}
@@ -0,0 +1,316 @@
+use std::cmp::Ordering;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// quality indicators of an approximation front, all objectives are minimised and the fronts are
+// cost vectors, e.g. from pareto::front_costs, the reference fronts come from
+// HasReferenceFront
+
+// samples of the Monte Carlo hypervolume beyond three objectives
+const HYPERVOLUME_SAMPLES: usize = 100_000;
+const HYPERVOLUME_SEED: u64 = 0;
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+// distance that only counts the objectives in which the point is worse than the reference one
+fn dominance_distance(point: &[f64], reference: &[f64]) -> f64 {
+    point
+        .iter()
+        .zip(reference.iter())
+        .map(|(x, y)| (x - y).max(0f64).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn mean_closest(from: &[Vec<f64>], to: &[Vec<f64>], metric: fn(&[f64], &[f64]) -> f64) -> f64 {
+    if from.is_empty() || to.is_empty() {
+        return f64::INFINITY;
+    }
+    from.iter()
+        .map(|a| {
+            to.iter()
+                .map(|b| metric(b, a))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .sum::<f64>()
+        / from.len() as f64
+}
+
+// mean distance of the front points to the closest reference point, measures convergence
+pub fn generational_distance(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    mean_closest(front, reference_front, distance)
+}
+
+// mean distance of the reference points to the closest front point, measures convergence and
+// coverage at once
+pub fn inverted_generational_distance(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    mean_closest(reference_front, front, distance)
+}
+
+// IGD+ of Ishibuchi et al. (2015), weakly pareto compliant since only the objectives in which
+// a front point is worse than the reference point count
+pub fn inverted_generational_distance_plus(
+    front: &[Vec<f64>],
+    reference_front: &[Vec<f64>],
+) -> f64 {
+    mean_closest(reference_front, front, dominance_distance)
+}
+
+// additive epsilon indicator, the smallest shift that makes the front weakly dominate every
+// reference point
+pub fn additive_epsilon(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    epsilon(front, reference_front, |a, r| a - r)
+}
+
+// multiplicative epsilon indicator, meant for positive objectives
+pub fn multiplicative_epsilon(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    epsilon(front, reference_front, |a, r| a / r)
+}
+
+fn epsilon(front: &[Vec<f64>], reference_front: &[Vec<f64>], gap: fn(f64, f64) -> f64) -> f64 {
+    reference_front
+        .iter()
+        .map(|r| {
+            front
+                .iter()
+                .map(|a| {
+                    a.iter()
+                        .zip(r.iter())
+                        .map(|(a, r)| gap(*a, *r))
+                        .fold(f64::NEG_INFINITY, f64::max)
+                })
+                .fold(f64::INFINITY, f64::min)
+        })
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+// generalised spread of Zhou et al. (2006), the distances of the extremes of the reference front
+// to the front plus the deviation of the nearest neighbour distances, 0 for an evenly spaced
+// front that reaches all the extremes
+pub fn spread(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    if front.len() < 2 || reference_front.is_empty() {
+        return 1f64;
+    }
+    let objectives = reference_front[0].len();
+    let extremes = (0..objectives)
+        .filter_map(|m| {
+            reference_front
+                .iter()
+                .max_by(|a, b| a[m].partial_cmp(&b[m]).unwrap_or(Ordering::Equal))
+        })
+        .map(|extreme| {
+            front
+                .iter()
+                .map(|point| distance(point, extreme))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .sum::<f64>();
+    let neighbours = front
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            front
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, b)| distance(a, b))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .collect::<Vec<_>>();
+    let mean = neighbours.iter().sum::<f64>() / neighbours.len() as f64;
+    let deviation = neighbours.iter().map(|d| (d - mean).abs()).sum::<f64>();
+    let denominator = extremes + front.len() as f64 * mean;
+    if denominator == 0f64 {
+        return 0f64;
+    }
+    (extremes + deviation) / denominator
+}
+
+// volume dominated by the front and bounded by the reference point, exact for up to three
+// objectives and a Monte Carlo estimate beyond
+pub fn hypervolume(front: &[Vec<f64>], reference_point: &[f64]) -> f64 {
+    let points = front
+        .iter()
+        .filter(|point| point.iter().zip(reference_point).all(|(x, r)| x < r))
+        .cloned()
+        .collect::<Vec<_>>();
+    if points.is_empty() {
+        return 0f64;
+    }
+    match reference_point.len() {
+        1 => reference_point[0] - points.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min),
+        2 => hypervolume_2d(points, reference_point),
+        3 => hypervolume_3d(points, reference_point),
+        _ => estimate_hypervolume(&points, reference_point, HYPERVOLUME_SAMPLES),
+    }
+}
+
+fn by_objective(m: usize) -> impl Fn(&Vec<f64>, &Vec<f64>) -> Ordering {
+    move |a, b| a[m].partial_cmp(&b[m]).unwrap_or(Ordering::Equal)
+}
+
+// sweep over the first objective, every point adds the slab below the best second objective
+fn hypervolume_2d(mut points: Vec<Vec<f64>>, reference_point: &[f64]) -> f64 {
+    points.sort_by(by_objective(0));
+    let mut volume = 0f64;
+    let mut bound = reference_point[1];
+    for point in points {
+        if point[1] < bound {
+            volume += (reference_point[0] - point[0]) * (bound - point[1]);
+            bound = point[1];
+        }
+    }
+    volume
+}
+
+// slices along the third objective, each slice is the 2D hypervolume of the points below it
+fn hypervolume_3d(mut points: Vec<Vec<f64>>, reference_point: &[f64]) -> f64 {
+    points.sort_by(by_objective(2));
+    let mut volume = 0f64;
+    for i in 0..points.len() {
+        let top = points.get(i + 1).map_or(reference_point[2], |p| p[2]);
+        if top > points[i][2] {
+            volume += hypervolume_2d(points[..=i].to_vec(), reference_point) * (top - points[i][2]);
+        }
+    }
+    volume
+}
+
+// share of uniform samples of the box between the ideal and the reference point that the front
+// dominates, seeded so that repeated evaluations agree
+pub fn estimate_hypervolume(front: &[Vec<f64>], reference_point: &[f64], samples: usize) -> f64 {
+    if front.is_empty() {
+        return 0f64;
+    }
+    let ideal = (0..reference_point.len())
+        .map(|m| {
+            front
+                .iter()
+                .map(|p| p[m])
+                .fold(f64::INFINITY, f64::min)
+                .min(reference_point[m])
+        })
+        .collect::<Vec<_>>();
+    let volume = ideal
+        .iter()
+        .zip(reference_point)
+        .map(|(low, high)| high - low)
+        .product::<f64>();
+    if volume == 0f64 {
+        return 0f64;
+    }
+    let mut rng = StdRng::seed_from_u64(HYPERVOLUME_SEED);
+    let mut sample = vec![0f64; reference_point.len()];
+    let dominated = (0..samples)
+        .filter(|_| {
+            sample
+                .iter_mut()
+                .zip(ideal.iter().zip(reference_point))
+                .for_each(|(x, (low, high))| *x = rng.gen_range(*low..*high));
+            front
+                .iter()
+                .any(|p| p.iter().zip(&sample).all(|(a, x)| a <= x))
+        })
+        .count();
+    volume * dominated as f64 / samples as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn linear(points: usize) -> Vec<Vec<f64>> {
+        (0..points)
+            .map(|i| {
+                let f1 = i as f64 / (points - 1) as f64;
+                vec![f1, 1f64 - f1]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hypervolumes() {
+        let front = vec![
+            vec![1f64, 3f64],
+            vec![2f64, 2f64],
+            vec![3f64, 1f64],
+            vec![3f64, 3f64],
+        ];
+        assert_eq!(hypervolume(&front, &[4f64, 4f64]), 6f64);
+        assert_eq!(hypervolume(&front, &[0f64, 0f64]), 0f64);
+
+        let cube = vec![vec![0f64, 0f64, 0f64]];
+        assert_eq!(hypervolume(&cube, &[2f64, 3f64, 4f64]), 24f64);
+        let steps = vec![
+            vec![0f64, 1f64, 1f64],
+            vec![1f64, 0f64, 1f64],
+            vec![1f64, 1f64, 0f64],
+        ];
+        // three 2 x 1 x 1 boxes sharing the unit cube at (1, 1, 1)
+        assert_eq!(hypervolume(&steps, &[2f64, 2f64, 2f64]), 4f64);
+
+        let four = vec![vec![0.5; 4]];
+        let estimate = hypervolume(&four, &[1f64; 4]);
+        assert!((estimate - 0.0625).abs() < 1e-12);
+        let four = vec![vec![0f64, 0.5, 0.5, 0.5], vec![0.5, 0f64, 0.5, 0.5]];
+        let estimate = estimate_hypervolume(&four, &[1f64; 4], 200_000);
+        assert!((estimate - 0.1875).abs() < 0.005);
+    }
+
+    #[test]
+    fn distances() {
+        let reference = linear(101);
+        let front = linear(11);
+        assert!(generational_distance(&front, &reference) < 1e-12);
+        assert!(inverted_generational_distance(&front, &reference) > 0f64);
+        let shifted = front
+            .iter()
+            .map(|p| p.iter().map(|f| f + 0.1).collect())
+            .collect::<Vec<_>>();
+        assert!((generational_distance(&shifted, &reference) - 0.1 * 2f64.sqrt()).abs() < 1e-9);
+        // IGD+ ignores the objectives in which the front is better
+        let better = front
+            .iter()
+            .map(|p| p.iter().map(|f| f - 0.1).collect())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            inverted_generational_distance_plus(&better, &reference),
+            0f64
+        );
+        assert!(inverted_generational_distance(&better, &reference) > 0.07);
+        assert!(inverted_generational_distance(&[], &reference).is_infinite());
+    }
+
+    #[test]
+    fn epsilons() {
+        let reference = linear(11);
+        assert!(additive_epsilon(&reference, &reference).abs() < 1e-12);
+        let shifted = reference
+            .iter()
+            .map(|p| p.iter().map(|f| f + 0.25).collect())
+            .collect::<Vec<_>>();
+        assert!((additive_epsilon(&shifted, &reference) - 0.25).abs() < 1e-12);
+        let scaled = vec![vec![2f64, 4f64]];
+        assert_eq!(multiplicative_epsilon(&scaled, &[vec![1f64, 1f64]]), 4f64);
+    }
+
+    #[test]
+    fn spreads() {
+        let reference = linear(101);
+        assert!(spread(&linear(11), &reference) < 1e-9);
+        let clustered = vec![
+            vec![0.4, 0.6],
+            vec![0.45, 0.55],
+            vec![0.5, 0.5],
+            vec![0.6, 0.4],
+        ];
+        assert!(spread(&clustered, &reference) > 0.5);
+        assert_eq!(spread(&linear(11)[..1], &reference), 1f64);
+    }
+}
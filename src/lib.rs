@@ -1,5 +1,11 @@
 pub mod benchmarks;
+pub mod budget;
+pub mod combinatorial;
+pub mod constraints;
 pub mod evol_arg;
+pub mod indicators;
+pub mod pareto;
 pub mod problem_definitions;
 pub mod solvers;
 pub mod tools;
+pub mod variables;
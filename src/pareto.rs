@@ -0,0 +1,339 @@
+use std::cmp::Ordering;
+
+use crate::problem_definitions::Real;
+
+// point of a multi-objective run, the violation is the total one from constraints::total_violation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution<F: Real> {
+    coordinates: Vec<F>,
+    costs: Vec<F>,
+    violation: f64,
+}
+
+impl<F: Real> Solution<F> {
+    pub fn new(coordinates: Vec<F>, costs: Vec<F>, violation: f64) -> Self {
+        Self {
+            coordinates,
+            costs,
+            violation,
+        }
+    }
+
+    pub fn get_coordinates(&self) -> &[F] {
+        &self.coordinates
+    }
+
+    pub fn get_costs(&self) -> &[F] {
+        &self.costs
+    }
+
+    pub fn get_violation(&self) -> f64 {
+        self.violation
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.violation == 0f64
+    }
+
+    // constrained dominance (Deb 2000): feasible beats infeasible, two infeasible solutions
+    // compare by their violation and two feasible ones by pareto dominance
+    pub fn dominates(&self, other: &Self) -> bool {
+        match (self.is_feasible(), other.is_feasible()) {
+            (true, true) => dominates(&self.costs, &other.costs),
+            (true, false) => true,
+            (false, true) => false,
+            (false, false) => self.violation < other.violation,
+        }
+    }
+}
+
+// a is no worse than b in every objective and strictly better in at least one
+pub fn dominates<F: Real>(a: &[F], b: &[F]) -> bool {
+    let mut strictly_better = false;
+    for (x, y) in a.iter().zip(b.iter()) {
+        if x > y {
+            return false;
+        }
+        if x < y {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+// fast non-dominated sort (Deb et al. 2002), indices of the points grouped into fronts, the
+// first front is the non-dominated one
+pub fn non_dominated_sort<F: Real>(costs: &[Vec<F>]) -> Vec<Vec<usize>> {
+    sort_into_fronts(costs.len(), |a, b| dominates(&costs[a], &costs[b]))
+}
+
+// the same sort under constrained dominance
+pub fn sort_solutions<F: Real>(solutions: &[Solution<F>]) -> Vec<Vec<usize>> {
+    sort_into_fronts(solutions.len(), |a, b| {
+        solutions[a].dominates(&solutions[b])
+    })
+}
+
+fn sort_into_fronts(count: usize, dominates: impl Fn(usize, usize) -> bool) -> Vec<Vec<usize>> {
+    let mut dominated_by = vec![0usize; count];
+    let mut dominating: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for a in 0..count {
+        for b in a + 1..count {
+            if dominates(a, b) {
+                dominating[a].push(b);
+                dominated_by[b] += 1;
+            } else if dominates(b, a) {
+                dominating[b].push(a);
+                dominated_by[a] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+    let mut front: Vec<usize> = (0..count).filter(|&i| dominated_by[i] == 0).collect();
+    while !front.is_empty() {
+        let mut next = Vec::new();
+        for &a in &front {
+            for &b in &dominating[a] {
+                dominated_by[b] -= 1;
+                if dominated_by[b] == 0 {
+                    next.push(b);
+                }
+            }
+        }
+        fronts.push(front);
+        front = next;
+    }
+    fronts
+}
+
+// crowding distance of the points of a single front, the extremes of every objective get
+// infinity so that the spread of the front is kept
+pub fn crowding_distance<F: Real>(costs: &[Vec<F>]) -> Vec<f64> {
+    let count = costs.len();
+    let mut distances = vec![0f64; count];
+    if count < 3 {
+        return vec![f64::INFINITY; count];
+    }
+    let mut order: Vec<usize> = (0..count).collect();
+    let columns = (0..costs[0].len()).map(|objective| {
+        costs
+            .iter()
+            .map(|cost| cost[objective].to_f64().unwrap())
+            .collect::<Vec<f64>>()
+    });
+    for column in columns {
+        let value = |i: usize| column[i];
+        order.sort_by(|&a, &b| value(a).partial_cmp(&value(b)).unwrap_or(Ordering::Equal));
+        let range = value(order[count - 1]) - value(order[0]);
+        distances[order[0]] = f64::INFINITY;
+        distances[order[count - 1]] = f64::INFINITY;
+        if range <= 0f64 {
+            continue;
+        }
+        for i in 1..count - 1 {
+            distances[order[i]] += (value(order[i + 1]) - value(order[i - 1])) / range;
+        }
+    }
+    distances
+}
+
+// cost vectors of the feasible solutions in f64, the form the indicators work with
+pub fn front_costs<F: Real>(solutions: &[Solution<F>]) -> Vec<Vec<f64>> {
+    solutions
+        .iter()
+        .filter(|solution| solution.is_feasible())
+        .map(|solution| {
+            solution
+                .costs
+                .iter()
+                .map(|cost| cost.to_f64().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+// points of the unit simplex whose coordinates are multiples of 1 / divisions (Das and Dennis
+// 1998), binomial(divisions + objectives - 1, objectives - 1) of them
+pub fn simplex_lattice(objectives: usize, divisions: usize) -> Vec<Vec<f64>> {
+    fn fill(point: &mut Vec<usize>, left: usize, slots: usize, points: &mut Vec<Vec<usize>>) {
+        if slots == 1 {
+            point.push(left);
+            points.push(point.clone());
+            point.pop();
+            return;
+        }
+        for value in 0..=left {
+            point.push(value);
+            fill(point, left - value, slots - 1, points);
+            point.pop();
+        }
+    }
+
+    if objectives == 0 {
+        return vec![];
+    }
+    let divisions = divisions.max(1);
+    let mut points = Vec::new();
+    fill(&mut Vec::new(), divisions, objectives, &mut points);
+    points
+        .into_iter()
+        .map(|point| {
+            point
+                .into_iter()
+                .map(|value| value as f64 / divisions as f64)
+                .collect()
+        })
+        .collect()
+}
+
+// mutually non-dominated solutions found so far, once the capacity is exceeded the most
+// crowded one is dropped
+#[derive(Debug, Clone)]
+pub struct ParetoArchive<F: Real> {
+    capacity: usize,
+    solutions: Vec<Solution<F>>,
+}
+
+impl<F: Real> ParetoArchive<F> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            solutions: Vec::new(),
+        }
+    }
+
+    // false when the solution is dominated by or duplicates a member, dominated members are
+    // removed otherwise
+    pub fn insert(&mut self, solution: Solution<F>) -> bool {
+        if self.solutions.iter().any(|member| {
+            member.dominates(&solution)
+                || (member.costs == solution.costs && member.violation == solution.violation)
+        }) {
+            return false;
+        }
+        self.solutions.retain(|member| !solution.dominates(member));
+        self.solutions.push(solution);
+        if self.solutions.len() > self.capacity {
+            self.prune();
+        }
+        true
+    }
+
+    fn prune(&mut self) {
+        let costs: Vec<Vec<F>> = self.solutions.iter().map(|s| s.costs.clone()).collect();
+        let distances = crowding_distance(&costs);
+        let most_crowded = distances
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(Ordering::Equal))
+            .map(|(index, _)| index)
+            .unwrap();
+        self.solutions.remove(most_crowded);
+    }
+
+    pub fn get_solutions(&self) -> &[Solution<F>] {
+        &self.solutions
+    }
+
+    pub fn get_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn costs() -> Vec<Vec<f64>> {
+        vec![
+            vec![1f64, 5f64],
+            vec![2f64, 3f64],
+            vec![4f64, 1f64],
+            vec![3f64, 4f64],
+            vec![5f64, 5f64],
+            vec![2f64, 3f64],
+        ]
+    }
+
+    #[test]
+    fn dominance() {
+        assert!(dominates(&[1f64, 2f64], &[1f64, 3f64]));
+        assert!(!dominates(&[1f64, 2f64], &[1f64, 2f64]));
+        assert!(!dominates(&[1f64, 4f64], &[2f64, 3f64]));
+
+        let feasible = Solution::new(vec![0f64], vec![9f64, 9f64], 0f64);
+        let slightly = Solution::new(vec![0f64], vec![0f64, 0f64], 0.1);
+        let badly = Solution::new(vec![0f64], vec![0f64, 0f64], 2f64);
+        assert!(feasible.dominates(&slightly));
+        assert!(slightly.dominates(&badly));
+        assert!(!badly.dominates(&feasible));
+    }
+
+    #[test]
+    fn fronts() {
+        let fronts = non_dominated_sort(&costs());
+        assert_eq!(fronts, vec![vec![0, 1, 2, 5], vec![3], vec![4]]);
+        assert!(non_dominated_sort::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn crowding() {
+        let front = vec![
+            vec![0f64, 4f64],
+            vec![1f64, 3f64],
+            vec![3f64, 1f64],
+            vec![4f64, 0f64],
+        ];
+        let distances = crowding_distance(&front);
+        assert!(distances[0].is_infinite() && distances[3].is_infinite());
+        assert!((distances[1] - 1.5).abs() < 1e-12);
+        assert!((distances[2] - 1.5).abs() < 1e-12);
+        assert!(crowding_distance(&front[..2])
+            .iter()
+            .all(|d| d.is_infinite()));
+    }
+
+    #[test]
+    fn lattice() {
+        let points = simplex_lattice(3, 4);
+        assert_eq!(points.len(), 15);
+        assert!(points
+            .iter()
+            .all(|p| (p.iter().sum::<f64>() - 1f64).abs() < 1e-12));
+        assert!(points.contains(&vec![0.25, 0.25, 0.5]));
+        assert_eq!(
+            simplex_lattice(2, 1),
+            vec![vec![0f64, 1f64], vec![1f64, 0f64]]
+        );
+    }
+
+    #[test]
+    fn archive() {
+        let mut archive = ParetoArchive::new(3);
+        for (i, cost) in costs().into_iter().enumerate() {
+            archive.insert(Solution::new(vec![i as f64], cost, 0f64));
+        }
+        assert_eq!(archive.len(), 3);
+        assert!(!archive.insert(Solution::new(vec![9f64], vec![3f64, 3f64], 0f64)));
+
+        // the archive stays within its capacity and keeps the extremes of the front
+        assert!(archive.insert(Solution::new(vec![7f64], vec![1.5, 4f64], 0f64)));
+        assert_eq!(archive.len(), 3);
+        let solutions = archive.get_solutions();
+        assert!(solutions.iter().any(|s| s.get_costs() == [1f64, 5f64]));
+        assert!(solutions.iter().any(|s| s.get_costs() == [4f64, 1f64]));
+
+        // a dominating point replaces everything it dominates
+        assert!(archive.insert(Solution::new(vec![8f64], vec![0f64, 0f64], 0f64)));
+        assert_eq!(archive.len(), 1);
+    }
+}
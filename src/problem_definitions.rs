@@ -1,6 +1,14 @@
 use std::fmt::{Debug, Display};
+use std::iter::Sum;
 use std::ops::{Add, Mul, Sub};
 
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
+use rand_distr::num_traits::{Float, FloatConst, ToPrimitive};
+use rand_distr::StandardNormal;
+
+use crate::benchmarks::traits::{clamp_into, local_next_in, random_in};
+
 pub trait ProblemDomain {
     type Item: PartialOrd
         + Clone
@@ -9,13 +17,61 @@ pub trait ProblemDomain {
         + Sub<<Self as ProblemDomain>::Item, Output = <Self as ProblemDomain>::Item>
         + Add<<Self as ProblemDomain>::Item, Output = <Self as ProblemDomain>::Item>
         + Mul<<Self as ProblemDomain>::Item, Output = <Self as ProblemDomain>::Item>
-        + Mul<f32, Output = f32>
-        + Into<f32>;
+        + ToPrimitive;
 
     fn get_minimum(&self) -> Self::Item;
     fn get_maximum(&self) -> Self::Item;
     fn get_dimensions(&self) -> usize;
     fn cost_function(&self, input: &[Self::Item]) -> Self::Item;
+
+    // maps a point produced by an arithmetic operator back onto the domain, e.g. rounds the
+    // integer variables, continuous problems take it as it is
+    fn repair(&self, input: Vec<Self::Item>) -> Vec<Self::Item> {
+        input
+    }
+
+    // values g(x) of the inequality constraints, each one is satisfied when g(x) <= 0
+    fn get_inequality_constraints(&self, _input: &[Self::Item]) -> Vec<Self::Item> {
+        vec![]
+    }
+
+    // values h(x) of the equality constraints, each one is satisfied when h(x) = 0
+    fn get_equality_constraints(&self, _input: &[Self::Item]) -> Vec<Self::Item> {
+        vec![]
+    }
+    // true once no evaluation is left, e.g. of a budget the caller wrapped around the problem,
+    // solvers stop instead of evaluating further
+    fn is_exhausted(&self) -> bool {
+        false
+    }
+}
+
+// floating point type of the continuous problems and solvers, f32 and f64 are provided
+pub trait Real:
+    Float + FloatConst + SampleUniform + Sum + Default + Debug + Display + Send + Sync + 'static
+{
+    fn from_f64(value: f64) -> Self;
+    fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+impl Real for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.sample(StandardNormal)
+    }
+}
+
+impl Real for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.sample(StandardNormal)
+    }
 }
 
 pub trait HasRandom: ProblemDomain {
@@ -30,3 +86,403 @@ pub trait HasLocal: ProblemDomain {
         input: &[<Self as ProblemDomain>::Item],
     ) -> Vec<<Self as ProblemDomain>::Item>;
 }
+
+pub trait HasGradient: ProblemDomain {
+    fn get_gradient(
+        &self,
+        input: &[<Self as ProblemDomain>::Item],
+    ) -> Vec<<Self as ProblemDomain>::Item>;
+
+    // cost function evaluations spent by one gradient, analytic gradients are free
+    fn get_gradient_cost(&self) -> i32 {
+        0
+    }
+}
+
+// problems with several objectives to minimise at once, cost_function stays their scalarisation
+// for the single objective solvers
+pub trait HasObjectives: ProblemDomain {
+    fn get_objectives(&self) -> usize;
+    fn cost_vector(
+        &self,
+        input: &[<Self as ProblemDomain>::Item],
+    ) -> Vec<<Self as ProblemDomain>::Item>;
+}
+
+// central finite differences, the step is relative to the magnitude of every coordinate
+pub fn numerical_gradient<T, F>(problem: &T, input: &[F], step: F) -> Vec<F>
+where
+    T: ProblemDomain<Item = F> + ?Sized,
+    F: Real,
+{
+    let mut shifted = input.to_vec();
+    (0..input.len())
+        .map(|i| {
+            let h = step * input[i].abs().max(F::one());
+            shifted[i] = input[i] + h;
+            let forward = problem.cost_function(&shifted);
+            shifted[i] = input[i] - h;
+            let backward = problem.cost_function(&shifted);
+            shifted[i] = input[i];
+            (forward - backward) / (h + h)
+        })
+        .collect()
+}
+
+// gives any problem a finite-difference gradient, every gradient costs 2 * dim evaluations
+pub struct NumericalGradient<T>
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    step: T::Item,
+    problem: T,
+}
+
+impl<T> NumericalGradient<T>
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    pub fn new(problem: T) -> Self {
+        Self {
+            step: T::Item::from_f64(1e-3),
+            problem,
+        }
+    }
+
+    pub fn set_step(mut self, step: T::Item) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn get_problem(&self) -> &T {
+        &self.problem
+    }
+}
+
+impl<T> ProblemDomain for NumericalGradient<T>
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    type Item = T::Item;
+
+    fn get_minimum(&self) -> T::Item {
+        self.problem.get_minimum()
+    }
+
+    fn get_maximum(&self) -> T::Item {
+        self.problem.get_maximum()
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.problem.get_dimensions()
+    }
+
+    fn cost_function(&self, input: &[T::Item]) -> T::Item {
+        self.problem.cost_function(input)
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.problem.is_exhausted()
+    }
+
+    fn repair(&self, input: Vec<T::Item>) -> Vec<T::Item> {
+        self.problem.repair(input)
+    }
+
+    fn get_inequality_constraints(&self, input: &[T::Item]) -> Vec<T::Item> {
+        self.problem.get_inequality_constraints(input)
+    }
+
+    fn get_equality_constraints(&self, input: &[T::Item]) -> Vec<T::Item> {
+        self.problem.get_equality_constraints(input)
+    }
+}
+
+impl<T> HasRandom for NumericalGradient<T>
+where
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
+{
+    fn get_random(&self) -> Vec<T::Item> {
+        self.problem.get_random()
+    }
+}
+
+impl<T> HasLocal for NumericalGradient<T>
+where
+    T: ProblemDomain + HasLocal,
+    T::Item: Real,
+{
+    fn get_local_next(&self, input: &[T::Item]) -> Vec<T::Item> {
+        self.problem.get_local_next(input)
+    }
+}
+
+impl<T> HasGradient for NumericalGradient<T>
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    fn get_gradient(&self, input: &[T::Item]) -> Vec<T::Item> {
+        numerical_gradient(&self.problem, input, self.step)
+    }
+
+    fn get_gradient_cost(&self) -> i32 {
+        2 * self.problem.get_dimensions() as i32
+    }
+}
+
+type Constraints<R> = Box<dyn Fn(&[R]) -> Vec<R>>;
+type Objectives<R> = Box<dyn Fn(&[R]) -> Vec<R>>;
+
+// ad-hoc problem from a closure, the box is [min, max]^dim unless per-variable bounds are set
+pub struct FnProblem<F, R = f32>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    min: R,
+    max: R,
+    dim: usize,
+    bounds: Option<Vec<(R, R)>>,
+    inequality_constraints: Option<Constraints<R>>,
+    equality_constraints: Option<Constraints<R>>,
+    objectives: Option<(usize, Objectives<R>)>,
+    function: F,
+}
+
+impl<F, R> FnProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    pub fn new(min: R, max: R, dim: usize, function: F) -> Self {
+        Self {
+            min,
+            max,
+            dim,
+            bounds: None,
+            inequality_constraints: None,
+            equality_constraints: None,
+            objectives: None,
+            function,
+        }
+    }
+
+    // the dimension follows the number of bounds, min and max become their envelope
+    pub fn set_bounds(mut self, bounds: Vec<(R, R)>) -> Self {
+        self.dim = bounds.len();
+        self.min = bounds.iter().map(|b| b.0).fold(R::infinity(), R::min);
+        self.max = bounds.iter().map(|b| b.1).fold(R::neg_infinity(), R::max);
+        self.bounds = Some(bounds);
+        self
+    }
+
+    pub fn get_bounds(&self) -> Vec<(R, R)> {
+        match &self.bounds {
+            Some(bounds) => bounds.clone(),
+            None => vec![(self.min, self.max); self.dim],
+        }
+    }
+
+    pub fn set_inequality_constraints(
+        mut self,
+        constraints: impl Fn(&[R]) -> Vec<R> + 'static,
+    ) -> Self {
+        self.inequality_constraints = Some(Box::new(constraints));
+        self
+    }
+
+    pub fn set_equality_constraints(
+        mut self,
+        constraints: impl Fn(&[R]) -> Vec<R> + 'static,
+    ) -> Self {
+        self.equality_constraints = Some(Box::new(constraints));
+        self
+    }
+
+    // the closure returns the given number of objectives, without it the problem has just one
+    pub fn set_objectives(
+        mut self,
+        objectives: usize,
+        function: impl Fn(&[R]) -> Vec<R> + 'static,
+    ) -> Self {
+        self.objectives = Some((objectives, Box::new(function)));
+        self
+    }
+}
+
+impl<F, R> Debug for FnProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnProblem")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("dim", &self.dim)
+            .field("bounds", &self.bounds)
+            .field("objectives", &self.get_objectives())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, R> ProblemDomain for FnProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    type Item = R;
+
+    fn get_minimum(&self) -> R {
+        self.min
+    }
+
+    fn get_maximum(&self) -> R {
+        self.max
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.dim
+    }
+
+    fn cost_function(&self, input: &[R]) -> R {
+        (self.function)(input)
+    }
+
+    // the operators only keep the points inside [min, max], the per-variable bounds are
+    // enforced here
+    fn repair(&self, input: Vec<R>) -> Vec<R> {
+        match &self.bounds {
+            Some(bounds) => clamp_into(bounds, input),
+            None => input,
+        }
+    }
+
+    fn get_inequality_constraints(&self, input: &[R]) -> Vec<R> {
+        self.inequality_constraints
+            .as_ref()
+            .map_or_else(Vec::new, |constraints| constraints(input))
+    }
+
+    fn get_equality_constraints(&self, input: &[R]) -> Vec<R> {
+        self.equality_constraints
+            .as_ref()
+            .map_or_else(Vec::new, |constraints| constraints(input))
+    }
+}
+
+impl<F, R> HasRandom for FnProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    fn get_random(&self) -> Vec<R> {
+        random_in(&self.get_bounds())
+    }
+}
+
+impl<F, R> HasObjectives for FnProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    fn get_objectives(&self) -> usize {
+        self.objectives
+            .as_ref()
+            .map_or(1, |(objectives, _)| *objectives)
+    }
+
+    fn cost_vector(&self, input: &[R]) -> Vec<R> {
+        match &self.objectives {
+            Some((_, function)) => function(input),
+            None => vec![(self.function)(input)],
+        }
+    }
+}
+
+impl<F, R> HasLocal for FnProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    fn get_local_next(&self, input: &[R]) -> Vec<R> {
+        local_next_in(&self.get_bounds(), (self.min, self.max), input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::evol_arg::de::{De, Strategy, Variant};
+    use crate::solvers::hill_climber::HillClimber;
+
+    #[test]
+    fn closure() {
+        let problem = FnProblem::new(-5f32, 5f32, 3, |x: &[f32]| x.iter().map(|v| v * v).sum());
+        assert_eq!(
+            ProblemDomain::cost_function(&problem, &[1f32, 2f32, 3f32]),
+            14f32
+        );
+        assert_eq!(problem.get_bounds(), vec![(-5f32, 5f32); 3]);
+        let random = HasRandom::get_random(&problem);
+        assert_eq!(random.len(), 3);
+        assert!(random.iter().all(|x| (-5f32..=5f32).contains(x)));
+        let mut solver = HillClimber::new(50, 20, problem);
+        solver.run();
+        assert!(solver.get_best_cost().unwrap() < 1f32);
+    }
+
+    #[test]
+    fn closure_bounds() {
+        let problem = FnProblem::new(0f32, 0f32, 0, |x: &[f32]| x[0] - x[1])
+            .set_bounds(vec![(0f32, 1f32), (10f32, 20f32)]);
+        assert_eq!(ProblemDomain::get_dimensions(&problem), 2);
+        assert_eq!(ProblemDomain::get_minimum(&problem), 0f32);
+        assert_eq!(ProblemDomain::get_maximum(&problem), 20f32);
+        for _ in 0..100 {
+            let random = HasRandom::get_random(&problem);
+            assert!((0f32..=1f32).contains(&random[0]));
+            assert!((10f32..=20f32).contains(&random[1]));
+            let local = HasLocal::get_local_next(&problem, &random);
+            assert!((0f32..=1f32).contains(&local[0]));
+            assert!((10f32..=20f32).contains(&local[1]));
+        }
+    }
+
+    #[test]
+    fn closure_bounds_kept() {
+        let problem = FnProblem::new(0f64, 0f64, 0, |x: &[f64]| x[0] + x[1])
+            .set_bounds(vec![(0f64, 1f64), (-10f64, 10f64)]);
+        assert_eq!(problem.repair(vec![-10f64, 12f64]), vec![0f64, 10f64]);
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 2000, 20, 0.8, 0.9, problem);
+        de.run();
+        // x0 would follow x1 down to -10 without the per-variable bounds
+        let best = de.get_best().unwrap();
+        assert!((best.get_coordinates()[0] - 0f64).abs() < 1e-3);
+        assert!((best.get_coordinates()[1] + 10f64).abs() < 1e-3);
+    }
+
+    #[test]
+    fn closure_f64() {
+        let problem = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| x.iter().map(|v| v * v).sum());
+        let gradient = NumericalGradient::new(problem).get_gradient(&[1f64, -2f64]);
+        assert!((gradient[0] - 2f64).abs() < 1e-6);
+        assert!((gradient[1] + 4f64).abs() < 1e-6);
+    }
+
+    #[test]
+    fn closure_objectives() {
+        let single = FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| x[0] + x[1]);
+        assert_eq!(single.get_objectives(), 1);
+        assert_eq!(single.cost_vector(&[1f64, 2f64]), vec![3f64]);
+        let problem = single.set_objectives(2, |x: &[f64]| vec![x[0], x[1]]);
+        assert_eq!(problem.get_objectives(), 2);
+        assert_eq!(problem.cost_vector(&[1f64, 2f64]), vec![1f64, 2f64]);
+        assert_eq!(ProblemDomain::cost_function(&problem, &[1f64, 2f64]), 3f64);
+    }
+}
@@ -0,0 +1,175 @@
+use rand_distr::{Distribution, Uniform};
+
+use rand_distr::num_traits::ToPrimitive;
+
+use crate::problem_definitions::{HasRandom, ProblemDomain, Real};
+use crate::solvers::neighbourhood::fit_in_bounds;
+use crate::solvers::simulated_annealing::metropolis_accepts;
+use crate::solvers::traits::Restartable;
+
+type Item<S> = <<S as Restartable>::Problem as ProblemDomain>::Item;
+
+// source: https://docs.scipy.org/doc/scipy/reference/generated/scipy.optimize.basinhopping.html
+pub struct BasinHopping<S>
+where
+    S: Restartable,
+    S::Problem: HasRandom,
+    Item<S>: Real,
+{
+    max_cf: i32,
+    temperature: f32,
+    step_size: f32,
+    // the step size is adapted every interval hops towards half of the hops accepted
+    adaptation_interval: Option<usize>,
+    current_best: Option<Item<S>>,
+    current_best_coords: Option<Vec<Item<S>>>,
+    cost_history: Vec<Item<S>>,
+    accepted_hops: usize,
+    cost_function_evaluations: i32,
+    solver: S,
+}
+
+impl<S> BasinHopping<S>
+where
+    S: Restartable,
+    S::Problem: HasRandom,
+    Item<S>: Real,
+{
+    pub fn new(max_cf: i32, temperature: f32, step_size: f32, solver: S) -> Self {
+        Self {
+            max_cf,
+            temperature,
+            step_size,
+            adaptation_interval: None,
+            current_best: None,
+            current_best_coords: None,
+            cost_history: vec![],
+            accepted_hops: 0,
+            cost_function_evaluations: 0,
+            solver,
+        }
+    }
+
+    pub fn set_adaptation_interval(mut self, interval: usize) -> Self {
+        self.adaptation_interval = Some(interval.max(1));
+        self
+    }
+
+    pub fn run(&mut self) {
+        let start_evaluations = self.solver.get_cost_function_evaluations();
+        let start = self.solver.get_problem().get_random();
+        let Some((mut current, mut current_coords)) = self.solver.run_from(start, self.max_cf)
+        else {
+            return;
+        };
+        self.cost_function_evaluations =
+            self.solver.get_cost_function_evaluations() - start_evaluations;
+        self.current_best = Some(current);
+        self.current_best_coords = Some(current_coords.clone());
+        self.cost_history.push(current);
+
+        let mut hops = 0;
+        let mut accepted_in_interval = 0;
+        while self.cost_function_evaluations < self.max_cf {
+            let displaced = self.displace(&current_coords);
+            let Some((cost, coords)) = self
+                .solver
+                .run_from(displaced, self.max_cf - self.cost_function_evaluations)
+            else {
+                break;
+            };
+            self.cost_function_evaluations =
+                self.solver.get_cost_function_evaluations() - start_evaluations;
+            hops += 1;
+
+            if self.current_best.is_none_or(|best| cost < best) {
+                self.current_best = Some(cost);
+                self.current_best_coords = Some(coords.clone());
+            }
+            let difference = (cost - current).to_f32().unwrap_or(f32::INFINITY);
+            if metropolis_accepts(difference, self.temperature) {
+                current = cost;
+                current_coords = coords;
+                self.accepted_hops += 1;
+                accepted_in_interval += 1;
+            }
+            self.cost_history.push(current);
+
+            if let Some(interval) = self.adaptation_interval {
+                if hops % interval == 0 {
+                    if accepted_in_interval as f32 / interval as f32 > 0.5 {
+                        self.step_size /= 0.9;
+                    } else {
+                        self.step_size *= 0.9;
+                    }
+                    accepted_in_interval = 0;
+                }
+            }
+        }
+    }
+
+    // uniform random displacement of every coordinate by at most step_size
+    fn displace(&self, input: &[Item<S>]) -> Vec<Item<S>> {
+        let problem = self.solver.get_problem();
+        let step_size = Item::<S>::from_f64(f64::from(self.step_size));
+        let range = Uniform::new_inclusive(-step_size, step_size);
+        let mut rng = rand::thread_rng();
+        let displaced = input
+            .iter()
+            .map(|&x| fit_in_bounds(problem, x + range.sample(&mut rng)))
+            .collect();
+        problem.repair(displaced)
+    }
+
+    // cost of the current basin after every hop
+    pub fn get_history(&self) -> &[Item<S>] {
+        &self.cost_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<Item<S>> {
+        self.current_best
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[Item<S>]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_accepted_hops(&self) -> usize {
+        self.accepted_hops
+    }
+
+    pub fn get_step_size(&self) -> f32 {
+        self.step_size
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{schwefel::Schwefel, traits::HasBuilder};
+    use crate::solvers::nelder_mead::NelderMead;
+
+    #[test]
+    fn budget() {
+        let problem = Schwefel::builder()
+            .minimum(-500f32)
+            .maximum(500f32)
+            .dimensions(5)
+            .build()
+            .unwrap();
+        let mut basin_hopping =
+            BasinHopping::new(5000, 100f32, 100f32, NelderMead::new(500, problem))
+                .set_adaptation_interval(5);
+        basin_hopping.run();
+        assert_eq!(basin_hopping.get_cost_function_evaluations(), 5000);
+        assert!(basin_hopping.get_accepted_hops() > 0);
+        assert!(basin_hopping
+            .get_history()
+            .iter()
+            .all(|cost| *cost >= basin_hopping.get_best_cost().unwrap()));
+    }
+}
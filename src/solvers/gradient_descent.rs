@@ -0,0 +1,288 @@
+use rand_distr::num_traits::{Float, One, Zero};
+
+use crate::problem_definitions::{HasGradient, HasRandom, ProblemDomain, Real};
+use crate::solvers::traits::Restartable;
+
+pub enum Update {
+    Plain,
+    Momentum { beta: f32 },
+    // source: https://arxiv.org/abs/1412.6980
+    Adam { beta1: f32, beta2: f32 },
+}
+
+// projected gradient descent, every step is projected back onto the domain box
+pub struct GradientDescent<T>
+where
+    T: ProblemDomain + HasGradient + HasRandom,
+    T::Item: Real,
+{
+    max_iter: i32,
+    learning_rate: f32,
+    update: Update,
+    // the descent stops when the projected gradient is shorter than this
+    tolerance: f32,
+    run_max_cf: Option<i32>,
+    current_best: Option<T::Item>,
+    current_best_coords: Option<Vec<T::Item>>,
+    cost_history: Vec<T::Item>,
+    cost_function_evaluations: i32,
+    gradient_evaluations: i32,
+    problem: T,
+}
+
+impl<T> GradientDescent<T>
+where
+    T: ProblemDomain + HasGradient + HasRandom,
+    T::Item: Real,
+{
+    pub fn new(max_iter: i32, learning_rate: f32, problem: T) -> Self {
+        Self {
+            max_iter,
+            learning_rate,
+            update: Update::Plain,
+            tolerance: 1e-6,
+            run_max_cf: None,
+            current_best: None,
+            current_best_coords: None,
+            cost_history: vec![],
+            cost_function_evaluations: 0,
+            gradient_evaluations: 0,
+            problem,
+        }
+    }
+
+    pub fn set_update(mut self, update: Update) -> Self {
+        self.update = update;
+        self
+    }
+
+    pub fn set_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn run(&mut self) {
+        let start = self.problem.get_random();
+        self.run_max_cf = None;
+        self.descend_from(start);
+    }
+
+    fn descend_from(&mut self, start: Vec<T::Item>) -> Option<(T::Item, Vec<T::Item>)> {
+        // a restart with no evaluation left would overrun the budget
+        if self.is_budget_exhausted() {
+            return None;
+        }
+        let real = |value: f32| T::Item::from_f64(f64::from(value));
+        let learning_rate = real(self.learning_rate);
+        let one = T::Item::one();
+        let mut coords = self.project(start);
+        let mut cost = self.run_cost_fn(&coords);
+        let mut best = (cost, coords.clone());
+        let mut first_moment = vec![T::Item::zero(); coords.len()];
+        let mut second_moment = vec![T::Item::zero(); coords.len()];
+        self.cost_history.push(cost);
+
+        for iteration in 1..=self.max_iter {
+            if self.lacks_budget_for(self.problem.get_gradient_cost() + 1) {
+                break;
+            }
+            let gradient = self.run_gradient(&coords);
+            if self.projected_gradient_norm(&coords, &gradient) <= real(self.tolerance) {
+                break;
+            }
+            let step: Vec<T::Item> = match self.update {
+                Update::Plain => gradient.iter().map(|&g| learning_rate * g).collect(),
+                Update::Momentum { beta } => {
+                    let beta = real(beta);
+                    first_moment
+                        .iter_mut()
+                        .zip(gradient.iter())
+                        .for_each(|(v, &g)| *v = beta * *v + learning_rate * g);
+                    first_moment.clone()
+                }
+                Update::Adam { beta1, beta2 } => {
+                    let (beta1, beta2) = (real(beta1), real(beta2));
+                    let correction1 = one - beta1.powi(iteration);
+                    let correction2 = one - beta2.powi(iteration);
+                    first_moment
+                        .iter_mut()
+                        .zip(second_moment.iter_mut())
+                        .zip(gradient.iter())
+                        .map(|((m, v), &g)| {
+                            *m = beta1 * *m + (one - beta1) * g;
+                            *v = beta2 * *v + (one - beta2) * g * g;
+                            learning_rate * (*m / correction1)
+                                / ((*v / correction2).sqrt() + T::Item::from_f64(1e-8))
+                        })
+                        .collect()
+                }
+            };
+            coords = self.project(
+                coords
+                    .iter()
+                    .zip(step.iter())
+                    .map(|(&x, &s)| x - s)
+                    .collect(),
+            );
+            cost = self.run_cost_fn(&coords);
+            if cost < best.0 {
+                best = (cost, coords.clone());
+            }
+            self.cost_history.push(cost);
+        }
+        self.current_best = Some(best.0);
+        self.current_best_coords = Some(best.1.clone());
+        Some(best)
+    }
+
+    fn project(&self, input: Vec<T::Item>) -> Vec<T::Item> {
+        let min = self.problem.get_minimum();
+        let max = self.problem.get_maximum();
+        input.into_iter().map(|x| x.clamp(min, max)).collect()
+    }
+
+    fn projected_gradient_norm(&self, coords: &[T::Item], gradient: &[T::Item]) -> T::Item {
+        let min = self.problem.get_minimum();
+        let max = self.problem.get_maximum();
+        coords
+            .iter()
+            .zip(gradient.iter())
+            .map(|(&x, &g)| ((x - g).clamp(min, max) - x).abs())
+            .fold(T::Item::zero(), T::Item::max)
+    }
+
+    fn run_cost_fn(&mut self, input: &[T::Item]) -> T::Item {
+        self.cost_function_evaluations += 1;
+        self.problem.cost_function(input)
+    }
+
+    // numerical gradients are charged as the cost evaluations they spend
+    fn run_gradient(&mut self, input: &[T::Item]) -> Vec<T::Item> {
+        self.gradient_evaluations += 1;
+        self.cost_function_evaluations += self.problem.get_gradient_cost();
+        self.problem.get_gradient(input)
+    }
+
+    fn is_budget_exhausted(&self) -> bool {
+        self.lacks_budget_for(1)
+    }
+
+    fn lacks_budget_for(&self, evaluations: i32) -> bool {
+        self.run_max_cf
+            .is_some_and(|max_cf| self.cost_function_evaluations + evaluations > max_cf)
+            || self.problem.is_exhausted()
+    }
+
+    pub fn get_history(&self) -> &[T::Item] {
+        &self.cost_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<T::Item> {
+        self.current_best
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[T::Item]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+
+    pub fn get_gradient_evaluations(&self) -> i32 {
+        self.gradient_evaluations
+    }
+}
+
+impl<T> Restartable for GradientDescent<T>
+where
+    T: ProblemDomain + HasGradient + HasRandom,
+    T::Item: Real,
+{
+    type Problem = T;
+
+    fn get_problem(&self) -> &T {
+        &self.problem
+    }
+
+    fn run_from(&mut self, start: Vec<T::Item>, max_cf: i32) -> Option<(T::Item, Vec<T::Item>)> {
+        self.run_max_cf = Some(self.cost_function_evaluations + max_cf);
+        self.descend_from(start)
+    }
+
+    fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{
+        fst_dejong::FstDeJong, styblinsky_and_tang::StyblinskyAndTang, traits::HasBuilder,
+    };
+    use crate::problem_definitions::NumericalGradient;
+
+    fn sphere() -> FstDeJong {
+        FstDeJong::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(5)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn plain() {
+        let mut descent = GradientDescent::new(200, 0.1, sphere());
+        descent.run();
+        assert!(descent.get_best_cost().unwrap() < 1e-4);
+        assert!(descent.get_gradient_evaluations() <= descent.get_cost_function_evaluations());
+    }
+
+    #[test]
+    fn momentum() {
+        let mut descent =
+            GradientDescent::new(500, 0.05, sphere()).set_update(Update::Momentum { beta: 0.9 });
+        descent.run();
+        assert!(descent.get_best_cost().unwrap() < 1e-4);
+    }
+
+    #[test]
+    fn adam() {
+        let mut descent = GradientDescent::new(1000, 0.1, sphere()).set_update(Update::Adam {
+            beta1: 0.9,
+            beta2: 0.999,
+        });
+        descent.run();
+        assert!(descent.get_best_cost().unwrap() < 1e-3);
+    }
+
+    #[test]
+    fn projected_onto_bounds() {
+        // the minimum at -2.9035 lies outside the box, the descent has to stop at its bound
+        let problem = StyblinskyAndTang::builder()
+            .minimum(-2f32)
+            .maximum(5f32)
+            .dimensions(3)
+            .build()
+            .unwrap();
+        let mut descent = GradientDescent::new(500, 0.01, NumericalGradient::new(problem));
+        descent.run_from(vec![-1.5, -1.5, -1.5], 1000);
+        assert!(descent
+            .get_best_coords()
+            .unwrap()
+            .iter()
+            .all(|x| (x + 2f32).abs() < 1e-3));
+    }
+
+    #[test]
+    fn numerical_gradient_charged() {
+        let mut descent =
+            GradientDescent::new(500, 0.1, NumericalGradient::new(sphere())).set_tolerance(0f32);
+        descent.run_from(vec![4f32; 5], 100);
+        // every iteration spends one cost evaluation and ten for the gradient
+        assert_eq!(descent.get_cost_function_evaluations(), 100);
+        assert_eq!(descent.get_gradient_evaluations(), 9);
+    }
+}
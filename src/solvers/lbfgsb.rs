@@ -0,0 +1,386 @@
+use std::collections::VecDeque;
+
+use rand_distr::num_traits::{Float, One, Zero};
+
+use crate::problem_definitions::{HasGradient, HasRandom, ProblemDomain, Real};
+use crate::solvers::traits::Restartable;
+
+const ARMIJO: f64 = 1e-4;
+const MAX_BACKTRACKS: i32 = 30;
+
+// pairs of coordinate and gradient differences
+type Corrections<F> = VecDeque<(Vec<F>, Vec<F>)>;
+
+// limited memory BFGS for box constraints, a simplified L-BFGS-B: coordinates held at a
+// bound by the gradient are fixed, the two-loop direction is computed for the free ones
+// and the step is a projected backtracking line search
+// source: https://en.wikipedia.org/wiki/Limited-memory_BFGS
+pub struct LBfgsB<T>
+where
+    T: ProblemDomain + HasGradient + HasRandom,
+    T::Item: Real,
+{
+    max_iter: i32,
+    memory: usize,
+    // the search stops when the projected gradient is shorter than this
+    tolerance: f32,
+    run_max_cf: Option<i32>,
+    current_best: Option<T::Item>,
+    current_best_coords: Option<Vec<T::Item>>,
+    cost_history: Vec<T::Item>,
+    cost_function_evaluations: i32,
+    gradient_evaluations: i32,
+    problem: T,
+}
+
+impl<T> LBfgsB<T>
+where
+    T: ProblemDomain + HasGradient + HasRandom,
+    T::Item: Real,
+{
+    pub fn new(max_iter: i32, memory: usize, problem: T) -> Self {
+        Self {
+            max_iter,
+            memory: memory.max(1),
+            tolerance: 1e-5,
+            run_max_cf: None,
+            current_best: None,
+            current_best_coords: None,
+            cost_history: vec![],
+            cost_function_evaluations: 0,
+            gradient_evaluations: 0,
+            problem,
+        }
+    }
+
+    pub fn set_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn run(&mut self) {
+        let start = self.problem.get_random();
+        self.run_max_cf = None;
+        self.minimise_from(start);
+    }
+
+    fn minimise_from(&mut self, start: Vec<T::Item>) -> Option<(T::Item, Vec<T::Item>)> {
+        // a restart with no evaluation left would overrun the budget
+        if self.lacks_budget_for(self.problem.get_gradient_cost() + 1) {
+            return None;
+        }
+        let mut coords = self.project(&start);
+        let mut cost = self.run_cost_fn(&coords);
+        let mut gradient = self.run_gradient(&coords);
+        let mut corrections: Corrections<T::Item> = VecDeque::new();
+        self.cost_history.push(cost);
+
+        for _ in 0..self.max_iter {
+            if self.is_budget_exhausted()
+                || self.projected_gradient_norm(&coords, &gradient)
+                    <= T::Item::from_f64(f64::from(self.tolerance))
+            {
+                break;
+            }
+            let free = self.free_variables(&coords, &gradient);
+            let mut direction = self.direction(&gradient, &free, &corrections);
+            if dot(&direction, &gradient) >= T::Item::zero() {
+                direction = gradient
+                    .iter()
+                    .zip(free.iter())
+                    .map(|(&g, f)| if *f { -g } else { T::Item::zero() })
+                    .collect();
+            }
+
+            let Some((new_cost, new_coords)) =
+                self.line_search(&coords, cost, &gradient, &direction)
+            else {
+                break;
+            };
+            if self.lacks_budget_for(self.problem.get_gradient_cost()) {
+                coords = new_coords;
+                cost = new_cost;
+                self.cost_history.push(cost);
+                break;
+            }
+            let new_gradient = self.run_gradient(&new_coords);
+            let s = difference(&new_coords, &coords);
+            let y = difference(&new_gradient, &gradient);
+            if dot(&s, &y) > T::Item::epsilon() * dot(&y, &y) {
+                if corrections.len() == self.memory {
+                    corrections.pop_front();
+                }
+                corrections.push_back((s, y));
+            }
+            coords = new_coords;
+            cost = new_cost;
+            gradient = new_gradient;
+            self.cost_history.push(cost);
+        }
+        self.current_best = Some(cost);
+        self.current_best_coords = Some(coords.clone());
+        Some((cost, coords))
+    }
+
+    // two-loop recursion restricted to the free variables
+    fn direction(
+        &self,
+        gradient: &[T::Item],
+        free: &[bool],
+        corrections: &Corrections<T::Item>,
+    ) -> Vec<T::Item> {
+        let mask = |v: &[T::Item]| -> Vec<T::Item> {
+            v.iter()
+                .zip(free.iter())
+                .map(|(x, f)| if *f { *x } else { T::Item::zero() })
+                .collect()
+        };
+        let mut q = mask(gradient);
+        let mut alphas = Vec::with_capacity(corrections.len());
+        for (s, y) in corrections.iter().rev() {
+            let (s, y) = (mask(s), mask(y));
+            let rho = T::Item::one() / dot(&y, &s);
+            if !rho.is_finite() || rho <= T::Item::zero() {
+                alphas.push(T::Item::zero());
+                continue;
+            }
+            let alpha = rho * dot(&s, &q);
+            q.iter_mut()
+                .zip(y.iter())
+                .for_each(|(q, &y)| *q = *q - alpha * y);
+            alphas.push(alpha);
+        }
+        let scale = corrections
+            .back()
+            .map(|(s, y)| {
+                let (s, y) = (mask(s), mask(y));
+                dot(&s, &y) / dot(&y, &y)
+            })
+            .filter(|scale| scale.is_finite() && *scale > T::Item::zero())
+            .unwrap_or(T::Item::one());
+        let mut r: Vec<T::Item> = q.iter().map(|&q| scale * q).collect();
+        for ((s, y), alpha) in corrections.iter().zip(alphas.into_iter().rev()) {
+            let (s, y) = (mask(s), mask(y));
+            let rho = T::Item::one() / dot(&y, &s);
+            if !rho.is_finite() || rho <= T::Item::zero() {
+                continue;
+            }
+            let beta = rho * dot(&y, &r);
+            r.iter_mut()
+                .zip(s.iter())
+                .for_each(|(r, &s)| *r = *r + s * (alpha - beta));
+        }
+        mask(&r).into_iter().map(|r| -r).collect()
+    }
+
+    // backtracking along the projected path x(t) = P(x + t * d)
+    fn line_search(
+        &mut self,
+        coords: &[T::Item],
+        cost: T::Item,
+        gradient: &[T::Item],
+        direction: &[T::Item],
+    ) -> Option<(T::Item, Vec<T::Item>)> {
+        let mut step = T::Item::one();
+        for _ in 0..MAX_BACKTRACKS {
+            if self.is_budget_exhausted() {
+                return None;
+            }
+            let candidate: Vec<T::Item> = self.project(
+                &coords
+                    .iter()
+                    .zip(direction.iter())
+                    .map(|(&x, &d)| x + step * d)
+                    .collect::<Vec<T::Item>>(),
+            );
+            let candidate_cost = self.run_cost_fn(&candidate);
+            let decrease = dot(gradient, &difference(&candidate, coords));
+            if candidate_cost <= cost + T::Item::from_f64(ARMIJO) * decrease {
+                return Some((candidate_cost, candidate));
+            }
+            step = step * T::Item::from_f64(0.5);
+        }
+        None
+    }
+
+    fn free_variables(&self, coords: &[T::Item], gradient: &[T::Item]) -> Vec<bool> {
+        let min = self.problem.get_minimum();
+        let max = self.problem.get_maximum();
+        coords
+            .iter()
+            .zip(gradient.iter())
+            .map(|(x, g)| {
+                !((*x <= min && *g > T::Item::zero()) || (*x >= max && *g < T::Item::zero()))
+            })
+            .collect()
+    }
+
+    fn project(&self, input: &[T::Item]) -> Vec<T::Item> {
+        let min = self.problem.get_minimum();
+        let max = self.problem.get_maximum();
+        input.iter().map(|x| x.clamp(min, max)).collect()
+    }
+
+    fn projected_gradient_norm(&self, coords: &[T::Item], gradient: &[T::Item]) -> T::Item {
+        difference(&self.project(&difference(coords, gradient)), coords)
+            .iter()
+            .fold(T::Item::zero(), |acc, x| acc.max(x.abs()))
+    }
+
+    fn run_cost_fn(&mut self, input: &[T::Item]) -> T::Item {
+        self.cost_function_evaluations += 1;
+        self.problem.cost_function(input)
+    }
+
+    // numerical gradients are charged as the cost evaluations they spend
+    fn run_gradient(&mut self, input: &[T::Item]) -> Vec<T::Item> {
+        self.gradient_evaluations += 1;
+        self.cost_function_evaluations += self.problem.get_gradient_cost();
+        self.problem.get_gradient(input)
+    }
+
+    fn is_budget_exhausted(&self) -> bool {
+        self.lacks_budget_for(1)
+    }
+
+    fn lacks_budget_for(&self, evaluations: i32) -> bool {
+        self.run_max_cf
+            .is_some_and(|max_cf| self.cost_function_evaluations + evaluations > max_cf)
+            || self.problem.is_exhausted()
+    }
+
+    pub fn get_history(&self) -> &[T::Item] {
+        &self.cost_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<T::Item> {
+        self.current_best
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[T::Item]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+
+    pub fn get_gradient_evaluations(&self) -> i32 {
+        self.gradient_evaluations
+    }
+}
+
+impl<T> Restartable for LBfgsB<T>
+where
+    T: ProblemDomain + HasGradient + HasRandom,
+    T::Item: Real,
+{
+    type Problem = T;
+
+    fn get_problem(&self) -> &T {
+        &self.problem
+    }
+
+    fn run_from(&mut self, start: Vec<T::Item>, max_cf: i32) -> Option<(T::Item, Vec<T::Item>)> {
+        self.run_max_cf = Some(self.cost_function_evaluations + max_cf);
+        self.minimise_from(start)
+    }
+
+    fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+fn dot<F: Real>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b.iter()).map(|(&a, &b)| a * b).sum()
+}
+
+fn difference<F: Real>(a: &[F], b: &[F]) -> Vec<F> {
+    a.iter().zip(b.iter()).map(|(&a, &b)| a - b).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{
+        fst_dejong::FstDeJong, snd_dejong::SndDeJong, styblinsky_and_tang::StyblinskyAndTang,
+        traits::HasBuilder,
+    };
+    use crate::problem_definitions::NumericalGradient;
+
+    #[test]
+    fn sphere() {
+        let problem = FstDeJong::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(10)
+            .build()
+            .unwrap();
+        let mut lbfgsb = LBfgsB::new(100, 5, problem);
+        lbfgsb.run();
+        assert!(lbfgsb.get_best_cost().unwrap() < 1e-6);
+    }
+
+    #[test]
+    fn rosenbrock() {
+        let problem = SndDeJong::builder()
+            .minimum(-2f32)
+            .maximum(2f32)
+            .dimensions(4)
+            .build()
+            .unwrap();
+        let mut lbfgsb = LBfgsB::new(1000, 7, problem).set_tolerance(1e-4);
+        lbfgsb.run_from(vec![-1.2, 1f32, -1.2, 1f32], 10000);
+        assert!(lbfgsb
+            .get_best_coords()
+            .unwrap()
+            .iter()
+            .all(|x| (x - 1f32).abs() < 1e-2));
+    }
+
+    #[test]
+    fn bounded() {
+        // the minimum at -2.9035 lies outside the box, the search has to stop at its bound
+        let problem = StyblinskyAndTang::builder()
+            .minimum(-2f32)
+            .maximum(5f32)
+            .dimensions(3)
+            .build()
+            .unwrap();
+        let mut lbfgsb = LBfgsB::new(100, 5, problem);
+        lbfgsb.run_from(vec![-1.5, 4f32, 0f32], 1000);
+        // every coordinate ends at the bound or in the local minimum at 2.7468
+        assert!(lbfgsb
+            .get_best_coords()
+            .unwrap()
+            .iter()
+            .all(|x| (x + 2f32).abs() < 1e-3 || (x - 2.7468).abs() < 1e-2));
+    }
+
+    #[test]
+    fn run_from_budget() {
+        let problem = SndDeJong::builder()
+            .minimum(-2f32)
+            .maximum(2f32)
+            .dimensions(4)
+            .build()
+            .unwrap();
+        let mut lbfgsb = LBfgsB::new(1000, 5, problem).set_tolerance(0f32);
+        lbfgsb.run_from(vec![-1.2, 1f32, -1.2, 1f32], 30);
+        assert!(lbfgsb.get_cost_function_evaluations() <= 30);
+    }
+
+    #[test]
+    fn numerical_gradient_charged() {
+        let problem = SndDeJong::builder()
+            .minimum(-2f32)
+            .maximum(2f32)
+            .dimensions(4)
+            .build()
+            .unwrap();
+        let mut lbfgsb = LBfgsB::new(1000, 5, NumericalGradient::new(problem)).set_tolerance(0f32);
+        lbfgsb.run_from(vec![-1.2, 1f32, -1.2, 1f32], 100);
+        assert!(lbfgsb.get_cost_function_evaluations() <= 100);
+        assert!(lbfgsb.get_cost_function_evaluations() > 8 * lbfgsb.get_gradient_evaluations());
+    }
+}
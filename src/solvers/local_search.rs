@@ -1,13 +1,31 @@
+use std::cmp::Ordering;
+
 use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+use crate::solvers::neighbourhood::{Neighbourhood, ProblemLocal};
+use crate::solvers::traits::Restartable;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Acceptance {
+    // samples the whole neighbourhood and moves to its best member
+    BestImprovement,
+    // moves to the first sampled neighbour better than the current solution
+    FirstImprovement,
+    // moves to a random neighbour regardless of its cost
+    RandomWalk,
+}
 
 pub struct LocalSearch<T>
 where
     T: ProblemDomain + HasLocal + HasRandom,
 {
     max_local_iter: i32,
+    max_cf: Option<i32>,
+    acceptance: Acceptance,
     curren_best: Option<T::Item>,
     curren_best_coords: Option<Vec<T::Item>>,
     cost_history: Vec<T::Item>,
+    cost_function_evaluations: i32,
+    neighbourhood: Box<dyn Neighbourhood<T>>,
     problem: T,
 }
 
@@ -18,47 +36,128 @@ where
     pub fn new(max_local_iter: i32, problem: T) -> Self {
         LocalSearch {
             max_local_iter,
+            max_cf: None,
+            acceptance: Acceptance::BestImprovement,
             curren_best: None,
             curren_best_coords: None,
             cost_history: vec![],
+            cost_function_evaluations: 0,
+            neighbourhood: Box::new(ProblemLocal),
             problem,
         }
     }
 
-    pub fn run(&mut self) -> () {
+    pub fn set_neighbourhood(mut self, neighbourhood: impl Neighbourhood<T> + 'static) -> Self {
+        self.neighbourhood = Box::new(neighbourhood);
+        self
+    }
+
+    pub fn set_acceptance(mut self, acceptance: Acceptance) -> Self {
+        self.acceptance = acceptance;
+        self
+    }
+
+    pub fn set_max_cf(mut self, max_cf: i32) -> Self {
+        self.max_cf = Some(max_cf);
+        self
+    }
+
+    pub fn run(&mut self) {
         let start_input = self.problem.get_random();
-        let start_cost = self.problem.cost_function(&start_input);
+        self.search_from(start_input);
+    }
+
+    // the search ends once no better neighbour is found, the random walk ends
+    // after max_local_iter steps without improving the best solution
+    fn search_from(&mut self, start_input: Vec<T::Item>) -> Option<(T::Item, Vec<T::Item>)> {
+        // a restart with no evaluation left would overrun the budget
+        if self.is_budget_exhausted() {
+            return None;
+        }
+        let start_cost = self.run_cost_fn(&start_input);
+        let mut current = start_cost.clone();
+        let mut current_coords = start_input;
         let mut current_best = start_cost.clone();
-        let mut current_best_coords = start_input;
+        let mut current_best_coords = current_coords.clone();
+        let mut stagnation = 0;
         self.cost_history.push(start_cost);
 
-        loop {
-            let (local_best, local_best_coords) = self.evaluate_local(&current_best_coords);
-            if local_best > current_best {
+        while !self.is_budget_exhausted() {
+            let Some((local_best, local_best_coords)) =
+                self.evaluate_local(&current_coords, &current)
+            else {
                 break;
+            };
+            if self.acceptance == Acceptance::RandomWalk {
+                current = local_best.clone();
+                current_coords = local_best_coords;
+                if current < current_best {
+                    current_best = current.clone();
+                    current_best_coords = current_coords.clone();
+                    stagnation = 0;
+                } else {
+                    stagnation += 1;
+                    if stagnation >= self.max_local_iter {
+                        break;
+                    }
+                }
+            } else {
+                if local_best.partial_cmp(&current) != Some(Ordering::Less) {
+                    break;
+                }
+                current = local_best.clone();
+                current_coords = local_best_coords;
+                current_best = current.clone();
+                current_best_coords = current_coords.clone();
             }
-
-            current_best = local_best.clone();
-            current_best_coords = local_best_coords;
             self.cost_history.push(local_best);
         }
-        self.curren_best = Some(current_best);
-        self.curren_best_coords = Some(current_best_coords);
+        self.curren_best = Some(current_best.clone());
+        self.curren_best_coords = Some(current_best_coords.clone());
+        Some((current_best, current_best_coords))
     }
 
-    fn evaluate_local(&mut self, input: &[T::Item]) -> (T::Item, Vec<T::Item>) {
-        let mut local_best_coords = self.problem.get_local_next(input);
-        let mut local_best = self.problem.cost_function(&local_best_coords);
+    fn evaluate_local(
+        &mut self,
+        input: &[T::Item],
+        input_cost: &T::Item,
+    ) -> Option<(T::Item, Vec<T::Item>)> {
+        let samples = match self.acceptance {
+            Acceptance::RandomWalk => 1,
+            _ => self.max_local_iter,
+        };
+        let mut local_best: Option<(T::Item, Vec<T::Item>)> = None;
 
-        for _ in 1..self.max_local_iter {
-            let new_local_coords = self.problem.get_local_next(input);
-            let new_local = self.problem.cost_function(&new_local_coords);
-            if new_local < local_best {
-                local_best = new_local;
-                local_best_coords = new_local_coords;
+        for _ in 0..samples {
+            if self.is_budget_exhausted() {
+                break;
+            }
+            let new_local_coords = self.neighbourhood.get_neighbour(&self.problem, input);
+            let new_local = self.run_cost_fn(&new_local_coords);
+            let improved = &new_local < input_cost;
+            self.neighbourhood.report(improved);
+            if local_best
+                .as_ref()
+                .is_none_or(|(best, _)| &new_local < best)
+            {
+                local_best = Some((new_local, new_local_coords));
+            }
+            if improved && self.acceptance == Acceptance::FirstImprovement {
+                break;
             }
         }
-        (local_best, local_best_coords)
+        local_best
+    }
+
+    fn run_cost_fn(&mut self, input: &[T::Item]) -> T::Item {
+        self.cost_function_evaluations += 1;
+        self.problem.cost_function(input)
+    }
+
+    fn is_budget_exhausted(&self) -> bool {
+        self.max_cf
+            .is_some_and(|max_cf| self.cost_function_evaluations >= max_cf)
+            || self.problem.is_exhausted()
     }
 
     pub fn get_history(&self) -> &[T::Item] {
@@ -68,6 +167,37 @@ where
     pub fn get_best_cost(&self) -> Option<T::Item> {
         self.curren_best.clone()
     }
+
+    pub fn get_best_coords(&self) -> Option<&[T::Item]> {
+        self.curren_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+impl<T> Restartable for LocalSearch<T>
+where
+    T: ProblemDomain + HasRandom + HasLocal,
+{
+    type Problem = T;
+
+    fn get_problem(&self) -> &T {
+        &self.problem
+    }
+
+    fn run_from(&mut self, start: Vec<T::Item>, max_cf: i32) -> Option<(T::Item, Vec<T::Item>)> {
+        let global_max_cf = self.max_cf;
+        self.max_cf = Some(self.cost_function_evaluations + max_cf);
+        let result = self.search_from(start);
+        self.max_cf = global_max_cf;
+        result
+    }
+
+    fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
 }
 
 #[cfg(test)]
@@ -125,6 +255,45 @@ mod test {
         assert_ne!(random_search.cost_history.len(), 0);
     }
 
+    #[test]
+    fn first_improvement_stops_at_optimum() {
+        let mut mocked_problem = MockMockProblem::new();
+        mocked_problem
+            .expect_get_random()
+            .returning(|| vec![3f32, 3f32]);
+        mocked_problem.expect_get_local_next().returning(|input| {
+            input
+                .iter()
+                .map(|x| if *x > 0f32 { x - 1f32 } else { x + 1f32 })
+                .collect()
+        });
+        mocked_problem
+            .expect_cost_function()
+            .returning(|input| input.iter().map(|x| x * x).sum());
+        let mut local_search =
+            LocalSearch::new(10, mocked_problem).set_acceptance(Acceptance::FirstImprovement);
+        local_search.run();
+        assert_eq!(local_search.get_best_cost(), Some(0f32));
+        // three improving steps, one evaluation each, and one failed neighbourhood scan
+        assert_eq!(local_search.get_cost_function_evaluations(), 1 + 3 + 10);
+    }
+
+    #[test]
+    fn no_budget_no_start() {
+        let mut mocked_problem = MockMockProblem::new();
+        mocked_problem
+            .expect_cost_function()
+            .returning(|input| input.iter().map(|x| x * x).sum());
+        mocked_problem
+            .expect_get_local_next()
+            .returning(|input| input.iter().map(|x| x * 0.5).collect());
+        let mut local_search = LocalSearch::new(10, mocked_problem).set_max_cf(0);
+        assert!(local_search.run_from(vec![1f32; 2], 0).is_none());
+        assert_eq!(local_search.get_cost_function_evaluations(), 0);
+        local_search.run_from(vec![1f32; 2], 5);
+        assert_eq!(local_search.get_cost_function_evaluations(), 5);
+    }
+
     //     #[test]
     //     fn get_0() {
     //         let range = Uniform::new(1usize, 1000usize);
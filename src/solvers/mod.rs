@@ -1,5 +1,13 @@
+pub mod basin_hopping;
+pub mod gradient_descent;
 pub mod hill_climber;
+pub mod lbfgsb;
 pub mod local_search;
+pub mod neighbourhood;
+pub mod nelder_mead;
 pub mod random_search;
+pub mod restart;
 pub mod simulated_annealing;
+pub mod tabu_search;
 pub mod traits;
+pub mod variable_neighbourhood;
@@ -0,0 +1,413 @@
+use std::f32::consts::PI;
+
+use rand::Rng;
+use rand_distr::num_traits::{Float, ToPrimitive};
+use rand_distr::{Cauchy as CauchyDistribution, Distribution, Normal, Uniform};
+
+use crate::problem_definitions::{HasLocal, ProblemDomain, Real};
+
+pub trait Neighbourhood<T>
+where
+    T: ProblemDomain,
+{
+    fn get_neighbour(&mut self, problem: &T, input: &[T::Item]) -> Vec<T::Item>;
+
+    // called by the solver after every evaluated neighbour, adaptive operators use it
+    fn report(&mut self, _improved: bool) {}
+}
+
+// default operator of every solver, uses the problem's own HasLocal implementation
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProblemLocal;
+
+impl<T> Neighbourhood<T> for ProblemLocal
+where
+    T: ProblemDomain + HasLocal,
+{
+    fn get_neighbour(&mut self, problem: &T, input: &[T::Item]) -> Vec<T::Item> {
+        problem.get_local_next(input)
+    }
+}
+
+// adaptive variant follows the 1/5 success rule
+#[derive(Debug, Clone)]
+pub struct Gaussian {
+    sigma: f32,
+    adaptive: bool,
+    adaptation_period: u32,
+    trials: u32,
+    successes: u32,
+}
+
+impl Gaussian {
+    pub fn new(sigma: f32) -> Self {
+        Self {
+            sigma,
+            adaptive: false,
+            adaptation_period: 20,
+            trials: 0,
+            successes: 0,
+        }
+    }
+
+    pub fn adaptive(sigma: f32) -> Self {
+        Self {
+            adaptive: true,
+            ..Self::new(sigma)
+        }
+    }
+
+    pub fn set_adaptation_period(mut self, period: u32) -> Self {
+        self.adaptation_period = period.max(1);
+        self
+    }
+
+    pub fn get_sigma(&self) -> f32 {
+        self.sigma
+    }
+}
+
+impl<T> Neighbourhood<T> for Gaussian
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    fn get_neighbour(&mut self, problem: &T, input: &[T::Item]) -> Vec<T::Item> {
+        // keeps the adapted step size usable
+        self.sigma = step_size(problem, self.sigma);
+        let sigma = <T::Item as Real>::from_f64(f64::from(self.sigma));
+        let mut rng = rand::thread_rng();
+        let neighbour = input
+            .iter()
+            .map(|&x| fit_in_bounds(problem, x + sigma * T::Item::standard_normal(&mut rng)))
+            .collect();
+        problem.repair(neighbour)
+    }
+
+    fn report(&mut self, improved: bool) {
+        if !self.adaptive {
+            return;
+        }
+        self.trials += 1;
+        if improved {
+            self.successes += 1;
+        }
+        if self.trials >= self.adaptation_period {
+            if self.successes as f32 / self.trials as f32 > 0.2 {
+                self.sigma /= 0.85;
+            } else {
+                self.sigma *= 0.85;
+            }
+            self.trials = 0;
+            self.successes = 0;
+        }
+    }
+}
+
+// uniform sample from the ball of the given radius around the input
+#[derive(Debug, Clone)]
+pub struct UniformBall {
+    radius: f32,
+}
+
+impl UniformBall {
+    pub fn new(radius: f32) -> Self {
+        Self { radius }
+    }
+}
+
+impl<T> Neighbourhood<T> for UniformBall
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    fn get_neighbour(&mut self, problem: &T, input: &[T::Item]) -> Vec<T::Item> {
+        let mut rng = rand::thread_rng();
+        let direction: Vec<T::Item> = input
+            .iter()
+            .map(|_| T::Item::standard_normal(&mut rng))
+            .collect();
+        let norm = direction.iter().map(|&x| x * x).sum::<T::Item>().sqrt();
+        let radius = step_size(problem, self.radius);
+        let length = <T::Item as Real>::from_f64(
+            f64::from(radius) * rng.gen::<f64>().powf(1f64 / input.len() as f64),
+        );
+        let neighbour = input
+            .iter()
+            .zip(direction)
+            .map(|(&x, d)| fit_in_bounds(problem, x + d / norm.max(T::Item::epsilon()) * length))
+            .collect();
+        problem.repair(neighbour)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cauchy {
+    scale: f32,
+}
+
+impl Cauchy {
+    pub fn new(scale: f32) -> Self {
+        Self { scale }
+    }
+}
+
+impl<T> Neighbourhood<T> for Cauchy
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    fn get_neighbour(&mut self, problem: &T, input: &[T::Item]) -> Vec<T::Item> {
+        let scale = step_size(problem, self.scale);
+        let distribution = CauchyDistribution::new(0f64, f64::from(scale)).unwrap();
+        let mut rng = rand::thread_rng();
+        let neighbour = input
+            .iter()
+            .map(|&x| {
+                let step = <T::Item as Real>::from_f64(distribution.sample(&mut rng));
+                fit_in_bounds(problem, x + step)
+            })
+            .collect();
+        problem.repair(neighbour)
+    }
+}
+
+// gaussian step in one randomly chosen coordinate
+#[derive(Debug, Clone)]
+pub struct SingleCoordinate {
+    sigma: f32,
+}
+
+impl SingleCoordinate {
+    pub fn new(sigma: f32) -> Self {
+        Self { sigma }
+    }
+}
+
+impl<T> Neighbourhood<T> for SingleCoordinate
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    fn get_neighbour(&mut self, problem: &T, input: &[T::Item]) -> Vec<T::Item> {
+        let sigma = <T::Item as Real>::from_f64(f64::from(step_size(problem, self.sigma)));
+        let mut rng = rand::thread_rng();
+        let index = Uniform::new(0, input.len()).sample(&mut rng);
+        let mut output = input.to_vec();
+        output[index] = fit_in_bounds(
+            problem,
+            output[index] + sigma * T::Item::standard_normal(&mut rng),
+        );
+        problem.repair(output)
+    }
+}
+
+// levy flight steps generated by Mantegna's algorithm, beta is kept in [0.3, 2] where the
+// algorithm is accurate, 1.5 is taken for NaN
+#[derive(Debug, Clone)]
+pub struct Levy {
+    scale: f32,
+    beta: f32,
+    sigma_u: f32,
+}
+
+impl Levy {
+    pub fn new(scale: f32, beta: f32) -> Self {
+        let beta = match beta.is_nan() {
+            true => 1.5,
+            false => beta.clamp(0.3, 2f32),
+        };
+        // sin(pi) of beta = 2 rounds below zero in f32
+        let sigma_u = (gamma(1f32 + beta) * (PI * beta / 2f32).sin()
+            / (gamma((1f32 + beta) / 2f32) * beta * 2f32.powf((beta - 1f32) / 2f32)))
+        .abs()
+        .powf(1f32 / beta)
+        .max(f32::EPSILON);
+        Self {
+            scale,
+            beta,
+            sigma_u,
+        }
+    }
+}
+
+impl<T> Neighbourhood<T> for Levy
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    fn get_neighbour(&mut self, problem: &T, input: &[T::Item]) -> Vec<T::Item> {
+        let scale = step_size(problem, self.scale);
+        let u_distribution = Normal::new(0f64, f64::from(self.sigma_u)).unwrap();
+        let v_distribution = Normal::new(0f64, 1f64).unwrap();
+        let mut rng = rand::thread_rng();
+        let neighbour = input
+            .iter()
+            .map(|&x| {
+                let u = u_distribution.sample(&mut rng);
+                let v = v_distribution.sample(&mut rng);
+                let step = f64::from(scale) * u / v.abs().powf(1f64 / f64::from(self.beta));
+                fit_in_bounds(problem, x + <T::Item as Real>::from_f64(step))
+            })
+            .collect();
+        problem.repair(neighbour)
+    }
+}
+
+// every step size of the operators is kept between the machine epsilon and the width of the
+// domain, a NaN or non-positive size therefore never reaches the distributions
+fn step_size<T>(problem: &T, size: f32) -> f32
+where
+    T: ProblemDomain,
+{
+    let width = (problem.get_maximum() - problem.get_minimum())
+        .to_f32()
+        .unwrap_or(f32::MAX);
+    match size.is_nan() {
+        true => f32::EPSILON,
+        false => size.min(width).max(f32::EPSILON),
+    }
+}
+
+// reflects the value back from the violated bound, clamps whatever still does not fit
+pub(crate) fn fit_in_bounds<T>(problem: &T, x: T::Item) -> T::Item
+where
+    T: ProblemDomain,
+    T::Item: Real,
+{
+    let min = problem.get_minimum();
+    let max = problem.get_maximum();
+    let reflected = match (x < min, x > max) {
+        (true, _) => min + (min - x),
+        (_, true) => max - (x - max),
+        (false, false) => x,
+    };
+    reflected.clamp(min, max)
+}
+
+// Lanczos approximation, precise enough for the Levy step scale
+fn gamma(x: f32) -> f32 {
+    const COEFFICIENTS: [f64; 8] = [
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let x = x as f64;
+    if x < 0.5 {
+        return (std::f64::consts::PI
+            / ((std::f64::consts::PI * x).sin() * gamma((1f64 - x) as f32) as f64))
+            as f32;
+    }
+    let x = x - 1f64;
+    let t = x + 7.5;
+    let series = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .fold(0.999_999_999_999_809_9, |acc, (i, c)| {
+            acc + c / (x + i as f64 + 1f64)
+        });
+    ((2f64 * std::f64::consts::PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * series) as f32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{fst_dejong::FstDeJong, traits::HasBuilder};
+    use crate::problem_definitions::HasRandom;
+
+    fn problem() -> FstDeJong {
+        FstDeJong::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(5usize)
+            .build()
+            .unwrap()
+    }
+
+    fn assert_in_range(operator: &mut dyn Neighbourhood<FstDeJong>) {
+        let problem = problem();
+        for input in [problem.get_random(), vec![-5f32; 5], vec![5f32; 5]] {
+            let neighbour = operator.get_neighbour(&problem, &input);
+            assert_eq!(neighbour.len(), 5);
+            assert!(neighbour.into_iter().all(|x| (-5f32..=5f32).contains(&x)));
+        }
+    }
+
+    #[test]
+    fn operators_in_range() {
+        assert_in_range(&mut ProblemLocal);
+        assert_in_range(&mut Gaussian::new(1f32));
+        assert_in_range(&mut Gaussian::adaptive(1f32));
+        assert_in_range(&mut UniformBall::new(1f32));
+        assert_in_range(&mut Cauchy::new(1f32));
+        assert_in_range(&mut SingleCoordinate::new(1f32));
+        assert_in_range(&mut Levy::new(1f32, 1.5));
+    }
+
+    #[test]
+    fn degenerate_step_sizes() {
+        for size in [0f32, -1f32, f32::NAN, f32::INFINITY] {
+            assert_in_range(&mut Gaussian::new(size));
+            assert_in_range(&mut UniformBall::new(size));
+            assert_in_range(&mut Cauchy::new(size));
+            assert_in_range(&mut SingleCoordinate::new(size));
+            assert_in_range(&mut Levy::new(size, 1.5));
+            assert_in_range(&mut Levy::new(1f32, size));
+        }
+    }
+
+    #[test]
+    fn single_coordinate_changes_one() {
+        let problem = problem();
+        let input = vec![0f32; 5];
+        let neighbour = SingleCoordinate::new(1f32).get_neighbour(&problem, &input);
+        assert_eq!(
+            neighbour
+                .iter()
+                .zip(input.iter())
+                .filter(|(a, b)| a != b)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn uniform_ball_within_radius() {
+        let problem = problem();
+        let input = vec![0f32; 5];
+        let mut operator = UniformBall::new(0.5);
+        for _ in 0..100 {
+            let neighbour = operator.get_neighbour(&problem, &input);
+            let distance = neighbour.iter().map(|x| x * x).sum::<f32>().sqrt();
+            assert!(distance <= 0.5 + f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn adaptive_sigma() {
+        let mut growing = Gaussian::adaptive(1f32).set_adaptation_period(10);
+        let mut shrinking = Gaussian::adaptive(1f32).set_adaptation_period(10);
+        for _ in 0..10 {
+            Neighbourhood::<FstDeJong>::report(&mut growing, true);
+            Neighbourhood::<FstDeJong>::report(&mut shrinking, false);
+        }
+        assert!(growing.get_sigma() > 1f32);
+        assert!(shrinking.get_sigma() < 1f32);
+
+        let mut fixed = Gaussian::new(1f32);
+        Neighbourhood::<FstDeJong>::report(&mut fixed, false);
+        assert_eq!(fixed.get_sigma(), 1f32);
+    }
+
+    #[test]
+    fn gamma_values() {
+        assert!((gamma(1f32) - 1f32).abs() < 1e-4);
+        assert!((gamma(5f32) - 24f32).abs() < 1e-3);
+        assert!((gamma(0.5) - PI.sqrt()).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,243 @@
+use std::cmp::Ordering;
+
+use rand_distr::num_traits::Float;
+
+use crate::problem_definitions::{HasRandom, ProblemDomain, Real};
+use crate::solvers::neighbourhood::fit_in_bounds;
+use crate::solvers::traits::Restartable;
+
+const REFLECTION: f64 = 1f64;
+const EXPANSION: f64 = 2f64;
+const CONTRACTION: f64 = 0.5;
+const SHRINK: f64 = 0.5;
+
+// source: https://en.wikipedia.org/wiki/Nelder%E2%80%93Mead_method
+pub struct NelderMead<T>
+where
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
+{
+    max_cf: i32,
+    // edge of the initial simplex as a fraction of the domain width
+    initial_step: f32,
+    // the search ends when the costs in the simplex differ by less than this
+    tolerance: f32,
+    run_max_cf: i32,
+    current_best: Option<T::Item>,
+    current_best_coords: Option<Vec<T::Item>>,
+    cost_history: Vec<T::Item>,
+    cost_function_evaluations: i32,
+    problem: T,
+}
+
+impl<T> NelderMead<T>
+where
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
+{
+    pub fn new(max_cf: i32, problem: T) -> Self {
+        Self {
+            max_cf,
+            initial_step: 0.05,
+            tolerance: 1e-6,
+            run_max_cf: max_cf,
+            current_best: None,
+            current_best_coords: None,
+            cost_history: vec![],
+            cost_function_evaluations: 0,
+            problem,
+        }
+    }
+
+    pub fn set_initial_step(mut self, initial_step: f32) -> Self {
+        self.initial_step = initial_step;
+        self
+    }
+
+    pub fn set_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn run(&mut self) {
+        let start = self.problem.get_random();
+        self.run_max_cf = self.cost_function_evaluations + self.max_cf;
+        self.minimise_from(start);
+    }
+
+    fn minimise_from(&mut self, start: Vec<T::Item>) -> Option<(T::Item, Vec<T::Item>)> {
+        // a restart with no evaluation left would overrun the budget
+        if self.is_budget_exhausted() {
+            return None;
+        }
+        let step = (self.problem.get_maximum() - self.problem.get_minimum())
+            * T::Item::from_f64(f64::from(self.initial_step));
+        let mut simplex = vec![self.evaluate(start.clone())];
+        for i in 0..start.len() {
+            if self.is_budget_exhausted() {
+                break;
+            }
+            let mut vertex = start.clone();
+            vertex[i] = if vertex[i] + step <= self.problem.get_maximum() {
+                vertex[i] + step
+            } else {
+                vertex[i] - step
+            };
+            simplex.push(self.evaluate(self.problem.repair(vertex)));
+        }
+
+        while !self.is_budget_exhausted() && simplex.len() == start.len() + 1 {
+            simplex.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+            self.cost_history.push(simplex[0].0);
+            let worst = simplex.len() - 1;
+            if simplex[worst].0 - simplex[0].0 <= T::Item::from_f64(f64::from(self.tolerance)) {
+                break;
+            }
+
+            let centroid = self.centroid(&simplex[..worst]);
+            let reflected = self.evaluate(self.towards(&centroid, &simplex[worst].1, -REFLECTION));
+            if reflected.0 < simplex[0].0 {
+                if self.is_budget_exhausted() {
+                    simplex[worst] = reflected;
+                    break;
+                }
+                let expanded =
+                    self.evaluate(self.towards(&centroid, &simplex[worst].1, -EXPANSION));
+                simplex[worst] = if expanded.0 < reflected.0 {
+                    expanded
+                } else {
+                    reflected
+                };
+            } else if reflected.0 < simplex[worst - 1].0 {
+                simplex[worst] = reflected;
+            } else {
+                if self.is_budget_exhausted() {
+                    break;
+                }
+                // outside contraction towards the reflected point, inside towards the worst one
+                let base = if reflected.0 < simplex[worst].0 {
+                    reflected.1.clone()
+                } else {
+                    simplex[worst].1.clone()
+                };
+                let contracted = self.evaluate(self.towards(&centroid, &base, CONTRACTION));
+                if contracted.0 < reflected.0.min(simplex[worst].0) {
+                    simplex[worst] = contracted;
+                } else {
+                    let best = simplex[0].1.clone();
+                    for vertex in simplex.iter_mut().skip(1) {
+                        if self.is_budget_exhausted() {
+                            break;
+                        }
+                        *vertex = self.evaluate(self.towards(&best, &vertex.1, SHRINK));
+                    }
+                }
+            }
+        }
+
+        let (best_cost, best_coords) = simplex
+            .into_iter()
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal))
+            .unwrap();
+        self.current_best = Some(best_cost);
+        self.current_best_coords = Some(best_coords.clone());
+        Some((best_cost, best_coords))
+    }
+
+    fn evaluate(&mut self, coords: Vec<T::Item>) -> (T::Item, Vec<T::Item>) {
+        self.cost_function_evaluations += 1;
+        (self.problem.cost_function(&coords), coords)
+    }
+
+    fn centroid(&self, vertices: &[(T::Item, Vec<T::Item>)]) -> Vec<T::Item> {
+        let count = T::Item::from_f64(vertices.len() as f64);
+        (0..vertices[0].1.len())
+            .map(|i| vertices.iter().map(|(_, v)| v[i]).sum::<T::Item>() / count)
+            .collect()
+    }
+
+    // point origin + coefficient * (target - origin), kept inside the domain
+    fn towards(&self, origin: &[T::Item], target: &[T::Item], coefficient: f64) -> Vec<T::Item> {
+        let coefficient = T::Item::from_f64(coefficient);
+        let point = origin
+            .iter()
+            .zip(target.iter())
+            .map(|(&o, &t)| fit_in_bounds(&self.problem, o + coefficient * (t - o)))
+            .collect();
+        self.problem.repair(point)
+    }
+
+    fn is_budget_exhausted(&self) -> bool {
+        self.cost_function_evaluations >= self.run_max_cf || self.problem.is_exhausted()
+    }
+
+    pub fn get_history(&self) -> &[T::Item] {
+        &self.cost_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<T::Item> {
+        self.current_best
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[T::Item]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+impl<T> Restartable for NelderMead<T>
+where
+    T: ProblemDomain + HasRandom,
+    T::Item: Real,
+{
+    type Problem = T;
+
+    fn get_problem(&self) -> &T {
+        &self.problem
+    }
+
+    fn run_from(&mut self, start: Vec<T::Item>, max_cf: i32) -> Option<(T::Item, Vec<T::Item>)> {
+        self.run_max_cf = self.cost_function_evaluations + max_cf.min(self.max_cf);
+        self.minimise_from(start)
+    }
+
+    fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{fst_dejong::FstDeJong, traits::HasBuilder};
+
+    #[test]
+    fn sphere() {
+        let problem = FstDeJong::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(3)
+            .build()
+            .unwrap();
+        let mut nelder_mead = NelderMead::new(2000, problem);
+        nelder_mead.run();
+        assert!(nelder_mead.get_cost_function_evaluations() <= 2000);
+        assert!(nelder_mead.get_best_cost().unwrap() < 1e-3);
+    }
+
+    #[test]
+    fn run_from_budget() {
+        let problem = FstDeJong::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(3)
+            .build()
+            .unwrap();
+        let mut nelder_mead = NelderMead::new(2000, problem).set_tolerance(0f32);
+        nelder_mead.run_from(vec![1f32, 2f32, 3f32], 50);
+        assert_eq!(nelder_mead.get_cost_function_evaluations(), 50);
+    }
+}
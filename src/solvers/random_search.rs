@@ -8,6 +8,7 @@ where
     current_best: Option<T::Item>,
     current_best_coords: Option<Vec<T::Item>>,
     cost_history: Vec<T::Item>,
+    cost_function_evaluations: i32,
     problem: T,
 }
 
@@ -21,20 +22,24 @@ where
             current_best: None,
             current_best_coords: None,
             cost_history: vec![],
+            cost_function_evaluations: 0,
             problem,
         }
     }
 
     pub fn run(&mut self) -> () {
         let start_input = self.problem.get_random();
-        let start_cost = self.problem.cost_function(&start_input);
+        let start_cost = self.run_cost_fn(&start_input);
         self.current_best = Some(start_cost.clone());
         self.current_best_coords = Some(start_input);
         self.cost_history.push(start_cost);
 
         for _ in 1..self.max_iter {
+            if self.problem.is_exhausted() {
+                break;
+            }
             let local_input = self.problem.get_random();
-            let local_cost = self.problem.cost_function(&local_input);
+            let local_cost = self.run_cost_fn(&local_input);
             match &mut self.current_best {
                 None => {
                     self.current_best = Some(local_cost.clone());
@@ -51,6 +56,11 @@ where
         }
     }
 
+    fn run_cost_fn(&mut self, input: &[T::Item]) -> T::Item {
+        self.cost_function_evaluations += 1;
+        self.problem.cost_function(input)
+    }
+
     pub fn get_history(&self) -> &[T::Item] {
         &self.cost_history
     }
@@ -58,6 +68,14 @@ where
     pub fn get_best_cost(&self) -> Option<T::Item> {
         self.current_best.clone()
     }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+
+    pub fn get_problem(&self) -> &T {
+        &self.problem
+    }
 }
 
 #[cfg(test)]
@@ -105,6 +123,7 @@ mod test {
         let mut random_search = RandomSearch::new(1000, mocked_problem);
         random_search.run();
         assert_eq!(random_search.cost_history.len(), 1000);
+        assert_eq!(random_search.get_cost_function_evaluations(), 1000);
     }
 
     #[test]
@@ -0,0 +1,253 @@
+use rand_distr::num_traits::ToPrimitive;
+
+use crate::problem_definitions::{HasRandom, ProblemDomain};
+use crate::solvers::neighbourhood::Neighbourhood;
+use crate::solvers::simulated_annealing::metropolis_accepts;
+use crate::solvers::traits::Restartable;
+
+type Item<S> = <<S as Restartable>::Problem as ProblemDomain>::Item;
+
+// runs the wrapped solver from random starting points until the shared budget is spent
+pub struct RandomRestart<S>
+where
+    S: Restartable,
+    S::Problem: HasRandom,
+{
+    max_cf: i32,
+    current_best: Option<Item<S>>,
+    current_best_coords: Option<Vec<Item<S>>>,
+    restarts_history: Vec<Item<S>>,
+    cost_function_evaluations: i32,
+    solver: S,
+}
+
+impl<S> RandomRestart<S>
+where
+    S: Restartable,
+    S::Problem: HasRandom,
+{
+    pub fn new(max_cf: i32, solver: S) -> Self {
+        Self {
+            max_cf,
+            current_best: None,
+            current_best_coords: None,
+            restarts_history: vec![],
+            cost_function_evaluations: 0,
+            solver,
+        }
+    }
+
+    pub fn run(&mut self) {
+        let start_evaluations = self.solver.get_cost_function_evaluations();
+        while self.cost_function_evaluations < self.max_cf {
+            let start = self.solver.get_problem().get_random();
+            let Some((cost, coords)) = self
+                .solver
+                .run_from(start, self.max_cf - self.cost_function_evaluations)
+            else {
+                break;
+            };
+            self.cost_function_evaluations =
+                self.solver.get_cost_function_evaluations() - start_evaluations;
+            if self.current_best.as_ref().is_none_or(|best| &cost < best) {
+                self.current_best = Some(cost.clone());
+                self.current_best_coords = Some(coords);
+            }
+            self.restarts_history.push(cost);
+        }
+    }
+
+    // best cost of every restart
+    pub fn get_history(&self) -> &[Item<S>] {
+        &self.restarts_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<Item<S>> {
+        self.current_best.clone()
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[Item<S>]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+
+    pub fn get_solver(&self) -> &S {
+        &self.solver
+    }
+}
+
+pub enum IlsAcceptance {
+    // continues from the new local optimum only if it is better
+    Better,
+    // always continues from the new local optimum
+    Always,
+    // metropolis criterion with the given temperature
+    Metropolis(f32),
+}
+
+// perturbs the current local optimum, runs the wrapped solver from the perturbed
+// point and decides by the acceptance criterion where to continue
+pub struct IteratedLocalSearch<S>
+where
+    S: Restartable,
+    S::Problem: HasRandom,
+{
+    max_cf: i32,
+    acceptance: IlsAcceptance,
+    current_best: Option<Item<S>>,
+    current_best_coords: Option<Vec<Item<S>>>,
+    cost_history: Vec<Item<S>>,
+    cost_function_evaluations: i32,
+    perturbation: Box<dyn Neighbourhood<S::Problem>>,
+    solver: S,
+}
+
+impl<S> IteratedLocalSearch<S>
+where
+    S: Restartable,
+    S::Problem: HasRandom,
+{
+    pub fn new(
+        max_cf: i32,
+        perturbation: impl Neighbourhood<S::Problem> + 'static,
+        solver: S,
+    ) -> Self {
+        Self {
+            max_cf,
+            acceptance: IlsAcceptance::Better,
+            current_best: None,
+            current_best_coords: None,
+            cost_history: vec![],
+            cost_function_evaluations: 0,
+            perturbation: Box::new(perturbation),
+            solver,
+        }
+    }
+
+    pub fn set_acceptance(mut self, acceptance: IlsAcceptance) -> Self {
+        self.acceptance = acceptance;
+        self
+    }
+
+    pub fn run(&mut self) {
+        let start_evaluations = self.solver.get_cost_function_evaluations();
+        let start = self.solver.get_problem().get_random();
+        let Some((mut current, mut current_coords)) = self.solver.run_from(start, self.max_cf)
+        else {
+            return;
+        };
+        self.cost_function_evaluations =
+            self.solver.get_cost_function_evaluations() - start_evaluations;
+        self.current_best = Some(current.clone());
+        self.current_best_coords = Some(current_coords.clone());
+        self.cost_history.push(current.clone());
+
+        while self.cost_function_evaluations < self.max_cf {
+            let perturbed = self
+                .perturbation
+                .get_neighbour(self.solver.get_problem(), &current_coords);
+            let Some((cost, coords)) = self
+                .solver
+                .run_from(perturbed, self.max_cf - self.cost_function_evaluations)
+            else {
+                break;
+            };
+            self.cost_function_evaluations =
+                self.solver.get_cost_function_evaluations() - start_evaluations;
+            self.perturbation.report(cost < current);
+
+            if self.current_best.as_ref().is_none_or(|best| &cost < best) {
+                self.current_best = Some(cost.clone());
+                self.current_best_coords = Some(coords.clone());
+            }
+            if self.accepts(&cost, &current) {
+                current = cost.clone();
+                current_coords = coords;
+            }
+            self.cost_history.push(cost);
+        }
+    }
+
+    fn accepts(&self, candidate: &Item<S>, current: &Item<S>) -> bool {
+        match self.acceptance {
+            IlsAcceptance::Better => candidate < current,
+            IlsAcceptance::Always => true,
+            IlsAcceptance::Metropolis(temperature) => {
+                let difference = (candidate.clone() - current.clone())
+                    .to_f32()
+                    .unwrap_or(f32::INFINITY);
+                metropolis_accepts(difference, temperature)
+            }
+        }
+    }
+
+    // cost of every local optimum found
+    pub fn get_history(&self) -> &[Item<S>] {
+        &self.cost_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<Item<S>> {
+        self.current_best.clone()
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[Item<S>]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+
+    pub fn get_solver(&self) -> &S {
+        &self.solver
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{fst_dejong::FstDeJong, traits::HasBuilder};
+    use crate::solvers::hill_climber::HillClimber;
+    use crate::solvers::local_search::{Acceptance, LocalSearch};
+    use crate::solvers::neighbourhood::Gaussian;
+
+    fn problem() -> FstDeJong {
+        FstDeJong::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(5)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn random_restart_budget() {
+        let mut restart = RandomRestart::new(5000, LocalSearch::new(10, problem()));
+        restart.run();
+        assert_eq!(restart.get_cost_function_evaluations(), 5000);
+        assert_eq!(restart.get_solver().get_cost_function_evaluations(), 5000);
+        assert!(restart.get_history().len() > 1);
+        assert!(restart
+            .get_history()
+            .iter()
+            .all(|cost| *cost >= restart.get_best_cost().unwrap()));
+    }
+
+    #[test]
+    fn iterated_local_search_budget() {
+        let solver =
+            HillClimber::new(50, 10, problem()).set_acceptance(Acceptance::FirstImprovement);
+        let mut ils = IteratedLocalSearch::new(3000, Gaussian::new(1f32), solver)
+            .set_acceptance(IlsAcceptance::Metropolis(1f32));
+        ils.run();
+        assert_eq!(ils.get_cost_function_evaluations(), 3000);
+        assert!(ils.get_best_coords().is_some());
+        assert!(ils
+            .get_history()
+            .iter()
+            .all(|cost| *cost >= ils.get_best_cost().unwrap()));
+    }
+}
@@ -2,7 +2,9 @@ use std::f32::consts::E;
 
 use rand_distr::{Distribution, Uniform};
 
+use crate::constraints::{evaluation, total_violation, ConstraintHandler, ConstraintHandling};
 use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+use crate::solvers::neighbourhood::{Neighbourhood, ProblemLocal};
 
 pub struct SimulatedAnnealing<T>
 where
@@ -13,8 +15,12 @@ where
     step: f32,
     current_temp: f32,
     current_best: Option<T::Item>,
+    current_best_violation: f64,
     current_best_coords: Option<Vec<T::Item>>,
     cost_history: Vec<T::Item>,
+    constraint_handler: ConstraintHandler,
+    neighbourhood: Box<dyn Neighbourhood<T>>,
+    cost_function_evaluations: i32,
     problem: T,
 }
 
@@ -29,56 +35,74 @@ where
             step,
             current_temp: max_temp,
             current_best: None,
+            current_best_violation: 0f64,
             current_best_coords: None,
             cost_history: vec![],
+            constraint_handler: ConstraintHandler::default(),
+            neighbourhood: Box::new(ProblemLocal),
+            cost_function_evaluations: 0,
             problem,
         }
     }
 
+    pub fn set_neighbourhood(mut self, neighbourhood: impl Neighbourhood<T> + 'static) -> Self {
+        self.neighbourhood = Box::new(neighbourhood);
+        self
+    }
+
+    // the acceptance compares costs and violations through the technique, feasibility rules
+    // by default, which reduce to the cost difference when nothing is violated
+    pub fn set_constraint_handling(mut self, technique: ConstraintHandling) -> Self {
+        self.constraint_handler = ConstraintHandler::new(technique);
+        self
+    }
+
     pub fn run(&mut self) -> () {
         let start_input = self.problem.get_random();
-        let start_cost = self.problem.cost_function(&start_input);
+        let start_cost = self.run_cost_fn(&start_input);
         let mut current_best = start_cost.clone();
+        let mut current_best_violation = total_violation(&self.problem, &start_input);
         let mut current_best_coords = start_input;
         self.cost_history.push(start_cost);
 
-        while self.current_temp >= self.min_temp {
+        while self.current_temp >= self.min_temp && !self.problem.is_exhausted() {
             for _ in 0..self.max_local_iter {
-                let local_coords = self.problem.get_local_next(&current_best_coords);
-                let local_cost = self.problem.cost_function(&local_coords);
-                let metro_result = self.metropolis(local_cost.clone());
-                if metro_result == 1 {
+                if self.problem.is_exhausted() {
+                    break;
+                }
+                let local_coords = self
+                    .neighbourhood
+                    .get_neighbour(&self.problem, &current_best_coords);
+                let local_cost = self.run_cost_fn(&local_coords);
+                let local_violation = total_violation(&self.problem, &local_coords);
+                let local = evaluation(&local_cost, local_violation);
+                let current = evaluation(&current_best, current_best_violation);
+                self.neighbourhood
+                    .report(self.constraint_handler.is_better(local, current));
+                if self.metropolis(local, current) {
                     current_best = local_cost.clone();
+                    current_best_violation = local_violation;
                     current_best_coords = local_coords;
                 }
                 self.cost_history.push(current_best.clone());
             }
+            self.constraint_handler
+                .next_generation(current_best_violation == 0f64);
             self.current_temp = self.current_temp * self.step;
         }
         self.current_best = Some(current_best);
+        self.current_best_violation = current_best_violation;
         self.current_best_coords = Some(current_best_coords);
     }
 
-    fn metropolis(&self, new_cost: T::Item) -> i32 {
-        if let (Some(current_best), Some(_)) =
-            (self.current_best.clone(), self.current_best_coords.clone())
-        {
-            let difference =
-                Into::<f32>::into(new_cost.clone()) - Into::<f32>::into(current_best.clone());
-            if difference < 0f32 {
-                1
-            } else {
-                let probability = 1f32 / E.powf(difference / self.current_temp);
-                let roll = Uniform::new(0f32, 1f32).sample(&mut (rand::thread_rng()));
-                if roll < probability {
-                    1
-                } else {
-                    -1
-                }
-            }
-        } else {
-            1
-        }
+    fn run_cost_fn(&mut self, input: &[T::Item]) -> T::Item {
+        self.cost_function_evaluations += 1;
+        self.problem.cost_function(input)
+    }
+
+    fn metropolis(&self, new: (f64, f64), current: (f64, f64)) -> bool {
+        let difference = self.constraint_handler.difference(new, current) as f32;
+        metropolis_accepts(difference, self.current_temp)
     }
 
     pub fn get_history(&self) -> &[T::Item] {
@@ -88,6 +112,36 @@ where
     pub fn get_best_cost(&self) -> Option<T::Item> {
         self.current_best.clone()
     }
+
+    pub fn get_best_violation(&self) -> f64 {
+        self.current_best_violation
+    }
+
+    pub fn is_best_feasible(&self) -> bool {
+        self.current_best_violation == 0f64
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[T::Item]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+
+    pub fn get_problem(&self) -> &T {
+        &self.problem
+    }
+}
+
+pub fn metropolis_accepts(difference: f32, temperature: f32) -> bool {
+    if difference < 0f32 {
+        true
+    } else {
+        let probability = 1f32 / E.powf(difference / temperature);
+        let roll = Uniform::new(0f32, 1f32).sample(&mut (rand::thread_rng()));
+        roll < probability
+    }
 }
 
 #[cfg(test)]
@@ -143,6 +197,22 @@ mod test {
         let mut sa = SimulatedAnnealing::new(10, 1000f32, 0.1, 0.98, mocked_problem);
         sa.run();
         assert_ne!(sa.cost_history.len(), 0);
+        assert_eq!(
+            sa.get_cost_function_evaluations() as usize,
+            sa.cost_history.len()
+        );
+    }
+
+    #[test]
+    fn constrained() {
+        let problem =
+            crate::problem_definitions::FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| x[0] + x[1])
+                .set_inequality_constraints(|x: &[f64]| vec![x[0].powi(2) + x[1].powi(2) - 4f64]);
+        let mut sa = SimulatedAnnealing::new(100, 1f32, 0.001, 0.9, problem)
+            .set_constraint_handling(ConstraintHandling::StaticPenalty { weight: 100f64 });
+        sa.run();
+        assert!(sa.get_best_violation() < 0.1);
+        assert!(sa.get_best_cost().unwrap() < 0f64);
     }
 
     //     #[test]
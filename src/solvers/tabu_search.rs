@@ -0,0 +1,232 @@
+use std::collections::VecDeque;
+
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain};
+use crate::solvers::neighbourhood::{Neighbourhood, ProblemLocal};
+use crate::solvers::traits::Restartable;
+
+// moves every iteration to the best sampled neighbour which is not tabu, even when it is worse,
+// the last tenure visited solutions are tabu unless they improve the best solution found
+// source: https://doi.org/10.1287/ijoc.1.3.190
+pub struct TabuSearch<T>
+where
+    T: ProblemDomain + HasLocal + HasRandom,
+{
+    max_iter: i32,
+    max_local_iter: i32,
+    tenure: usize,
+    max_cf: Option<i32>,
+    tabu_list: VecDeque<Vec<T::Item>>,
+    current_best: Option<T::Item>,
+    current_best_coords: Option<Vec<T::Item>>,
+    cost_history: Vec<T::Item>,
+    cost_function_evaluations: i32,
+    neighbourhood: Box<dyn Neighbourhood<T>>,
+    problem: T,
+}
+
+impl<T> TabuSearch<T>
+where
+    T: ProblemDomain + HasRandom + HasLocal,
+{
+    pub fn new(max_iter: i32, max_local_iter: i32, tenure: usize, problem: T) -> Self {
+        TabuSearch {
+            max_iter,
+            max_local_iter,
+            tenure,
+            max_cf: None,
+            tabu_list: VecDeque::new(),
+            current_best: None,
+            current_best_coords: None,
+            cost_history: vec![],
+            cost_function_evaluations: 0,
+            neighbourhood: Box::new(ProblemLocal),
+            problem,
+        }
+    }
+
+    pub fn set_neighbourhood(mut self, neighbourhood: impl Neighbourhood<T> + 'static) -> Self {
+        self.neighbourhood = Box::new(neighbourhood);
+        self
+    }
+
+    pub fn set_max_cf(mut self, max_cf: i32) -> Self {
+        self.max_cf = Some(max_cf);
+        self
+    }
+
+    pub fn run(&mut self) {
+        let start_input = self.problem.get_random();
+        self.search_from(start_input);
+    }
+
+    fn search_from(&mut self, start_input: Vec<T::Item>) -> Option<(T::Item, Vec<T::Item>)> {
+        // a restart with no evaluation left would overrun the budget
+        if self.is_budget_exhausted() {
+            return None;
+        }
+        let start_cost = self.run_cost_fn(&start_input);
+        let mut current_coords = start_input;
+        let mut current_best = start_cost.clone();
+        let mut current_best_coords = current_coords.clone();
+        self.tabu_list.clear();
+        self.make_tabu(current_coords.clone());
+        self.cost_history.push(start_cost);
+
+        for _ in 0..self.max_iter {
+            let Some((local_best, local_best_coords)) =
+                self.evaluate_local(&current_coords, &current_best)
+            else {
+                break;
+            };
+            current_coords = local_best_coords;
+            self.make_tabu(current_coords.clone());
+            if local_best < current_best {
+                current_best = local_best.clone();
+                current_best_coords = current_coords.clone();
+            }
+            self.cost_history.push(local_best);
+        }
+        self.current_best = Some(current_best.clone());
+        self.current_best_coords = Some(current_best_coords.clone());
+        Some((current_best, current_best_coords))
+    }
+
+    // best admissible neighbour, None when every sampled neighbour is tabu
+    fn evaluate_local(
+        &mut self,
+        input: &[T::Item],
+        best_cost: &T::Item,
+    ) -> Option<(T::Item, Vec<T::Item>)> {
+        let mut local_best: Option<(T::Item, Vec<T::Item>)> = None;
+
+        for _ in 0..self.max_local_iter {
+            if self.is_budget_exhausted() {
+                break;
+            }
+            let new_local_coords = self.neighbourhood.get_neighbour(&self.problem, input);
+            let new_local = self.run_cost_fn(&new_local_coords);
+            let improved = &new_local < best_cost;
+            self.neighbourhood.report(improved);
+            if !improved && self.tabu_list.contains(&new_local_coords) {
+                continue;
+            }
+            if local_best
+                .as_ref()
+                .is_none_or(|(best, _)| &new_local < best)
+            {
+                local_best = Some((new_local, new_local_coords));
+            }
+        }
+        local_best
+    }
+
+    fn make_tabu(&mut self, coords: Vec<T::Item>) {
+        self.tabu_list.push_back(coords);
+        while self.tabu_list.len() > self.tenure {
+            self.tabu_list.pop_front();
+        }
+    }
+
+    fn run_cost_fn(&mut self, input: &[T::Item]) -> T::Item {
+        self.cost_function_evaluations += 1;
+        self.problem.cost_function(input)
+    }
+
+    fn is_budget_exhausted(&self) -> bool {
+        self.max_cf
+            .is_some_and(|max_cf| self.cost_function_evaluations >= max_cf)
+            || self.problem.is_exhausted()
+    }
+
+    pub fn get_history(&self) -> &[T::Item] {
+        &self.cost_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<T::Item> {
+        self.current_best.clone()
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[T::Item]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+impl<T> Restartable for TabuSearch<T>
+where
+    T: ProblemDomain + HasRandom + HasLocal,
+{
+    type Problem = T;
+
+    fn get_problem(&self) -> &T {
+        &self.problem
+    }
+
+    fn run_from(&mut self, start: Vec<T::Item>, max_cf: i32) -> Option<(T::Item, Vec<T::Item>)> {
+        let global_max_cf = self.max_cf;
+        self.max_cf = Some(self.cost_function_evaluations + max_cf);
+        let result = self.search_from(start);
+        self.max_cf = global_max_cf;
+        result
+    }
+
+    fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::combinatorial::permutation::{is_permutation, PermutationMove};
+    use crate::combinatorial::tsp::{EdgeWeightType, Tsp};
+    use std::f64::consts::PI;
+
+    // the optimal tour visits the cities in the order around the circle
+    fn circle(n: usize) -> Tsp {
+        let coordinates: Vec<(f64, f64)> = (0..n)
+            .map(|i| {
+                let angle = 2f64 * PI * i as f64 / n as f64;
+                (100f64 * angle.cos(), 100f64 * angle.sin())
+            })
+            .collect();
+        Tsp::from_coordinates(&coordinates, EdgeWeightType::Euc2d).unwrap()
+    }
+
+    #[test]
+    fn finds_circle_tour() {
+        let tsp = circle(10);
+        let optimum = tsp.tour_length(&(0..10).collect::<Vec<i64>>());
+        let mut tabu = TabuSearch::new(300, 40, 20, tsp);
+        tabu.run();
+        assert!(is_permutation(tabu.get_best_coords().unwrap()));
+        assert_eq!(tabu.get_best_cost(), Some(optimum));
+    }
+
+    #[test]
+    fn keeps_best() {
+        let mut tabu =
+            TabuSearch::new(200, 5, 10, circle(12)).set_neighbourhood(PermutationMove::Swap);
+        tabu.run();
+        let best = tabu.get_best_cost().unwrap();
+        assert!(best <= tabu.get_history()[0]);
+        assert!(tabu.get_history().iter().all(|cost| *cost >= best));
+    }
+
+    #[test]
+    fn max_cf() {
+        let mut tabu = TabuSearch::new(1000, 10, 10, circle(12)).set_max_cf(500);
+        tabu.run();
+        assert_eq!(tabu.get_cost_function_evaluations(), 500);
+    }
+
+    #[test]
+    fn tenure() {
+        let mut tabu = TabuSearch::new(50, 5, 3, circle(8));
+        tabu.run();
+        assert!(tabu.tabu_list.len() <= 3);
+    }
+}
@@ -1 +1,21 @@
+use crate::problem_definitions::ProblemDomain;
 
+// best cost and coordinates found by one search
+type Found<P> = (<P as ProblemDomain>::Item, Vec<<P as ProblemDomain>::Item>);
+
+// solvers which can be started from a given point, used by the restart drivers
+pub trait Restartable {
+    type Problem: ProblemDomain;
+
+    fn get_problem(&self) -> &Self::Problem;
+
+    // runs one search using at most max_cf evaluations, returns its best cost and coordinates,
+    // None when no evaluation is left for the start point
+    fn run_from(
+        &mut self,
+        start: Vec<<Self::Problem as ProblemDomain>::Item>,
+        max_cf: i32,
+    ) -> Option<Found<Self::Problem>>;
+
+    fn get_cost_function_evaluations(&self) -> i32;
+}
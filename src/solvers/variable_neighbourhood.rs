@@ -0,0 +1,136 @@
+use crate::problem_definitions::{HasRandom, ProblemDomain, Real};
+use crate::solvers::neighbourhood::{Neighbourhood, UniformBall};
+use crate::solvers::traits::Restartable;
+
+type Item<S> = <<S as Restartable>::Problem as ProblemDomain>::Item;
+
+// basic variable neighbourhood search, the wrapped solver is the local search phase
+// source: https://en.wikipedia.org/wiki/Variable_neighborhood_search
+pub struct VariableNeighbourhoodSearch<S>
+where
+    S: Restartable,
+    S::Problem: HasRandom,
+    Item<S>: Real,
+{
+    max_cf: i32,
+    // shaking radii ordered from the smallest neighbourhood to the largest
+    radii: Vec<f32>,
+    current_best: Option<Item<S>>,
+    current_best_coords: Option<Vec<Item<S>>>,
+    cost_history: Vec<Item<S>>,
+    neighbourhood_history: Vec<usize>,
+    cost_function_evaluations: i32,
+    solver: S,
+}
+
+impl<S> VariableNeighbourhoodSearch<S>
+where
+    S: Restartable,
+    S::Problem: HasRandom,
+    Item<S>: Real,
+{
+    pub fn new(max_cf: i32, radii: Vec<f32>, solver: S) -> Self {
+        assert!(
+            !radii.is_empty(),
+            "at least one neighbourhood radius is needed"
+        );
+        Self {
+            max_cf,
+            radii,
+            current_best: None,
+            current_best_coords: None,
+            cost_history: vec![],
+            neighbourhood_history: vec![],
+            cost_function_evaluations: 0,
+            solver,
+        }
+    }
+
+    pub fn run(&mut self) {
+        let start_evaluations = self.solver.get_cost_function_evaluations();
+        let start = self.solver.get_problem().get_random();
+        let Some((mut current, mut current_coords)) = self.solver.run_from(start, self.max_cf)
+        else {
+            return;
+        };
+        self.cost_function_evaluations =
+            self.solver.get_cost_function_evaluations() - start_evaluations;
+        self.cost_history.push(current);
+
+        let mut k = 0;
+        while self.cost_function_evaluations < self.max_cf {
+            let shaken = UniformBall::new(self.radii[k])
+                .get_neighbour(self.solver.get_problem(), &current_coords);
+            let Some((cost, coords)) = self
+                .solver
+                .run_from(shaken, self.max_cf - self.cost_function_evaluations)
+            else {
+                break;
+            };
+            self.cost_function_evaluations =
+                self.solver.get_cost_function_evaluations() - start_evaluations;
+            self.neighbourhood_history.push(k);
+
+            if cost < current {
+                current = cost;
+                current_coords = coords;
+                k = 0;
+            } else {
+                k = (k + 1) % self.radii.len();
+            }
+            self.cost_history.push(current);
+        }
+        self.current_best = Some(current);
+        self.current_best_coords = Some(current_coords);
+    }
+
+    pub fn get_history(&self) -> &[Item<S>] {
+        &self.cost_history
+    }
+
+    // index of the shaking neighbourhood used in every iteration
+    pub fn get_neighbourhood_history(&self) -> &[usize] {
+        &self.neighbourhood_history
+    }
+
+    pub fn get_best_cost(&self) -> Option<Item<S>> {
+        self.current_best
+    }
+
+    pub fn get_best_coords(&self) -> Option<&[Item<S>]> {
+        self.current_best_coords.as_deref()
+    }
+
+    pub fn get_cost_function_evaluations(&self) -> i32 {
+        self.cost_function_evaluations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::{rastrigin::Rastrigin, traits::HasBuilder};
+    use crate::solvers::local_search::LocalSearch;
+
+    #[test]
+    fn budget_and_neighbourhoods() {
+        let problem = Rastrigin::builder()
+            .minimum(-5.12)
+            .maximum(5.12)
+            .dimensions(5)
+            .build()
+            .unwrap();
+        let mut vns = VariableNeighbourhoodSearch::new(
+            5000,
+            vec![0.5, 1f32, 2f32],
+            LocalSearch::new(10, problem),
+        );
+        vns.run();
+        assert_eq!(vns.get_cost_function_evaluations(), 5000);
+        assert!(vns.get_neighbourhood_history().iter().all(|k| *k < 3));
+        assert!(vns
+            .get_history()
+            .windows(2)
+            .all(|costs| costs[1] <= costs[0]));
+    }
+}
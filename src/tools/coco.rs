@@ -0,0 +1,302 @@
+use std::{
+    cell::RefCell,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use crate::benchmarks::{bbob::noiseless::Bbob, traits::Benchmark};
+
+// target precision of the BBOB experiments
+const PRECISION: f64 = 1e-8;
+// number of logged targets and evaluation triggers per decade
+const PER_DECADE: f64 = 5.0;
+
+#[derive(Debug, Default)]
+struct LoggerState {
+    evaluations: usize,
+    best: Option<f64>,
+    // exponent of the last logged target, the next line needs to get below it
+    target_level: Option<i32>,
+    trigger_level: i32,
+    last_line: Option<String>,
+    dat: Vec<String>,
+    tdat: Vec<String>,
+    finished: bool,
+}
+
+// writes the evaluations of a BBOB function in the (old) COCO format, the output folder can be
+// post-processed by cocopp, one logger covers one run on one instance
+// source: https://numbbo.github.io/coco-doc/bbob-biobj/perf-assessment/#data-format
+#[derive(Debug)]
+pub struct CocoLogger<const F: usize> {
+    problem: Bbob<F>,
+    folder: PathBuf,
+    algorithm: String,
+    state: RefCell<LoggerState>,
+}
+
+impl<const F: usize> CocoLogger<F> {
+    pub fn new(problem: Bbob<F>, folder: impl Into<PathBuf>, algorithm: &str) -> Self {
+        Self {
+            problem,
+            folder: folder.into(),
+            algorithm: algorithm.to_string(),
+            state: RefCell::new(LoggerState::default()),
+        }
+    }
+
+    pub fn get_problem(&self) -> &Bbob<F> {
+        &self.problem
+    }
+
+    pub fn get_evaluations(&self) -> usize {
+        self.state.borrow().evaluations
+    }
+
+    pub fn get_best_precision(&self) -> Option<f64> {
+        self.state
+            .borrow()
+            .best
+            .map(|best| best - self.problem.get_fopt())
+    }
+
+    fn data_name(&self, extension: &str) -> String {
+        format!(
+            "data_f{}/bbobexp_f{}_DIM{}.{}",
+            F,
+            F,
+            self.problem.get_dim(),
+            extension
+        )
+    }
+
+    fn header(&self) -> String {
+        let coordinates: Vec<String> = (1..=self.problem.get_dim())
+            .map(|i| format!("x{}", i))
+            .collect();
+        format!(
+            "% function evaluation | noise-free fitness - Fopt ({}) | best noise-free fitness - Fopt | measured fitness | best measured fitness | {}",
+            scientific(self.problem.get_fopt(), 12),
+            coordinates.join(" | ")
+        )
+    }
+
+    fn log(&self, input: &[f64], value: f64) {
+        let mut state = self.state.borrow_mut();
+        let fopt = self.problem.get_fopt();
+        state.evaluations += 1;
+        let best = state.best.map_or(value, |best| best.min(value));
+        state.best = Some(best);
+
+        let coordinates: Vec<String> = input.iter().map(|x| scientific(*x, 4)).collect();
+        let line = format!(
+            "{} {} {} {} {} {}",
+            state.evaluations,
+            scientific(value - fopt, 9),
+            scientific(best - fopt, 9),
+            scientific(value, 9),
+            scientific(best, 9),
+            coordinates.join(" ")
+        );
+
+        let level = ((best - fopt).max(PRECISION).log10() * PER_DECADE).floor() as i32;
+        if state.target_level.is_none_or(|target| level < target) {
+            state.target_level = Some(level);
+            state.dat.push(line.clone());
+        }
+
+        let trigger =
+            self.problem.get_dim() as f64 * 10f64.powf(f64::from(state.trigger_level) / PER_DECADE);
+        if state.evaluations == 1 || state.evaluations as f64 >= trigger {
+            while state.evaluations as f64
+                >= self.problem.get_dim() as f64
+                    * 10f64.powf(f64::from(state.trigger_level) / PER_DECADE)
+            {
+                state.trigger_level += 1;
+            }
+            state.tdat.push(line.clone());
+        }
+        state.last_line = Some(line);
+    }
+
+    // appends the run to the data files and the .info file, called on drop as well
+    pub fn finish(&self) -> io::Result<()> {
+        let mut state = self.state.borrow_mut();
+        if state.finished {
+            return Ok(());
+        }
+        state.finished = true;
+        let Some(last_line) = state.last_line.clone() else {
+            return Ok(());
+        };
+        if state.dat.last() != Some(&last_line) {
+            state.dat.push(last_line.clone());
+        }
+        if state.tdat.last() != Some(&last_line) {
+            state.tdat.push(last_line);
+        }
+
+        fs::create_dir_all(self.folder.join(format!("data_f{}", F)))?;
+        let header = self.header();
+        for (extension, lines) in [("dat", &state.dat), ("tdat", &state.tdat)] {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.folder.join(self.data_name(extension)))?;
+            writeln!(file, "{}", header)?;
+            for line in lines.iter() {
+                writeln!(file, "{}", line)?;
+            }
+        }
+
+        let mut info = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.folder.join(format!("bbobexp_f{}.info", F)))?;
+        writeln!(
+            info,
+            "funcId = {}, DIM = {}, Precision = {}, algId = '{}'",
+            F,
+            self.problem.get_dim(),
+            scientific(PRECISION, 3).trim_start_matches('+'),
+            self.algorithm
+        )?;
+        writeln!(info, "% {}", Bbob::<F>::FUNCTION_NAME)?;
+        writeln!(
+            info,
+            "{}, {}:{}|{}",
+            self.data_name("dat"),
+            self.problem.get_instance(),
+            state.evaluations,
+            scientific(
+                state.best.unwrap_or(f64::INFINITY) - self.problem.get_fopt(),
+                1
+            )
+            .trim_start_matches('+')
+        )
+    }
+}
+
+// C style exponent with sign and at least two digits, e.g. +1.500e-08
+fn scientific(value: f64, precision: usize) -> String {
+    let formatted = format!("{:+.*e}", precision, value);
+    match formatted.split_once('e') {
+        Some((mantissa, exponent)) => {
+            let exponent: i32 = exponent.parse().unwrap_or(0);
+            let sign = if exponent < 0 { '-' } else { '+' };
+            format!("{}e{}{:02}", mantissa, sign, exponent.abs())
+        }
+        None => formatted,
+    }
+}
+
+impl<const F: usize> Drop for CocoLogger<F> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+impl<const F: usize> Benchmark for CocoLogger<F> {
+    const FUNCTION_NAME: &'static str = Bbob::<F>::FUNCTION_NAME;
+
+    fn get_min(&self) -> f32 {
+        self.problem.get_min()
+    }
+
+    fn get_max(&self) -> f32 {
+        self.problem.get_max()
+    }
+
+    fn get_dim(&self) -> usize {
+        self.problem.get_dim()
+    }
+
+    fn get_expected_min(&self) -> Option<f32> {
+        self.problem.get_expected_min()
+    }
+
+    fn get_expected_min_coords(&self) -> Option<&[f32]> {
+        self.problem.get_expected_min_coords()
+    }
+
+    fn set_min(mut self, value: f32) -> Self {
+        self.problem = std::mem::take(&mut self.problem).set_min(value);
+        self
+    }
+
+    fn set_max(mut self, value: f32) -> Self {
+        self.problem = std::mem::take(&mut self.problem).set_max(value);
+        self
+    }
+
+    fn set_dim(mut self, value: usize) -> Self {
+        self.problem = std::mem::take(&mut self.problem).set_dim(value);
+        self
+    }
+
+    fn set_expected_min(mut self, value: Option<f32>) -> Self {
+        self.problem = std::mem::take(&mut self.problem).set_expected_min(value);
+        self
+    }
+
+    fn set_expected_min_coords(mut self, value: Option<Vec<f32>>) -> Self {
+        self.problem = std::mem::take(&mut self.problem).set_expected_min_coords(value);
+        self
+    }
+
+    fn cost_function(&self, input: &[f32]) -> f32 {
+        let input: Vec<f64> = input.iter().map(|x| f64::from(*x)).collect();
+        self.cost_function_f64(&input) as f32
+    }
+
+    fn cost_function_f64(&self, input: &[f64]) -> f64 {
+        let value = self.problem.evaluate(input);
+        self.log(input, value);
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::traits::HasBuilder;
+
+    #[test]
+    fn scientific_format() {
+        assert_eq!(scientific(1e-8, 3), "+1.000e-08");
+        assert_eq!(scientific(-462.09, 2), "-4.62e+02");
+        assert_eq!(scientific(0.0, 1), "+0.0e+00");
+    }
+
+    #[test]
+    fn writes_files() {
+        let folder = std::env::temp_dir().join(format!("coco_logger_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&folder);
+        let problem = Bbob::<1>::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(2)
+            .build()
+            .unwrap();
+        let logger = CocoLogger::new(problem, &folder, "test");
+        for i in 0..100 {
+            let x = 4f32 - i as f32 * 0.04;
+            Benchmark::cost_function(&logger, &[x, x]);
+        }
+        assert_eq!(logger.get_evaluations(), 100);
+        drop(logger);
+
+        let info = fs::read_to_string(folder.join("bbobexp_f1.info")).unwrap();
+        assert!(info.starts_with("funcId = 1, DIM = 2, Precision = 1.000e-08, algId = 'test'"));
+        assert!(info.contains("data_f1/bbobexp_f1_DIM2.dat, 1:100|"));
+        let dat = fs::read_to_string(folder.join("data_f1/bbobexp_f1_DIM2.dat")).unwrap();
+        let lines: Vec<&str> = dat.lines().collect();
+        assert!(lines[0].starts_with("% function evaluation"));
+        assert!(lines[1].starts_with("1 "));
+        assert!(lines.last().unwrap().starts_with("100 "));
+        let tdat = fs::read_to_string(folder.join("data_f1/bbobexp_f1_DIM2.tdat")).unwrap();
+        assert!(tdat.lines().any(|line| line.starts_with("20 ")));
+        fs::remove_dir_all(&folder).unwrap();
+    }
+}
@@ -1 +1,3 @@
+pub mod coco;
 pub mod printer;
+pub mod report;
@@ -1,16 +1,18 @@
-use crate::benchmarks::traits::Benchmark;
+use crate::benchmarks::registry::DynBenchmark;
 use plotters::prelude::*;
 
+const SAMPLES: f64 = 200.0;
+
 pub struct Printer<T>
 where
-    T: Benchmark,
+    T: DynBenchmark,
 {
     problem: T,
 }
 
 impl<T> Printer<T>
 where
-    T: Benchmark,
+    T: DynBenchmark,
 {
     pub fn new(problem: T) -> Self {
         Self { problem }
@@ -19,15 +21,21 @@ where
 
 impl<T> Printer<T>
 where
-    T: Benchmark,
+    T: DynBenchmark,
 {
     pub fn print2d(&self) {
-        let path = format!("out/{} 2d.png", T::FUNCTION_NAME);
-        let title = format!("{} 2D", T::FUNCTION_NAME);
-        let data: Vec<(f32, f32)> = (self.problem.get_min()..self.problem.get_max())
-            .step(0.1)
+        let path = format!("out/{} 2d.png", self.problem.get_name());
+        let title = format!("{} 2D", self.problem.get_name());
+        let (x_min, x_max) = (
+            f64::from(self.problem.get_min()),
+            f64::from(self.problem.get_max()),
+        );
+        let data: Vec<(f64, f64)> = (x_min..x_max)
+            .step(step(x_min, x_max))
             .values()
-            .map(|x| (x, self.problem.cost_function(&[x])))
+            .map(|x| (x, self.problem.cost_function_f64(&[x])))
+            // points where the function is not defined are left out
+            .filter(|(_, y)| y.is_finite())
             .collect();
         let result_min = data
             .clone()
@@ -50,47 +58,47 @@ where
             .margin(5)
             .x_label_area_size(30)
             .y_label_area_size(30)
-            .build_cartesian_2d(
-                self.problem.get_min()..self.problem.get_max(),
-                result_min..result_max,
-            )
+            .build_cartesian_2d(x_min..x_max, result_min..result_max)
             .unwrap();
 
         chart.configure_mesh().draw().unwrap();
-        chart
-            .draw_series(LineSeries::new(data, &RED))
-            .unwrap();
+        chart.draw_series(LineSeries::new(data, &RED)).unwrap();
 
         let _ = drawing_area.present();
     }
 
-    pub fn print3d(&self, density: f64, pitch:f64, color_th:f32) {
-        let path = format!("out/{} 3d.png", T::FUNCTION_NAME);
-        let title = format!("{} 3D", T::FUNCTION_NAME);
-        let data: Vec<f32> = (self.problem.get_min()..self.problem.get_max())
-            .step(0.1)
+    pub fn print3d(&self, density: f64, pitch: f64, color_th: f32) {
+        let path = format!("out/{} 3d.png", self.problem.get_name());
+        let title = format!("{} 3D", self.problem.get_name());
+        let (x_min, x_max) = self.axis(0);
+        let (z_min, z_max) = self.axis(1);
+        let (x_step, z_step) = (step(x_min, x_max), step(z_min, z_max));
+        let data: Vec<f64> = (x_min..x_max)
+            .step(x_step)
             .values()
             .map(|x| {
-                (self.problem.get_min()..self.problem.get_max())
-                    .step(0.1)
+                (z_min..z_max)
+                    .step(z_step)
                     .values()
                     .map(move |y| {
                         let input_arr = [x, y];
-                        self.problem.cost_function(&input_arr)
+                        self.problem.cost_function_f64(&input_arr)
                     })
-                    .collect::<Vec<f32>>()
+                    .collect::<Vec<f64>>()
             })
             .flatten()
             .collect();
         let result_min = data
             .clone()
             .iter()
+            .filter(|y| y.is_finite())
             .min_by(|a, b| a.total_cmp(b))
             .unwrap()
             .to_owned();
         let result_max = data
             .clone()
             .iter()
+            .filter(|y| y.is_finite())
             .max_by(|a, b| a.total_cmp(b))
             .unwrap()
             .to_owned();
@@ -99,11 +107,7 @@ where
         let mut chart = ChartBuilder::on(&drawing_area)
             .caption(title, ("arial", 50).into_font())
             .margin(5)
-            .build_cartesian_3d(
-                self.problem.get_min()..self.problem.get_max(),
-                result_min..result_max,
-                self.problem.get_min()..self.problem.get_max(),
-            )
+            .build_cartesian_3d(x_min..x_max, result_min..result_max, z_min..z_max)
             .unwrap();
 
         chart.with_projection(|mut pb| {
@@ -122,301 +126,75 @@ where
         chart
             .draw_series(
                 SurfaceSeries::xoz(
-                    (self.problem.get_min()..self.problem.get_max())
-                        .step(0.1)
-                        .values(),
-                    (self.problem.get_min()..self.problem.get_max())
-                        .step(0.1)
-                        .values(),
-                    |x, z| self.problem.cost_function(&[x, z]),
+                    (x_min..x_max).step(x_step).values(),
+                    (z_min..z_max).step(z_step).values(),
+                    // points where the function is not defined are drawn at the top
+                    |x, z| match self.problem.cost_function_f64(&[x, z]) {
+                        cost if cost.is_finite() => cost,
+                        _ => result_max,
+                    },
                 )
                 // .style(BLUE.mix(density).filled()),
-                .style_func(&|&v| (VulcanoHSL::get_color(v / color_th).mix(density)).into())
+                .style_func(&|&v| {
+                    (VulcanoHSL::get_color(v / f64::from(color_th)).mix(density)).into()
+                }),
             )
             .unwrap();
 
         let _ = drawing_area.present();
     }
+
+    // functions with their own domain per variable are drawn over it, sampled in f64
+    fn axis(&self, index: usize) -> (f64, f64) {
+        let (min, max) = self
+            .problem
+            .get_bounds()
+            .get(index)
+            .copied()
+            .unwrap_or((self.problem.get_min(), self.problem.get_max()));
+        (f64::from(min), f64::from(max))
+    }
+}
+
+// the same number of samples over every domain, a domain 20 wide is sampled every 0.1
+fn step(min: f64, max: f64) -> f64 {
+    ((max - min) / SAMPLES).max(f64::EPSILON)
 }
 
 #[cfg(test)]
 mod test {
-    use std::f32::consts::PI;
-
     use super::*;
-    use crate::benchmarks::{
-        ackley::Ackley, alpine2::Alpine2, deb1::Deb1, foth_dejong::FothDejong, fst_dejong::FstDeJong, griewank::Griewank, michalewicz::Michalewicz, periodic::Periodic, qing::Qing, quintic::Quintic, rastrigin::Rastrigin, salomon::Salomon, schwefel::Schwefel, styblinsky_and_tang::StyblinskyAndTang, traits::HasBuilder, trd_dejong::TrdDejong, xinsheyang::XinSheYang
-    };
-
-    #[test]
-    fn fstdejong() {
-        let problem = FstDeJong::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(1)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.01,1.0,15.0);
-    }
-
-    #[test]
-    fn schwefel() {
-        let schwefel = Schwefel::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(1)
-            .build()
-            .unwrap();
-        let sch_printer = Printer::new(schwefel);
-        sch_printer.print2d();
-        sch_printer.print3d(0.05,1.0,480.0);
-    }
-
-    #[test]
-    fn michalewicz() {
-        let michalewicz = Michalewicz::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(2)
-            .build()
-            .unwrap()
-            .set_m(10);
-        let mich_printer = Printer::new(michalewicz);
-        mich_printer.print2d();
-        mich_printer.print3d(0.05,0.5,0.1);
-    }
-
-    #[test]
-    fn rastrigin() {
-        // pitch 1.0
-        let rastrigin = Rastrigin::builder()
-            .minimum(-4f32)
-            .maximum(4f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let ras_printer = Printer::new(rastrigin);
-        ras_printer.print2d();
-        ras_printer.print3d(0.30,1.0,15.0);
-    }
-
-    #[ignore]
-    #[test]
-    fn trd_dejong() {
-        // pitch 1.0
-        let trd_dejong = TrdDejong::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(trd_dejong);
-        printer.print2d();
-        printer.print3d(0.05,1.0,15.0);
-    }
-
-    #[test]
-    fn trd_dejong_zoom1() {
-        // pitch 1.0
-        let trd_dejong = TrdDejong::builder()
-            .minimum(-4f32)
-            .maximum(4f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(trd_dejong);
-        printer.print2d();
-        printer.print3d(0.8,1.0,30.0);
-    }
-
-    #[test]
-    fn griewank_zoom1() {
-        // pitch 1.0
-        let problem = Griewank::builder()
-            .minimum(-10f32)
-            .maximum(10f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.5,0.5,1.0);
-    }
-
-    #[ignore]
-    #[test]
-    fn griewank() {
-        // pitch 1.0
-        let problem = Griewank::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.15,10.0,15.0);
-    }
-
-    #[ignore]
-    #[test]
-    fn styblinsky_and_tang() {
-        // pitch 1.0
-        let problem = StyblinskyAndTang::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.15,1.0,15.0);
-    }
-
-    #[test]
-    fn styblinsky_and_tang_zoom1() {
-        // pitch 1.0
-        let problem = StyblinskyAndTang::builder()
-            .minimum(-5f32)
-            .maximum(5f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.50,1.0,30.0);
-    }
-
-    #[test]
-    fn ackley() {
-        // pitch 0.5
-        let problem = Ackley::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(2)
-            .build()
-            .unwrap()
-            .set_a(20)
-            .set_b(0.2)
-            .set_c(2f32 * PI);
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.05,0.5,15.0);
-    }
-
-    #[test]
-    fn alpine2() {
-        // pitch 1.0
-        let problem = Alpine2::builder()
-            .minimum(0f32)
-            .maximum(10f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.5,1.0,15.0);
-    }
-
-    #[test]
-    fn foth_dejong() {
-        // pitch 1.0
-        let problem = FothDejong::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.05,1.0,150000000.0);
-    }
-
-    #[test]
-    fn salomon() {
-        // pitch 1.0
-        let problem = Salomon::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.15,1.0,15.0);
-    }
-
-    #[test]
-    fn periodic() {
-        // pitch 1.0
-        let problem = Periodic::builder()
-            .minimum(-10f32)
-            .maximum(10f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.3,0.5,5.0);
-    }
-
-    #[test]
-    fn xin_she_yang() {
-        // pitch 1.0
-        // slow
-        let problem = XinSheYang::builder()
-            .minimum(-100f32)
-            .maximum(100f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.15,1.0,15.0);
-    }
-
-     #[test]
-    fn qing() {
-        // pitch 1.0
-        let problem = Qing::builder()
-            .minimum(-2f32)
-            .maximum(2f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.8,1.0,15.0);
-    }
-
-     #[test]
-    fn deb1() {
-        // pitch 1.0
-        // slow
-        let problem = Deb1::builder()
-            .minimum(-1f32)
-            .maximum(1f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.8,1.0,0.01);
-    }
-
-     #[test]
-    fn quintic() {
-        // pitch 1.0
-        let problem = Quintic::builder()
-            .minimum(-10f32)
-            .maximum(10f32)
-            .dimensions(2)
-            .build()
-            .unwrap();
-        let printer = Printer::new(problem);
-        printer.print2d();
-        printer.print3d(0.8, 1.0,150000.0);
+    use crate::benchmarks::{registry, traits::random_in};
+
+    // colour range from a sample of the surface
+    fn threshold(problem: &dyn DynBenchmark) -> f32 {
+        (0..100)
+            .map(|_| {
+                problem
+                    .cost_function(&random_in(&problem.get_bounds()))
+                    .abs()
+            })
+            .filter(|cost| cost.is_finite())
+            .fold(f32::EPSILON, f32::max)
+    }
+
+    // every benchmark defined in 2 dimensions is drawn in 3D, the scalable ones also in 2D
+    #[test]
+    fn registry() {
+        for info in registry::benchmarks() {
+            if info.get_dimensions().is_some_and(|dim| dim != 2) {
+                continue;
+            }
+            // scalable functions may still refuse 1 or 2 dimensions, e.g. Powell
+            if let Ok(problem) = info.build(2) {
+                let color_th = threshold(problem.as_ref());
+                Printer::new(problem).print3d(0.3, 1.0, color_th);
+            }
+            if info.get_dimensions().is_none() {
+                if let Ok(problem) = info.build(1) {
+                    Printer::new(problem).print2d();
+                }
+            }
+        }
     }
 }
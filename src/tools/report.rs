@@ -0,0 +1,258 @@
+use std::{
+    fmt::Display,
+    fs::OpenOptions,
+    io::{self, Write},
+    path::Path,
+};
+
+use crate::indicators::{
+    additive_epsilon, generational_distance, hypervolume, inverted_generational_distance,
+    inverted_generational_distance_plus, spread,
+};
+use crate::pareto::{front_costs, Solution};
+use crate::problem_definitions::Real;
+
+const CSV_HEADER: &str = "problem,solver,points,hypervolume,igd,igd_plus,gd,spread,epsilon";
+
+// indicator values of one approximation front
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontQuality {
+    points: usize,
+    hypervolume: f64,
+    igd: f64,
+    igd_plus: f64,
+    gd: f64,
+    spread: f64,
+    epsilon: f64,
+}
+
+impl FrontQuality {
+    pub fn new(front: &[Vec<f64>], reference_front: &[Vec<f64>], reference_point: &[f64]) -> Self {
+        Self {
+            points: front.len(),
+            hypervolume: hypervolume(front, reference_point),
+            igd: inverted_generational_distance(front, reference_front),
+            igd_plus: inverted_generational_distance_plus(front, reference_front),
+            gd: generational_distance(front, reference_front),
+            spread: spread(front, reference_front),
+            epsilon: additive_epsilon(front, reference_front),
+        }
+    }
+
+    pub fn get_points(&self) -> usize {
+        self.points
+    }
+
+    pub fn get_hypervolume(&self) -> f64 {
+        self.hypervolume
+    }
+
+    pub fn get_igd(&self) -> f64 {
+        self.igd
+    }
+
+    pub fn get_igd_plus(&self) -> f64 {
+        self.igd_plus
+    }
+
+    pub fn get_gd(&self) -> f64 {
+        self.gd
+    }
+
+    pub fn get_spread(&self) -> f64 {
+        self.spread
+    }
+
+    pub fn get_epsilon(&self) -> f64 {
+        self.epsilon
+    }
+}
+
+// compares the fronts several solvers found on one problem, every front is measured against the
+// same reference front and hypervolume reference point
+#[derive(Debug, Clone)]
+pub struct FrontReport {
+    problem: String,
+    reference_front: Vec<Vec<f64>>,
+    reference_point: Vec<f64>,
+    rows: Vec<(String, FrontQuality)>,
+}
+
+impl FrontReport {
+    // the reference point lies 10 % of the extent of the reference front past its nadir
+    pub fn new(problem: &str, reference_front: Vec<Vec<f64>>) -> Self {
+        let objectives = reference_front.first().map_or(0, |point| point.len());
+        let reference_point = (0..objectives)
+            .map(|m| {
+                let values = reference_front.iter().map(|point| point[m]);
+                let nadir = values.clone().fold(f64::NEG_INFINITY, f64::max);
+                let ideal = values.fold(f64::INFINITY, f64::min);
+                nadir + 0.1 * (nadir - ideal)
+            })
+            .collect();
+        Self {
+            problem: problem.to_string(),
+            reference_front,
+            reference_point,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn set_reference_point(mut self, reference_point: Vec<f64>) -> Self {
+        self.reference_point = reference_point;
+        self
+    }
+
+    pub fn get_reference_point(&self) -> &[f64] {
+        &self.reference_point
+    }
+
+    pub fn add_front(&mut self, solver: &str, front: &[Vec<f64>]) -> &FrontQuality {
+        let quality = FrontQuality::new(front, &self.reference_front, &self.reference_point);
+        self.rows.push((solver.to_string(), quality));
+        &self.rows[self.rows.len() - 1].1
+    }
+
+    // only the feasible solutions count
+    pub fn add_solutions<F: Real>(
+        &mut self,
+        solver: &str,
+        solutions: &[Solution<F>],
+    ) -> &FrontQuality {
+        self.add_front(solver, &front_costs(solutions))
+    }
+
+    pub fn get_quality(&self, solver: &str) -> Option<&FrontQuality> {
+        self.rows
+            .iter()
+            .find(|(name, _)| name == solver)
+            .map(|(_, quality)| quality)
+    }
+
+    pub fn get_rows(&self) -> &[(String, FrontQuality)] {
+        &self.rows
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", CSV_HEADER);
+        for line in self.csv_lines() {
+            csv.push_str(&line);
+            csv.push('\n');
+        }
+        csv
+    }
+
+    // appends the rows, the header goes only into a new file, so that the reports of several
+    // problems can share one file
+    pub fn write_csv(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let new_file = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if new_file {
+            writeln!(file, "{}", CSV_HEADER)?;
+        }
+        for line in self.csv_lines() {
+            writeln!(file, "{}", line)?;
+        }
+        Ok(())
+    }
+
+    fn csv_lines(&self) -> Vec<String> {
+        self.rows
+            .iter()
+            .map(|(solver, q)| {
+                format!(
+                    "{},{},{},{},{},{},{},{},{}",
+                    self.problem,
+                    solver,
+                    q.points,
+                    q.hypervolume,
+                    q.igd,
+                    q.igd_plus,
+                    q.gd,
+                    q.spread,
+                    q.epsilon
+                )
+            })
+            .collect()
+    }
+}
+
+impl Display for FrontReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .rows
+            .iter()
+            .map(|(solver, _)| solver.len())
+            .max()
+            .unwrap_or(0)
+            .max("solver".len());
+        writeln!(f, "{}", self.problem)?;
+        writeln!(
+            f,
+            "{:<width$} {:>6} {:>11} {:>11} {:>11} {:>11} {:>11} {:>11}",
+            "solver", "points", "HV", "IGD", "IGD+", "GD", "spread", "eps+"
+        )?;
+        for (solver, q) in &self.rows {
+            writeln!(
+                f,
+                "{:<width$} {:>6} {:>11.4e} {:>11.4e} {:>11.4e} {:>11.4e} {:>11.4e} {:>11.4e}",
+                solver, q.points, q.hypervolume, q.igd, q.igd_plus, q.gd, q.spread, q.epsilon
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::benchmarks::multi_objective::{zdt::Zdt, HasReferenceFront};
+    use std::fs;
+
+    #[test]
+    fn compares_fronts() {
+        let reference = Zdt::<1>::new().get_reference_front(200);
+        let mut report = FrontReport::new("ZDT1", reference.clone());
+        assert_eq!(report.get_reference_point(), &[1.1, 1.1]);
+
+        let exact = report.add_front("exact", &reference).clone();
+        assert!(exact.get_igd() < 1e-12 && exact.get_gd() < 1e-12);
+        assert!((exact.get_hypervolume() - (1.21 - 1f64 / 3f64)).abs() < 1e-2);
+        let shifted = reference
+            .iter()
+            .step_by(10)
+            .map(|p| vec![p[0] + 0.05, p[1] + 0.05])
+            .collect::<Vec<_>>();
+        let worse = report.add_front("shifted", &shifted).clone();
+        assert!(worse.get_hypervolume() < exact.get_hypervolume());
+        assert!(worse.get_igd() > exact.get_igd());
+        assert!(worse.get_epsilon() >= 0.05 && worse.get_epsilon() < 0.2);
+        assert_eq!(worse.get_points(), 20);
+
+        let solutions = vec![
+            Solution::new(vec![0f64], vec![0f64, 1f64], 0f64),
+            Solution::new(vec![1f64], vec![1f64, 0f64], 0f64),
+            Solution::new(vec![2f64], vec![0f64, 0f64], 1f64),
+        ];
+        assert_eq!(report.add_solutions("pair", &solutions).get_points(), 2);
+        assert_eq!(report.get_rows().len(), 3);
+        assert!(report.get_quality("shifted").is_some());
+
+        let table = report.to_string();
+        assert!(table.starts_with("ZDT1\nsolver "));
+        assert_eq!(table.lines().count(), 5);
+        let csv = report.to_csv();
+        assert!(csv.starts_with(CSV_HEADER));
+        assert!(csv.lines().nth(2).unwrap().starts_with("ZDT1,shifted,20,"));
+
+        let path = std::env::temp_dir().join(format!("front_report_{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        report.write_csv(&path).unwrap();
+        report.write_csv(&path).unwrap();
+        let written = fs::read_to_string(&path).unwrap();
+        assert_eq!(written.lines().count(), 7);
+        assert_eq!(written.matches("problem,solver").count(), 1);
+        fs::remove_file(&path).unwrap();
+    }
+}
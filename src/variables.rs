@@ -0,0 +1,304 @@
+use std::fmt::Debug;
+
+use rand::Rng;
+
+use crate::problem_definitions::{HasLocal, HasRandom, ProblemDomain, Real};
+
+// type of one decision variable, every value is encoded in the problem's float type
+#[derive(Debug, Clone, PartialEq)]
+pub enum VariableType {
+    Continuous { min: f64, max: f64 },
+    // whole numbers from min to max, both included
+    Integer { min: i64, max: i64 },
+    // unordered levels encoded as their index 0..levels
+    Categorical { levels: usize },
+}
+
+impl VariableType {
+    pub fn get_bounds(&self) -> (f64, f64) {
+        match self {
+            VariableType::Continuous { min, max } => (*min, *max),
+            VariableType::Integer { min, max } => (*min as f64, *max as f64),
+            VariableType::Categorical { levels } => (0f64, levels.saturating_sub(1) as f64),
+        }
+    }
+
+    pub fn is_discrete(&self) -> bool {
+        !matches!(self, VariableType::Continuous { .. })
+    }
+
+    // clamps into the bounds and rounds the discrete variables to the closest value
+    pub fn repair<F: Real>(&self, value: F) -> F {
+        let (min, max) = self.get_bounds();
+        let value = value.to_f64().unwrap_or(min);
+        let value = if value.is_nan() { min } else { value };
+        let value = if self.is_discrete() {
+            value.round()
+        } else {
+            value
+        };
+        F::from_f64(value.clamp(min, max))
+    }
+
+    pub fn random<F: Real, R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        match self {
+            VariableType::Continuous { min, max } => F::from_f64(rng.gen_range(*min..=*max)),
+            VariableType::Integer { min, max } => F::from_f64(rng.gen_range(*min..=*max) as f64),
+            VariableType::Categorical { levels } => {
+                F::from_f64(rng.gen_range(0..(*levels).max(1)) as f64)
+            }
+        }
+    }
+
+    // continuous variables take a gaussian step of a sixtieth of the domain, integers move to a
+    // neighbouring value and categories switch to a different level
+    pub fn neighbour<F: Real, R: Rng + ?Sized>(&self, value: F, rng: &mut R) -> F {
+        let (min, max) = self.get_bounds();
+        let current = self.repair(value).to_f64().unwrap_or(min);
+        let next = match self {
+            VariableType::Continuous { .. } => {
+                let std_dev = (max - min) / 60f64;
+                let mut next = current + std_dev * f64::standard_normal(rng);
+                while next < min || next > max {
+                    next = current + std_dev * f64::standard_normal(rng);
+                }
+                next
+            }
+            VariableType::Integer { .. } => {
+                if max <= min {
+                    min
+                } else if current <= min {
+                    current + 1f64
+                } else if current >= max || rng.gen_bool(0.5) {
+                    current - 1f64
+                } else {
+                    current + 1f64
+                }
+            }
+            VariableType::Categorical { levels } => {
+                if *levels < 2 {
+                    0f64
+                } else {
+                    let level = rng.gen_range(0..levels - 1) as f64;
+                    if level >= current {
+                        level + 1f64
+                    } else {
+                        level
+                    }
+                }
+            }
+        };
+        F::from_f64(next)
+    }
+}
+
+pub fn random_mixed<F: Real>(types: &[VariableType]) -> Vec<F> {
+    let mut rng = rand::thread_rng();
+    types.iter().map(|kind| kind.random(&mut rng)).collect()
+}
+
+// every continuous variable moves, from the discrete ones a single randomly chosen one does
+pub fn local_next_mixed<F: Real>(types: &[VariableType], input: &[F]) -> Vec<F> {
+    let mut rng = rand::thread_rng();
+    let discrete: Vec<usize> = (0..types.len())
+        .filter(|i| types[*i].is_discrete())
+        .collect();
+    let moved = (!discrete.is_empty()).then(|| discrete[rng.gen_range(0..discrete.len())]);
+    types
+        .iter()
+        .zip(input.iter())
+        .enumerate()
+        .map(|(i, (kind, &x))| {
+            if !kind.is_discrete() || moved == Some(i) {
+                kind.neighbour(x, &mut rng)
+            } else {
+                kind.repair(x)
+            }
+        })
+        .collect()
+}
+
+pub fn repair_mixed<F: Real>(types: &[VariableType], input: Vec<F>) -> Vec<F> {
+    types
+        .iter()
+        .zip(input)
+        .map(|(kind, x)| kind.repair(x))
+        .collect()
+}
+
+// envelope of the variable bounds, used as the problem's minimum and maximum
+pub(crate) fn envelope<F: Real>(types: &[VariableType]) -> (F, F) {
+    let (min, max) = types
+        .iter()
+        .map(VariableType::get_bounds)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |acc, (min, max)| {
+            (acc.0.min(min), acc.1.max(max))
+        });
+    (F::from_f64(min), F::from_f64(max))
+}
+
+// ad-hoc mixed-integer problem from a closure, discrete values reach it already rounded
+pub struct MixedProblem<F, R = f32>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    types: Vec<VariableType>,
+    function: F,
+    marker: std::marker::PhantomData<R>,
+}
+
+impl<F, R> MixedProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    pub fn new(types: Vec<VariableType>, function: F) -> Self {
+        Self {
+            types,
+            function,
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn get_variable_types(&self) -> &[VariableType] {
+        &self.types
+    }
+}
+
+impl<F, R> Debug for MixedProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MixedProblem")
+            .field("types", &self.types)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, R> ProblemDomain for MixedProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    type Item = R;
+
+    fn get_minimum(&self) -> R {
+        envelope::<R>(&self.types).0
+    }
+
+    fn get_maximum(&self) -> R {
+        envelope::<R>(&self.types).1
+    }
+
+    fn get_dimensions(&self) -> usize {
+        self.types.len()
+    }
+
+    fn cost_function(&self, input: &[R]) -> R {
+        (self.function)(&repair_mixed(&self.types, input.to_vec()))
+    }
+
+    fn repair(&self, input: Vec<R>) -> Vec<R> {
+        repair_mixed(&self.types, input)
+    }
+}
+
+impl<F, R> HasRandom for MixedProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    fn get_random(&self) -> Vec<R> {
+        random_mixed(&self.types)
+    }
+}
+
+impl<F, R> HasLocal for MixedProblem<F, R>
+where
+    F: Fn(&[R]) -> R,
+    R: Real,
+{
+    fn get_local_next(&self, input: &[R]) -> Vec<R> {
+        local_next_mixed(&self.types, input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::evol_arg::de::{De, Strategy, Variant};
+
+    fn types() -> Vec<VariableType> {
+        vec![
+            VariableType::Continuous {
+                min: -2.0,
+                max: 2.0,
+            },
+            VariableType::Integer { min: -3, max: 5 },
+            VariableType::Categorical { levels: 3 },
+        ]
+    }
+
+    fn valid(types: &[VariableType], input: &[f32]) -> bool {
+        types.iter().zip(input.iter()).all(|(kind, &x)| {
+            let (min, max) = kind.get_bounds();
+            (min..=max).contains(&f64::from(x)) && (!kind.is_discrete() || x.fract() == 0f32)
+        })
+    }
+
+    #[test]
+    fn random_valid() {
+        let types = types();
+        for _ in 0..100 {
+            let random: Vec<f32> = random_mixed(&types);
+            assert!(valid(&types, &random));
+        }
+    }
+
+    #[test]
+    fn repair() {
+        let types = types();
+        assert_eq!(
+            repair_mixed(&types, vec![3.5f32, 2.4, 7.0]),
+            vec![2f32, 2f32, 2f32]
+        );
+        assert_eq!(
+            repair_mixed(&types, vec![-0.5f32, -3.6, -1.0]),
+            vec![-0.5f32, -3f32, 0f32]
+        );
+    }
+
+    #[test]
+    fn local_next_moves_one_discrete() {
+        let types = types();
+        for _ in 0..100 {
+            let input: Vec<f32> = random_mixed(&types);
+            let next = local_next_mixed(&types, &input);
+            assert!(valid(&types, &next));
+            let changed = (1..3).filter(|i| next[*i] != input[*i]).count();
+            assert_eq!(changed, 1);
+            assert!((next[1] - input[1]).abs() <= 1f32);
+        }
+    }
+
+    #[test]
+    fn envelope_bounds() {
+        assert_eq!(envelope::<f32>(&types()), (-3f32, 5f32));
+    }
+
+    #[test]
+    fn de_mixed() {
+        let problem = MixedProblem::new(types(), |x: &[f32]| {
+            x[0].powi(2) + (x[1] - 2f32).powi(2) + if x[2] == 1f32 { 0f32 } else { 1f32 }
+        });
+        // a small population can settle on one level of the discrete variables for good
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 4000, 20, 0.8, 0.9, problem);
+        de.run();
+        let best = de.get_best().unwrap();
+        assert!(valid(&types(), best.get_coordinates()));
+        assert!(best.get_cost() < 0.1);
+    }
+}
@@ -52,6 +52,7 @@ mod random_search {
 }
 mod local_search {
     use heuristics::solvers::local_search::LocalSearch;
+    use heuristics::solvers::neighbourhood::Cauchy;
 
     use super::*;
 
@@ -95,6 +96,27 @@ mod local_search {
         println!("best: {:?}", local.get_best_cost());
         assert!(local.get_best_cost().is_some());
     }
+    #[test]
+    fn schwefel_cauchy() {
+        let problem = Schwefel::builder()
+            .minimum(-500f32)
+            .maximum(500f32)
+            .dimensions(10)
+            .build()
+            .unwrap();
+        let mut local = LocalSearch::new(10, problem).set_neighbourhood(Cauchy::new(5f32));
+        local.run();
+
+        println!("best: {:?}", local.get_best_cost());
+        // the heavy tailed steps reflect off the bounds, the search only accepts improvements
+        let start = local.get_history()[0];
+        assert!(local.get_best_cost().unwrap() < start);
+        assert!(local
+            .get_best_coords()
+            .unwrap()
+            .iter()
+            .all(|x| (-500f32..=500f32).contains(x)));
+    }
 }
 
 mod hill_climber {
@@ -145,6 +167,7 @@ mod hill_climber {
 }
 
 mod simulated_annealing {
+    use heuristics::solvers::neighbourhood::Gaussian;
     use heuristics::solvers::simulated_annealing::SimulatedAnnealing;
 
     use super::*;
@@ -187,7 +210,34 @@ mod simulated_annealing {
         simulated_annealing.run();
 
         println!("best: {:?}", simulated_annealing.get_best_cost());
-        assert!(simulated_annealing.get_best_cost().is_some());
+        let start = simulated_annealing.get_history()[0];
+        assert!(simulated_annealing.get_best_cost().unwrap() < start);
+        assert!(simulated_annealing
+            .get_best_coords()
+            .unwrap()
+            .iter()
+            .all(|x| (-500f32..=500f32).contains(x)));
+    }
+    #[test]
+    fn schwefel_adaptive_gaussian() {
+        let problem = Schwefel::builder()
+            .minimum(-500f32)
+            .maximum(500f32)
+            .dimensions(10)
+            .build()
+            .unwrap();
+        let mut simulated_annealing = SimulatedAnnealing::new(10, 1000f32, 0.1, 0.998, problem)
+            .set_neighbourhood(Gaussian::adaptive(10f32));
+        simulated_annealing.run();
+
+        println!("best: {:?}", simulated_annealing.get_best_cost());
+        let start = simulated_annealing.get_history()[0];
+        assert!(simulated_annealing.get_best_cost().unwrap() < start);
+        assert!(simulated_annealing
+            .get_best_coords()
+            .unwrap()
+            .iter()
+            .all(|x| (-500f32..=500f32).contains(x)));
     }
 }
 
@@ -295,3 +345,1100 @@ mod pso {
         assert_eq!(pso.get_cost_function_evaluations(), 4000);
     }
 }
+
+mod variable_neighbourhood {
+    use super::*;
+    use heuristics::benchmarks::{michalewicz::Michalewicz, rastrigin::Rastrigin};
+    use heuristics::solvers::local_search::LocalSearch;
+    use heuristics::solvers::variable_neighbourhood::VariableNeighbourhoodSearch;
+
+    #[test]
+    fn schwefel() {
+        let problem = Schwefel::builder()
+            .minimum(-500f32)
+            .maximum(500f32)
+            .dimensions(10)
+            .build()
+            .unwrap();
+        let mut vns = VariableNeighbourhoodSearch::new(
+            10000,
+            vec![10f32, 50f32, 100f32, 250f32],
+            LocalSearch::new(10, problem),
+        );
+        vns.run();
+
+        println!("best: {:?}", vns.get_best_cost());
+        assert!(vns.get_best_cost().is_some());
+        assert_eq!(vns.get_cost_function_evaluations(), 10000);
+    }
+    #[test]
+    fn rastrigin() {
+        let problem = Rastrigin::builder()
+            .minimum(-5.12)
+            .maximum(5.12)
+            .dimensions(10)
+            .build()
+            .unwrap();
+        let mut vns = VariableNeighbourhoodSearch::new(
+            10000,
+            vec![0.5, 1f32, 2f32],
+            LocalSearch::new(10, problem),
+        );
+        vns.run();
+
+        println!("best: {:?}", vns.get_best_cost());
+        assert!(vns.get_best_cost().is_some());
+    }
+    #[test]
+    fn michalewicz() {
+        let problem = Michalewicz::builder()
+            .minimum(0f32)
+            .maximum(std::f32::consts::PI)
+            .dimensions(5)
+            .build()
+            .unwrap()
+            .set_m(10);
+        let mut vns = VariableNeighbourhoodSearch::new(
+            5000,
+            vec![0.1, 0.3, 0.6],
+            LocalSearch::new(10, problem),
+        );
+        vns.run();
+
+        println!("best: {:?}", vns.get_best_cost());
+        assert!(vns.get_best_cost().is_some());
+    }
+}
+
+mod basin_hopping {
+    use super::*;
+    use heuristics::benchmarks::{michalewicz::Michalewicz, rastrigin::Rastrigin};
+    use heuristics::solvers::basin_hopping::BasinHopping;
+    use heuristics::solvers::local_search::LocalSearch;
+    use heuristics::solvers::nelder_mead::NelderMead;
+
+    #[test]
+    fn schwefel() {
+        let problem = Schwefel::builder()
+            .minimum(-500f32)
+            .maximum(500f32)
+            .dimensions(10)
+            .build()
+            .unwrap();
+        let mut basin_hopping =
+            BasinHopping::new(10000, 100f32, 100f32, NelderMead::new(1000, problem))
+                .set_adaptation_interval(10);
+        basin_hopping.run();
+
+        println!("best: {:?}", basin_hopping.get_best_cost());
+        assert!(basin_hopping.get_best_cost().is_some());
+        assert_eq!(basin_hopping.get_cost_function_evaluations(), 10000);
+    }
+    #[test]
+    fn rastrigin() {
+        let problem = Rastrigin::builder()
+            .minimum(-5.12)
+            .maximum(5.12)
+            .dimensions(10)
+            .build()
+            .unwrap();
+        let mut basin_hopping = BasinHopping::new(10000, 1f32, 0.5, LocalSearch::new(10, problem));
+        basin_hopping.run();
+
+        println!("best: {:?}", basin_hopping.get_best_cost());
+        assert!(basin_hopping.get_best_cost().is_some());
+    }
+    #[test]
+    fn michalewicz() {
+        let problem = Michalewicz::builder()
+            .minimum(0f32)
+            .maximum(std::f32::consts::PI)
+            .dimensions(5)
+            .build()
+            .unwrap()
+            .set_m(10);
+        let mut basin_hopping = BasinHopping::new(5000, 0.5, 0.3, NelderMead::new(500, problem));
+        basin_hopping.run();
+
+        println!("best: {:?}", basin_hopping.get_best_cost());
+        assert!(basin_hopping.get_best_cost().is_some());
+    }
+}
+
+mod island_model {
+    use super::*;
+    use heuristics::evol_arg::de::{De, Strategy, Variant};
+    use heuristics::evol_arg::island::{Island, IslandModel, Replacement, Topology};
+    use heuristics::evol_arg::pso::Pso;
+
+    fn problem() -> Schwefel {
+        Schwefel::builder()
+            .minimum(-100f32)
+            .maximum(100f32)
+            .dimensions(20usize)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn schwefel_ring() {
+        let islands: Vec<Box<dyn Island>> = vec![
+            Box::new(De::new(
+                Variant::Rnd,
+                1,
+                Strategy::Bin,
+                4000,
+                10,
+                0.8,
+                0.9,
+                problem(),
+            )),
+            Box::new(De::new(
+                Variant::Best,
+                1,
+                Strategy::Bin,
+                4000,
+                10,
+                0.8,
+                0.5,
+                problem(),
+            )),
+            Box::new(Pso::new(4000, 10, 0.5, 0.8, 0.9, problem())),
+        ];
+        let mut model = IslandModel::new(4000, 10, islands).unwrap();
+        model.run();
+        println!("best: {:?}", model.get_best_cost());
+        assert!(model.get_best_cost().is_some());
+        assert_eq!(model.get_cost_function_evaluations(), 4000);
+    }
+
+    #[test]
+    fn schwefel_random() {
+        let islands: Vec<Box<dyn Island>> = vec![
+            Box::new(De::new(
+                Variant::Rnd,
+                1,
+                Strategy::Bin,
+                4000,
+                10,
+                0.8,
+                0.9,
+                problem(),
+            )),
+            Box::new(De::new(
+                Variant::Rnd,
+                1,
+                Strategy::Bin,
+                4000,
+                10,
+                0.5,
+                0.5,
+                problem(),
+            )),
+        ];
+        let mut model = IslandModel::new(4000, 5, islands)
+            .unwrap()
+            .set_topology(Topology::Random)
+            .set_replacement(Replacement::Random)
+            .set_migrants(2);
+        model.run();
+        println!("best: {:?}", model.get_best_cost());
+        assert!(model.get_best_cost().is_some());
+        assert_eq!(model.get_cost_function_evaluations(), 4000);
+    }
+}
+
+mod transformed {
+    use super::*;
+    use heuristics::benchmarks::{rastrigin::Rastrigin, transformed::Transformed};
+    use heuristics::evol_arg::de::{De, Strategy, Variant};
+
+    #[test]
+    fn shifted_rotated_rastrigin() {
+        let rastrigin = Rastrigin::builder()
+            .minimum(-5.12)
+            .maximum(5.12)
+            .dimensions(10usize)
+            .expected_min(0f32)
+            .expected_min_coords(vec![0f32; 10])
+            .build()
+            .unwrap();
+        let problem = Transformed::new(rastrigin)
+            .random_shift(42)
+            .random_rotation(42);
+        let mut de_rnd_1_bin = De::new(Variant::Rnd, 1, Strategy::Bin, 4000, 10, 0.8, 0.9, problem);
+        de_rnd_1_bin.run();
+        println!("best: {:?}", de_rnd_1_bin.get_best());
+        assert!(de_rnd_1_bin.get_best().is_some());
+        assert_eq!(de_rnd_1_bin.get_cost_function_evaluations(), 4000);
+    }
+}
+
+mod cec2017 {
+    use super::*;
+    use heuristics::benchmarks::cec::cec2017::Cec2017;
+    use heuristics::evol_arg::pso::Pso;
+
+    #[test]
+    fn rastrigin() {
+        let problem = Cec2017::<5>::builder()
+            .minimum(-100f32)
+            .maximum(100f32)
+            .dimensions(10usize)
+            .build()
+            .unwrap();
+        let mut pso = Pso::new(4000, 10, 0.5, 0.8, 0.9, problem);
+        pso.run();
+        println!("best: {:?}", pso.get_best());
+        assert!(pso.get_best().unwrap() >= 500f32);
+        assert_eq!(pso.get_cost_function_evaluations(), 4000);
+    }
+}
+
+mod bbob {
+    use super::*;
+    use heuristics::benchmarks::bbob::noiseless::Bbob;
+    use heuristics::evol_arg::de::{De, Strategy, Variant};
+    use heuristics::solvers::simulated_annealing::SimulatedAnnealing;
+    use heuristics::tools::coco::CocoLogger;
+
+    #[test]
+    fn de_rastrigin() {
+        let folder = std::env::temp_dir().join("bbob_de_rastrigin");
+        let problem = Bbob::<15>::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(5usize)
+            .build()
+            .unwrap();
+        let logger = CocoLogger::new(problem, &folder, "DE/rand/1/bin");
+        let mut de_rnd_1_bin = De::new(Variant::Rnd, 1, Strategy::Bin, 4000, 10, 0.8, 0.9, logger);
+        de_rnd_1_bin.run();
+        println!("best: {:?}", de_rnd_1_bin.get_best());
+        assert!(de_rnd_1_bin.get_best().unwrap().get_cost() >= 1000f32);
+        drop(de_rnd_1_bin);
+        assert!(folder.join("bbobexp_f15.info").exists());
+        assert!(folder.join("data_f15/bbobexp_f15_DIM5.dat").exists());
+    }
+
+    #[test]
+    fn simulated_annealing_sphere() {
+        let folder = std::env::temp_dir().join("bbob_simulated_annealing_sphere");
+        let problem = Bbob::<1>::builder()
+            .minimum(-5f32)
+            .maximum(5f32)
+            .dimensions(5usize)
+            .build()
+            .unwrap()
+            .set_instance(2);
+        let logger = CocoLogger::new(problem, &folder, "SA");
+        let mut simulated_annealing = SimulatedAnnealing::new(10, 1000f32, 0.1, 0.998, logger);
+        simulated_annealing.run();
+        println!("best: {:?}", simulated_annealing.get_best_cost());
+        assert!(simulated_annealing.get_best_cost().is_some());
+    }
+}
+
+mod registry {
+    use heuristics::benchmarks::registry::{self, Modality};
+    use heuristics::evol_arg::pso::Pso;
+
+    #[test]
+    fn unimodal() {
+        for info in registry::benchmarks()
+            .into_iter()
+            .filter(|info| info.get_modality() == Modality::Unimodal)
+        {
+            let dim = info.get_dimensions().unwrap_or(4);
+            let problem = info.build(dim).unwrap();
+            let mut pso = Pso::new(1000, 10, 0.5, 0.8, 0.9, problem);
+            pso.run();
+            println!("{} best: {:?}", info.get_id(), pso.get_best());
+            assert!(pso.get_best().is_some());
+        }
+    }
+}
+
+mod dyn_benchmark {
+    use super::*;
+    use heuristics::benchmarks::registry::DynBenchmark;
+    use heuristics::benchmarks::{bbob::noiseless::Bbob, rastrigin::Rastrigin};
+    use heuristics::evol_arg::pso::Pso;
+
+    #[test]
+    fn experiment_grid() {
+        let problems: Vec<fn() -> Box<dyn DynBenchmark>> = vec![
+            || Box::new(FstDeJong::builder().dimensions(5).build().unwrap()),
+            || Box::new(Schwefel::builder().dimensions(5).build().unwrap()),
+            || Box::new(Rastrigin::builder().dimensions(5).build().unwrap()),
+            || Box::new(Bbob::<8>::builder().dimensions(5).build().unwrap()),
+        ];
+        for problem in problems.iter() {
+            let mut random_search = RandomSearch::new(1000, problem());
+            random_search.run();
+            println!("random search best: {:?}", random_search.get_best_cost());
+            assert!(random_search.get_best_cost().is_some());
+
+            let mut pso = Pso::new(100, 10, 0.5, 0.8, 0.9, problem());
+            pso.run();
+            println!("pso best: {:?}", pso.get_best());
+            assert!(pso.get_best().is_some());
+        }
+    }
+}
+
+mod fn_problem {
+    use heuristics::evol_arg::de::{De, Strategy, Variant};
+    use heuristics::problem_definitions::FnProblem;
+
+    #[test]
+    fn de_closure() {
+        let target = [1f32, -2f32, 3f32];
+        let problem = FnProblem::new(-5f32, 5f32, 3, move |x: &[f32]| {
+            x.iter()
+                .zip(target.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum()
+        });
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 4000, 20, 0.8, 0.9, problem);
+        de.run();
+        println!("best: {:?}", de.get_best());
+        assert!(de.get_best().unwrap().get_cost() < 1f32);
+    }
+}
+
+mod double_precision {
+    use super::*;
+    use heuristics::benchmarks::{
+        double_precision::DoublePrecision, styblinsky_and_tang::StyblinskyAndTang,
+    };
+    use heuristics::evol_arg::island::{Island, IslandModel};
+    use heuristics::evol_arg::{de::De, de::Strategy, de::Variant, pso::Pso};
+    use heuristics::problem_definitions::NumericalGradient;
+    use heuristics::solvers::lbfgsb::LBfgsB;
+
+    fn problem() -> DoublePrecision<StyblinskyAndTang> {
+        DoublePrecision::new(StyblinskyAndTang::builder().dimensions(10).build().unwrap())
+    }
+
+    #[test]
+    fn lbfgsb_styblinsky_and_tang() {
+        let mut lbfgsb = LBfgsB::new(200, 5, NumericalGradient::new(problem()).set_step(1e-6));
+        lbfgsb.run();
+        println!("best: {:?}", lbfgsb.get_best_cost());
+        assert!(lbfgsb.get_best_cost().is_some());
+    }
+
+    #[test]
+    fn island_model() {
+        let islands: Vec<Box<dyn Island<f64>>> = vec![
+            Box::new(De::new(
+                Variant::Rnd,
+                1,
+                Strategy::Bin,
+                2000,
+                10,
+                0.8,
+                0.9,
+                problem(),
+            )),
+            Box::new(Pso::new(2000, 10, 0.5, 0.8, 0.9, problem())),
+        ];
+        let mut model = IslandModel::new(4000, 5, islands).unwrap();
+        model.run();
+        let best: f64 = model.get_best_cost().unwrap();
+        println!("best: {:?}", best);
+        assert!(best < 0f64);
+    }
+}
+
+mod mixed_integer {
+    use heuristics::benchmarks::mixed_integer::{MixedEllipsoid, MixedSphere};
+    use heuristics::evol_arg::{de::De, de::Strategy, de::Variant, pso::Pso};
+    use heuristics::solvers::hill_climber::HillClimber;
+
+    fn integral(problem: &MixedSphere, coordinates: &[f32]) -> bool {
+        problem
+            .get_variable_types()
+            .iter()
+            .zip(coordinates.iter())
+            .all(|(kind, x)| !kind.is_discrete() || x.fract() == 0f32)
+    }
+
+    #[test]
+    fn de_sphere() {
+        let problem = MixedSphere::new(3, 3, 2);
+        let mut de = De::new(
+            Variant::Rnd,
+            1,
+            Strategy::Bin,
+            3000,
+            20,
+            0.8,
+            0.9,
+            problem.clone(),
+        );
+        de.run();
+        let best = de.get_best().unwrap();
+        println!("best: {:?}", best);
+        assert!(integral(&problem, best.get_coordinates()));
+        assert!(best.get_cost() < 1f32);
+    }
+
+    #[test]
+    fn pso_sphere() {
+        let problem = MixedSphere::new(3, 3, 2);
+        let mut pso = Pso::new(3000, 20, 0.5, 0.8, 0.9, problem.clone());
+        pso.run();
+        println!("best: {:?}", pso.get_best());
+        assert!(pso
+            .get_particles()
+            .iter()
+            .all(|particle| integral(&problem, particle.get_coordinates())));
+    }
+
+    #[test]
+    fn hill_climber_ellipsoid() {
+        let mut climber = HillClimber::new(1000, 10, MixedEllipsoid::new(2, 2, 2));
+        climber.run();
+        println!("best: {:?}", climber.get_best_cost());
+        assert!(climber.get_best_cost().is_some());
+    }
+}
+
+mod permutation {
+    use heuristics::combinatorial::{
+        permutation::{is_permutation, PermutationMove},
+        qap::Qap,
+        tsp::Tsp,
+    };
+    use heuristics::problem_definitions::ProblemDomain;
+    use heuristics::solvers::{
+        hill_climber::HillClimber, local_search::LocalSearch,
+        simulated_annealing::SimulatedAnnealing, tabu_search::TabuSearch,
+    };
+    use std::fs;
+
+    // cities on a 4 x 3 grid with unit spacing, the optimal tour has length 12
+    fn tsp() -> Tsp {
+        let folder = std::env::temp_dir().join("heuristics_tsplib");
+        fs::create_dir_all(&folder).unwrap();
+        let coordinates: Vec<String> = (0..12)
+            .map(|i| format!("{} {} {}", i + 1, (i % 4) * 10, (i / 4) * 10))
+            .collect();
+        let path = folder.join("grid12.tsp");
+        fs::write(
+            &path,
+            format!(
+                "NAME : grid12\nTYPE : TSP\nDIMENSION : 12\nEDGE_WEIGHT_TYPE : EUC_2D\nNODE_COORD_SECTION\n{}\nEOF\n",
+                coordinates.join("\n")
+            ),
+        )
+        .unwrap();
+        Tsp::load(path).unwrap()
+    }
+
+    fn qap() -> Qap {
+        let content = "4\n0 1 2 3\n1 0 1 2\n2 1 0 1\n3 2 1 0\n0 5 2 4\n5 0 3 0\n2 3 0 0\n4 0 0 0\n";
+        Qap::parse(content).unwrap()
+    }
+
+    #[test]
+    fn local_search_tsp() {
+        let mut local_search = LocalSearch::new(200, tsp().set_local_move(PermutationMove::OrOpt));
+        local_search.run();
+        println!("best: {:?}", local_search.get_best_cost());
+        assert!(is_permutation(local_search.get_best_coords().unwrap()));
+    }
+
+    #[test]
+    fn hill_climber_tsp() {
+        let mut hill_climber = HillClimber::new(500, 20, tsp());
+        hill_climber.run();
+        println!("best: {:?}", hill_climber.get_best_cost());
+        assert!(is_permutation(hill_climber.get_best_coords().unwrap()));
+        assert!(hill_climber.get_best_cost().unwrap() >= 120);
+    }
+
+    #[test]
+    fn simulated_annealing_tsp() {
+        let mut annealing = SimulatedAnnealing::new(100, 100f32, 0.1f32, 0.9f32, tsp())
+            .set_neighbourhood(PermutationMove::Insertion);
+        annealing.run();
+        println!("best: {:?}", annealing.get_best_cost());
+        assert!(annealing.get_best_cost().unwrap() >= 120);
+    }
+
+    #[test]
+    fn tabu_search_tsp() {
+        let mut tabu = TabuSearch::new(500, 50, 20, tsp());
+        tabu.run();
+        println!("best: {:?}", tabu.get_best_cost());
+        assert_eq!(tabu.get_best_cost(), Some(120));
+    }
+
+    #[test]
+    fn tabu_search_qap() {
+        let problem = qap();
+        let mut tabu = TabuSearch::new(100, 10, 5, problem.clone());
+        tabu.run();
+        let best = tabu.get_best_cost().unwrap();
+        println!("best: {:?}", best);
+        // every permutation of four items
+        let optimum = (0..256)
+            .map(|code: i64| (0..4).map(|i| (code >> (2 * i)) & 3).collect::<Vec<i64>>())
+            .filter(|permutation| is_permutation(permutation))
+            .map(|permutation| problem.cost_function(&permutation))
+            .min()
+            .unwrap();
+        assert_eq!(best, optimum);
+    }
+}
+
+mod binary {
+    use heuristics::combinatorial::{
+        binary::BitMove,
+        knapsack::Knapsack,
+        max_sat::MaxSat,
+        nk_landscape::NkLandscape,
+        pseudo_boolean::{LeadingOnes, OneMax, Trap},
+    };
+    use heuristics::solvers::{
+        hill_climber::HillClimber, random_search::RandomSearch,
+        simulated_annealing::SimulatedAnnealing,
+    };
+    use std::fs;
+
+    #[test]
+    fn one_max() {
+        let mut random = RandomSearch::new(1000, OneMax::new(50));
+        random.run();
+        let mut hill_climber = HillClimber::new(1000, 50, OneMax::new(50));
+        hill_climber.run();
+        println!(
+            "best: random {:?}, hill climber {:?}",
+            random.get_best_cost(),
+            hill_climber.get_best_cost()
+        );
+        assert_eq!(hill_climber.get_best_cost(), Some(0f64));
+        assert!(random.get_best_cost().unwrap() > 0f64);
+    }
+
+    #[test]
+    fn leading_ones_and_trap() {
+        let mut hill_climber = HillClimber::new(2000, 30, LeadingOnes::new(30));
+        hill_climber.run();
+        println!("best: {:?}", hill_climber.get_best_cost());
+        assert_eq!(hill_climber.get_best_cost(), Some(0f64));
+
+        let mut annealing = SimulatedAnnealing::new(100, 5f32, 0.01f32, 0.95f32, Trap::new(5, 4))
+            .set_neighbourhood(BitMove::KFlip(2));
+        annealing.run();
+        println!("best: {:?}", annealing.get_best_cost());
+        assert!(annealing.get_best_cost().is_some());
+    }
+
+    #[test]
+    fn knapsack_file() {
+        let folder = std::env::temp_dir().join("heuristics_knapsack");
+        fs::create_dir_all(&folder).unwrap();
+        let path = folder.join("f1_l-d_kp_10_269");
+        fs::write(
+            &path,
+            "10 269\n55 95\n10 4\n47 60\n5 32\n4 23\n50 72\n8 80\n61 62\n85 65\n87 46\n",
+        )
+        .unwrap();
+        let knapsack = Knapsack::load(&path).unwrap();
+        assert_eq!(knapsack.get_name(), "f1_l-d_kp_10_269");
+        let mut hill_climber = HillClimber::new(500, 20, knapsack.set_local_move(BitMove::Uniform));
+        hill_climber.run();
+        println!("best: {:?}", hill_climber.get_best_cost());
+        assert!(hill_climber.get_best_cost().unwrap() < 0f64);
+    }
+
+    #[test]
+    fn nk_landscape() {
+        let mut random = RandomSearch::new(500, NkLandscape::new(30, 4, 42));
+        random.run();
+        let mut annealing =
+            SimulatedAnnealing::new(50, 0.1f32, 0.001f32, 0.9f32, NkLandscape::new(30, 4, 42));
+        annealing.run();
+        println!(
+            "best: random {:?}, annealing {:?}",
+            random.get_best_cost(),
+            annealing.get_best_cost()
+        );
+        assert!(annealing.get_best_cost().unwrap() < 1f64);
+    }
+
+    #[test]
+    fn max_sat_file() {
+        let folder = std::env::temp_dir().join("heuristics_dimacs");
+        fs::create_dir_all(&folder).unwrap();
+        let path = folder.join("chain.cnf");
+        // x1 = x2 = ... = x20 = true is the only satisfying assignment, the rest are plateaus
+        let clauses: Vec<String> = (1..20)
+            .map(|i| format!("-{} {} 0", i, i + 1))
+            .chain(std::iter::once(String::from("1 0")))
+            .collect();
+        fs::write(
+            &path,
+            format!("c chain\np cnf 20 20\n{}\n", clauses.join("\n")),
+        )
+        .unwrap();
+        let problem = MaxSat::load(&path).unwrap();
+        let mut hill_climber = HillClimber::new(2000, 20, problem);
+        hill_climber.run();
+        println!("best: {:?}", hill_climber.get_best_cost());
+        assert!(hill_climber.get_best_cost().unwrap() <= hill_climber.get_history()[0]);
+    }
+}
+
+mod constraints {
+    use heuristics::constraints::{total_violation, ConstraintHandling};
+    use heuristics::evol_arg::{de::De, de::Strategy, de::Variant, pso::Pso};
+    use heuristics::problem_definitions::FnProblem;
+    use heuristics::solvers::simulated_annealing::SimulatedAnnealing;
+
+    // minimum of x + y on the disk x^2 + y^2 <= 4 is -2 * sqrt(2)
+    fn problem() -> FnProblem<impl Fn(&[f64]) -> f64, f64> {
+        FnProblem::new(-5f64, 5f64, 2, |x: &[f64]| x[0] + x[1])
+            .set_inequality_constraints(|x: &[f64]| vec![x[0].powi(2) + x[1].powi(2) - 4f64])
+    }
+
+    #[test]
+    fn de_techniques() {
+        let techniques = [
+            ConstraintHandling::FeasibilityRules,
+            ConstraintHandling::EpsilonConstrained {
+                epsilon: 1f64,
+                control_generations: 100,
+                exponent: 2f64,
+            },
+            ConstraintHandling::AdaptivePenalty {
+                weight: 1f64,
+                decrease: 1.5,
+                increase: 2f64,
+                generations: 5,
+            },
+            ConstraintHandling::StochasticRanking { probability: 0.45 },
+        ];
+        for technique in techniques {
+            let mut de = De::new(
+                Variant::Rnd,
+                1,
+                Strategy::Bin,
+                4000,
+                20,
+                0.8,
+                0.9,
+                problem(),
+            )
+            .set_constraint_handling(technique);
+            de.run();
+            let best = de.get_best().unwrap();
+            println!(
+                "best: {:?} {} violation {}",
+                technique,
+                best.get_cost(),
+                best.get_violation()
+            );
+            assert!(best.get_violation() < 0.1);
+            assert!(best.get_cost() < -2f64);
+        }
+    }
+
+    #[test]
+    fn pso_static_penalty() {
+        let mut pso = Pso::new(4000, 20, 0.5, 0.8, 0.9, problem())
+            .set_constraint_handling(ConstraintHandling::StaticPenalty { weight: 100f64 });
+        pso.run();
+        println!(
+            "best: {:?} violation {}",
+            pso.get_best(),
+            pso.get_best_violation()
+        );
+        assert!(pso.get_best_violation() < 0.1);
+        assert!(pso.get_best().unwrap() < -2f64);
+    }
+
+    #[test]
+    fn annealing_epsilon() {
+        let mut annealing = SimulatedAnnealing::new(100, 1f32, 0.001, 0.9, problem())
+            .set_constraint_handling(ConstraintHandling::EpsilonConstrained {
+                epsilon: 1f64,
+                control_generations: 40,
+                exponent: 2f64,
+            });
+        annealing.run();
+        let coords = annealing.get_best_coords().unwrap();
+        println!(
+            "best: {:?} violation {}",
+            annealing.get_best_cost(),
+            total_violation(&problem(), coords)
+        );
+        assert!(annealing.get_best_violation() < 0.1);
+    }
+}
+
+mod constrained_benchmarks {
+    use heuristics::benchmarks::cec::cec2006::Cec2006;
+    use heuristics::benchmarks::double_precision::DoublePrecision;
+    use heuristics::benchmarks::engineering::{PressureVessel, ThreeBarTruss};
+    use heuristics::benchmarks::registry;
+    use heuristics::benchmarks::traits::{Benchmark, HasBuilder};
+    use heuristics::constraints::ConstraintHandling;
+    use heuristics::evol_arg::de::{De, Strategy, Variant};
+
+    #[test]
+    fn de_g06() {
+        let problem = DoublePrecision::new(Cec2006::<6>::builder().build().unwrap());
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 10000, 20, 0.8, 0.9, problem)
+            .set_constraint_handling(ConstraintHandling::FeasibilityRules);
+        de.run();
+        let best = de.get_best().unwrap();
+        println!(
+            "best: {} violation {}",
+            best.get_cost(),
+            best.get_violation()
+        );
+        assert!(best.is_feasible());
+        assert!(best.get_cost() < -6000f64);
+    }
+
+    #[test]
+    fn de_three_bar_truss() {
+        let truss = ThreeBarTruss::builder().build().unwrap();
+        let expected = truss.get_expected_min().unwrap();
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 4000, 20, 0.8, 0.9, truss);
+        de.run();
+        let best = de.get_best().unwrap();
+        println!(
+            "best: {} violation {}",
+            best.get_cost(),
+            best.get_violation()
+        );
+        assert!(best.is_feasible());
+        assert!(best.get_cost() < expected + 1f32);
+    }
+
+    #[test]
+    fn de_pressure_vessel() {
+        let problem = registry::build("pressure_vessel", 4).unwrap();
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 10000, 20, 0.8, 0.9, problem)
+            .set_constraint_handling(ConstraintHandling::EpsilonConstrained {
+                epsilon: 1f64,
+                control_generations: 200,
+                exponent: 2f64,
+            });
+        de.run();
+        let best = de.get_best().unwrap();
+        println!(
+            "best: {} at {:?} violation {}",
+            best.get_cost(),
+            best.get_coordinates(),
+            best.get_violation()
+        );
+        assert!(best.is_feasible());
+        assert!(best.get_coordinates()[..2]
+            .iter()
+            .all(|x| x.fract() == 0f32));
+        assert!(best.get_cost() < PressureVessel::get_known_min(4).unwrap() * 1.5);
+    }
+}
+
+mod nsga2 {
+    use heuristics::evol_arg::nsga2::Nsga2;
+    use heuristics::pareto::dominates;
+    use heuristics::problem_definitions::FnProblem;
+
+    #[test]
+    fn fonseca_fleming() {
+        // the pareto set is x1 = x2 = x3 in [-1 / sqrt(3), 1 / sqrt(3)]
+        let shift = 1f64 / 3f64.sqrt();
+        let problem = FnProblem::new(-4f64, 4f64, 3, |x: &[f64]| x.iter().sum()).set_objectives(
+            2,
+            move |x: &[f64]| {
+                let f1 = x.iter().map(|v| (v - shift).powi(2)).sum::<f64>();
+                let f2 = x.iter().map(|v| (v + shift).powi(2)).sum::<f64>();
+                vec![1f64 - (-f1).exp(), 1f64 - (-f2).exp()]
+            },
+        );
+        let mut nsga2 = Nsga2::new(20000, 50, problem).set_archive_capacity(100);
+        nsga2.run();
+        let front = nsga2.get_front();
+        let first = front
+            .iter()
+            .map(|s| s.get_costs()[0])
+            .fold(f64::MAX, f64::min);
+        let last = front
+            .iter()
+            .map(|s| s.get_costs()[0])
+            .fold(f64::MIN, f64::max);
+        // the projection onto the diagonal dominates every point, this is how far off it is
+        let distance = front
+            .iter()
+            .map(|s| {
+                let x = s.get_coordinates();
+                let mean = x.iter().sum::<f64>() / 3f64;
+                x.iter().map(|v| (v - mean).powi(2)).sum::<f64>()
+            })
+            .sum::<f64>()
+            / front.len() as f64;
+        println!(
+            "best: {} points spanning f1 in [{}, {}], distance {}",
+            front.len(),
+            first,
+            last,
+            distance
+        );
+        assert_eq!(front.len(), 50);
+        assert!(first < 0.05 && last > 0.9);
+        assert!(distance < 0.02);
+
+        let archive = nsga2.get_archive().get_solutions();
+        assert_eq!(archive.len(), 100);
+        for a in archive {
+            assert!(archive
+                .iter()
+                .all(|b| !dominates(b.get_costs(), a.get_costs())));
+        }
+        let history = nsga2.get_fronts_history();
+        assert_eq!(history.len(), 400);
+        assert!(history[0].len() <= history[history.len() - 1].len());
+    }
+}
+
+mod multi_objective {
+    use heuristics::benchmarks::multi_objective::{
+        dtlz::Dtlz, wfg::Wfg, zdt::Zdt, HasReferenceFront,
+    };
+    use heuristics::evol_arg::moead::Moead;
+    use heuristics::evol_arg::nsga2::Nsga2;
+    use heuristics::evol_arg::spea2::Spea2;
+    use heuristics::pareto::{front_costs, ParetoArchive, Solution};
+    use heuristics::problem_definitions::{HasObjectives, HasRandom};
+    use heuristics::tools::report::FrontReport;
+
+    // non-dominated points among as many random samples as the solver evaluates
+    fn random_front<T>(problem: &T, evaluations: usize) -> Vec<Vec<f64>>
+    where
+        T: HasObjectives<Item = f64> + HasRandom,
+    {
+        let mut archive = ParetoArchive::new(evaluations);
+        for _ in 0..evaluations {
+            let coordinates = problem.get_random();
+            let costs = problem.cost_vector(&coordinates);
+            archive.insert(Solution::new(coordinates, costs, 0f64));
+        }
+        front_costs(archive.get_solutions())
+    }
+
+    fn compare<T>(name: &str, problem: T, evaluations: i32, reference_size: usize) -> FrontReport
+    where
+        T: HasObjectives<Item = f64> + HasRandom + HasReferenceFront + Clone,
+    {
+        let mut report = FrontReport::new(name, problem.get_reference_front(reference_size));
+        report.add_front("random", &random_front(&problem, evaluations as usize));
+        let mut nsga2 = Nsga2::new(evaluations, 100, problem.clone());
+        nsga2.run();
+        let front: Vec<Solution<f64>> = nsga2.get_front().to_vec();
+        report.add_solutions("nsga2", &front);
+        // about 100 subproblems
+        let divisions = match problem.get_objectives() {
+            2 => 99,
+            _ => 13,
+        };
+        let mut moead = Moead::new(evaluations, divisions, problem.clone());
+        moead.run();
+        report.add_solutions("moead", moead.get_front());
+        let mut spea2 = Spea2::new(evaluations, 100, problem);
+        spea2.run();
+        report.add_solutions("spea2", spea2.get_front());
+        println!("best: {}", report);
+        report
+    }
+
+    #[test]
+    fn zdt1() {
+        let report = compare("ZDT1", Zdt::<1>::new(), 20000, 500);
+        let nsga2 = report.get_quality("nsga2").unwrap();
+        let random = report.get_quality("random").unwrap();
+        assert!(nsga2.get_hypervolume() > random.get_hypervolume());
+        assert!(nsga2.get_igd() < 0.05);
+        assert!(nsga2.get_igd_plus() < random.get_igd_plus());
+        for solver in ["moead", "spea2"] {
+            let quality = report.get_quality(solver).unwrap();
+            assert!(quality.get_hypervolume() > random.get_hypervolume());
+            assert!(quality.get_igd() < 0.05);
+        }
+    }
+
+    #[test]
+    fn dtlz2() {
+        let report = compare("DTLZ2", Dtlz::<2>::new(3), 20000, 300);
+        let nsga2 = report.get_quality("nsga2").unwrap();
+        let random = report.get_quality("random").unwrap();
+        assert!(nsga2.get_hypervolume() > random.get_hypervolume());
+        assert!(nsga2.get_gd() < 0.15);
+        for solver in ["moead", "spea2"] {
+            let quality = report.get_quality(solver).unwrap();
+            assert!(quality.get_hypervolume() > random.get_hypervolume());
+            assert!(quality.get_gd() < 0.15);
+        }
+    }
+
+    #[test]
+    fn wfg4() {
+        let report = compare("WFG4", Wfg::<4>::new(2), 20000, 500);
+        let nsga2 = report.get_quality("nsga2").unwrap();
+        let random = report.get_quality("random").unwrap();
+        assert!(nsga2.get_igd() < random.get_igd());
+        assert!(nsga2.get_epsilon() < random.get_epsilon());
+        for solver in ["moead", "spea2"] {
+            let quality = report.get_quality(solver).unwrap();
+            assert!(quality.get_igd() < random.get_igd());
+        }
+    }
+}
+
+mod uncertain {
+    use heuristics::benchmarks::dynamic::Dynamic;
+    use heuristics::benchmarks::fst_dejong::FstDeJong;
+    use heuristics::benchmarks::noisy::{Noise, Noisy};
+    use heuristics::benchmarks::traits::{Benchmark, HasBuilder};
+    use heuristics::evol_arg::{de::De, de::Strategy, de::Variant, pso::Pso};
+
+    #[test]
+    fn de_noisy_sphere() {
+        let sphere = FstDeJong::builder().dimensions(5).build().unwrap();
+        let problem = Noisy::new(sphere, Noise::Gaussian(0.1));
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 20000, 20, 0.8, 0.9, problem)
+            .set_resampling(4)
+            .set_reevaluation(true);
+        de.run();
+        let best = de.get_best().unwrap();
+        let sphere = FstDeJong::builder().dimensions(5).build().unwrap();
+        let cost = sphere.cost_function(best.get_coordinates());
+        println!("best: {} noiseless {}", best.get_cost(), cost);
+        assert!(cost < 0.5);
+    }
+
+    #[test]
+    fn pso_dynamic_sphere() {
+        let sphere = FstDeJong::builder().dimensions(5).build().unwrap();
+        let problem = Dynamic::new(sphere)
+            .set_period(5000)
+            .set_severity(2f32)
+            .set_seed(7);
+        let mut pso = Pso::new(20000, 30, 0.7, 1.5, 1.5, problem).set_change_detection(1e-6);
+        pso.run();
+        let changes = pso.get_detected_changes();
+        println!(
+            "best: {} changes detected at {:?}",
+            pso.get_best().unwrap(),
+            changes
+        );
+        assert_eq!(changes.len(), 3);
+        assert!(pso.get_best().unwrap() < 1f32);
+    }
+
+    #[test]
+    fn de_dynamic_sphere() {
+        let sphere = FstDeJong::builder().dimensions(5).build().unwrap();
+        let problem = Dynamic::new(sphere)
+            .set_period(5000)
+            .set_severity(2f32)
+            .set_seed(7);
+        let mut de = De::new(Variant::Rnd, 1, Strategy::Bin, 20000, 20, 0.8, 0.9, problem)
+            .set_change_detection(1e-6)
+            .set_reseeding(0.25);
+        de.run();
+        let changes = de.get_detected_changes();
+        println!(
+            "best: {} changes detected at {:?}",
+            de.get_best().unwrap().get_cost(),
+            changes
+        );
+        assert_eq!(changes.len(), 3);
+        assert!(de.get_best().unwrap().get_cost() < 1f32);
+    }
+}
+
+mod budget {
+    use heuristics::benchmarks::fst_dejong::FstDeJong;
+    use heuristics::benchmarks::traits::HasBuilder;
+    use heuristics::budget::Counted;
+    use heuristics::evol_arg::de::{De, Strategy, Variant};
+    use heuristics::problem_definitions::ProblemDomain;
+    use heuristics::solvers::hill_climber::HillClimber;
+    use heuristics::solvers::random_search::RandomSearch;
+    use heuristics::solvers::simulated_annealing::SimulatedAnnealing;
+    use heuristics::solvers::traits::Restartable;
+
+    // every solver asks for more than the budget and stops once it is exhausted
+    fn problem() -> Counted<FstDeJong> {
+        let sphere = FstDeJong::builder().dimensions(5).build().unwrap();
+        Counted::new(sphere).set_budget(3000).set_best_history(true)
+    }
+
+    fn check(name: &str, evaluations: i32, problem: &Counted<FstDeJong>) -> f32 {
+        let history = problem.get_best_history();
+        let best = problem.get_best_cost().unwrap();
+        println!(
+            "best: {} {} after {} evaluations, {} improvements",
+            name,
+            best,
+            problem.get_evaluations(),
+            problem.get_improvements().len()
+        );
+        assert_eq!(evaluations as usize, problem.get_evaluations());
+        assert!(problem.get_evaluations() <= 3000);
+        assert!(problem.is_exhausted());
+        assert_eq!(problem.get_refused(), 0);
+        assert_eq!(history.len(), problem.get_evaluations());
+        assert!(history.windows(2).all(|w| w[1] <= w[0]));
+        best
+    }
+
+    #[test]
+    fn equal_budget() {
+        let mut random = RandomSearch::new(5000, problem());
+        random.run();
+        let random_best = check(
+            "random search",
+            random.get_cost_function_evaluations(),
+            random.get_problem(),
+        );
+
+        let mut sa = SimulatedAnnealing::new(10, 1000f32, 0.1, 0.98, problem());
+        sa.run();
+        check(
+            "simulated annealing",
+            sa.get_cost_function_evaluations(),
+            sa.get_problem(),
+        );
+
+        let mut hill_climber = HillClimber::new(1000, 10, problem());
+        hill_climber.run();
+        check(
+            "hill climber",
+            hill_climber.get_cost_function_evaluations(),
+            hill_climber.get_problem(),
+        );
+
+        let mut de = De::new(
+            Variant::Rnd,
+            1,
+            Strategy::Bin,
+            5000,
+            20,
+            0.8,
+            0.9,
+            problem(),
+        );
+        de.run();
+        let de_best = check("de", de.get_cost_function_evaluations(), de.get_problem());
+        assert!(de_best < random_best);
+    }
+}